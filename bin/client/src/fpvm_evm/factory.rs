@@ -3,6 +3,8 @@
 use super::precompiles::OpFpvmPrecompiles;
 use alloy_evm::{Database, EvmEnv, EvmFactory};
 use alloy_op_evm::OpEvm;
+use alloy_primitives::Address;
+use kona_executor::PrecompileOverrideFactory;
 use kona_preimage::{Channel, HintWriter, OracleReader};
 use op_revm::{
     DefaultOp, OpContext, OpEvm as RevmOpEvm, OpHaltReason, OpSpecId, OpTransaction,
@@ -13,6 +15,8 @@ use revm::{
     context::{Evm as RevmEvm, EvmData, TxEnv, result::EVMError},
     handler::instructions::EthInstructions,
     inspector::NoOpInspector,
+    precompile::PrecompileFn,
+    primitives::hash_map::HashMap,
 };
 
 /// Factory producing [`OpEvm`]s with FPVM-accelerated precompile overrides enabled.
@@ -22,6 +26,8 @@ pub struct FpvmOpEvmFactory<C: Channel + Clone> {
     hint_writer: HintWriter<C>,
     /// The oracle reader.
     oracle_reader: OracleReader<C>,
+    /// The precompile overrides, keyed by address.
+    precompile_overrides: HashMap<Address, PrecompileFn>,
 }
 
 impl<C> FpvmOpEvmFactory<C>
@@ -30,7 +36,7 @@ where
 {
     /// Creates a new [`FpvmOpEvmFactory`].
     pub fn new(hint_writer: HintWriter<C>, oracle_reader: OracleReader<C>) -> Self {
-        Self { hint_writer, oracle_reader }
+        Self { hint_writer, oracle_reader, precompile_overrides: HashMap::default() }
     }
 
     /// Returns a reference to the inner [`HintWriter`].
@@ -44,6 +50,21 @@ where
     }
 }
 
+impl<C> PrecompileOverrideFactory for FpvmOpEvmFactory<C>
+where
+    C: Channel + Clone + Send + Sync + 'static,
+{
+    /// Sets the precompile overrides for all [`OpEvm`]s created by the factory. An override takes
+    /// priority over both the FPVM-accelerated and canonical precompile at its address.
+    fn with_precompile_overrides(
+        mut self,
+        precompile_overrides: HashMap<Address, PrecompileFn>,
+    ) -> Self {
+        self.precompile_overrides = precompile_overrides;
+        self
+    }
+}
+
 impl<C> EvmFactory for FpvmOpEvmFactory<C>
 where
     C: Channel + Clone + Send + Sync + 'static,
@@ -70,7 +91,8 @@ where
                 spec_id,
                 self.hint_writer.clone(),
                 self.oracle_reader.clone(),
-            ),
+            )
+            .with_overrides(self.precompile_overrides.clone()),
         });
 
         OpEvm::new(revm_evm, false)
@@ -91,9 +113,50 @@ where
                 spec_id,
                 self.hint_writer.clone(),
                 self.oracle_reader.clone(),
-            ),
+            )
+            .with_overrides(self.precompile_overrides.clone()),
         });
 
         OpEvm::new(revm_evm, true)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::fpvm_evm::precompiles::test_utils::test_accelerated_precompile;
+    use alloy_evm::Evm;
+    use alloy_primitives::Bytes;
+    use revm::{
+        database_interface::EmptyDB,
+        precompile::{PrecompileOutput, PrecompileResult, u64_to_address},
+    };
+
+    /// An identity precompile override that returns its input reversed.
+    fn reversed_identity(input: &Bytes, _gas_limit: u64) -> PrecompileResult {
+        Ok(PrecompileOutput::new(1, input.iter().rev().copied().collect()))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_precompile_override() {
+        test_accelerated_precompile(|hint_writer, oracle_reader| {
+            let identity = u64_to_address(4);
+            let input = Bytes::from_static(&[1, 2, 3]);
+            let factory = FpvmOpEvmFactory::new(hint_writer.clone(), oracle_reader.clone());
+
+            // Without an override, the canonical identity precompile is invoked.
+            let mut evm = factory.create_evm(EmptyDB::default(), EvmEnv::default());
+            let result = evm.transact_system_call(Address::ZERO, identity, input.clone()).unwrap();
+            assert_eq!(result.result.output(), Some(&input));
+
+            // With an override, the override is invoked instead.
+            let factory = factory.with_precompile_overrides(
+                [(identity, reversed_identity as PrecompileFn)].into_iter().collect(),
+            );
+            let mut evm = factory.create_evm(EmptyDB::default(), EvmEnv::default());
+            let result = evm.transact_system_call(Address::ZERO, identity, input).unwrap();
+            assert_eq!(result.result.output(), Some(&Bytes::from_static(&[3, 2, 1])));
+        })
+        .await;
+    }
+}
@@ -19,4 +19,4 @@ mod kzg_point_eval;
 mod utils;
 
 #[cfg(test)]
-mod test_utils;
+pub(crate) mod test_utils;
@@ -3,7 +3,12 @@
 use crate::fpvm_evm::precompiles::{
     ecrecover::ECRECOVER_ADDR, kzg_point_eval::KZG_POINT_EVAL_ADDR,
 };
-use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 use alloy_primitives::{Address, Bytes};
 use kona_preimage::{Channel, HintWriter, OracleReader};
 use op_revm::{
@@ -14,7 +19,9 @@ use revm::{
     context::{Cfg, ContextTr},
     handler::{EthPrecompiles, PrecompileProvider},
     interpreter::{Gas, InputsImpl, InstructionResult, InterpreterResult},
-    precompile::{PrecompileError, PrecompileResult, Precompiles, bls12_381_const, bn128},
+    precompile::{
+        PrecompileError, PrecompileFn, PrecompileResult, Precompiles, bls12_381_const, bn128,
+    },
     primitives::{hardfork::SpecId, hash_map::HashMap},
 };
 
@@ -25,6 +32,9 @@ pub struct OpFpvmPrecompiles<C: Channel> {
     inner: EthPrecompiles,
     /// The accelerated precompiles for the current [`OpSpecId`].
     accelerated_precompiles: HashMap<Address, AcceleratedPrecompileFn<C>>,
+    /// The precompile overrides, which take priority over both the accelerated and canonical
+    /// precompiles.
+    overrides: HashMap<Address, PrecompileFn>,
     /// The [`OpSpecId`] of the precompiles.
     spec: OpSpecId,
     /// The inner [`HintWriter`].
@@ -64,11 +74,57 @@ impl<C: Channel + Send + Sync> OpFpvmPrecompiles<C> {
                 .into_iter()
                 .map(|p| (p.address, p.precompile))
                 .collect(),
+            overrides: HashMap::default(),
             spec,
             hint_writer,
             oracle_reader,
         }
     }
+
+    /// Sets the precompile overrides. An override takes priority over both the accelerated and
+    /// canonical precompile at its [`Address`], and persists across spec changes.
+    pub fn with_overrides(mut self, overrides: HashMap<Address, PrecompileFn>) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// Runs the precompile at the given [`Address`], returning `None` if it does not exist.
+    ///
+    /// In debug builds, the gas charged by an accelerated precompile is validated against the
+    /// canonical precompile. An accelerator that is correct on output but wrong on gas causes
+    /// execution to diverge from consensus, which is surfaced as a [`PrecompileGasMismatch`].
+    fn run_precompile(
+        &self,
+        address: &Address,
+        input: &Bytes,
+        gas_limit: u64,
+    ) -> Result<Option<PrecompileResult>, PrecompileGasMismatch> {
+        // Priority:
+        // 1. If the precompile is overridden, use the override.
+        // 2. If the precompile has an accelerated version, use that.
+        // 3. If the precompile is not accelerated, use the default version.
+        // 4. If the precompile is not found, return None.
+        if let Some(precompile) = self.overrides.get(address) {
+            Ok(Some((*precompile)(input, gas_limit)))
+        } else if let Some(accelerated) = self.accelerated_precompiles.get(address) {
+            let output = (accelerated)(input, gas_limit, &self.hint_writer, &self.oracle_reader);
+
+            #[cfg(debug_assertions)]
+            if let Some(canonical) = self.inner.precompiles.get(address) {
+                let expected = charged_gas(&(*canonical)(input, gas_limit), gas_limit);
+                let actual = charged_gas(&output, gas_limit);
+                if expected != actual {
+                    return Err(PrecompileGasMismatch { address: *address, expected, actual });
+                }
+            }
+
+            Ok(Some(output))
+        } else if let Some(precompile) = self.inner.precompiles.get(address) {
+            Ok(Some((*precompile)(input, gas_limit)))
+        } else {
+            Ok(None)
+        }
+    }
 }
 
 impl<CTX, C> PrecompileProvider<CTX> for OpFpvmPrecompiles<C>
@@ -83,7 +139,9 @@ where
         if spec == self.spec {
             return false;
         }
-        *self = Self::new_with_spec(spec, self.hint_writer.clone(), self.oracle_reader.clone());
+        let overrides = core::mem::take(&mut self.overrides);
+        *self = Self::new_with_spec(spec, self.hint_writer.clone(), self.oracle_reader.clone())
+            .with_overrides(overrides);
         true
     }
 
@@ -102,15 +160,9 @@ where
             output: Bytes::new(),
         };
 
-        // Priority:
-        // 1. If the precompile has an accelerated version, use that.
-        // 2. If the precompile is not accelerated, use the default version.
-        // 3. If the precompile is not found, return None.
-        let output = if let Some(accelerated) = self.accelerated_precompiles.get(address) {
-            (accelerated)(&inputs.input, gas_limit, &self.hint_writer, &self.oracle_reader)
-        } else if let Some(precompile) = self.inner.precompiles.get(address) {
-            (*precompile)(&inputs.input, gas_limit)
-        } else {
+        let Some(output) =
+            self.run_precompile(address, &inputs.input, gas_limit).map_err(|e| e.to_string())?
+        else {
             return Ok(None);
         };
 
@@ -136,15 +188,37 @@ where
 
     #[inline]
     fn warm_addresses(&self) -> Box<impl Iterator<Item = Address>> {
-        self.inner.warm_addresses()
+        // Overrides at addresses without a canonical precompile are warmed as well.
+        let overrides =
+            self.overrides.keys().filter(|address| !self.inner.contains(address)).copied();
+        Box::new(self.inner.warm_addresses().chain(overrides))
     }
 
     #[inline]
     fn contains(&self, address: &Address) -> bool {
-        self.inner.contains(address)
+        self.overrides.contains_key(address) || self.inner.contains(address)
     }
 }
 
+/// An accelerated precompile charged a different amount of gas than the canonical precompile.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+#[error("Precompile {address} charged {actual} gas, expected {expected}")]
+#[cfg_attr(not(debug_assertions), allow(dead_code))]
+pub(crate) struct PrecompileGasMismatch {
+    /// The address of the precompile.
+    pub(crate) address: Address,
+    /// The gas charged by the canonical precompile.
+    pub(crate) expected: u64,
+    /// The gas charged by the accelerated precompile.
+    pub(crate) actual: u64,
+}
+
+/// Returns the gas charged for a precompile call. Failed calls consume the entire gas limit.
+#[cfg(debug_assertions)]
+fn charged_gas(result: &PrecompileResult, gas_limit: u64) -> u64 {
+    result.as_ref().map_or(gas_limit, |output| output.gas_used)
+}
+
 /// A precompile function that can be accelerated by the FPVM.
 type AcceleratedPrecompileFn<C> =
     fn(&[u8], u64, &HintWriter<C>, &OracleReader<C>) -> PrecompileResult;
@@ -225,3 +299,87 @@ fn accelerated_isthmus<C: Channel + Send + Sync>() -> Vec<AcceleratedPrecompile<
     ));
     base
 }
+
+#[cfg(all(test, debug_assertions))]
+mod test {
+    use super::*;
+    use crate::fpvm_evm::precompiles::test_utils::test_accelerated_precompile;
+    use alloy_primitives::hex;
+    use op_revm::OpContext;
+    use revm::{database_interface::EmptyDB, precompile::PrecompileOutput};
+
+    /// An `ecrecover` accelerator that returns no output, charging less gas than the canonical
+    /// precompile.
+    fn wrong_gas_ec_recover<C: Channel + Send + Sync>(
+        _input: &[u8],
+        _gas_limit: u64,
+        _hint_writer: &HintWriter<C>,
+        _oracle_reader: &OracleReader<C>,
+    ) -> PrecompileResult {
+        Ok(PrecompileOutput::new(2_000, Bytes::new()))
+    }
+
+    /// A precompile override that returns no output.
+    fn noop_precompile(_input: &Bytes, _gas_limit: u64) -> PrecompileResult {
+        Ok(PrecompileOutput::new(0, Bytes::new()))
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_precompile_gas_mismatch() {
+        test_accelerated_precompile(|hint_writer, oracle_reader| {
+            let mut precompiles = OpFpvmPrecompiles::new_with_spec(
+                OpSpecId::ISTHMUS,
+                hint_writer.clone(),
+                oracle_reader.clone(),
+            );
+            let input = Bytes::from_static(&hex!(
+                "456e9aea5e197a1f1af7a3e85a3212fa4049a3ba34c2289b4c860fc0b0c64ef3000000000000000000000000000000000000000000000000000000000000001c9242685bf161793cc25603c231bc2f568eb630ea16aa137d2664ac80388256084f8ae3bd7535248d0bd448298cc2e2071e56992d0774dc340c368ae950852ada"
+            ));
+
+            // The registered accelerator charges the same gas as the canonical precompile.
+            let output = precompiles.run_precompile(&ECRECOVER_ADDR, &input, u64::MAX).unwrap();
+            assert_eq!(output.unwrap().unwrap().gas_used, 3_000);
+
+            // A deliberately wrong accelerator is caught, even though it succeeds.
+            precompiles.accelerated_precompiles.insert(ECRECOVER_ADDR, wrong_gas_ec_recover);
+            let err = precompiles.run_precompile(&ECRECOVER_ADDR, &input, u64::MAX).unwrap_err();
+            assert_eq!(
+                err,
+                PrecompileGasMismatch { address: ECRECOVER_ADDR, expected: 3_000, actual: 2_000 }
+            );
+
+            // Precompiles without an accelerator are not validated.
+            let identity = revm::precompile::u64_to_address(4);
+            assert!(precompiles.run_precompile(&identity, &input, u64::MAX).unwrap().is_some());
+        })
+        .await;
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_warm_addresses_include_overrides() {
+        test_accelerated_precompile(|hint_writer, oracle_reader| {
+            let identity = revm::precompile::u64_to_address(4);
+            let custom = Address::repeat_byte(0xFF);
+            let overrides: HashMap<Address, PrecompileFn> = [identity, custom]
+                .into_iter()
+                .map(|address| (address, noop_precompile as PrecompileFn))
+                .collect();
+            let precompiles = OpFpvmPrecompiles::new_with_spec(
+                OpSpecId::ISTHMUS,
+                hint_writer.clone(),
+                oracle_reader.clone(),
+            )
+            .with_overrides(overrides);
+
+            let warm =
+                <OpFpvmPrecompiles<_> as PrecompileProvider<OpContext<EmptyDB>>>::warm_addresses(
+                    &precompiles,
+                )
+                .collect::<Vec<_>>();
+            assert_eq!(warm.iter().filter(|address| **address == identity).count(), 1);
+            assert!(warm.contains(&custom));
+            assert!(warm.contains(&ECRECOVER_ADDR));
+        })
+        .await;
+    }
+}
@@ -3,15 +3,18 @@
 use super::FaultProofProgramError;
 use crate::interop::util::fetch_output_block_hash;
 use alloc::sync::Arc;
+use alloy_primitives::B256;
 use core::fmt::Debug;
 use kona_executor::TrieDBProvider;
+use kona_interop::MessageGraphError;
 use kona_preimage::{HintWriterClient, PreimageOracleClient};
 use kona_proof::{CachingOracle, l2::OracleL2ChainProvider};
 use kona_proof_interop::{
-    BootInfo, HintType, OracleInteropProvider, PreState, SuperchainConsolidator,
+    BootInfo, ConsolidationError, HintType, INVALID_TRANSITION_HASH, OracleInteropProvider,
+    PreState, SuperchainConsolidator,
 };
 use kona_registry::{HashMap, ROLLUP_CONFIGS};
-use tracing::info;
+use tracing::{info, warn};
 
 /// Executes the consolidation phase of the interop proof with the given [PreimageOracleClient] and
 /// [HintWriterClient].
@@ -100,7 +103,11 @@ where
 
     // Consolidate the superchain
     let global_provider = OracleInteropProvider::new(oracle.clone(), boot.clone(), headers);
-    SuperchainConsolidator::new(&mut boot, global_provider, l2_providers).consolidate().await?;
+    if let Err(e) =
+        SuperchainConsolidator::new(&mut boot, global_provider, l2_providers).consolidate().await
+    {
+        return route_consolidation_error(e, boot.claimed_post_state);
+    }
 
     // Transition to the Super Root at the next timestamp.
     let post = boot
@@ -116,3 +123,107 @@ where
 
     Ok(())
 }
+
+/// Routes a [ConsolidationError] to the appropriate program outcome, as classified by
+/// [ConsolidationError::is_fatal].
+///
+/// Errors that indicate a fault in the claimed transition, as classified by
+/// [ConsolidationError::is_claim_fault], cause the superchain to transition to the
+/// [INVALID_TRANSITION_HASH], and the claim is checked against it. All other fatal errors are
+/// returned as-is. Recoverable errors, which indicate missing witness data, are logged as such,
+/// and surface as an [OracleProviderError] where possible.
+///
+/// [OracleProviderError]: kona_proof::errors::OracleProviderError
+fn route_consolidation_error(
+    err: ConsolidationError,
+    claimed_post_state: B256,
+) -> Result<(), FaultProofProgramError> {
+    if err.is_claim_fault() {
+        warn!(
+            target: "client_interop",
+            "Consolidation found invalid dependencies: {err}; Transitioning to invalid state."
+        );
+
+        return (claimed_post_state == INVALID_TRANSITION_HASH).then_some(()).ok_or(
+            FaultProofProgramError::InvalidClaim(INVALID_TRANSITION_HASH, claimed_post_state),
+        );
+    }
+
+    if err.is_fatal() {
+        return Err(FaultProofProgramError::Consolidation(err));
+    }
+
+    warn!(target: "client_interop", "Consolidation failed to fetch witness data: {err}");
+    match err {
+        ConsolidationError::OracleProvider(e) |
+        ConsolidationError::MessageGraph(MessageGraphError::InteropProviderError(e)) => {
+            Err(FaultProofProgramError::OracleProvider(e))
+        }
+        e => Err(FaultProofProgramError::Consolidation(e)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use kona_preimage::errors::PreimageOracleError;
+    use kona_proof::errors::OracleProviderError;
+
+    fn missing_preimage() -> OracleProviderError {
+        OracleProviderError::Preimage(PreimageOracleError::KeyNotFound)
+    }
+
+    #[test]
+    fn test_route_claim_fault() {
+        let fault =
+            || ConsolidationError::MessageGraph(MessageGraphError::InvalidMessages(vec![10]));
+
+        // A claim of the invalid transition hash is valid when the dependencies are invalid.
+        assert!(route_consolidation_error(fault(), INVALID_TRANSITION_HASH).is_ok());
+
+        // Any other claim is invalid.
+        let claimed = B256::repeat_byte(0xFF);
+        assert!(matches!(
+            route_consolidation_error(fault(), claimed),
+            Err(FaultProofProgramError::InvalidClaim(expected, actual))
+                if expected == INVALID_TRANSITION_HASH && actual == claimed
+        ));
+    }
+
+    #[test]
+    fn test_route_witness_errors() {
+        let errors = [
+            ConsolidationError::OracleProvider(missing_preimage()),
+            ConsolidationError::MessageGraph(MessageGraphError::InteropProviderError(
+                missing_preimage(),
+            )),
+        ];
+        for err in errors {
+            assert!(!err.is_fatal());
+            assert!(matches!(
+                route_consolidation_error(err, INVALID_TRANSITION_HASH),
+                Err(FaultProofProgramError::OracleProvider(OracleProviderError::Preimage(
+                    PreimageOracleError::KeyNotFound
+                )))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_route_fatal_errors() {
+        let errors = [
+            ConsolidationError::InvalidPreStateVariant,
+            ConsolidationError::MissingRollupConfig(10),
+            ConsolidationError::MissingLocalProvider(10),
+            ConsolidationError::MessageGraph(MessageGraphError::EmptyDependencySet),
+        ];
+        for err in errors {
+            assert!(err.is_fatal());
+            assert!(matches!(
+                route_consolidation_error(err, INVALID_TRANSITION_HASH),
+                Err(FaultProofProgramError::Consolidation(_))
+            ));
+        }
+    }
+}
@@ -3,14 +3,14 @@
 use alloc::sync::Arc;
 use alloy_primitives::B256;
 use consolidate::consolidate_dependencies;
-use core::{cmp::Ordering, fmt::Debug};
+use core::fmt::Debug;
 use kona_derive::errors::PipelineErrorKind;
 use kona_driver::DriverError;
 use kona_executor::ExecutorError;
 use kona_preimage::{HintWriterClient, PreimageOracleClient};
 use kona_proof::{CachingOracle, errors::OracleProviderError};
 use kona_proof_interop::{
-    BootInfo, ConsolidationError, PreState, TRANSITION_STATE_MAX_STEPS, boot::BootstrapError,
+    BootInfo, ConsolidationError, SubProblem, TRANSITION_STATE_MAX_STEPS, boot::BootstrapError,
 };
 use thiserror::Error;
 use tracing::{error, info};
@@ -73,51 +73,36 @@ where
         }
     };
 
-    // Load in the agreed pre-state from the preimage oracle in order to determine the active
-    // sub-problem.
-    match boot.agreed_pre_state {
-        PreState::SuperRoot(ref super_root) => {
-            // If the claimed L2 block timestamp is less than the super root timestamp, the
-            // post-state muust be the agreed pre-state to accommodate trace extension.
-            if super_root.timestamp >= boot.claimed_l2_timestamp {
-                if boot.agreed_pre_state_commitment == boot.claimed_post_state {
-                    return Ok(());
-                } else {
-                    return Err(FaultProofProgramError::InvalidClaim(
-                        boot.agreed_pre_state_commitment,
-                        boot.claimed_post_state,
-                    ));
-                }
-            }
-
-            // If the pre-state is a super root, the first sub-problem is always selected.
-            sub_transition(oracle, boot).await
-        }
-        PreState::TransitionState(ref transition_state) => {
-            // If the claimed L2 block timestamp is less than the prestate timestamp, the
-            // the claim must be invalid.
-            if transition_state.pre_state.timestamp >= boot.claimed_l2_timestamp {
-                return Err(FaultProofProgramError::InvalidClaim(
+    // Select the active sub-problem from the agreed pre-state.
+    match boot.agreed_pre_state.sub_problem(boot.claimed_l2_timestamp) {
+        SubProblem::TraceExtension => {
+            // If the claimed L2 block timestamp is not past the super root timestamp, the
+            // post-state must be the agreed pre-state to accommodate trace extension.
+            if boot.agreed_pre_state_commitment == boot.claimed_post_state {
+                Ok(())
+            } else {
+                Err(FaultProofProgramError::InvalidClaim(
                     boot.agreed_pre_state_commitment,
                     boot.claimed_post_state,
-                ));
-            }
-
-            // If the pre-state is a transition state, the sub-problem is selected based on the
-            // current step.
-            match transition_state.step.cmp(&TRANSITION_STATE_MAX_STEPS) {
-                Ordering::Equal => consolidate_dependencies(oracle, boot).await,
-                Ordering::Less => sub_transition(oracle, boot).await,
-                Ordering::Greater => {
-                    error!(
-                        target: "client_interop",
-                        transition_state_step = transition_state.step,
-                        transition_state_max_steps = TRANSITION_STATE_MAX_STEPS,
-                        "Invalid transition state step; 'step' is greater than maximum value."
-                    );
-                    Err(FaultProofProgramError::StateTransitionFailed)
-                }
+                ))
             }
         }
+        SubProblem::InvalidTransition => Err(FaultProofProgramError::InvalidClaim(
+            boot.agreed_pre_state_commitment,
+            boot.claimed_post_state,
+        )),
+        // The sub-problem targets the chain indexed by the pre-state's active step, in chain ID
+        // order.
+        SubProblem::Transition => sub_transition(oracle, boot).await,
+        SubProblem::Consolidation => consolidate_dependencies(oracle, boot).await,
+        SubProblem::InvalidStep => {
+            error!(
+                target: "client_interop",
+                transition_state_step = boot.agreed_pre_state.active_step(),
+                transition_state_max_steps = TRANSITION_STATE_MAX_STEPS,
+                "Invalid transition state step; 'step' is greater than maximum value."
+            );
+            Err(FaultProofProgramError::StateTransitionFailed)
+        }
     }
 }
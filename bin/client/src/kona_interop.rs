@@ -20,12 +20,6 @@ static ORACLE_READER_PIPE: FileChannel =
 static HINT_WRITER_PIPE: FileChannel =
     FileChannel::new(FileDescriptor::HintRead, FileDescriptor::HintWrite);
 
-/// The global preimage oracle reader.
-static ORACLE_READER: OracleReader<FileChannel> = OracleReader::new(ORACLE_READER_PIPE);
-
-/// The global hint writer.
-static HINT_WRITER: HintWriter<FileChannel> = HintWriter::new(HINT_WRITER_PIPE);
-
 #[client_entry(100_000_000)]
 fn main() -> Result<(), String> {
     #[cfg(feature = "client-tracing")]
@@ -37,5 +31,9 @@ fn main() -> Result<(), String> {
             .expect("Failed to set tracing subscriber");
     }
 
-    kona_proof::block_on(kona_client::interop::run(ORACLE_READER, HINT_WRITER))
+    // The preimage oracle reader and hint writer share their poisoned state across clones.
+    let oracle_reader = OracleReader::new(ORACLE_READER_PIPE);
+    let hint_writer = HintWriter::new(HINT_WRITER_PIPE);
+
+    kona_proof::block_on(kona_client::interop::run(oracle_reader, hint_writer))
 }
@@ -37,6 +37,10 @@ where
         let kv_store = self.inner.read().await;
         kv_store.get(key.into()).ok_or(PreimageOracleError::KeyNotFound)
     }
+
+    async fn contains(&self, key: PreimageKey) -> bool {
+        self.inner.read().await.contains(key.into())
+    }
 }
 
 #[async_trait]
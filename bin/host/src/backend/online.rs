@@ -8,7 +8,12 @@ use kona_preimage::{
     errors::{PreimageOracleError, PreimageOracleResult},
 };
 use kona_proof::{Hint, errors::HintParsingError};
-use std::{collections::HashSet, hash::Hash, str::FromStr, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+    str::FromStr,
+    sync::Arc,
+};
 use tokio::sync::RwLock;
 use tracing::{debug, error, trace};
 
@@ -54,6 +59,8 @@ where
     kv: SharedKeyValueStore,
     /// The providers that are used to fetch data in response to hints.
     providers: C::Providers,
+    /// Providers that override the default providers for specific hint types.
+    provider_overrides: HashMap<C::HintType, C::Providers>,
     /// Hints that should be immediately executed by the host.
     proactive_hints: HashSet<C::HintType>,
     /// The last hint that was received.
@@ -74,6 +81,7 @@ where
             cfg,
             kv,
             providers,
+            provider_overrides: HashMap::default(),
             proactive_hints: HashSet::default(),
             last_hint: Arc::new(RwLock::new(None)),
             _hint_handler: std::marker::PhantomData,
@@ -85,6 +93,22 @@ where
         self.proactive_hints.insert(hint_type);
         self
     }
+
+    /// Overrides the providers used to fetch data in response to hints of the given type, e.g. to
+    /// serve receipts from an archive node while block headers are served by a faster node.
+    pub fn with_provider_override(
+        mut self,
+        hint_type: C::HintType,
+        providers: C::Providers,
+    ) -> Self {
+        self.provider_overrides.insert(hint_type, providers);
+        self
+    }
+
+    /// Returns the providers used to fetch data in response to hints of the given type.
+    fn providers_for(&self, hint_type: &C::HintType) -> &C::Providers {
+        self.provider_overrides.get(hint_type).unwrap_or(&self.providers)
+    }
 }
 
 #[async_trait]
@@ -101,7 +125,8 @@ where
             hint.parse::<Hint<C::HintType>>().map_err(|_| PreimageOracleError::KeyNotFound)?;
         if self.proactive_hints.contains(&parsed_hint.ty) {
             debug!(target: "host_backend", "Proactive hint received; Immediately fetching {hint}");
-            H::fetch_hint(parsed_hint, &self.cfg, &self.providers, self.kv.clone())
+            let providers = self.providers_for(&parsed_hint.ty);
+            H::fetch_hint(parsed_hint, &self.cfg, providers, self.kv.clone())
                 .await
                 .map_err(|e| PreimageOracleError::Other(e.to_string()))?;
         } else {
@@ -133,8 +158,9 @@ where
         // Use a loop to keep retrying the prefetch as long as the key is not found
         while preimage.is_none() {
             if let Some(hint) = self.last_hint.read().await.as_ref() {
+                let providers = self.providers_for(&hint.ty);
                 let value =
-                    H::fetch_hint(hint.clone(), &self.cfg, &self.providers, self.kv.clone()).await;
+                    H::fetch_hint(hint.clone(), &self.cfg, providers, self.kv.clone()).await;
 
                 if let Err(e) = value {
                     error!(target: "host_backend", "Failed to prefetch hint: {e}");
@@ -149,3 +175,67 @@ where
         preimage.ok_or(PreimageOracleError::KeyNotFound)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MemoryKeyValueStore;
+    use alloy_primitives::{B256, keccak256};
+    use kona_preimage::PreimageKeyType;
+    use kona_proof::HintType;
+    use std::sync::Mutex;
+
+    /// Providers that record the types of the hints they served.
+    #[derive(Debug, Default)]
+    struct RecordingProviders {
+        served: Mutex<Vec<HintType>>,
+    }
+
+    struct TestCfg;
+
+    impl OnlineHostBackendCfg for TestCfg {
+        type HintType = HintType;
+        type Providers = Arc<RecordingProviders>;
+    }
+
+    struct TestHintHandler;
+
+    #[async_trait]
+    impl HintHandler for TestHintHandler {
+        type Cfg = TestCfg;
+
+        async fn fetch_hint(
+            hint: Hint<HintType>,
+            _: &TestCfg,
+            providers: &Arc<RecordingProviders>,
+            kv: SharedKeyValueStore,
+        ) -> Result<()> {
+            providers.served.lock().unwrap().push(hint.ty);
+            let key = PreimageKey::new(*keccak256(&hint.data), PreimageKeyType::Keccak256);
+            kv.write().await.set(key.into(), hint.data.to_vec())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_hint_routed_to_provider_override() {
+        let default = Arc::new(RecordingProviders::default());
+        let archive = Arc::new(RecordingProviders::default());
+        let kv = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+        let backend = OnlineHostBackend::new(TestCfg, kv, default.clone(), TestHintHandler)
+            .with_provider_override(HintType::L1Receipts, archive.clone())
+            .with_proactive_hint(HintType::L1BlockHeader);
+
+        // Receipts are fetched from the overriding providers when the preimage is requested.
+        let hash = B256::repeat_byte(0x01);
+        backend.route_hint(format!("l1-receipts {hash}")).await.unwrap();
+        let key = PreimageKey::new(*keccak256(hash), PreimageKeyType::Keccak256);
+        assert_eq!(backend.get_preimage(key).await.unwrap(), hash.to_vec());
+
+        // Block headers are proactively fetched from the default providers.
+        let hash = B256::repeat_byte(0x02);
+        backend.route_hint(format!("l1-block-header {hash}")).await.unwrap();
+
+        assert_eq!(*archive.served.lock().unwrap(), [HintType::L1Receipts]);
+        assert_eq!(*default.served.lock().unwrap(), [HintType::L1BlockHeader]);
+    }
+}
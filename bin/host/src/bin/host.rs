@@ -6,8 +6,12 @@
 
 use anyhow::Result;
 use clap::{ArgAction, Parser, Subcommand};
-use kona_cli::{cli_styles, init_tracing_subscriber};
+use kona_cli::{
+    LogFormat, cli_parsers::parse_log_format, cli_styles, init_prometheus_server,
+    init_tracing_subscriber,
+};
 use serde::Serialize;
+use std::net::IpAddr;
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
@@ -28,6 +32,29 @@ pub struct HostCli {
     /// By default, the verbosity level is set to 3 (info level).
     #[arg(long, short, default_value = "3", action = ArgAction::Count)]
     pub v: u8,
+    /// The format of the printed logs.
+    #[arg(long, value_parser = parse_log_format, default_value = "full")]
+    #[serde(skip)]
+    pub log_format: LogFormat,
+    /// Whether to serve Prometheus metrics, such as the preimage server's request counters.
+    #[arg(long = "metrics.enabled", global = true, env = "KONA_HOST_METRICS_ENABLED")]
+    pub metrics_enabled: bool,
+    /// The port to serve Prometheus metrics on.
+    #[arg(
+        long = "metrics.port",
+        global = true,
+        default_value = "9090",
+        env = "KONA_HOST_METRICS_PORT"
+    )]
+    pub metrics_port: u16,
+    /// The IP address to serve Prometheus metrics on.
+    #[arg(
+        long = "metrics.addr",
+        global = true,
+        default_value = "0.0.0.0",
+        env = "KONA_HOST_METRICS_ADDR"
+    )]
+    pub metrics_addr: IpAddr,
     /// Host mode
     #[command(subcommand)]
     pub mode: HostMode,
@@ -48,7 +75,10 @@ pub enum HostMode {
 #[tokio::main(flavor = "multi_thread")]
 async fn main() -> Result<()> {
     let cfg = HostCli::parse();
-    init_tracing_subscriber(cfg.v, None::<EnvFilter>)?;
+    init_tracing_subscriber(cfg.v, cfg.log_format, None::<EnvFilter>)?;
+    if cfg.metrics_enabled {
+        init_prometheus_server(cfg.metrics_addr, cfg.metrics_port)?;
+    }
 
     match cfg.mode {
         #[cfg(feature = "single")]
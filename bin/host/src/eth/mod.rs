@@ -6,6 +6,7 @@ use alloy_transport_http::Http;
 use reqwest::Client;
 
 mod precompiles;
+pub use precompiles::PrecompileAccelerator;
 pub(crate) use precompiles::execute;
 
 /// Returns an HTTP provider for the given URL.
@@ -2,7 +2,8 @@
 
 use alloy_primitives::{Address, Bytes};
 use anyhow::{Result, anyhow};
-use revm::precompile::{self, PrecompileWithAddress};
+use revm::precompile::{self, PrecompileFn, PrecompileWithAddress};
+use std::collections::HashMap;
 
 /// List of precompiles that are accelerated by the host program.
 pub(crate) const ACCELERATED_PRECOMPILES: &[PrecompileWithAddress] = &[
@@ -19,16 +20,67 @@ pub(crate) const ACCELERATED_PRECOMPILES: &[PrecompileWithAddress] = &[
     precompile::kzg_point_evaluation::POINT_EVALUATION, // KZG point evaluation
 ];
 
+/// A registry of accelerated precompiles, keyed by their [Address].
+///
+/// The [Default] registry holds the precompiles accelerated by the host program. Integrators may
+/// register additional precompiles with [PrecompileAccelerator::with_precompile].
+#[derive(Debug, Clone)]
+pub struct PrecompileAccelerator {
+    /// The accelerated precompiles, keyed by their address.
+    precompiles: HashMap<Address, PrecompileFn>,
+}
+
+impl Default for PrecompileAccelerator {
+    fn default() -> Self {
+        Self {
+            precompiles: ACCELERATED_PRECOMPILES
+                .iter()
+                .map(|precompile| (precompile.0, *precompile.precompile()))
+                .collect(),
+        }
+    }
+}
+
+impl PrecompileAccelerator {
+    /// Creates a new [PrecompileAccelerator] without any precompiles.
+    pub fn empty() -> Self {
+        Self { precompiles: HashMap::new() }
+    }
+
+    /// Registers a precompile at the given address, replacing any existing precompile.
+    pub fn with_precompile(mut self, address: Address, precompile: PrecompileFn) -> Self {
+        self.precompiles.insert(address, precompile);
+        self
+    }
+
+    /// Returns `true` if a precompile is registered at the given address.
+    pub fn contains(&self, address: &Address) -> bool {
+        self.precompiles.contains_key(address)
+    }
+
+    /// Executes the precompile registered at the given address.
+    pub fn execute<T: Into<Bytes>>(&self, address: Address, input: T, gas: u64) -> Result<Vec<u8>> {
+        let precompile =
+            self.precompiles.get(&address).ok_or_else(|| anyhow!("Precompile not accelerated"))?;
+        run(precompile, input, gas)
+    }
+}
+
 /// Executes an accelerated precompile on [revm].
 pub(crate) fn execute<T: Into<Bytes>>(address: Address, input: T, gas: u64) -> Result<Vec<u8>> {
     if let Some(precompile) =
         ACCELERATED_PRECOMPILES.iter().find(|precompile| precompile.0 == address)
     {
-        let output = precompile.precompile()(&input.into(), gas)
-            .map_err(|e| anyhow!("Failed precompile execution: {e}"))?;
-
-        Ok(output.bytes.into())
+        run(precompile.precompile(), input, gas)
     } else {
         anyhow::bail!("Precompile not accelerated");
     }
 }
+
+/// Runs the given precompile, returning its output bytes.
+fn run<T: Into<Bytes>>(precompile: &PrecompileFn, input: T, gas: u64) -> Result<Vec<u8>> {
+    let output =
+        precompile(&input.into(), gas).map_err(|e| anyhow!("Failed precompile execution: {e}"))?;
+
+    Ok(output.bytes.into())
+}
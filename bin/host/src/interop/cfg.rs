@@ -2,9 +2,9 @@
 
 use super::{InteropHintHandler, InteropLocalInputs};
 use crate::{
-    DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend,
-    OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore, SplitKeyValueStore,
-    eth::http_provider, server::PreimageServerError,
+    DEFAULT_SLOW_REQUEST_THRESHOLD, DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend,
+    OnlineHostBackend, OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore,
+    SplitKeyValueStore, eth::http_provider, server::PreimageServerError,
 };
 use alloy_primitives::{B256, Bytes};
 use alloy_provider::{Provider, RootProvider};
@@ -19,12 +19,17 @@ use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
 use kona_std_fpvm::{FileChannel, FileDescriptor};
 use op_alloy_network::Optimism;
 use serde::Serialize;
-use std::{collections::HashMap, path::PathBuf, str::FromStr, sync::Arc};
+use std::{
+    collections::HashMap, num::NonZeroUsize, path::PathBuf, str::FromStr, sync::Arc, time::Duration,
+};
 use tokio::{
     sync::RwLock,
     task::{self, JoinHandle},
 };
 
+/// The number of verified blobs cached by the host's [OnlineBlobProvider].
+const BLOB_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(16).unwrap();
+
 /// The interop host application.
 #[derive(Default, Parser, Serialize, Clone, Debug)]
 #[command(styles = cli_styles())]
@@ -92,6 +97,10 @@ pub struct InteropHost {
     /// look up the configs in the superchain registry.
     #[arg(long, alias = "rollup-cfgs", value_delimiter = ',', env)]
     pub rollup_config_paths: Option<Vec<PathBuf>>,
+    /// The latency, in milliseconds, above which a single preimage request or hint is logged as
+    /// slow. Defaults to [DEFAULT_SLOW_REQUEST_THRESHOLD].
+    #[arg(long, env)]
+    pub slow_request_threshold_ms: Option<u64>,
 }
 
 /// An error that can occur when handling interop hosts
@@ -144,14 +153,16 @@ impl InteropHost {
         C: Channel + Send + Sync + 'static,
     {
         let kv_store = self.create_key_value_store()?;
+        let slow_request_threshold = self.slow_request_threshold();
 
         let task_handle = if self.is_offline() {
-            task::spawn(async {
+            task::spawn(async move {
                 PreimageServer::new(
                     OracleServer::new(preimage),
                     HintReader::new(hint),
                     Arc::new(OfflineHostBackend::new(kv_store)),
                 )
+                .with_slow_request_threshold(slow_request_threshold)
                 .start()
                 .await
                 .map_err(InteropHostError::from)
@@ -166,12 +177,13 @@ impl InteropHost {
             )
             .with_proactive_hint(HintType::L2BlockData);
 
-            task::spawn(async {
+            task::spawn(async move {
                 PreimageServer::new(
                     OracleServer::new(preimage),
                     HintReader::new(hint),
                     Arc::new(backend),
                 )
+                .with_slow_request_threshold(slow_request_threshold)
                 .start()
                 .await
                 .map_err(InteropHostError::from)
@@ -199,6 +211,11 @@ impl InteropHost {
         std::process::exit(client_result.is_err() as i32)
     }
 
+    /// Returns the latency above which a single request to the [PreimageServer] is logged as slow.
+    pub fn slow_request_threshold(&self) -> Duration {
+        self.slow_request_threshold_ms.map_or(DEFAULT_SLOW_REQUEST_THRESHOLD, Duration::from_millis)
+    }
+
     /// Returns `true` if the host is running in offline mode.
     pub const fn is_offline(&self) -> bool {
         self.l1_node_address.is_none() &&
@@ -251,11 +268,14 @@ impl InteropHost {
             self.l1_node_address.as_ref().ok_or(InteropHostError::Other("Provider must be set"))?,
         );
 
-        let blob_provider = OnlineBlobProvider::init(OnlineBeaconClient::new_http(
-            self.l1_beacon_address
-                .clone()
-                .ok_or(InteropHostError::Other("Beacon API URL must be set"))?,
-        ))
+        let blob_provider = OnlineBlobProvider::init(
+            OnlineBeaconClient::new_http(
+                self.l1_beacon_address
+                    .clone()
+                    .ok_or(InteropHostError::Other("Beacon API URL must be set"))?,
+            ),
+            BLOB_CACHE_SIZE,
+        )
         .await;
 
         // Resolve all chain IDs to their corresponding providers.
@@ -300,7 +320,81 @@ impl InteropProviders {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::b256;
+    use alloy_primitives::{Address, b256};
+
+    const CHAIN_A: u64 = 10;
+    const CHAIN_B: u64 = 8453;
+
+    fn test_providers() -> InteropProviders {
+        InteropProviders {
+            l1: http_provider("http://localhost:8545"),
+            blobs: OnlineBlobProvider::new(
+                OnlineBeaconClient::new_http("http://localhost:5052".to_string()),
+                0,
+                12,
+                BLOB_CACHE_SIZE,
+            ),
+            l2s: HashMap::from([
+                (CHAIN_A, http_provider("http://localhost:9545")),
+                (CHAIN_B, http_provider("http://localhost:9546")),
+            ]),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_l2_hint_routed_to_tagged_chain() {
+        let providers = test_providers();
+
+        let hint = HintType::L2BlockHeader
+            .with_data(&[B256::ZERO.as_slice(), CHAIN_A.to_be_bytes().as_slice()]);
+        let chain_id = InteropHintHandler::hint_chain_id(&hint).unwrap();
+        assert_eq!(chain_id, CHAIN_A);
+
+        let routed = providers.l2(&chain_id).unwrap();
+        assert!(std::ptr::eq(routed, &providers.l2s[&CHAIN_A]));
+        assert!(!std::ptr::eq(routed, &providers.l2s[&CHAIN_B]));
+    }
+
+    #[tokio::test]
+    async fn test_l2_hint_for_unknown_chain() {
+        let providers = test_providers();
+
+        let hint =
+            HintType::L2Receipts.with_data(&[B256::ZERO.as_slice(), 1u64.to_be_bytes().as_slice()]);
+        let chain_id = InteropHintHandler::hint_chain_id(&hint).unwrap();
+        assert!(matches!(providers.l2(&chain_id), Err(InteropHostError::RootProviderError(1))));
+    }
+
+    #[test]
+    fn test_hint_chain_id_requires_tagged_length() {
+        let output_root = HintType::L2OutputRoot.with_data(&[B256::ZERO.as_slice()]);
+        assert_eq!(InteropHintHandler::hint_chain_id(&output_root), None);
+
+        let output_root = HintType::L2OutputRoot
+            .with_data(&[B256::ZERO.as_slice(), CHAIN_B.to_be_bytes().as_slice()]);
+        assert_eq!(InteropHintHandler::hint_chain_id(&output_root), Some(CHAIN_B));
+
+        // The final 8 bytes of a hint with an unexpected length are not a chain ID.
+        let header = HintType::L2BlockHeader.with_data(&[
+            B256::ZERO.as_slice(),
+            CHAIN_A.to_be_bytes().as_slice(),
+            [0u8].as_slice(),
+        ]);
+        assert_eq!(InteropHintHandler::hint_chain_id(&header), None);
+
+        let account_proof = HintType::L2AccountProof.with_data(&[
+            1u64.to_be_bytes().as_slice(),
+            Address::ZERO.as_slice(),
+            CHAIN_A.to_be_bytes().as_slice(),
+        ]);
+        assert_eq!(InteropHintHandler::hint_chain_id(&account_proof), Some(CHAIN_A));
+    }
+
+    #[test]
+    fn test_l1_hint_has_no_chain_id() {
+        let hint = HintType::L1BlockHeader.with_data(&[B256::ZERO.as_slice()]);
+        assert_eq!(InteropHintHandler::hint_chain_id(&hint), None);
+    }
 
     #[test]
     fn test_parse_interop_host_cli() {
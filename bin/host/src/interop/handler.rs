@@ -42,6 +42,41 @@ use tracing::warn;
 #[derive(Debug, Clone, Copy)]
 pub struct InteropHintHandler;
 
+impl InteropHintHandler {
+    /// Returns the L2 chain ID that the given [Hint] targets, or [None] if the hint is not specific
+    /// to an L2 chain.
+    ///
+    /// L2 hints carry the chain ID of the chain they target as a big-endian `u64` in their final 8
+    /// bytes. The host serves these hints with the providers of that chain, while sharing a single
+    /// key-value store across all chains. [None] is also returned if the hint data does not have
+    /// the length of a tagged hint of its type, such as an [HintType::L2OutputRoot] hint that only
+    /// carries the output root.
+    pub fn hint_chain_id(hint: &Hint<HintType>) -> Option<u64> {
+        let len = match hint.ty {
+            HintType::L2BlockHeader |
+            HintType::L2Transactions |
+            HintType::L2Receipts |
+            HintType::L2Code |
+            HintType::L2OutputRoot |
+            HintType::L2StateNode => 40,
+            HintType::L2AccountProof => 36,
+            HintType::L2AccountStorageProof => 68,
+            HintType::L2BlockData => 72,
+            HintType::L1BlockHeader |
+            HintType::L1Transactions |
+            HintType::L1Receipts |
+            HintType::L1Blob |
+            HintType::L1Precompile |
+            HintType::AgreedPreState |
+            HintType::L2PayloadWitness => return None,
+        };
+        if hint.data.len() != len {
+            return None;
+        }
+        Some(u64::from_be_bytes(hint.data[len - 8..].try_into().ok()?))
+    }
+}
+
 #[async_trait]
 impl HintHandler for InteropHintHandler {
     type Cfg = InteropHost;
@@ -199,14 +234,21 @@ impl HintHandler for InteropHintHandler {
                 const L2_TO_L1_MESSAGE_PASSER_ADDRESS: Address =
                     address!("4200000000000000000000000000000000000016");
 
-                ensure!(hint.data.len() >= 32 && hint.data.len() <= 40, "Invalid hint data length");
+                ensure!(hint.data.len() == 32 || hint.data.len() == 40, "Invalid hint data length");
 
                 let hash = B256::from_slice(&hint.data.as_ref()[0..32]);
-                let chain_id = u64::from_be_bytes(hint.data.as_ref()[32..40].try_into()?);
-                let l2_provider = providers.l2(&chain_id)?;
 
                 // Decode the pre-state to determine the timestamp of the block.
                 let pre = PreState::decode(&mut cfg.agreed_l2_pre_state.as_ref())?;
+
+                // Output root hints without a chain ID target the active chain of the pre-state.
+                let chain_id = match Self::hint_chain_id(&hint) {
+                    Some(chain_id) => chain_id,
+                    None => {
+                        pre.active_l2_chain_id().ok_or(anyhow!("Missing active L2 chain ID"))?
+                    }
+                };
+                let l2_provider = providers.l2(&chain_id)?;
                 let timestamp = match pre {
                     PreState::SuperRoot(super_root) => super_root.timestamp,
                     PreState::TransitionState(transition_state) => {
@@ -260,7 +302,8 @@ impl HintHandler for InteropHintHandler {
                 ensure!(hint.data.len() == 40, "Invalid hint data length");
 
                 let hash: B256 = hint.data.as_ref()[..32].try_into()?;
-                let chain_id = u64::from_be_bytes(hint.data[32..40].try_into()?);
+                let chain_id =
+                    Self::hint_chain_id(&hint).ok_or(anyhow!("Missing hint chain ID"))?;
 
                 let raw_header: Bytes =
                     providers.l2(&chain_id)?.client().request("debug_getRawHeader", [hash]).await?;
@@ -272,7 +315,8 @@ impl HintHandler for InteropHintHandler {
                 ensure!(hint.data.len() == 40, "Invalid hint data length");
 
                 let hash: B256 = hint.data.as_ref()[..32].try_into()?;
-                let chain_id = u64::from_be_bytes(hint.data[32..40].try_into()?);
+                let chain_id =
+                    Self::hint_chain_id(&hint).ok_or(anyhow!("Missing hint chain ID"))?;
 
                 let Block { transactions, .. } = providers
                     .l2(&chain_id)?
@@ -291,7 +335,8 @@ impl HintHandler for InteropHintHandler {
                 ensure!(hint.data.len() == 40, "Invalid hint data length");
 
                 let hash: B256 = hint.data.as_ref()[..32].try_into()?;
-                let chain_id = u64::from_be_bytes(hint.data[32..40].try_into()?);
+                let chain_id =
+                    Self::hint_chain_id(&hint).ok_or(anyhow!("Missing hint chain ID"))?;
 
                 let raw_receipts: Vec<Bytes> = providers
                     .l2(&chain_id)?
@@ -308,7 +353,8 @@ impl HintHandler for InteropHintHandler {
                 ensure!(hint.data.len() == 40, "Invalid hint data length");
 
                 let hash: B256 = B256::from_slice(&hint.data[0..32]);
-                let chain_id = u64::from_be_bytes(hint.data[32..40].try_into()?);
+                let chain_id =
+                    Self::hint_chain_id(&hint).ok_or(anyhow!("Missing hint chain ID"))?;
                 let l2_provider = providers.l2(&chain_id)?;
 
                 // Attempt to fetch the code from the L2 chain provider.
@@ -335,8 +381,9 @@ impl HintHandler for InteropHintHandler {
             HintType::L2StateNode => {
                 ensure!(hint.data.len() == 40, "Invalid hint data length");
 
-                let hash: B256 = hint.data.as_ref().try_into()?;
-                let chain_id = u64::from_be_bytes(hint.data[32..40].try_into()?);
+                let hash: B256 = hint.data.as_ref()[..32].try_into()?;
+                let chain_id =
+                    Self::hint_chain_id(&hint).ok_or(anyhow!("Missing hint chain ID"))?;
 
                 // Fetch the preimage from the L2 chain provider.
                 let preimage: Bytes =
@@ -350,7 +397,8 @@ impl HintHandler for InteropHintHandler {
 
                 let block_number = u64::from_be_bytes(hint.data.as_ref()[..8].try_into()?);
                 let address = Address::from_slice(&hint.data.as_ref()[8..28]);
-                let chain_id = u64::from_be_bytes(hint.data[28..].try_into()?);
+                let chain_id =
+                    Self::hint_chain_id(&hint).ok_or(anyhow!("Missing hint chain ID"))?;
 
                 let proof_response = providers
                     .l2(&chain_id)?
@@ -373,7 +421,8 @@ impl HintHandler for InteropHintHandler {
                 let block_number = u64::from_be_bytes(hint.data.as_ref()[..8].try_into()?);
                 let address = Address::from_slice(&hint.data.as_ref()[8..28]);
                 let slot = B256::from_slice(&hint.data.as_ref()[28..60]);
-                let chain_id = u64::from_be_bytes(hint.data[60..].try_into()?);
+                let chain_id =
+                    Self::hint_chain_id(&hint).ok_or(anyhow!("Missing hint chain ID"))?;
 
                 let mut proof_response = providers
                     .l2(&chain_id)?
@@ -405,7 +454,8 @@ impl HintHandler for InteropHintHandler {
 
                 let agreed_block_hash = B256::from_slice(&hint.data.as_ref()[..32]);
                 let disputed_block_hash = B256::from_slice(&hint.data.as_ref()[32..64]);
-                let chain_id = u64::from_be_bytes(hint.data.as_ref()[64..72].try_into()?);
+                let chain_id =
+                    Self::hint_chain_id(&hint).ok_or(anyhow!("Missing hint chain ID"))?;
 
                 let l2_provider = providers.l2(&chain_id)?;
                 let rollup_config = ROLLUP_CONFIGS
@@ -0,0 +1,68 @@
+//! Contains a concrete implementation of the [KeyValueStore] trait that records the keys that are
+//! read from an inner [KeyValueStore].
+
+use super::KeyValueStore;
+use alloy_primitives::B256;
+use anyhow::Result;
+use std::{
+    collections::BTreeSet,
+    sync::{Arc, Mutex},
+};
+
+/// A shared, deduplicated log of the keys that were successfully read from an
+/// [AccessLogKeyValueStore].
+#[derive(Default, Clone, Debug)]
+pub struct AccessLog(Arc<Mutex<BTreeSet<B256>>>);
+
+impl AccessLog {
+    /// Records an access to the given key.
+    fn record(&self, key: B256) {
+        self.0.lock().expect("access log poisoned").insert(key);
+    }
+
+    /// Returns the keys that were accessed, in ascending order.
+    pub fn keys(&self) -> Vec<B256> {
+        self.0.lock().expect("access log poisoned").iter().copied().collect()
+    }
+}
+
+/// A [KeyValueStore] that records every key successfully read from the inner [KeyValueStore] in an
+/// [AccessLog].
+#[derive(Clone, Debug)]
+pub struct AccessLogKeyValueStore<KV>
+where
+    KV: KeyValueStore,
+{
+    inner: KV,
+    access_log: AccessLog,
+}
+
+impl<KV> AccessLogKeyValueStore<KV>
+where
+    KV: KeyValueStore,
+{
+    /// Create a new [AccessLogKeyValueStore] that records reads from `inner` in the given
+    /// [AccessLog].
+    pub const fn new(inner: KV, access_log: AccessLog) -> Self {
+        Self { inner, access_log }
+    }
+}
+
+impl<KV> KeyValueStore for AccessLogKeyValueStore<KV>
+where
+    KV: KeyValueStore,
+{
+    fn get(&self, key: B256) -> Option<Vec<u8>> {
+        let value = self.inner.get(key)?;
+        self.access_log.record(key);
+        Some(value)
+    }
+
+    fn contains(&self, key: B256) -> bool {
+        self.inner.contains(key)
+    }
+
+    fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()> {
+        self.inner.set(key, value)
+    }
+}
@@ -4,10 +4,14 @@
 use super::{KeyValueStore, MemoryKeyValueStore};
 use alloy_primitives::B256;
 use anyhow::{Result, anyhow};
-use rocksdb::{DB, Options};
-use std::path::PathBuf;
+use rocksdb::{DB, IteratorMode, Options, WriteBatch};
+use std::{collections::HashSet, path::PathBuf};
 
 /// A simple, synchronous key-value store that stores data on disk.
+///
+/// Writes are atomic: an entry is either fully written or absent, even if the host is
+/// interrupted mid-write, so a partially written preimage is never read back. The data directory
+/// is locked while the store is open, so it cannot be shared with another host process.
 #[derive(Debug)]
 pub struct DiskKeyValueStore {
     data_directory: PathBuf,
@@ -23,6 +27,32 @@ impl DiskKeyValueStore {
         Self { data_directory, db }
     }
 
+    /// Removes all entries whose keys are not in `keep`, returning the number of entries removed.
+    ///
+    /// The database is compacted afterwards, so that the disk space held by the removed entries
+    /// is reclaimed.
+    pub fn prune(&self, keep: &HashSet<B256>) -> Result<usize> {
+        let mut batch = WriteBatch::default();
+        for entry in self.db.iterator(IteratorMode::Start) {
+            let (key, _) = entry.map_err(|e| anyhow!("Failed to iterate database: {e}"))?;
+            let retained = B256::try_from(key.as_ref()).is_ok_and(|key| keep.contains(&key));
+            if !retained {
+                batch.delete(key);
+            }
+        }
+
+        let removed = batch.len();
+        self.db.write(batch).map_err(|e| anyhow!("Failed to prune database: {e}"))?;
+        self.db.compact_range::<&[u8], &[u8]>(None, None);
+
+        Ok(removed)
+    }
+
+    /// Removes all entries from the store.
+    pub fn clear(&self) -> Result<()> {
+        self.prune(&HashSet::new()).map(|_| ())
+    }
+
     /// Gets the [Options] for the underlying RocksDB instance.
     fn get_db_options() -> Options {
         let mut options = Options::default();
@@ -37,6 +67,10 @@ impl KeyValueStore for DiskKeyValueStore {
         self.db.get(*key).ok()?
     }
 
+    fn contains(&self, key: alloy_primitives::B256) -> bool {
+        self.db.get_pinned(*key).is_ok_and(|value| value.is_some())
+    }
+
     fn set(&mut self, key: alloy_primitives::B256, value: Vec<u8>) -> Result<()> {
         self.db.put(*key, value).map_err(|e| anyhow!("Failed to set key-value pair: {}", e))
     }
@@ -77,7 +111,37 @@ mod test {
         proptest,
         test_runner::Config,
     };
-    use std::env::temp_dir;
+    use std::{collections::HashSet, env::temp_dir};
+
+    #[test]
+    fn test_prune_retains_kept_keys() {
+        let data_directory = tempfile::tempdir().unwrap();
+        let mut disk_kv = DiskKeyValueStore::new(data_directory.path().to_path_buf());
+        let keys = (0..8u8).map(|i| [i; 32].into()).collect::<Vec<_>>();
+        for key in &keys {
+            disk_kv.set(*key, key.to_vec()).unwrap();
+        }
+
+        let keep = keys.iter().step_by(3).copied().collect::<HashSet<_>>();
+        assert_eq!(disk_kv.prune(&keep).unwrap(), keys.len() - keep.len());
+        for key in &keys {
+            assert_eq!(disk_kv.get(*key).is_some(), keep.contains(key));
+        }
+
+        // Pruning again is a no-op.
+        assert_eq!(disk_kv.prune(&keep).unwrap(), 0);
+
+        disk_kv.clear().unwrap();
+        assert!(keys.iter().all(|key| disk_kv.get(*key).is_none()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Failed to open database")]
+    fn test_data_directory_is_locked() {
+        let data_directory = tempfile::tempdir().unwrap();
+        let _disk_kv = DiskKeyValueStore::new(data_directory.path().to_path_buf());
+        DiskKeyValueStore::new(data_directory.path().to_path_buf());
+    }
 
     proptest! {
         #![proptest_config(Config::with_cases(16))]
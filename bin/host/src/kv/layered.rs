@@ -0,0 +1,135 @@
+//! Contains a concrete implementation of the [KeyValueStore] trait that chains an arbitrary number
+//! of [KeyValueStore]s.
+
+use super::KeyValueStore;
+use alloy_primitives::B256;
+use anyhow::{Result, anyhow};
+
+/// A layer of a [LayeredKeyValueStore].
+struct Layer {
+    /// The [KeyValueStore] backing the layer.
+    store: Box<dyn KeyValueStore + Send + Sync>,
+    /// Whether values are written to the layer.
+    writable: bool,
+}
+
+/// A [KeyValueStore] that chains multiple [KeyValueStore]s.
+///
+/// On [KeyValueStore::get], the layers are queried in the order that they were added, and the first
+/// value found is returned. On [KeyValueStore::set], the value is written to the first writable
+/// layer.
+#[derive(Default)]
+pub struct LayeredKeyValueStore {
+    layers: Vec<Layer>,
+}
+
+impl LayeredKeyValueStore {
+    /// Create a new [LayeredKeyValueStore] without any layers.
+    pub const fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Appends a read-only layer to the [LayeredKeyValueStore].
+    pub fn with_layer<KV>(mut self, store: KV) -> Self
+    where
+        KV: KeyValueStore + Send + Sync + 'static,
+    {
+        self.layers.push(Layer { store: Box::new(store), writable: false });
+        self
+    }
+
+    /// Appends a writable layer to the [LayeredKeyValueStore].
+    pub fn with_writable_layer<KV>(mut self, store: KV) -> Self
+    where
+        KV: KeyValueStore + Send + Sync + 'static,
+    {
+        self.layers.push(Layer { store: Box::new(store), writable: true });
+        self
+    }
+
+    /// Returns the [KeyValueStore] of the layer at the given index, if it exists.
+    pub fn layer(&self, index: usize) -> Option<&(dyn KeyValueStore + Send + Sync)> {
+        self.layers.get(index).map(|layer| layer.store.as_ref())
+    }
+}
+
+impl core::fmt::Debug for LayeredKeyValueStore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LayeredKeyValueStore")
+            .field("writable", &self.layers.iter().map(|l| l.writable).collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl KeyValueStore for LayeredKeyValueStore {
+    fn get(&self, key: B256) -> Option<Vec<u8>> {
+        self.layers.iter().find_map(|layer| layer.store.get(key))
+    }
+
+    fn contains(&self, key: B256) -> bool {
+        self.layers.iter().any(|layer| layer.store.contains(key))
+    }
+
+    fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()> {
+        self.layers
+            .iter_mut()
+            .find(|layer| layer.writable)
+            .ok_or_else(|| anyhow!("No writable layer in the key-value store"))?
+            .store
+            .set(key, value)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::MemoryKeyValueStore;
+
+    fn store_with(entries: &[(B256, &str)]) -> MemoryKeyValueStore {
+        let mut store = MemoryKeyValueStore::new();
+        for (key, value) in entries {
+            store.set(*key, value.as_bytes().to_vec()).unwrap();
+        }
+        store
+    }
+
+    #[test]
+    fn test_get_queries_layers_in_order() {
+        let shadowed = B256::repeat_byte(0x01);
+        let last_only = B256::repeat_byte(0x02);
+        let store = LayeredKeyValueStore::new()
+            .with_layer(store_with(&[(shadowed, "first")]))
+            .with_writable_layer(MemoryKeyValueStore::new())
+            .with_layer(store_with(&[(shadowed, "last"), (last_only, "last only")]));
+
+        assert_eq!(store.get(shadowed).unwrap(), b"first");
+        assert_eq!(store.get(last_only).unwrap(), b"last only");
+        assert!(store.get(B256::ZERO).is_none());
+
+        assert!(store.contains(shadowed));
+        assert!(store.contains(last_only));
+        assert!(!store.contains(B256::ZERO));
+    }
+
+    #[test]
+    fn test_set_writes_to_first_writable_layer() {
+        let key = B256::repeat_byte(0x01);
+        let mut store = LayeredKeyValueStore::new()
+            .with_layer(MemoryKeyValueStore::new())
+            .with_writable_layer(MemoryKeyValueStore::new())
+            .with_writable_layer(MemoryKeyValueStore::new());
+
+        store.set(key, b"value".to_vec()).unwrap();
+
+        assert!(store.layer(0).unwrap().get(key).is_none());
+        assert_eq!(store.layer(1).unwrap().get(key).unwrap(), b"value");
+        assert!(store.layer(2).unwrap().get(key).is_none());
+        assert_eq!(store.get(key).unwrap(), b"value");
+    }
+
+    #[test]
+    fn test_set_without_writable_layer() {
+        let mut store = LayeredKeyValueStore::new().with_layer(MemoryKeyValueStore::new());
+        assert!(store.set(B256::ZERO, vec![]).is_err());
+    }
+}
@@ -2,21 +2,68 @@
 
 use super::KeyValueStore;
 use alloy_primitives::B256;
-use anyhow::Result;
-use std::collections::HashMap;
+use anyhow::{Result, bail};
+use std::collections::{HashMap, VecDeque};
+
+/// The behavior of a [MemoryKeyValueStore] when an insertion would exceed its capacity.
+#[derive(Default, Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CapacityPolicy {
+    /// Evict the oldest entries, in insertion order, until the new entry fits.
+    #[default]
+    Evict,
+    /// Reject the insertion with an error.
+    Reject,
+}
 
 /// A simple, synchronous key-value store that stores data in memory. This is useful for testing and
 /// development purposes.
+///
+/// The store may optionally be capped to a maximum number of bytes, see
+/// [MemoryKeyValueStore::with_max_bytes].
 #[derive(Default, Clone, Debug, Eq, PartialEq)]
 pub struct MemoryKeyValueStore {
     /// The underlying store.
-    pub store: HashMap<B256, Vec<u8>>,
+    store: HashMap<B256, Vec<u8>>,
+    /// The keys of the store in insertion order. Only tracked if the store is capped.
+    insertion_order: VecDeque<B256>,
+    /// The total size of the keys and values in the store, in bytes.
+    total_bytes: usize,
+    /// The maximum size of the store, in bytes.
+    max_bytes: Option<usize>,
+    /// The behavior of the store when `max_bytes` would be exceeded.
+    policy: CapacityPolicy,
 }
 
 impl MemoryKeyValueStore {
-    /// Create a new [MemoryKeyValueStore] with an empty store.
+    /// Create a new [MemoryKeyValueStore] with an empty, unbounded store.
     pub fn new() -> Self {
-        Self { store: HashMap::default() }
+        Self::default()
+    }
+
+    /// Create a new [MemoryKeyValueStore] with an empty store that holds at most `max_bytes` of
+    /// keys and values. Once the capacity is exceeded, the store behaves according to `policy`.
+    pub fn with_max_bytes(max_bytes: usize, policy: CapacityPolicy) -> Self {
+        Self { max_bytes: Some(max_bytes), policy, ..Self::default() }
+    }
+
+    /// Returns the number of entries in the store.
+    pub fn len(&self) -> usize {
+        self.store.len()
+    }
+
+    /// Returns `true` if the store has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.store.is_empty()
+    }
+
+    /// Returns the total size of the keys and values in the store, in bytes.
+    pub const fn total_bytes(&self) -> usize {
+        self.total_bytes
+    }
+
+    /// Returns the size of an entry with the given value, in bytes.
+    const fn entry_bytes(value: &[u8]) -> usize {
+        B256::len_bytes() + value.len()
     }
 }
 
@@ -25,8 +72,122 @@ impl KeyValueStore for MemoryKeyValueStore {
         self.store.get(&key).cloned()
     }
 
+    fn contains(&self, key: B256) -> bool {
+        self.store.contains_key(&key)
+    }
+
     fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()> {
-        self.store.insert(key, value);
+        let entry_bytes = Self::entry_bytes(&value);
+
+        if let Some(max_bytes) = self.max_bytes {
+            if entry_bytes > max_bytes {
+                bail!(
+                    "Entry of {entry_bytes} bytes exceeds the store capacity of {max_bytes} bytes"
+                );
+            }
+
+            let replaced_bytes = self.store.get(&key).map_or(0, |v| Self::entry_bytes(v));
+            let new_total = self.total_bytes - replaced_bytes + entry_bytes;
+            if self.policy == CapacityPolicy::Reject && new_total > max_bytes {
+                bail!("Store capacity of {max_bytes} bytes exceeded ({new_total} bytes)");
+            }
+
+            // Re-inserted keys are moved to the back of the eviction queue.
+            if replaced_bytes > 0 {
+                self.insertion_order.retain(|k| *k != key);
+            }
+            self.insertion_order.push_back(key);
+        }
+
+        if let Some(replaced) = self.store.insert(key, value) {
+            self.total_bytes -= Self::entry_bytes(&replaced);
+        }
+        self.total_bytes += entry_bytes;
+
+        // Evict the oldest entries until the store fits within its capacity.
+        while self.max_bytes.is_some_and(|max_bytes| self.total_bytes > max_bytes) {
+            let Some(evicted) = self.insertion_order.pop_front() else { break };
+            if let Some(value) = self.store.remove(&evicted) {
+                self.total_bytes -= Self::entry_bytes(&value);
+            }
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn key(i: u8) -> B256 {
+        [i; 32].into()
+    }
+
+    #[test]
+    fn test_size_accounting() {
+        let mut store = MemoryKeyValueStore::new();
+        assert!(store.is_empty());
+
+        store.set(key(0), vec![0; 8]).unwrap();
+        store.set(key(1), vec![1; 16]).unwrap();
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.total_bytes(), 2 * 32 + 8 + 16);
+
+        // Overwriting a key replaces its size.
+        store.set(key(0), vec![0; 4]).unwrap();
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.total_bytes(), 2 * 32 + 4 + 16);
+    }
+
+    #[test]
+    fn test_evicts_in_insertion_order() {
+        let mut store = MemoryKeyValueStore::with_max_bytes(3 * 40, CapacityPolicy::Evict);
+        for i in 0..3 {
+            store.set(key(i), vec![i; 8]).unwrap();
+        }
+        assert_eq!(store.total_bytes(), 3 * 40);
+
+        // Re-inserting the oldest key moves it to the back of the eviction queue.
+        store.set(key(0), vec![0; 8]).unwrap();
+        store.set(key(3), vec![3; 8]).unwrap();
+        assert_eq!(store.get(key(1)), None);
+        assert!([0, 2, 3].into_iter().all(|i| store.get(key(i)).is_some()));
+
+        // A larger entry evicts as many of the oldest entries as necessary.
+        store.set(key(4), vec![4; 48]).unwrap();
+        assert_eq!(store.get(key(2)), None);
+        assert_eq!(store.get(key(0)), None);
+        assert_eq!(store.get(key(3)), Some(vec![3; 8]));
+        assert_eq!(store.get(key(4)), Some(vec![4; 48]));
+        assert_eq!(store.len(), 2);
+        assert_eq!(store.total_bytes(), 40 + 80);
+    }
+
+    #[test]
+    fn test_reject_mode() {
+        let mut store = MemoryKeyValueStore::with_max_bytes(2 * 40, CapacityPolicy::Reject);
+        store.set(key(0), vec![0; 8]).unwrap();
+        store.set(key(1), vec![1; 8]).unwrap();
+
+        assert!(store.set(key(2), vec![2; 8]).is_err());
+        assert_eq!(store.get(key(2)), None);
+        assert_eq!(store.len(), 2);
+
+        // Overwriting an entry within the capacity is allowed.
+        store.set(key(1), vec![1; 4]).unwrap();
+        assert!(store.set(key(0), vec![0; 16]).is_err());
+        assert_eq!(store.get(key(0)), Some(vec![0; 8]));
+        assert_eq!(store.total_bytes(), 2 * 32 + 8 + 4);
+    }
+
+    #[test]
+    fn test_rejects_oversized_entry() {
+        for policy in [CapacityPolicy::Evict, CapacityPolicy::Reject] {
+            let mut store = MemoryKeyValueStore::with_max_bytes(40, policy);
+            store.set(key(0), vec![0; 8]).unwrap();
+            assert!(store.set(key(1), vec![1; 9]).is_err());
+            assert_eq!(store.get(key(0)), Some(vec![0; 8]));
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 mod mem;
-pub use mem::MemoryKeyValueStore;
+pub use mem::{CapacityPolicy, MemoryKeyValueStore};
 
 mod disk;
 pub use disk::DiskKeyValueStore;
@@ -14,6 +14,12 @@ pub use disk::DiskKeyValueStore;
 mod split;
 pub use split::SplitKeyValueStore;
 
+mod layered;
+pub use layered::LayeredKeyValueStore;
+
+mod access_log;
+pub use access_log::{AccessLog, AccessLogKeyValueStore};
+
 /// A type alias for a shared key-value store.
 pub type SharedKeyValueStore = Arc<RwLock<dyn KeyValueStore + Send + Sync>>;
 
@@ -22,6 +28,11 @@ pub trait KeyValueStore {
     /// Get the value associated with the given key.
     fn get(&self, key: B256) -> Option<Vec<u8>>;
 
+    /// Returns `true` if a value is associated with the given key, without reading the value.
+    fn contains(&self, key: B256) -> bool {
+        self.get(key).is_some()
+    }
+
     /// Set the value associated with the given key.
     fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()>;
 }
@@ -41,6 +41,14 @@ where
         }
     }
 
+    fn contains(&self, key: B256) -> bool {
+        match PreimageKeyType::try_from(key[0]) {
+            Ok(PreimageKeyType::Local) => self.local_store.contains(key),
+            Ok(_) => self.remote_store.contains(key),
+            Err(_) => false,
+        }
+    }
+
     fn set(&mut self, key: B256, value: Vec<u8>) -> Result<()> {
         self.remote_store.set(key, value)
     }
@@ -2,13 +2,22 @@
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
 mod server;
-pub use server::{PreimageServer, PreimageServerError};
+pub use server::{DEFAULT_SLOW_REQUEST_THRESHOLD, PreimageServer, PreimageServerError};
+
+mod metrics;
+pub use metrics::{
+    BACKEND_MISSES_TOTAL, HINT_WRITES_TOTAL, ORACLE_GETS_TOTAL, PreimageServerMetrics,
+};
 
 mod kv;
 pub use kv::{
-    DiskKeyValueStore, KeyValueStore, MemoryKeyValueStore, SharedKeyValueStore, SplitKeyValueStore,
+    AccessLog, AccessLogKeyValueStore, CapacityPolicy, DiskKeyValueStore, KeyValueStore,
+    LayeredKeyValueStore, MemoryKeyValueStore, SharedKeyValueStore, SplitKeyValueStore,
 };
 
+mod witness;
+pub use witness::{InMemoryOracle, Witness, WitnessError};
+
 mod backend;
 pub use backend::{HintHandler, OfflineHostBackend, OnlineHostBackend, OnlineHostBackendCfg};
 
@@ -0,0 +1,129 @@
+//! Metrics for the [PreimageServer].
+//!
+//! Metrics are recorded through the [metrics] facade, and are exported by whichever recorder is
+//! installed by the binary (e.g. the prometheus recorder enabled via `--metrics.enabled`).
+//!
+//! [PreimageServer]: crate::PreimageServer
+
+use async_trait::async_trait;
+use kona_preimage::{
+    HintRouter, PreimageFetcher, PreimageKey, PreimageServerBackend, errors::PreimageOracleResult,
+};
+use std::{
+    fmt::Display,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tracing::warn;
+
+/// Counter of the number of preimage requests served by the oracle server.
+pub const ORACLE_GETS_TOTAL: &str = "kona_host_oracle_gets_total";
+
+/// Counter of the number of hints routed by the hint router.
+pub const HINT_WRITES_TOTAL: &str = "kona_host_hint_writes_total";
+
+/// Counter of the number of preimage requests that the backend could not serve.
+pub const BACKEND_MISSES_TOTAL: &str = "kona_host_backend_misses_total";
+
+/// Request metrics for a [PreimageServer].
+///
+/// [PreimageServer]: crate::PreimageServer
+#[derive(Debug, Clone, Copy)]
+pub struct PreimageServerMetrics;
+
+impl PreimageServerMetrics {
+    /// Describes the preimage server metrics to the installed recorder.
+    pub fn describe() {
+        metrics::describe_counter!(
+            ORACLE_GETS_TOTAL,
+            "Total number of preimage requests served by the host"
+        );
+        metrics::describe_counter!(HINT_WRITES_TOTAL, "Total number of hints routed by the host");
+        metrics::describe_counter!(
+            BACKEND_MISSES_TOTAL,
+            "Total number of preimage requests that the host backend could not serve"
+        );
+    }
+
+    /// Records a preimage request, and whether the backend was able to serve it.
+    fn record_oracle_get(found: bool) {
+        metrics::counter!(ORACLE_GETS_TOTAL).increment(1);
+        if !found {
+            metrics::counter!(BACKEND_MISSES_TOTAL).increment(1);
+        }
+    }
+
+    /// Records a routed hint.
+    fn record_hint_write() {
+        metrics::counter!(HINT_WRITES_TOTAL).increment(1);
+    }
+}
+
+/// A [PreimageServerBackend] that records [PreimageServerMetrics] for every request served by the
+/// inner backend, and logs requests that take longer than the slow request threshold.
+#[derive(Debug)]
+pub(crate) struct MeteredBackend<B> {
+    /// The inner backend.
+    inner: Arc<B>,
+    /// The latency above which a request is logged as slow.
+    slow_request_threshold: Duration,
+}
+
+impl<B> MeteredBackend<B> {
+    /// Creates a new [MeteredBackend] wrapping the given backend.
+    pub(crate) const fn new(inner: Arc<B>, slow_request_threshold: Duration) -> Self {
+        Self { inner, slow_request_threshold }
+    }
+
+    /// Logs a warning if the request that started at `start` exceeded the slow request threshold.
+    fn check_latency(&self, start: Instant, request: impl Display) {
+        let elapsed = start.elapsed();
+        if elapsed > self.slow_request_threshold {
+            warn!(
+                target: "host_server",
+                ?elapsed,
+                threshold = ?self.slow_request_threshold,
+                "Slow request: {request}"
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl<B> PreimageFetcher for MeteredBackend<B>
+where
+    B: PreimageServerBackend + Send + Sync,
+{
+    async fn get_preimage(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
+        let start = Instant::now();
+        let res = self.inner.get_preimage(key).await;
+        PreimageServerMetrics::record_oracle_get(res.is_ok());
+        self.check_latency(start, format_args!("preimage {key}"));
+        res
+    }
+
+    async fn contains(&self, key: PreimageKey) -> bool {
+        let start = Instant::now();
+        let contains = self.inner.contains(key).await;
+        if !contains {
+            // The request is answered without a fetch, so it is recorded here.
+            PreimageServerMetrics::record_oracle_get(false);
+            self.check_latency(start, format_args!("preimage {key}"));
+        }
+        contains
+    }
+}
+
+#[async_trait]
+impl<B> HintRouter for MeteredBackend<B>
+where
+    B: PreimageServerBackend + Send + Sync,
+{
+    async fn route_hint(&self, hint: String) -> PreimageOracleResult<()> {
+        let start = Instant::now();
+        let res = self.inner.route_hint(hint.clone()).await;
+        PreimageServerMetrics::record_hint_write();
+        self.check_latency(start, format_args!("hint {hint}"));
+        res
+    }
+}
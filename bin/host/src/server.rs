@@ -1,12 +1,16 @@
 //! This module contains the [PreimageServer] struct and its implementation.
 
+use crate::metrics::{MeteredBackend, PreimageServerMetrics};
 use kona_preimage::{
     HintReaderServer, PreimageOracleServer, PreimageServerBackend, errors::PreimageOracleError,
 };
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 use tokio::spawn;
 use tracing::{error, info};
 
+/// The default latency above which a single request to the [PreimageServer] is logged as slow.
+pub const DEFAULT_SLOW_REQUEST_THRESHOLD: Duration = Duration::from_secs(5);
+
 /// The [PreimageServer] is responsible for waiting for incoming preimage requests and
 /// serving them to the client.
 #[derive(Debug)]
@@ -17,6 +21,8 @@ pub struct PreimageServer<P, H, B> {
     hint_reader: H,
     /// [PreimageServerBackend] that routes hints and retrieves preimages.
     backend: Arc<B>,
+    /// The latency above which a single request is logged as slow.
+    slow_request_threshold: Duration,
 }
 
 /// An error that can occur when handling preimage requests
@@ -42,14 +48,29 @@ where
     /// Create a new [PreimageServer] with the given [PreimageOracleServer],
     /// [HintReaderServer], and [PreimageServerBackend].
     pub const fn new(oracle_server: P, hint_reader: H, backend: Arc<B>) -> Self {
-        Self { oracle_server, hint_reader, backend }
+        Self {
+            oracle_server,
+            hint_reader,
+            backend,
+            slow_request_threshold: DEFAULT_SLOW_REQUEST_THRESHOLD,
+        }
+    }
+
+    /// Sets the latency above which a single preimage request or hint is logged as slow. Defaults
+    /// to [DEFAULT_SLOW_REQUEST_THRESHOLD].
+    pub const fn with_slow_request_threshold(mut self, threshold: Duration) -> Self {
+        self.slow_request_threshold = threshold;
+        self
     }
 
     /// Starts the [PreimageServer] and waits for incoming requests.
     pub async fn start(self) -> Result<(), PreimageServerError> {
+        PreimageServerMetrics::describe();
+        let backend = Arc::new(MeteredBackend::new(self.backend, self.slow_request_threshold));
+
         // Create the futures for the oracle server and hint router.
-        let server = spawn(Self::start_oracle_server(self.oracle_server, self.backend.clone()));
-        let hint_router = spawn(Self::start_hint_router(self.hint_reader, self.backend.clone()));
+        let server = spawn(Self::start_oracle_server(self.oracle_server, backend.clone()));
+        let hint_router = spawn(Self::start_hint_router(self.hint_reader, backend));
 
         // Race the two futures to completion, returning the result of the first one to finish.
         tokio::select! {
@@ -62,7 +83,7 @@ where
     /// client.
     async fn start_oracle_server(
         oracle_server: P,
-        backend: Arc<B>,
+        backend: Arc<MeteredBackend<B>>,
     ) -> Result<(), PreimageServerError> {
         info!(target: "host_server", "Starting oracle server");
         loop {
@@ -81,7 +102,10 @@ where
 
     /// Starts the hint router, which waits for incoming hints and routes them to the appropriate
     /// handler.
-    async fn start_hint_router(hint_reader: H, backend: Arc<B>) -> Result<(), PreimageServerError> {
+    async fn start_hint_router(
+        hint_reader: H,
+        backend: Arc<MeteredBackend<B>>,
+    ) -> Result<(), PreimageServerError> {
         info!(target: "host_server", "Starting hint router");
         loop {
             // Route the next hint. This `await` will yield to the runtime if no progress can be
@@ -97,3 +121,82 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{BACKEND_MISSES_TOTAL, HINT_WRITES_TOTAL, ORACLE_GETS_TOTAL};
+    use alloy_primitives::keccak256;
+    use async_trait::async_trait;
+    use kona_preimage::{
+        BidirectionalChannel, HintReader, HintRouter, HintWriter, HintWriterClient, OracleReader,
+        OracleServer, PreimageFetcher, PreimageKey, PreimageOracleClient,
+        errors::PreimageOracleResult,
+    };
+    use metrics_exporter_prometheus::PrometheusBuilder;
+    use std::collections::HashMap;
+    use tokio::runtime::Builder;
+
+    /// A backend that serves a fixed set of preimages and accepts all hints.
+    struct MockBackend(HashMap<PreimageKey, Vec<u8>>);
+
+    #[async_trait]
+    impl PreimageFetcher for MockBackend {
+        async fn get_preimage(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
+            self.0.get(&key).cloned().ok_or(PreimageOracleError::KeyNotFound)
+        }
+
+        async fn contains(&self, key: PreimageKey) -> bool {
+            self.0.contains_key(&key)
+        }
+    }
+
+    #[async_trait]
+    impl HintRouter for MockBackend {
+        async fn route_hint(&self, _hint: String) -> PreimageOracleResult<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_request_metrics() {
+        let key = |data: &[u8]| PreimageKey::new_keccak256(*keccak256(data));
+        let backend = MockBackend([b"a", b"b"].map(|data| (key(data), data.to_vec())).into());
+
+        // The server and client run on a single thread with a local recorder, so that only the
+        // requests made by this test are observed.
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        metrics::with_local_recorder(&recorder, || {
+            Builder::new_current_thread().build().unwrap().block_on(async {
+                let hint = BidirectionalChannel::new().unwrap();
+                let preimage = BidirectionalChannel::new().unwrap();
+                let server = PreimageServer::new(
+                    OracleServer::new(preimage.host).with_not_found_sentinel(true),
+                    HintReader::new(hint.host),
+                    Arc::new(backend),
+                )
+                .with_slow_request_threshold(Duration::ZERO);
+                let server = tokio::task::spawn(server.start());
+
+                let reader = OracleReader::new(preimage.client).with_not_found_sentinel(true);
+                let writer = HintWriter::new(hint.client);
+                writer.write("test-hint 0xfacade").await.unwrap();
+                assert_eq!(reader.get(key(b"a")).await.unwrap(), b"a");
+                assert_eq!(reader.get(key(b"b")).await.unwrap(), b"b");
+                assert!(matches!(
+                    reader.get(key(b"c")).await,
+                    Err(PreimageOracleError::KeyNotFound)
+                ));
+                drop(reader);
+                drop(writer);
+                server.await.unwrap().unwrap();
+            })
+        });
+
+        let rendered = handle.render();
+        assert!(rendered.contains(&format!("{ORACLE_GETS_TOTAL} 3\n")));
+        assert!(rendered.contains(&format!("{BACKEND_MISSES_TOTAL} 1\n")));
+        assert!(rendered.contains(&format!("{HINT_WRITES_TOTAL} 1\n")));
+    }
+}
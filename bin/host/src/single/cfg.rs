@@ -2,12 +2,14 @@
 
 use super::{SingleChainHintHandler, SingleChainLocalInputs};
 use crate::{
-    DiskKeyValueStore, MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend,
-    OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore, SplitKeyValueStore,
-    eth::http_provider, server::PreimageServerError,
+    AccessLog, AccessLogKeyValueStore, DEFAULT_SLOW_REQUEST_THRESHOLD, DiskKeyValueStore,
+    KeyValueStore, MemoryKeyValueStore, OfflineHostBackend, OnlineHostBackend,
+    OnlineHostBackendCfg, PreimageServer, SharedKeyValueStore, SplitKeyValueStore, Witness,
+    WitnessError, eth::http_provider, server::PreimageServerError,
 };
 use alloy_primitives::B256;
-use alloy_provider::RootProvider;
+use alloy_provider::{Provider, RootProvider};
+use alloy_transport::TransportError;
 use clap::Parser;
 use kona_cli::cli_styles;
 use kona_client::fpvm_evm::FpvmOpEvmFactory;
@@ -17,14 +19,19 @@ use kona_preimage::{
 };
 use kona_proof::HintType;
 use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
+use kona_registry::ROLLUP_CONFIGS;
 use kona_std_fpvm::{FileChannel, FileDescriptor};
 use op_alloy_network::Optimism;
 use serde::Serialize;
-use std::{path::PathBuf, sync::Arc};
+use std::{num::NonZeroUsize, path::PathBuf, sync::Arc, time::Duration};
 use tokio::{
     sync::RwLock,
     task::{self, JoinHandle},
 };
+use tracing::info;
+
+/// The number of verified blobs cached by the host's [OnlineBlobProvider].
+const BLOB_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(16).unwrap();
 
 /// The host binary CLI application arguments.
 #[derive(Default, Parser, Serialize, Clone, Debug)]
@@ -107,6 +114,14 @@ pub struct SingleChainHost {
         env
     )]
     pub rollup_config_path: Option<PathBuf>,
+    /// Path to export the preimages consumed by the run to once the client completes. The
+    /// exported witness can be loaded into an `InMemoryOracle` for offline or zkVM proving.
+    #[arg(long, env)]
+    pub export_witness: Option<PathBuf>,
+    /// The latency, in milliseconds, above which a single preimage request or hint is logged as
+    /// slow. Defaults to [DEFAULT_SLOW_REQUEST_THRESHOLD].
+    #[arg(long, env)]
+    pub slow_request_threshold_ms: Option<u64>,
 }
 
 /// An error that can occur when handling single chain hosts
@@ -124,6 +139,49 @@ pub enum SingleChainHostError {
     /// Task failed to execute to completion.
     #[error("Join error: {0}")]
     ExecutionError(#[from] tokio::task::JoinError),
+    /// An error when exporting the witness.
+    #[error("Witness error: {0}")]
+    WitnessError(#[from] WitnessError),
+    /// An RPC error when querying a provider.
+    #[error("RPC error: {0}")]
+    RpcError(#[from] TransportError),
+    /// The L1 provider is connected to a different chain than the rollup config settles on.
+    #[error("L1 provider chain ID mismatch: expected {expected}, got {actual}")]
+    L1ChainIdMismatch {
+        /// The L1 chain ID from the rollup config.
+        expected: u64,
+        /// The chain ID reported by the L1 provider.
+        actual: u64,
+    },
+    /// The L2 provider is connected to a different chain than the rollup config describes.
+    #[error("L2 provider chain ID mismatch: expected {expected}, got {actual}")]
+    L2ChainIdMismatch {
+        /// The L2 chain ID from the rollup config.
+        expected: u64,
+        /// The chain ID reported by the L2 provider.
+        actual: u64,
+    },
+    /// The L1 head is too old to contain the batch for the claimed L2 block.
+    #[error(
+        "L1 head {l1_head} (#{l1_head_number}) is likely too low to contain L2 block \
+         #{claimed_l2_block_number}: its timestamp {l1_head_timestamp} precedes the earliest \
+         possible L1 origin timestamp {min_l1_origin_timestamp}"
+    )]
+    L1HeadTooLow {
+        /// The hash of the L1 head.
+        l1_head: B256,
+        /// The number of the L1 head.
+        l1_head_number: u64,
+        /// The timestamp of the L1 head.
+        l1_head_timestamp: u64,
+        /// The number of the claimed L2 block.
+        claimed_l2_block_number: u64,
+        /// The earliest possible timestamp of the claimed L2 block's L1 origin.
+        min_l1_origin_timestamp: u64,
+    },
+    /// The timestamp of the claimed L2 block overflows.
+    #[error("Timestamp of claimed L2 block #{0} overflows")]
+    ClaimedTimestampOverflow(u64),
     /// Any other error.
     #[error("Error: {0}")]
     Other(&'static str),
@@ -152,21 +210,27 @@ impl SingleChainHost {
     where
         C: Channel + Send + Sync + 'static,
     {
-        let kv_store = self.create_key_value_store()?;
+        let access_log = self.export_witness.is_some().then(AccessLog::default);
+        let kv_store = self.create_key_value_store_with_log(access_log.clone())?;
+        let slow_request_threshold = self.slow_request_threshold();
 
         let task_handle = if self.is_offline() {
-            task::spawn(async {
+            let kv_store = kv_store.clone();
+            task::spawn(async move {
                 PreimageServer::new(
                     OracleServer::new(preimage),
                     HintReader::new(hint),
                     Arc::new(OfflineHostBackend::new(kv_store)),
                 )
+                .with_slow_request_threshold(slow_request_threshold)
                 .start()
                 .await
                 .map_err(SingleChainHostError::from)
             })
         } else {
             let providers = self.create_providers().await?;
+            self.verify_l1_head(&providers).await?;
+
             let backend = OnlineHostBackend::new(
                 self.clone(),
                 kv_store.clone(),
@@ -175,18 +239,35 @@ impl SingleChainHost {
             )
             .with_proactive_hint(HintType::L2PayloadWitness);
 
-            task::spawn(async {
+            task::spawn(async move {
                 PreimageServer::new(
                     OracleServer::new(preimage),
                     HintReader::new(hint),
                     Arc::new(backend),
                 )
+                .with_slow_request_threshold(slow_request_threshold)
                 .start()
                 .await
                 .map_err(SingleChainHostError::from)
             })
         };
 
+        // Once the client has completed, export the preimages that it consumed.
+        if let (Some(path), Some(access_log)) = (self.export_witness.clone(), access_log) {
+            return Ok(task::spawn(async move {
+                task_handle.await??;
+                let witness = Witness::from_access_log(&*kv_store.read().await, &access_log);
+                witness.write(&path)?;
+                info!(
+                    target: "host",
+                    "Exported witness with {} preimages to {}",
+                    witness.preimages.len(),
+                    path.display()
+                );
+                Ok(())
+            }));
+        }
+
         Ok(task_handle)
     }
 
@@ -209,6 +290,11 @@ impl SingleChainHost {
         std::process::exit(client_result.is_err() as i32)
     }
 
+    /// Returns the latency above which a single request to the [PreimageServer] is logged as slow.
+    pub fn slow_request_threshold(&self) -> Duration {
+        self.slow_request_threshold_ms.map_or(DEFAULT_SLOW_REQUEST_THRESHOLD, Duration::from_millis)
+    }
+
     /// Returns `true` if the host is running in offline mode.
     pub const fn is_offline(&self) -> bool {
         self.l1_node_address.is_none() &&
@@ -234,16 +320,25 @@ impl SingleChainHost {
 
     /// Creates the key-value store for the host backend.
     pub fn create_key_value_store(&self) -> Result<SharedKeyValueStore, SingleChainHostError> {
+        self.create_key_value_store_with_log(None)
+    }
+
+    /// Creates the key-value store for the host backend, recording the keys read from it in the
+    /// given [AccessLog], if any.
+    fn create_key_value_store_with_log(
+        &self,
+        access_log: Option<AccessLog>,
+    ) -> Result<SharedKeyValueStore, SingleChainHostError> {
         let local_kv_store = SingleChainLocalInputs::new(self.clone());
 
-        let kv_store: SharedKeyValueStore = if let Some(ref data_dir) = self.data_dir {
+        let kv_store = if let Some(ref data_dir) = self.data_dir {
             let disk_kv_store = DiskKeyValueStore::new(data_dir.clone());
             let split_kv_store = SplitKeyValueStore::new(local_kv_store, disk_kv_store);
-            Arc::new(RwLock::new(split_kv_store))
+            share_key_value_store(split_kv_store, access_log)
         } else {
             let mem_kv_store = MemoryKeyValueStore::new();
             let split_kv_store = SplitKeyValueStore::new(local_kv_store, mem_kv_store);
-            Arc::new(RwLock::new(split_kv_store))
+            share_key_value_store(split_kv_store, access_log)
         };
 
         Ok(kv_store)
@@ -256,11 +351,14 @@ impl SingleChainHost {
                 .as_ref()
                 .ok_or(SingleChainHostError::Other("Provider must be set"))?,
         );
-        let blob_provider = OnlineBlobProvider::init(OnlineBeaconClient::new_http(
-            self.l1_beacon_address
-                .clone()
-                .ok_or(SingleChainHostError::Other("Beacon API URL must be set"))?,
-        ))
+        let blob_provider = OnlineBlobProvider::init(
+            OnlineBeaconClient::new_http(
+                self.l1_beacon_address
+                    .clone()
+                    .ok_or(SingleChainHostError::Other("Beacon API URL must be set"))?,
+            ),
+            BLOB_CACHE_SIZE,
+        )
         .await;
         let l2_provider = http_provider::<Optimism>(
             self.l2_node_address
@@ -268,7 +366,91 @@ impl SingleChainHost {
                 .ok_or(SingleChainHostError::Other("L2 node address must be set"))?,
         );
 
-        Ok(SingleChainProviders { l1: l1_provider, blobs: blob_provider, l2: l2_provider })
+        let providers =
+            SingleChainProviders { l1: l1_provider, blobs: blob_provider, l2: l2_provider };
+
+        let (l1_chain_id, l2_chain_id) = self.expected_chain_ids()?;
+        providers.verify_chain_ids(l1_chain_id, l2_chain_id).await?;
+
+        Ok(providers)
+    }
+
+    /// Verifies that the L1 head is recent enough to plausibly contain the batch for the claimed L2
+    /// block, failing early rather than deep within derivation.
+    ///
+    /// The check is skipped if the rollup config is not available.
+    pub async fn verify_l1_head(
+        &self,
+        providers: &SingleChainProviders,
+    ) -> Result<(), SingleChainHostError> {
+        let Some(rollup_config) = self.rollup_config()? else {
+            return Ok(());
+        };
+
+        let header = providers
+            .l1
+            .get_block_by_hash(self.l1_head)
+            .await?
+            .ok_or(SingleChainHostError::Other("L1 head block not found"))?
+            .header;
+        self.check_l1_head(&rollup_config, header.number, header.timestamp)
+    }
+
+    /// Checks that an L1 head with the given number and timestamp may contain the batch for the
+    /// claimed L2 block.
+    ///
+    /// The timestamp of the claimed L2 block is derived from the L2 genesis and block time. Its L1
+    /// origin may trail it by at most the max sequencer drift, and its batch cannot be included in
+    /// L1 before its L1 origin, so the L1 head must be at least as recent as that bound.
+    fn check_l1_head(
+        &self,
+        rollup_config: &RollupConfig,
+        l1_head_number: u64,
+        l1_head_timestamp: u64,
+    ) -> Result<(), SingleChainHostError> {
+        let genesis = &rollup_config.genesis;
+        let claimed_timestamp = self
+            .claimed_l2_block_number
+            .saturating_sub(genesis.l2.number)
+            .checked_mul(rollup_config.block_time)
+            .and_then(|elapsed| genesis.l2_time.checked_add(elapsed))
+            .ok_or(SingleChainHostError::ClaimedTimestampOverflow(self.claimed_l2_block_number))?;
+        let min_l1_origin_timestamp =
+            claimed_timestamp.saturating_sub(rollup_config.max_sequencer_drift(claimed_timestamp));
+
+        if l1_head_timestamp < min_l1_origin_timestamp {
+            return Err(SingleChainHostError::L1HeadTooLow {
+                l1_head: self.l1_head,
+                l1_head_number,
+                l1_head_timestamp,
+                claimed_l2_block_number: self.claimed_l2_block_number,
+                min_l1_origin_timestamp,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Returns the [RollupConfig] of the L2 chain, if it is known.
+    fn rollup_config(&self) -> Result<Option<RollupConfig>, SingleChainHostError> {
+        if let Some(l2_chain_id) = self.l2_chain_id {
+            return Ok(ROLLUP_CONFIGS.get(&l2_chain_id).cloned());
+        }
+        self.read_rollup_config().map(Some)
+    }
+
+    /// Returns the expected L1 and L2 chain IDs of the providers.
+    ///
+    /// The L1 chain ID is only known if the rollup config is available, either from the superchain
+    /// registry or the provided rollup config path.
+    fn expected_chain_ids(&self) -> Result<(Option<u64>, u64), SingleChainHostError> {
+        if let Some(l2_chain_id) = self.l2_chain_id {
+            let l1_chain_id = ROLLUP_CONFIGS.get(&l2_chain_id).map(|cfg| cfg.l1_chain_id);
+            return Ok((l1_chain_id, l2_chain_id));
+        }
+
+        let rollup_config = self.read_rollup_config()?;
+        Ok((Some(rollup_config.l1_chain_id), rollup_config.l2_chain_id))
     }
 }
 
@@ -288,11 +470,138 @@ pub struct SingleChainProviders {
     pub l2: RootProvider<Optimism>,
 }
 
+impl SingleChainProviders {
+    /// Verifies that the L1 and L2 providers are connected to the expected chains.
+    ///
+    /// The L1 chain ID is only checked if it is known.
+    pub async fn verify_chain_ids(
+        &self,
+        l1_chain_id: Option<u64>,
+        l2_chain_id: u64,
+    ) -> Result<(), SingleChainHostError> {
+        if let Some(expected) = l1_chain_id {
+            let actual = self.l1.get_chain_id().await?;
+            if actual != expected {
+                return Err(SingleChainHostError::L1ChainIdMismatch { expected, actual });
+            }
+        }
+
+        let actual = self.l2.get_chain_id().await?;
+        if actual != l2_chain_id {
+            return Err(SingleChainHostError::L2ChainIdMismatch { expected: l2_chain_id, actual });
+        }
+
+        Ok(())
+    }
+}
+
+/// Shares the given [KeyValueStore], recording the keys read from it in the given [AccessLog], if
+/// any.
+fn share_key_value_store<KV>(kv_store: KV, access_log: Option<AccessLog>) -> SharedKeyValueStore
+where
+    KV: KeyValueStore + Send + Sync + 'static,
+{
+    match access_log {
+        Some(access_log) => {
+            Arc::new(RwLock::new(AccessLogKeyValueStore::new(kv_store, access_log)))
+        }
+        None => Arc::new(RwLock::new(kv_store)),
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::single::SingleChainHost;
-    use alloy_primitives::B256;
+    use super::BLOB_CACHE_SIZE;
+    use crate::{
+        DEFAULT_SLOW_REQUEST_THRESHOLD,
+        single::{SingleChainHost, SingleChainHostError, SingleChainProviders},
+    };
+    use alloy_primitives::{B256, U64};
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_transport::mock::Asserter;
     use clap::Parser;
+    use kona_genesis::RollupConfig;
+    use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
+    use std::time::Duration;
+
+    /// Returns [SingleChainProviders] whose L1 and L2 providers report the given chain IDs.
+    fn mock_providers(l1_chain_id: u64, l2_chain_id: u64) -> SingleChainProviders {
+        let l1 = Asserter::new();
+        l1.push_success(&U64::from(l1_chain_id));
+        let l2 = Asserter::new();
+        l2.push_success(&U64::from(l2_chain_id));
+
+        SingleChainProviders {
+            l1: RootProvider::new(RpcClient::mocked(l1)),
+            blobs: OnlineBlobProvider::new(
+                OnlineBeaconClient::new_http("http://localhost:5052".to_string()),
+                0,
+                12,
+                BLOB_CACHE_SIZE,
+            ),
+            l2: RootProvider::new(RpcClient::mocked(l2)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_verify_chain_ids() {
+        mock_providers(1, 10).verify_chain_ids(Some(1), 10).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_chain_ids_unknown_l1() {
+        mock_providers(11155111, 10).verify_chain_ids(None, 10).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_verify_chain_ids_l1_mismatch() {
+        let err = mock_providers(11155111, 10).verify_chain_ids(Some(1), 10).await.unwrap_err();
+        assert!(matches!(
+            err,
+            SingleChainHostError::L1ChainIdMismatch { expected: 1, actual: 11155111 }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_verify_chain_ids_l2_mismatch() {
+        let err = mock_providers(1, 8453).verify_chain_ids(Some(1), 10).await.unwrap_err();
+        assert!(matches!(
+            err,
+            SingleChainHostError::L2ChainIdMismatch { expected: 10, actual: 8453 }
+        ));
+        assert_eq!(err.to_string(), "L2 provider chain ID mismatch: expected 10, got 8453");
+    }
+
+    #[test]
+    fn test_check_l1_head() {
+        let mut rollup_config =
+            RollupConfig { block_time: 2, max_sequencer_drift: 600, ..Default::default() };
+        rollup_config.genesis.l2_time = 1_000;
+        let host = SingleChainHost { claimed_l2_block_number: 1_000_000, ..Default::default() };
+
+        // The claimed L2 block is at timestamp 2_001_000; its L1 origin may lag by 600 seconds.
+        host.check_l1_head(&rollup_config, 166_700, 2_000_400).unwrap();
+        host.check_l1_head(&rollup_config, 166_800, 2_001_000).unwrap();
+
+        let err = host.check_l1_head(&rollup_config, 100, 1_200).unwrap_err();
+        assert!(matches!(
+            err,
+            SingleChainHostError::L1HeadTooLow {
+                l1_head_number: 100,
+                l1_head_timestamp: 1_200,
+                claimed_l2_block_number: 1_000_000,
+                min_l1_origin_timestamp: 2_000_400,
+                ..
+            }
+        ));
+
+        let host = SingleChainHost { claimed_l2_block_number: u64::MAX, ..Default::default() };
+        assert!(matches!(
+            host.check_l1_head(&rollup_config, 166_800, 2_001_000),
+            Err(SingleChainHostError::ClaimedTimestampOverflow(u64::MAX))
+        ));
+    }
 
     #[test]
     fn test_flags() {
@@ -352,4 +661,35 @@ mod test {
             assert_eq!(parsed.is_ok(), valid);
         }
     }
+
+    #[test]
+    fn test_slow_request_threshold() {
+        let zero_hash_str = &B256::ZERO.to_string();
+        let args = [
+            "single",
+            "--l1-head",
+            zero_hash_str,
+            "--l2-head",
+            zero_hash_str,
+            "--l2-output-root",
+            zero_hash_str,
+            "--l2-claim",
+            zero_hash_str,
+            "--l2-block-number",
+            "0",
+            "--server",
+            "--l2-chain-id",
+            "0",
+            "--data-dir",
+            "dummy",
+        ];
+
+        let host = SingleChainHost::parse_from(args);
+        assert_eq!(host.slow_request_threshold(), DEFAULT_SLOW_REQUEST_THRESHOLD);
+
+        let host = SingleChainHost::parse_from(
+            args.into_iter().chain(["--slow-request-threshold-ms", "250"]),
+        );
+        assert_eq!(host.slow_request_threshold(), Duration::from_millis(250));
+    }
 }
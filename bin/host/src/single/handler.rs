@@ -9,7 +9,7 @@ use alloy_eips::{
     eip2718::Encodable2718,
     eip4844::{FIELD_ELEMENTS_PER_BLOB, IndexedBlobHash},
 };
-use alloy_primitives::{Address, B256, Bytes, address, keccak256};
+use alloy_primitives::{Address, B256, Bytes, U256, address, keccak256};
 use alloy_provider::Provider;
 use alloy_rlp::Decodable;
 use alloy_rpc_types::{Block, debug::ExecutionWitness};
@@ -331,6 +331,41 @@ impl HintHandler for SingleChainHintHandler {
                     Ok::<(), anyhow::Error>(())
                 })?;
             }
+            HintType::L2AccountStorageRange => {
+                // The maximum number of slots that may be requested by a single hint.
+                const MAX_SLOT_COUNT: u64 = 1024;
+
+                // block_number (8) ++ address (20) ++ start_slot (32) ++ count (8)
+                ensure!(hint.data.len() == 8 + 20 + 32 + 8, "Invalid hint data length");
+
+                let block_number = u64::from_be_bytes(hint.data.as_ref()[..8].try_into()?);
+                let address = Address::from_slice(&hint.data.as_ref()[8..28]);
+                let start_slot = U256::from_be_slice(&hint.data.as_ref()[28..60]);
+                let count = u64::from_be_bytes(hint.data.as_ref()[60..].try_into()?);
+                ensure!(
+                    count > 0 && count <= MAX_SLOT_COUNT,
+                    "Invalid storage range length {count}, expected 1..={MAX_SLOT_COUNT}"
+                );
+                ensure!(
+                    start_slot.checked_add(U256::from(count - 1)).is_some(),
+                    "Storage range overflows the slot space"
+                );
+
+                let slots =
+                    (0..count).map(|i| B256::from(start_slot + U256::from(i))).collect::<Vec<_>>();
+                let proof_response =
+                    providers.l2.get_proof(address, slots).block_id(block_number.into()).await?;
+
+                // Write the account proof nodes and the storage proof nodes of every slot in the
+                // range to the key-value store.
+                let mut kv_lock = kv.write().await;
+                let storage_proof_nodes =
+                    proof_response.storage_proof.into_iter().flat_map(|proof| proof.proof);
+                for node in proof_response.account_proof.into_iter().chain(storage_proof_nodes) {
+                    let key = PreimageKey::new_keccak256(*keccak256(node.as_ref()));
+                    kv_lock.set(key.into(), node.into())?;
+                }
+            }
             HintType::L2PayloadWitness => {
                 ensure!(hint.data.len() >= 32, "Invalid hint data length");
 
@@ -371,3 +406,97 @@ impl HintHandler for SingleChainHintHandler {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{MemoryKeyValueStore, single::SingleChainProviders};
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_transport::mock::Asserter;
+    use kona_providers_alloy::{OnlineBeaconClient, OnlineBlobProvider};
+    use serde_json::json;
+    use std::{num::NonZeroUsize, sync::Arc};
+    use tokio::sync::RwLock;
+
+    /// Returns [SingleChainProviders] whose L2 provider serves the responses of the given
+    /// [Asserter].
+    fn mock_providers(l2: Asserter) -> SingleChainProviders {
+        SingleChainProviders {
+            l1: RootProvider::new(RpcClient::mocked(Asserter::new())),
+            blobs: OnlineBlobProvider::new(
+                OnlineBeaconClient::new_http("http://localhost:5052".to_string()),
+                0,
+                12,
+                NonZeroUsize::new(1).unwrap(),
+            ),
+            l2: RootProvider::new(RpcClient::mocked(l2)),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_l2_account_storage_range() {
+        let address = Address::repeat_byte(0xAA);
+        let account_proof = [Bytes::from_static(&[0x01; 40]), Bytes::from_static(&[0x02; 40])];
+        let storage_proofs = (0u8..3)
+            .map(|i| {
+                json!({
+                    "key": B256::from(U256::from(5 + i)),
+                    "value": U256::from(i),
+                    "proof": [Bytes::from(vec![0x10 + i; 40]), Bytes::from(vec![0x20 + i; 40])],
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let l2 = Asserter::new();
+        l2.push_success(&json!({
+            "address": address,
+            "balance": U256::from(1),
+            "codeHash": B256::repeat_byte(0xCC),
+            "nonce": U256::from(1),
+            "storageHash": B256::repeat_byte(0xDD),
+            "accountProof": account_proof,
+            "storageProof": storage_proofs,
+        }));
+        let providers = mock_providers(l2);
+
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+        let hint = HintType::L2AccountStorageRange.with_data(&[
+            &10u64.to_be_bytes(),
+            address.as_slice(),
+            B256::from(U256::from(5)).as_slice(),
+            &3u64.to_be_bytes(),
+        ]);
+        let cfg = SingleChainHost::default();
+        SingleChainHintHandler::fetch_hint(hint, &cfg, &providers, kv.clone()).await.unwrap();
+
+        // The account proof and the storage proofs of all slots in the range are stored.
+        let kv = kv.read().await;
+        let storage_nodes = (0u8..3).flat_map(|i| [vec![0x10 + i; 40], vec![0x20 + i; 40]]);
+        for node in account_proof.iter().map(|n| n.to_vec()).chain(storage_nodes) {
+            let key = PreimageKey::new_keccak256(*keccak256(&node));
+            assert_eq!(kv.get(key.into()), Some(node));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_l2_account_storage_range_invalid_count() {
+        let providers = mock_providers(Asserter::new());
+        let kv: SharedKeyValueStore = Arc::new(RwLock::new(MemoryKeyValueStore::new()));
+        let cfg = SingleChainHost::default();
+
+        for count in [0u64, 1025] {
+            let hint = HintType::L2AccountStorageRange.with_data(&[
+                &10u64.to_be_bytes(),
+                Address::ZERO.as_slice(),
+                B256::ZERO.as_slice(),
+                &count.to_be_bytes(),
+            ]);
+            assert!(
+                SingleChainHintHandler::fetch_hint(hint, &cfg, &providers, kv.clone())
+                    .await
+                    .is_err()
+            );
+        }
+    }
+}
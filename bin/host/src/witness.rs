@@ -0,0 +1,581 @@
+//! Contains the [Witness] type, a portable set of preimages consumed by a proof run, and the
+//! [InMemoryOracle] that serves it.
+
+use crate::{AccessLog, KeyValueStore, eth::PrecompileAccelerator};
+use alloy_eips::eip4844::FIELD_ELEMENTS_PER_BLOB;
+use alloy_primitives::{Address, B256, keccak256};
+use ark_ff::{BigInteger, PrimeField};
+use async_trait::async_trait;
+use kona_preimage::{
+    HintWriterClient, PreimageKey, PreimageKeyType, PreimageOracleClient, ROOTS_OF_UNITY,
+    errors::{BlobVerificationError, PreimageOracleError, PreimageOracleResult},
+    verify_blob_preimages,
+};
+use rkyv::{Archive, Deserialize, Serialize, rancor, util::AlignedVec};
+use std::{
+    collections::{BTreeSet, HashMap},
+    path::Path,
+};
+
+/// The length of a blob key, `commitment ++ z`.
+const BLOB_KEY_LENGTH: usize = 80;
+
+/// An error that can occur when exporting or loading a [Witness].
+#[derive(Debug, thiserror::Error)]
+pub enum WitnessError {
+    /// An IO error.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    /// The witness could not be (de)serialized.
+    #[error("Witness serialization error: {0}")]
+    Serialization(#[from] rancor::Error),
+    /// The preimage does not hash to its keccak256 [PreimageKey].
+    #[error("Preimage does not match keccak256 key {0}")]
+    KeccakMismatch(PreimageKey),
+    /// The [PreimageKeyType::GlobalGeneric] key does not identify a known global preimage.
+    #[error("Unknown global generic preimage key {0}")]
+    UnknownGlobalGeneric(PreimageKey),
+    /// The [PreimageKeyType::Precompile] key does not have a well-formed keccak256 preimage for
+    /// its precompile call.
+    #[error("Missing or malformed precompile input for key {0}")]
+    MissingPrecompileInput(PreimageKey),
+    /// The precompile is not registered with the [PrecompileAccelerator].
+    #[error("Precompile {0} is not accelerated")]
+    UnknownPrecompile(Address),
+    /// The preimage does not match the result of its precompile call.
+    #[error("Preimage does not match precompile result for key {0}")]
+    PrecompileMismatch(PreimageKey),
+    /// The key does not have a valid [PreimageKeyType].
+    #[error("Invalid preimage key {0}")]
+    InvalidKey(B256),
+    /// The [PreimageKeyType::Blob] preimages do not reassemble into blobs that match their KZG
+    /// commitments.
+    #[error("Invalid blob preimages: {0}")]
+    Blob(#[from] BlobVerificationError),
+}
+
+/// A deduplicated set of preimages, keyed by their raw [PreimageKey], that can be used to replay a
+/// proof run without a host.
+///
+/// The witness is serialized with [rkyv], allowing zkVM hosts to load it as an [InMemoryOracle].
+#[derive(Archive, Serialize, Deserialize, Default, Clone, Debug, PartialEq, Eq)]
+pub struct Witness {
+    /// The `(key, preimage)` pairs, sorted by key.
+    pub preimages: Vec<([u8; 32], Vec<u8>)>,
+}
+
+impl Witness {
+    /// Collects the preimages of all keys in the [AccessLog] from the given [KeyValueStore].
+    ///
+    /// The auxiliary preimages that [InMemoryOracle::verify] requires to verify the accessed
+    /// preimages are collected as well, even though the client never requests them.
+    pub fn from_access_log<KV>(kv_store: &KV, access_log: &AccessLog) -> Self
+    where
+        KV: KeyValueStore + ?Sized,
+    {
+        let mut keys = BTreeSet::new();
+        for key in access_log.keys() {
+            if let Ok(preimage_key) = PreimageKey::try_from(key.0) {
+                keys.extend(Self::auxiliary_keys(kv_store, preimage_key));
+            }
+            keys.insert(key);
+        }
+
+        let preimages = keys
+            .into_iter()
+            .filter_map(|key| kv_store.get(key).map(|value| (key.0, value)))
+            .collect();
+        Self { preimages }
+    }
+
+    /// Returns the keys of the preimages that are required to verify the preimage of `key`.
+    fn auxiliary_keys<KV>(kv_store: &KV, key: PreimageKey) -> Vec<B256>
+    where
+        KV: KeyValueStore + ?Sized,
+    {
+        match key.key_type() {
+            // The precompile call is the keccak256 preimage with the same digest.
+            PreimageKeyType::Precompile => vec![PreimageKey::new_keccak256(key.into()).into()],
+            PreimageKeyType::Blob => {
+                // The `commitment ++ z` blob key is the keccak256 preimage with the same digest.
+                let blob_key = PreimageKey::new_keccak256(key.into());
+                let mut keys = vec![blob_key.into()];
+
+                // The blob is verified against its commitment with the KZG proof, which the host
+                // stores as the 4096th element of the blob. Its blob key is the commitment
+                // followed by the last root of unity, with the last 8 bytes replaced by the
+                // big-endian element index.
+                if let Some(preimage) =
+                    kv_store.get(blob_key.into()).filter(|p| p.len() == BLOB_KEY_LENGTH)
+                {
+                    let mut proof_key = [0u8; BLOB_KEY_LENGTH];
+                    proof_key[..48].copy_from_slice(&preimage[..48]);
+                    proof_key[48..].copy_from_slice(
+                        &ROOTS_OF_UNITY[FIELD_ELEMENTS_PER_BLOB as usize - 1]
+                            .into_bigint()
+                            .to_bytes_be(),
+                    );
+                    proof_key[72..].copy_from_slice(&FIELD_ELEMENTS_PER_BLOB.to_be_bytes());
+                    let proof_hash = keccak256(proof_key);
+                    keys.push(PreimageKey::new_keccak256(*proof_hash).into());
+                    keys.push(PreimageKey::new(*proof_hash, PreimageKeyType::Blob).into());
+                }
+                keys
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Serializes the [Witness] into bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, WitnessError> {
+        Ok(rkyv::to_bytes::<rancor::Error>(self)?.to_vec())
+    }
+
+    /// Deserializes a [Witness] from bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, WitnessError> {
+        // The archive must be aligned in order to be validated.
+        let mut aligned = AlignedVec::<16>::with_capacity(bytes.len());
+        aligned.extend_from_slice(bytes);
+        Ok(rkyv::from_bytes::<Self, rancor::Error>(&aligned)?)
+    }
+
+    /// Writes the serialized [Witness] to the given path.
+    pub fn write(&self, path: &Path) -> Result<(), WitnessError> {
+        std::fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+
+    /// Reads a serialized [Witness] from the given path.
+    pub fn read(path: &Path) -> Result<Self, WitnessError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+}
+
+/// A preimage oracle that serves preimages from a [Witness], ignoring all hints.
+#[derive(Default, Clone, Debug)]
+pub struct InMemoryOracle {
+    /// The preimages, keyed by their raw [PreimageKey].
+    preimages: HashMap<B256, Vec<u8>>,
+    /// The precompiles used to verify [PreimageKeyType::Precompile] preimages.
+    accelerator: PrecompileAccelerator,
+}
+
+impl InMemoryOracle {
+    /// Creates a new, empty [InMemoryOracle]. Preimages can be accumulated with
+    /// [InMemoryOracle::insert].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a new [InMemoryOracle] from a [Witness].
+    pub fn from_witness(witness: Witness) -> Self {
+        let preimages = witness.preimages.into_iter().map(|(k, v)| (B256::from(k), v)).collect();
+        Self { preimages, accelerator: PrecompileAccelerator::default() }
+    }
+
+    /// Sets the [PrecompileAccelerator] used to verify [PreimageKeyType::Precompile] preimages.
+    pub fn with_precompile_accelerator(mut self, accelerator: PrecompileAccelerator) -> Self {
+        self.accelerator = accelerator;
+        self
+    }
+
+    /// Creates a new [InMemoryOracle] from a serialized [Witness].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, WitnessError> {
+        Ok(Self::from_witness(Witness::from_bytes(bytes)?))
+    }
+
+    /// Creates a new [InMemoryOracle] from a [Witness] file written by
+    /// [InMemoryOracle::to_file] or [Witness::write].
+    pub fn from_file(path: &Path) -> Result<Self, WitnessError> {
+        Ok(Self::from_witness(Witness::read(path)?))
+    }
+
+    /// Inserts a preimage into the oracle, replacing any existing preimage for the key.
+    ///
+    /// The preimage is verified against its key as described in [InMemoryOracle::verify], so that
+    /// mistakes are caught while accumulating preimages rather than when replaying a proof run.
+    /// [PreimageKeyType::Precompile] preimages are only verified by [InMemoryOracle::verify], as
+    /// the input of their precompile call may be inserted afterwards.
+    pub fn insert(&mut self, key: PreimageKey, value: Vec<u8>) -> Result<(), WitnessError> {
+        Self::verify_preimage(key, &value)?;
+        self.preimages.insert(B256::from(key), value);
+        Ok(())
+    }
+
+    /// Verifies every preimage in the oracle against its key.
+    ///
+    /// - [PreimageKeyType::Keccak256] preimages must hash to their key.
+    /// - [PreimageKeyType::GlobalGeneric] keys identify global constants from an agreed-upon
+    ///   registry. The registry is currently empty, so every such key is rejected with
+    ///   [WitnessError::UnknownGlobalGeneric].
+    /// - [PreimageKeyType::Precompile] preimages must match the result of re-executing their
+    ///   precompile call with the [PrecompileAccelerator]. The call is read from the
+    ///   [PreimageKeyType::Keccak256] preimage with the same digest.
+    /// - [PreimageKeyType::Blob] preimages must reassemble into complete blobs that match their KZG
+    ///   commitments, as described in [verify_blob_preimages].
+    /// - All other key types are trusted as-is.
+    pub fn verify(&self) -> Result<(), WitnessError> {
+        // The blob preimages are verified together with the keccak256 preimages that may hold
+        // their `commitment ++ z` blob keys.
+        let mut blob_entries = Vec::new();
+        for (key, value) in &self.preimages {
+            let key = PreimageKey::try_from(key.0).map_err(|_| WitnessError::InvalidKey(*key))?;
+            Self::verify_preimage(key, value)?;
+            match key.key_type() {
+                PreimageKeyType::Precompile => self.verify_precompile(key, value)?,
+                PreimageKeyType::Blob => blob_entries.push((key, value.clone())),
+                PreimageKeyType::Keccak256 if value.len() == BLOB_KEY_LENGTH => {
+                    blob_entries.push((key, value.clone()))
+                }
+                _ => {}
+            }
+        }
+        verify_blob_preimages(&blob_entries)?;
+        Ok(())
+    }
+
+    /// Verifies a [PreimageKeyType::Precompile] preimage against the result of its precompile
+    /// call. See [InMemoryOracle::verify].
+    fn verify_precompile(&self, key: PreimageKey, value: &[u8]) -> Result<(), WitnessError> {
+        // The call is committed to as `address ++ gas ++ input`, keyed by its keccak256 digest.
+        let call_key = PreimageKey::new_keccak256(key.into());
+        let call = self
+            .preimages
+            .get(&B256::from(call_key))
+            .filter(|call| call.len() >= 28)
+            .ok_or(WitnessError::MissingPrecompileInput(key))?;
+        let address = Address::from_slice(&call[..20]);
+        let mut gas = [0u8; 8];
+        gas.copy_from_slice(&call[20..28]);
+        if !self.accelerator.contains(&address) {
+            return Err(WitnessError::UnknownPrecompile(address));
+        }
+
+        // The result is prefixed with a status byte, as written by the host's hint handlers.
+        let expected = self
+            .accelerator
+            .execute(address, call[28..].to_vec(), u64::from_be_bytes(gas))
+            .map_or_else(
+                |_| vec![0u8; 1],
+                |output| {
+                    let mut res = Vec::with_capacity(1 + output.len());
+                    res.push(0x01);
+                    res.extend_from_slice(&output);
+                    res
+                },
+            );
+        if value != expected {
+            return Err(WitnessError::PrecompileMismatch(key));
+        }
+        Ok(())
+    }
+
+    /// Verifies a single preimage against its key. See [InMemoryOracle::verify].
+    fn verify_preimage(key: PreimageKey, value: &[u8]) -> Result<(), WitnessError> {
+        match key.key_type() {
+            PreimageKeyType::Keccak256 if PreimageKey::new_keccak256(*keccak256(value)) != key => {
+                Err(WitnessError::KeccakMismatch(key))
+            }
+            PreimageKeyType::GlobalGeneric => Err(WitnessError::UnknownGlobalGeneric(key)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the number of preimages in the oracle.
+    pub fn len(&self) -> usize {
+        self.preimages.len()
+    }
+
+    /// Returns `true` if the oracle holds no preimages.
+    pub fn is_empty(&self) -> bool {
+        self.preimages.is_empty()
+    }
+
+    /// Converts the preimages of the oracle into a [Witness].
+    pub fn to_witness(&self) -> Witness {
+        let mut preimages =
+            self.preimages.iter().map(|(k, v)| (k.0, v.clone())).collect::<Vec<_>>();
+        preimages.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+        Witness { preimages }
+    }
+
+    /// Writes the preimages of the oracle to the given path as a serialized [Witness].
+    pub fn to_file(&self, path: &Path) -> Result<(), WitnessError> {
+        self.to_witness().write(path)
+    }
+}
+
+#[async_trait]
+impl PreimageOracleClient for InMemoryOracle {
+    async fn get(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
+        self.preimages.get(&B256::from(key)).cloned().ok_or(PreimageOracleError::KeyNotFound)
+    }
+
+    async fn get_exact(&self, key: PreimageKey, buf: &mut [u8]) -> PreimageOracleResult<()> {
+        let value = self.preimages.get(&B256::from(key)).ok_or(PreimageOracleError::KeyNotFound)?;
+        if value.len() != buf.len() {
+            return Err(PreimageOracleError::BufferLengthMismatch(value.len(), buf.len()));
+        }
+        buf.copy_from_slice(value);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl HintWriterClient for InMemoryOracle {
+    async fn write(&self, _hint: &str) -> PreimageOracleResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{AccessLogKeyValueStore, MemoryKeyValueStore, OfflineHostBackend, PreimageServer};
+    use alloy_primitives::keccak256;
+    use kona_preimage::{
+        BidirectionalChannel, HintReader, OracleReader, OracleServer, PreimageKeyType,
+    };
+    use revm::precompile::{PrecompileOutput, PrecompileResult};
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+
+    fn keccak_key(data: &[u8]) -> PreimageKey {
+        PreimageKey::new(*keccak256(data), PreimageKeyType::Keccak256)
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_oracle_insert() {
+        let mut oracle = InMemoryOracle::new();
+        assert!(oracle.is_empty());
+
+        oracle.insert(keccak_key(b"preimage"), b"preimage".to_vec()).unwrap();
+        assert_eq!(oracle.get(keccak_key(b"preimage")).await.unwrap(), b"preimage");
+
+        // Non-keccak preimages are not verified.
+        let local_key = PreimageKey::new_local(1);
+        oracle.insert(local_key, b"local".to_vec()).unwrap();
+        assert_eq!(oracle.get(local_key).await.unwrap(), b"local");
+        assert_eq!(oracle.len(), 2);
+    }
+
+    #[test]
+    fn test_in_memory_oracle_insert_keccak_mismatch() {
+        let mut oracle = InMemoryOracle::new();
+        let key = keccak_key(b"preimage");
+
+        let err = oracle.insert(key, b"not the preimage".to_vec()).unwrap_err();
+        assert!(matches!(err, WitnessError::KeccakMismatch(k) if k == key));
+        assert!(oracle.is_empty());
+    }
+
+    #[test]
+    fn test_in_memory_oracle_unknown_global_generic() {
+        let key = PreimageKey::new([0xff; 32], PreimageKeyType::GlobalGeneric);
+
+        let mut oracle = InMemoryOracle::new();
+        let err = oracle.insert(key, b"constant".to_vec()).unwrap_err();
+        assert!(matches!(err, WitnessError::UnknownGlobalGeneric(k) if k == key));
+        assert!(oracle.is_empty());
+
+        // Witnesses loaded from elsewhere are rejected by verification rather than panicking.
+        let mut witness = Witness { preimages: vec![(keccak_key(b"a").into(), b"a".to_vec())] };
+        let oracle = InMemoryOracle::from_witness(witness.clone());
+        oracle.verify().unwrap();
+        witness.preimages.push((key.into(), b"constant".to_vec()));
+        let oracle = InMemoryOracle::from_witness(witness);
+        assert!(matches!(oracle.verify(), Err(WitnessError::UnknownGlobalGeneric(k)) if k == key));
+    }
+
+    /// A custom precompile that returns its input reversed.
+    fn reverse_precompile(input: &[u8], _gas: u64) -> PrecompileResult {
+        Ok(PrecompileOutput::new(0, input.iter().rev().copied().collect()))
+    }
+
+    #[test]
+    fn test_in_memory_oracle_custom_precompile() {
+        let address = Address::repeat_byte(0xaa);
+        let mut call = address.to_vec();
+        call.extend_from_slice(&100_000u64.to_be_bytes());
+        call.extend_from_slice(b"input");
+        let call_key = keccak_key(&call);
+        let key = PreimageKey::new(call_key.into(), PreimageKeyType::Precompile);
+
+        let mut oracle = InMemoryOracle::new();
+        oracle.insert(call_key, call).unwrap();
+        oracle.insert(key, b"\x01tupni".to_vec()).unwrap();
+
+        // The precompile is not accelerated by default.
+        assert!(matches!(oracle.verify(), Err(WitnessError::UnknownPrecompile(a)) if a == address));
+
+        let accelerator =
+            PrecompileAccelerator::default().with_precompile(address, reverse_precompile);
+        let mut oracle = oracle.with_precompile_accelerator(accelerator);
+        oracle.verify().unwrap();
+
+        oracle.insert(key, b"\x01input".to_vec()).unwrap();
+        assert!(matches!(oracle.verify(), Err(WitnessError::PrecompileMismatch(k)) if k == key));
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_oracle_file_round_trip() {
+        const PREIMAGES: [&[u8]; 3] = [b"a", b"b", b"c"];
+
+        let mut oracle = InMemoryOracle::new();
+        for data in PREIMAGES {
+            oracle.insert(keccak_key(data), data.to_vec()).unwrap();
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("witness.bin");
+        oracle.to_file(&path).unwrap();
+
+        let loaded = InMemoryOracle::from_file(&path).unwrap();
+        assert_eq!(loaded.len(), PREIMAGES.len());
+        for data in PREIMAGES {
+            assert_eq!(loaded.get(keccak_key(data)).await.unwrap(), data);
+        }
+        assert_eq!(Witness::read(&path).unwrap(), oracle.to_witness());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_exported_witness_replays_run() {
+        const TOUCHED: [&[u8]; 2] = [b"touched-a", b"touched-b"];
+        const UNTOUCHED: &[u8] = b"untouched";
+
+        let mut mem = MemoryKeyValueStore::new();
+        for data in TOUCHED.into_iter().chain([UNTOUCHED]) {
+            mem.set(keccak_key(data).into(), data.to_vec()).unwrap();
+        }
+        let access_log = AccessLog::default();
+        let kv_store = Arc::new(RwLock::new(AccessLogKeyValueStore::new(mem, access_log.clone())));
+
+        // Serve a run that only requests the touched preimages, requesting one of them twice.
+        let hint = BidirectionalChannel::new().unwrap();
+        let preimage = BidirectionalChannel::new().unwrap();
+        let server = tokio::task::spawn(
+            PreimageServer::new(
+                OracleServer::new(preimage.host),
+                HintReader::new(hint.host),
+                Arc::new(OfflineHostBackend::new(kv_store.clone())),
+            )
+            .start(),
+        );
+        let reader = OracleReader::new(preimage.client);
+        for data in TOUCHED.into_iter().chain([TOUCHED[0]]) {
+            assert_eq!(reader.get(keccak_key(data)).await.unwrap(), data);
+        }
+        drop(reader);
+        drop(hint.client);
+        server.await.unwrap().unwrap();
+
+        let witness = Witness::from_access_log(&*kv_store.read().await, &access_log);
+        assert_eq!(witness.preimages.len(), TOUCHED.len());
+
+        // The exported witness alone must satisfy the same run.
+        let oracle = InMemoryOracle::from_bytes(&witness.to_bytes().unwrap()).unwrap();
+        for data in TOUCHED {
+            assert_eq!(oracle.get(keccak_key(data)).await.unwrap(), data);
+        }
+        assert!(matches!(
+            oracle.get(keccak_key(UNTOUCHED)).await,
+            Err(PreimageOracleError::KeyNotFound)
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_exported_witness_verifies_precompile() {
+        let address = Address::repeat_byte(0xaa);
+        let mut call = address.to_vec();
+        call.extend_from_slice(&100_000u64.to_be_bytes());
+        call.extend_from_slice(b"input");
+        let call_key = keccak_key(&call);
+        let key = PreimageKey::new(call_key.into(), PreimageKeyType::Precompile);
+
+        // The host stores the precompile call alongside its result.
+        let mut mem = MemoryKeyValueStore::new();
+        mem.set(call_key.into(), call).unwrap();
+        mem.set(key.into(), b"\x01tupni".to_vec()).unwrap();
+        let access_log = AccessLog::default();
+        let kv_store = Arc::new(RwLock::new(AccessLogKeyValueStore::new(mem, access_log.clone())));
+
+        // Serve a run that, like the client, only requests the precompile result.
+        let hint = BidirectionalChannel::new().unwrap();
+        let preimage = BidirectionalChannel::new().unwrap();
+        let server = tokio::task::spawn(
+            PreimageServer::new(
+                OracleServer::new(preimage.host),
+                HintReader::new(hint.host),
+                Arc::new(OfflineHostBackend::new(kv_store.clone())),
+            )
+            .start(),
+        );
+        let reader = OracleReader::new(preimage.client);
+        assert_eq!(reader.get(key).await.unwrap(), b"\x01tupni");
+        drop(reader);
+        drop(hint.client);
+        server.await.unwrap().unwrap();
+        assert_eq!(access_log.keys(), vec![B256::from(key)]);
+
+        let witness = Witness::from_access_log(&*kv_store.read().await, &access_log);
+        let accelerator =
+            PrecompileAccelerator::default().with_precompile(address, reverse_precompile);
+        let oracle = InMemoryOracle::from_bytes(&witness.to_bytes().unwrap())
+            .unwrap()
+            .with_precompile_accelerator(accelerator);
+        oracle.verify().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_exported_witness_verifies_blob() {
+        use alloy_eips::eip4844::builder::{SidecarBuilder, SimpleCoder};
+
+        let sidecar = SidecarBuilder::<SimpleCoder>::from_slice(b"blob data").build().unwrap();
+        let (blob, commitment, proof) =
+            (&sidecar.blobs[0], &sidecar.commitments[0], &sidecar.proofs[0]);
+
+        // Store the blob in the same layout as the host's blob hint handler.
+        let mut mem = MemoryKeyValueStore::new();
+        let mut field_element_keys = Vec::new();
+        let mut blob_key = [0u8; BLOB_KEY_LENGTH];
+        blob_key[..48].copy_from_slice(commitment.as_ref());
+        for (root, element) in ROOTS_OF_UNITY.iter().zip(blob.chunks_exact(32)) {
+            blob_key[48..].copy_from_slice(&root.into_bigint().to_bytes_be());
+            let hash = keccak256(blob_key);
+            mem.set(PreimageKey::new_keccak256(*hash).into(), blob_key.to_vec()).unwrap();
+            let key = PreimageKey::new(*hash, PreimageKeyType::Blob);
+            mem.set(key.into(), element.to_vec()).unwrap();
+            field_element_keys.push(key);
+        }
+        blob_key[72..].copy_from_slice(&FIELD_ELEMENTS_PER_BLOB.to_be_bytes());
+        let hash = keccak256(blob_key);
+        mem.set(PreimageKey::new_keccak256(*hash).into(), blob_key.to_vec()).unwrap();
+        mem.set(PreimageKey::new(*hash, PreimageKeyType::Blob).into(), proof.to_vec()).unwrap();
+        let access_log = AccessLog::default();
+        let kv_store = Arc::new(RwLock::new(AccessLogKeyValueStore::new(mem, access_log.clone())));
+
+        // Serve a run that, like the client, only requests the field elements of the blob.
+        let hint = BidirectionalChannel::new().unwrap();
+        let preimage = BidirectionalChannel::new().unwrap();
+        let server = tokio::task::spawn(
+            PreimageServer::new(
+                OracleServer::new(preimage.host),
+                HintReader::new(hint.host),
+                Arc::new(OfflineHostBackend::new(kv_store.clone())),
+            )
+            .start(),
+        );
+        let reader = OracleReader::new(preimage.client);
+        for (key, element) in field_element_keys.iter().zip(blob.chunks_exact(32)) {
+            assert_eq!(reader.get(*key).await.unwrap(), element);
+        }
+        drop(reader);
+        drop(hint.client);
+        server.await.unwrap().unwrap();
+        assert_eq!(access_log.keys().len(), FIELD_ELEMENTS_PER_BLOB as usize);
+
+        let witness = Witness::from_access_log(&*kv_store.read().await, &access_log);
+        let oracle = InMemoryOracle::from_bytes(&witness.to_bytes().unwrap()).unwrap();
+        oracle.verify().unwrap();
+    }
+}
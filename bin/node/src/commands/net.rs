@@ -2,8 +2,11 @@
 
 use crate::flags::{GlobalArgs, MetricsArgs, P2PArgs, RpcArgs};
 use clap::Parser;
+use jsonrpsee::server::ServerHandle;
 use kona_p2p::{NetRpcRequest, NetworkBuilder, NetworkRpc};
 use kona_rpc::{OpP2PApiServer, RpcConfig};
+use tokio::sync::broadcast::error::RecvError;
+use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
 /// The `net` Subcommand
@@ -51,6 +54,11 @@ impl NetCommand {
         let handle = launcher.start().await?;
         info!("Started RPC server on {:?}:{}", rpc_config.listen_addr, rpc_config.listen_port);
 
+        // A single cancellation token shared between the network and the RPC server.
+        // Stopping either component cancels the token, which in turn stops the other.
+        let cancellation = CancellationToken::new();
+        let rpc_shutdown = tokio::spawn(Self::rpc_shutdown(handle, cancellation.clone()));
+
         // Get the rollup config from the args
         let rollup_config = args
             .rollup_config()
@@ -65,7 +73,7 @@ impl NetCommand {
             .with_rollup_config(rollup_config)
             .build()?;
         let mut recv = network.unsafe_block_recv();
-        network.start()?;
+        network.start(cancellation.clone())?;
         info!("Network started, receiving blocks.");
 
         // On an interval, use the rpc tx to request stats about the p2p network.
@@ -73,9 +81,23 @@ impl NetCommand {
 
         loop {
             tokio::select! {
+                _ = cancellation.cancelled() => {
+                    info!("Received shutdown signal, stopping the network");
+                    break;
+                }
+                _ = tokio::signal::ctrl_c() => {
+                    info!("Received interrupt, shutting down");
+                    cancellation.cancel();
+                    break;
+                }
                 payload = recv.recv() => {
                     match payload {
                         Ok(payload) => info!("Received unsafe payload: {:?}", payload.payload_hash),
+                        Err(RecvError::Closed) => {
+                            warn!("Network stopped");
+                            cancellation.cancel();
+                            break;
+                        }
                         Err(e) => debug!("Failed to receive unsafe payload: {:?}", e),
                     }
                 }
@@ -103,11 +125,69 @@ impl NetCommand {
                         }
                     }).await.unwrap();
                 }
-                _ = handle.clone().stopped() => {
-                    warn!("RPC server stopped");
-                    return Ok(());
+            }
+        }
+
+        // The cancellation token is cancelled on every exit from the loop above, which stops the
+        // network. Wait for the RPC server to finish shutting down.
+        rpc_shutdown.await?;
+        Ok(())
+    }
+
+    /// Ties the lifetime of the RPC server to the given [`CancellationToken`].
+    ///
+    /// If the RPC server stops on its own, the token is cancelled so the network shuts down.
+    /// If the token is cancelled first, the RPC server is stopped. In both cases, this
+    /// returns once the RPC server has fully stopped.
+    async fn rpc_shutdown(handle: ServerHandle, cancellation: CancellationToken) {
+        tokio::select! {
+            _ = handle.clone().stopped() => {
+                warn!("RPC server stopped");
+                cancellation.cancel();
+            }
+            _ = cancellation.cancelled() => {
+                info!("Received shutdown signal, stopping the RPC server");
+                if handle.stop().is_err() {
+                    debug!("RPC server already stopped");
                 }
+                handle.stopped().await;
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jsonrpsee::{RpcModule, server::Server};
+    use std::time::Duration;
+
+    async fn start_server() -> ServerHandle {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        server.start(RpcModule::new(()))
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_stops_rpc_server() {
+        let handle = start_server().await;
+        let cancellation = CancellationToken::new();
+        let shutdown = tokio::spawn(NetCommand::rpc_shutdown(handle.clone(), cancellation.clone()));
+
+        cancellation.cancel();
+
+        tokio::time::timeout(Duration::from_secs(5), shutdown).await.unwrap().unwrap();
+        assert!(handle.is_stopped());
+    }
+
+    #[tokio::test]
+    async fn test_rpc_server_stop_cancels_token() {
+        let handle = start_server().await;
+        let cancellation = CancellationToken::new();
+        let shutdown = tokio::spawn(NetCommand::rpc_shutdown(handle.clone(), cancellation.clone()));
+
+        handle.stop().unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), shutdown).await.unwrap().unwrap();
+        assert!(cancellation.is_cancelled());
+    }
+}
@@ -5,7 +5,7 @@ use anyhow::{Result, bail};
 use clap::Parser;
 use kona_engine::{EngineKind, SyncConfig, SyncMode};
 use kona_genesis::RollupConfig;
-use kona_node_service::{RollupNode, RollupNodeService};
+use kona_node_service::{NodeMode, RollupNode, RollupNodeService};
 use op_alloy_provider::ext::engine::OpEngineApi;
 use serde_json::from_reader;
 use std::{fs::File, path::PathBuf, sync::Arc};
@@ -39,6 +39,16 @@ pub struct NodeCommand {
     /// This MUST be a valid path to a file containing the hex-encoded JWT secret.
     #[arg(long, visible_alias = "l2.jwt-secret", env = "L2_ENGINE_AUTH")]
     pub l2_engine_jwt_secret: Option<PathBuf>,
+    /// Hex-encoded JWT secret for the auth-rpc endpoint of the execution client.
+    /// Takes precedence over reading the secret from a file.
+    #[arg(
+        long = "engine-jwt-secret",
+        visible_alias = "l2.jwt-secret-encoded",
+        env = "L2_ENGINE_JWT_SECRET",
+        conflicts_with = "l2_engine_jwt_secret",
+        value_parser = parse_jwt_secret
+    )]
+    pub l2_engine_jwt_encoded: Option<JwtSecret>,
     /// Path to a custom L2 rollup configuration file
     /// (overrides the default rollup configuration from the registry)
     #[arg(long, visible_alias = "rollup-cfg")]
@@ -52,6 +62,10 @@ pub struct NodeCommand {
         help = "The kind of engine client, used to control the behavior of optimism in respect to different types of engine clients. Supported engine clients are: [\"geth\", \"reth\", \"erigon\"]."
     )]
     pub l2_engine_kind: EngineKind,
+    /// The number of blocks the unsafe head may get ahead of the safe head before an alarm is
+    /// raised, which signals that the batcher is not keeping up. Disabled if not set.
+    #[arg(long, env = "SAFE_HEAD_LAG_ALARM")]
+    pub safe_head_lag_alarm: Option<u64>,
     /// P2P CLI arguments.
     #[command(flatten)]
     pub p2p_flags: P2PArgs,
@@ -117,7 +131,10 @@ impl NodeCommand {
         let p2p_config = self.p2p_flags.config(&cfg, args, Some(self.l1_eth_rpc.clone())).await?;
         let rpc_config = self.rpc_flags.into();
 
-        RollupNode::builder(cfg)
+        let mode =
+            if self.sequencer_flags.enabled { NodeMode::Sequencer } else { NodeMode::Validator };
+        let mut builder = RollupNode::builder(cfg)
+            .with_mode(mode)
             .with_jwt_secret(jwt_secret)
             .with_sync_config(sync_config)
             .with_l1_provider_rpc_url(self.l1_eth_rpc)
@@ -126,11 +143,18 @@ impl NodeCommand {
             .with_l2_engine_rpc_url(self.l2_engine_rpc)
             .with_p2p_config(p2p_config)
             .with_network_disabled(self.p2p_flags.disabled)
-            .with_rpc_config(rpc_config)
-            .build()
-            .start()
-            .await
-            .map_err(Into::into)
+            .with_rpc_config(rpc_config);
+        if let Some(block_time) = self.sequencer_flags.block_time {
+            builder = builder.with_sequencer_block_time(block_time);
+        }
+        if let Some(key) = self.p2p_flags.sequencer_key {
+            builder = builder.with_sequencer_key(key);
+        }
+        if let Some(threshold) = self.safe_head_lag_alarm {
+            builder = builder.with_safe_head_lag_threshold(threshold);
+        }
+
+        builder.build().start().await.map_err(Into::into)
     }
 
     /// Get the L2 rollup config, either from a file or the superchain registry.
@@ -152,10 +176,14 @@ impl NodeCommand {
         }
     }
 
-    /// Returns the JWT secret for the engine API
-    /// using the provided [PathBuf]. If the file is not found,
-    /// it will return the default JWT secret.
+    /// Returns the JWT secret for the engine API.
+    ///
+    /// The hex-encoded secret is used if provided. Otherwise, the secret is read from
+    /// the provided [PathBuf]. If the file is not found, it will return the default JWT secret.
     pub fn jwt_secret(&self) -> Option<JwtSecret> {
+        if let Some(secret) = self.l2_engine_jwt_encoded {
+            return Some(secret);
+        }
         if let Some(path) = &self.l2_engine_jwt_secret {
             if let Ok(secret) = std::fs::read_to_string(path) {
                 return JwtSecret::from_hex(secret).ok();
@@ -186,3 +214,93 @@ impl NodeCommand {
         )
     }
 }
+
+/// Parses a hex-encoded [JwtSecret] from the command line.
+fn parse_jwt_secret(hex: &str) -> Result<JwtSecret, String> {
+    JwtSecret::from_hex(hex).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_engine::Claims;
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    const SECRET: &str = "f79ae8046bc11c9927afe911db7143c51a806c4a537cc08e0d37140b0192f430";
+
+    fn parse(extra: &[&str]) -> Result<NodeCommand, clap::Error> {
+        let base = [
+            "node",
+            "--l1-eth-rpc",
+            "http://localhost:8545",
+            "--l1-beacon",
+            "http://localhost:5052",
+            "--l2-engine-rpc",
+            "http://localhost:8551",
+            "--l2-provider-rpc",
+            "http://localhost:9545",
+        ];
+        NodeCommand::try_parse_from(base.iter().chain(extra))
+    }
+
+    #[test]
+    fn test_engine_jwt_secret_hex() {
+        let cmd = parse(&["--engine-jwt-secret", SECRET]).unwrap();
+        assert_eq!(cmd.jwt_secret(), Some(JwtSecret::from_hex(SECRET).unwrap()));
+    }
+
+    #[test]
+    fn test_engine_jwt_secret_invalid_hex() {
+        assert!(parse(&["--engine-jwt-secret", "not-hex"]).is_err());
+    }
+
+    #[test]
+    fn test_engine_jwt_secret_conflicts_with_path() {
+        let args = ["--engine-jwt-secret", SECRET, "--l2-engine-jwt-secret", "jwt.hex"];
+        assert!(parse(&args).is_err());
+    }
+
+    #[test]
+    fn test_sequencer_flags() {
+        let cmd = parse(&[]).unwrap();
+        assert_eq!(cmd.sequencer_flags.block_time, None);
+        assert_eq!(cmd.p2p_flags.sequencer_key, None);
+
+        let args = [
+            "--sequencer.enabled",
+            "--sequencer.block-time-ms",
+            "500",
+            "--p2p.sequencer.key",
+            SECRET,
+        ];
+        let cmd = parse(&args).unwrap();
+        assert!(cmd.sequencer_flags.enabled);
+        assert_eq!(cmd.sequencer_flags.block_time, Some(std::time::Duration::from_millis(500)));
+        assert_eq!(cmd.p2p_flags.sequencer_key, Some(SECRET.parse().unwrap()));
+    }
+
+    #[test]
+    fn test_safe_head_lag_alarm() {
+        assert_eq!(parse(&[]).unwrap().safe_head_lag_alarm, None);
+
+        let cmd = parse(&["--safe-head-lag-alarm", "1800"]).unwrap();
+        assert_eq!(cmd.safe_head_lag_alarm, Some(1800));
+    }
+
+    #[test]
+    fn test_engine_jwt_token_fresh_iat() {
+        let secret = JwtSecret::from_hex(SECRET).unwrap();
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let token = secret.encode(&Claims { iat: now, exp: None }).unwrap();
+
+        // A JWT is composed of a header, claims, and signature.
+        assert_eq!(token.split('.').count(), 3);
+
+        // Validation checks the HS256 signature and that `iat` is within the allowed drift.
+        assert!(secret.validate(&token).is_ok());
+
+        // A stale `iat` must be rejected by the engine.
+        let stale = secret.encode(&Claims { iat: now - 3600, exp: None }).unwrap();
+        assert!(secret.validate(&stale).is_err());
+    }
+}
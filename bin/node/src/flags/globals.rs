@@ -2,7 +2,7 @@
 
 use alloy_primitives::Address;
 use clap::{ArgAction, Parser};
-use kona_cli::init_tracing_subscriber;
+use kona_cli::{LogFormat, cli_parsers::parse_log_format, init_tracing_subscriber};
 use kona_genesis::RollupConfig;
 use kona_registry::{OPCHAINS, ROLLUP_CONFIGS};
 use tracing_subscriber::EnvFilter;
@@ -15,6 +15,9 @@ pub struct GlobalArgs {
     /// By default, the verbosity level is set to 3 (info level).
     #[arg(long, short, global = true, default_value = "3", action = ArgAction::Count)]
     pub v: u8,
+    /// The format of the printed logs.
+    #[arg(long, global = true, value_parser = parse_log_format, default_value = "full")]
+    pub log_format: LogFormat,
     /// The L2 chain ID to use.
     #[arg(long, short = 'c', global = true, default_value = "10", help = "The L2 chain ID to use")]
     pub l2_chain_id: u64,
@@ -23,7 +26,7 @@ pub struct GlobalArgs {
 impl GlobalArgs {
     /// Initializes the telemetry stack and Prometheus metrics recorder.
     pub fn init_tracing(&self, filter: Option<EnvFilter>) -> anyhow::Result<()> {
-        Ok(init_tracing_subscriber(self.v, filter)?)
+        Ok(init_tracing_subscriber(self.v, self.log_format, filter)?)
     }
 
     /// Returns the [`RollupConfig`] for the [`GlobalArgs::l2_chain_id`] specified on the global
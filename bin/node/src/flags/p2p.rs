@@ -8,7 +8,7 @@ use crate::flags::GlobalArgs;
 use alloy_primitives::B256;
 use anyhow::Result;
 use clap::Parser;
-use discv5::{Enr, enr::k256};
+use discv5::Enr;
 use kona_genesis::RollupConfig;
 use kona_p2p::{Config, LocalNode, PeerMonitoring, PeerScoreLevel};
 use kona_sources::RuntimeLoader;
@@ -23,7 +23,7 @@ use tokio::time::Duration;
 use url::Url;
 
 /// P2P CLI Flags
-#[derive(Parser, Clone, Debug, PartialEq, Eq)]
+#[derive(Parser, Clone, Debug, PartialEq)]
 pub struct P2PArgs {
     /// Fully disable the P2P stack.
     #[arg(long = "p2p.disable", default_value = "false", env = "KONA_NODE_P2P_DISABLE")]
@@ -34,6 +34,8 @@ pub struct P2PArgs {
     /// Read the hex-encoded 32-byte private key for the peer ID from this txt file.
     /// Created if not already exists. Important to persist to keep the same network identity after
     /// restarting, maintaining the previous advertised identity.
+    /// The file holds the node's private key and must be protected: anyone able to read it can
+    /// impersonate the node on the network.
     #[arg(long = "p2p.priv.path", env = "KONA_NODE_P2P_PRIV_PATH")]
     pub priv_path: Option<PathBuf>,
     /// The hex-encoded 32-byte private key for the peer ID.
@@ -141,6 +143,25 @@ pub struct P2PArgs {
     #[arg(long = "p2p.ban.duration", default_value = "30", env = "KONA_NODE_P2P_BAN_DURATION")]
     pub ban_duration: u32,
 
+    /// The interval in seconds at which the scores of banned peers decay, allowing them to be
+    /// unbanned before their ban expires. A value of `0` disables score decay.
+    /// Note that for peers to be banned, the `p2p.ban.peers` flag must be set to `true`.
+    #[arg(
+        long = "p2p.ban.decay-interval",
+        default_value = "0",
+        env = "KONA_NODE_P2P_BAN_DECAY_INTERVAL"
+    )]
+    pub ban_decay_interval: u64,
+
+    /// The factor that the scores of banned peers are multiplied by at every decay interval.
+    /// Must be in the range `[0, 1)` for scores to decay.
+    #[arg(
+        long = "p2p.ban.decay-factor",
+        default_value = "0.9",
+        env = "KONA_NODE_P2P_BAN_DECAY_FACTOR"
+    )]
+    pub ban_decay_factor: f64,
+
     /// The interval in seconds to find peers using the discovery service.
     /// Defaults to 5 seconds.
     #[arg(
@@ -158,6 +179,26 @@ pub struct P2PArgs {
     #[arg(long = "p2p.redial", env = "KONA_NODE_P2P_REDIAL")]
     pub peer_redial: Option<u64>,
 
+    /// The number of gossip messages per second that each peer may send before its messages are
+    /// ignored. By default, or if set to 0, peers are not rate limited.
+    #[arg(long = "p2p.gossip.rate-limit", env = "KONA_NODE_P2P_GOSSIP_RATE_LIMIT")]
+    pub gossip_rate_limit: Option<u32>,
+
+    /// Refuse inbound connections from peers whose discovered ENR advertises a chain id other
+    /// than the chain id of the network.
+    #[arg(long = "p2p.chain-filter", default_value = "false", env = "KONA_NODE_P2P_CHAIN_FILTER")]
+    pub strict_chain_filter: bool,
+
+    /// With `p2p.chain-filter`, also refuse inbound connections from peers whose ENR is unknown.
+    /// Only the ENRs of discovered peers are known, so this refuses every inbound peer that was
+    /// not found through discovery.
+    #[arg(
+        long = "p2p.chain-filter.reject-unknown",
+        default_value = "false",
+        env = "KONA_NODE_P2P_CHAIN_FILTER_REJECT_UNKNOWN"
+    )]
+    pub reject_unknown_peers: bool,
+
     /// An optional list of bootnode ENRs to start the node with.
     #[arg(long = "p2p.bootnodes", value_delimiter = ',', env = "KONA_NODE_P2P_BOOTNODES")]
     pub bootnodes: Vec<Enr>,
@@ -168,6 +209,11 @@ pub struct P2PArgs {
     /// specified L2 chain ID.
     #[arg(long = "p2p.unsafe.block.signer", env = "KONA_NODE_P2P_UNSAFE_BLOCK_SIGNER")]
     pub unsafe_block_signer: Option<alloy_primitives::Address>,
+
+    /// The hex-encoded 32-byte private key used to sign the unsafe blocks of the sequencer before
+    /// they are gossiped. Sequenced blocks are not gossiped if no key is set.
+    #[arg(long = "p2p.sequencer.key", env = "KONA_NODE_P2P_SEQUENCER_KEY")]
+    pub sequencer_key: Option<B256>,
 }
 
 impl Default for P2PArgs {
@@ -195,11 +241,17 @@ impl Default for P2PArgs {
             ban_enabled: false,
             ban_threshold: 0,
             ban_duration: 30,
+            ban_decay_interval: 0,
+            ban_decay_factor: 0.9,
             discovery_interval: 5,
             bootnodes: Vec::new(),
             bootstore: None,
             peer_redial: None,
+            gossip_rate_limit: None,
+            strict_chain_filter: false,
+            reject_unknown_peers: false,
             unsafe_block_signer: None,
+            sequencer_key: None,
         }
     }
 }
@@ -317,10 +369,7 @@ impl P2PArgs {
         };
 
         let keypair = self.keypair().unwrap_or_else(|_| Keypair::generate_secp256k1());
-        let secp256k1_key = keypair.clone().try_into_secp256k1()
-            .map_err(|e| anyhow::anyhow!("Impossible to convert keypair to secp256k1. This is a bug since we only support secp256k1 keys: {e}"))?
-            .secret().to_bytes();
-        let local_node_key = k256::ecdsa::SigningKey::from_bytes(&secp256k1_key.into())
+        let local_node_key = kona_p2p::discovery_signing_key(&keypair)
             .map_err(|e| anyhow::anyhow!("Impossible to convert keypair to k256 signing key. This is a bug since we only support secp256k1 keys: {e}"))?;
 
         let discovery_address =
@@ -335,10 +384,13 @@ impl P2PArgs {
         let block_time = config.block_time;
 
         let monitor_peers = if self.ban_enabled {
-            Some(PeerMonitoring {
-                ban_duration: Duration::from_secs(self.ban_duration.into()),
-                ban_threshold: self.ban_threshold as f64,
-            })
+            Some(
+                PeerMonitoring::new(
+                    self.ban_threshold as f64,
+                    Duration::from_secs(self.ban_duration.into()),
+                )
+                .with_decay(Duration::from_secs(self.ban_decay_interval), self.ban_decay_factor),
+            )
         } else {
             None
         };
@@ -354,7 +406,7 @@ impl P2PArgs {
             discovery_interval: Duration::from_secs(self.discovery_interval),
             discovery_address,
             gossip_address,
-            keypair: self.keypair().unwrap_or_else(|_| Keypair::generate_secp256k1()),
+            keypair,
             unsafe_block_signer: self.unsafe_block_signer(config, args, l1_rpc).await?,
             gossip_config,
             scoring: self.scoring,
@@ -362,6 +414,9 @@ impl P2PArgs {
             monitor_peers,
             bootstore: self.bootstore.clone(),
             redial: self.peer_redial,
+            gossip_rate_limit: self.gossip_rate_limit,
+            strict_chain_filter: self.strict_chain_filter,
+            reject_unknown_peers: self.reject_unknown_peers,
             // It is ok to clone here since the config only happens at startup
             // and that we assume the number of bootnodes explicitly specified
             // through the CLI is small.
@@ -482,6 +537,41 @@ mod tests {
         assert_eq!(args.p2p.listen_ip, expected);
     }
 
+    #[test]
+    fn test_p2p_args_ban_decay() {
+        let args = MockCommand::parse_from([
+            "test",
+            "--p2p.ban.decay-interval",
+            "10",
+            "--p2p.ban.decay-factor",
+            "0.5",
+        ]);
+        assert_eq!(args.p2p.ban_decay_interval, 10);
+        assert_eq!(args.p2p.ban_decay_factor, 0.5);
+    }
+
+    #[test]
+    fn test_p2p_args_gossip_rate_limit() {
+        let args = MockCommand::parse_from(["test"]);
+        assert_eq!(args.p2p.gossip_rate_limit, None);
+        let args = MockCommand::parse_from(["test", "--p2p.gossip.rate-limit", "10"]);
+        assert_eq!(args.p2p.gossip_rate_limit, Some(10));
+    }
+
+    #[test]
+    fn test_p2p_args_chain_filter() {
+        let args = MockCommand::parse_from(["test"]);
+        assert!(!args.p2p.strict_chain_filter);
+        assert!(!args.p2p.reject_unknown_peers);
+        let args = MockCommand::parse_from([
+            "test",
+            "--p2p.chain-filter",
+            "--p2p.chain-filter.reject-unknown",
+        ]);
+        assert!(args.p2p.strict_chain_filter);
+        assert!(args.p2p.reject_unknown_peers);
+    }
+
     #[test]
     fn test_p2p_args_listen_tcp_port() {
         let args = MockCommand::parse_from(["test", "--p2p.listen.tcp", "1234"]);
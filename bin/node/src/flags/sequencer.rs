@@ -5,6 +5,7 @@
 //! [op-node]: https://github.com/ethereum-optimism/optimism/blob/develop/op-node/flags/flags.go#L233-L265
 
 use clap::Parser;
+use std::{num::ParseIntError, time::Duration};
 
 /// Sequencer CLI Flags
 #[derive(Parser, Clone, Debug, PartialEq, Eq)]
@@ -36,10 +37,27 @@ pub struct SequencerArgs {
     /// Forces the sequencer to strictly prepare the next L1 origin and create empty L2 blocks
     #[arg(long = "sequencer.recover", default_value = "false", env = "KONA_SSEQUENCER_RECOVER")]
     pub recover: bool,
+
+    /// The interval at which new blocks are built, in milliseconds. Defaults to the block time of
+    /// the rollup config. A shorter interval lets the sequencer catch up with the wall clock, such
+    /// as on devnets.
+    #[arg(
+        long = "sequencer.block-time-ms",
+        env = "KONA_SEQUENCER_BLOCK_TIME_MS",
+        value_parser = |arg: &str| -> Result<Duration, ParseIntError> {Ok(Duration::from_millis(arg.parse()?))}
+    )]
+    pub block_time: Option<Duration>,
 }
 
 impl Default for SequencerArgs {
     fn default() -> Self {
-        Self { enabled: false, stopped: false, max_safe_lag: 0, l1_confs: 4, recover: false }
+        Self {
+            enabled: false,
+            stopped: false,
+            max_safe_lag: 0,
+            l1_confs: 4,
+            recover: false,
+            block_time: None,
+        }
     }
 }
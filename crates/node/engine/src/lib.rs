@@ -11,9 +11,9 @@ extern crate tracing;
 
 mod task_queue;
 pub use task_queue::{
-    BuildTask, BuildTaskError, ConsolidateTask, ConsolidateTaskError, Engine, EngineTask,
-    EngineTaskError, EngineTaskExt, ForkchoiceTask, ForkchoiceTaskError, InsertUnsafeTask,
-    InsertUnsafeTaskError,
+    BuildOutcome, BuildTask, BuildTaskError, BuiltPayload, ConsolidateTask, ConsolidateTaskError,
+    Engine, EngineTask, EngineTaskError, EngineTaskExt, ForkchoiceTask, ForkchoiceTaskError,
+    InsertUnsafeTask, InsertUnsafeTaskError,
 };
 
 mod attributes;
@@ -29,7 +29,7 @@ mod sync;
 pub use sync::{SyncConfig, SyncMode, SyncStatus};
 
 mod state;
-pub use state::{EngineState, EngineStateBuilder, EngineStateBuilderError};
+pub use state::{EngineState, EngineStateBuilder, EngineStateBuilderError, EngineStateSnapshot};
 
 mod kinds;
 pub use kinds::EngineKind;
@@ -1,6 +1,8 @@
 //! An [`EngineState`] builder.
 
-use crate::{EngineClient, EngineState, SyncStatus, client::EngineClientError};
+use crate::{
+    EngineClient, EngineState, EngineStateSnapshot, SyncStatus, client::EngineClientError,
+};
 use alloy_eips::eip1898::BlockNumberOrTag;
 use kona_genesis::ChainGenesis;
 use thiserror::Error;
@@ -72,6 +74,21 @@ impl EngineStateBuilder {
         }
     }
 
+    /// Constructs a new [`EngineStateBuilder`] with the heads of an [`EngineStateSnapshot`],
+    /// restoring the forkchoice of a previous [`EngineState`] without fetching it from the
+    /// client.
+    pub const fn from_snapshot(
+        client: EngineClient,
+        genesis: ChainGenesis,
+        snapshot: EngineStateSnapshot,
+    ) -> Self {
+        let mut builder = Self::new(client, genesis);
+        builder.unsafe_head = Some(snapshot.unsafe_head);
+        builder.safe_head = Some(snapshot.safe_head);
+        builder.finalized_head = Some(snapshot.finalized_head);
+        builder
+    }
+
     /// Fetches the unsafe head block info if it is not already set.
     async fn fetch_unsafe_head(&mut self) -> Result<&mut Self, EngineStateBuilderError> {
         if self.unsafe_head.is_none() {
@@ -155,3 +172,56 @@ impl EngineStateBuilder {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_engine::JwtSecret;
+    use kona_genesis::RollupConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_build_from_snapshot() {
+        let client = EngineClient::new_http(
+            "http://127.0.0.1:8551".parse().unwrap(),
+            "http://127.0.0.1:8545".parse().unwrap(),
+            Arc::new(RollupConfig::default()),
+            JwtSecret::random(),
+        );
+
+        let mut state = EngineStateBuilder::from_snapshot(
+            client.clone(),
+            ChainGenesis::default(),
+            EngineStateSnapshot {
+                unsafe_head: L2BlockInfo::default(),
+                safe_head: L2BlockInfo::default(),
+                finalized_head: L2BlockInfo::default(),
+            },
+        )
+        .build()
+        .await
+        .unwrap();
+        let mut unsafe_head = L2BlockInfo::default();
+        unsafe_head.block_info.number = 3;
+        state.set_unsafe_head(unsafe_head);
+        let mut safe_head = L2BlockInfo::default();
+        safe_head.block_info.number = 2;
+        state.set_safe_head(safe_head);
+        let mut finalized_head = L2BlockInfo::default();
+        finalized_head.block_info.number = 1;
+        state.set_finalized_head(finalized_head);
+
+        // Heads restored from a snapshot must not be fetched from the (unreachable) engine.
+        let snapshot = state.to_snapshot();
+        let restored = EngineStateBuilder::from_snapshot(client, ChainGenesis::default(), snapshot)
+            .build()
+            .await
+            .unwrap();
+
+        assert_eq!(restored.unsafe_head(), state.unsafe_head());
+        assert_eq!(restored.safe_head(), state.safe_head());
+        assert_eq!(restored.finalized_head(), state.finalized_head());
+        assert_eq!(restored.to_snapshot(), snapshot);
+        assert_eq!(restored.create_forkchoice_state(), state.create_forkchoice_state());
+    }
+}
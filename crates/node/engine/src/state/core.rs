@@ -4,6 +4,19 @@ use crate::SyncStatus;
 use alloy_rpc_types_engine::ForkchoiceState;
 use kona_protocol::L2BlockInfo;
 
+/// A serializable snapshot of the forkchoice tracked by an [`EngineState`], allowing it to be
+/// checkpointed to disk and restored with [`crate::EngineStateBuilder::from_snapshot`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngineStateSnapshot {
+    /// The unsafe head.
+    pub unsafe_head: L2BlockInfo,
+    /// The safe head.
+    pub safe_head: L2BlockInfo,
+    /// The finalized head.
+    pub finalized_head: L2BlockInfo,
+}
+
 /// The chain state viewed by the engine controller.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct EngineState {
@@ -58,6 +71,15 @@ impl EngineState {
         }
     }
 
+    /// Returns an [`EngineStateSnapshot`] of the unsafe, safe, and finalized heads.
+    pub const fn to_snapshot(&self) -> EngineStateSnapshot {
+        EngineStateSnapshot {
+            unsafe_head: self.unsafe_head,
+            safe_head: self.safe_head,
+            finalized_head: self.finalized_head,
+        }
+    }
+
     /// Returns if consolidation is needed.
     ///
     /// [Consolidation] is only performed by a rollup node when the unsafe head
@@ -143,3 +165,23 @@ impl EngineState {
         self.need_fcu_call_backup_unsafe_reorg = reorg;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_snapshot_serde_roundtrip() {
+        let mut snapshot = EngineStateSnapshot {
+            unsafe_head: L2BlockInfo::default(),
+            safe_head: L2BlockInfo::default(),
+            finalized_head: L2BlockInfo::default(),
+        };
+        snapshot.unsafe_head.block_info.number = 3;
+        snapshot.safe_head.block_info.number = 2;
+        snapshot.finalized_head.block_info.number = 1;
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        assert_eq!(serde_json::from_str::<EngineStateSnapshot>(&json).unwrap(), snapshot);
+    }
+}
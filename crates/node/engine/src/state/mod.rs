@@ -1,7 +1,7 @@
 //! Engine State
 
 mod core;
-pub use core::EngineState;
+pub use core::{EngineState, EngineStateSnapshot};
 
 mod builder;
 pub use builder::{EngineStateBuilder, EngineStateBuilderError};
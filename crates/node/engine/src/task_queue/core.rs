@@ -30,16 +30,37 @@ impl Engine {
         Self { state: initial_state, tasks: VecDeque::new() }
     }
 
+    /// Returns a reference to the current [EngineState].
+    pub const fn state(&self) -> &EngineState {
+        &self.state
+    }
+
     /// Enqueues a new [EngineTask] for execution.
     pub fn enqueue(&mut self, task: EngineTask) {
         self.tasks.push_back(task);
     }
 
+    /// Returns `true` if there are no [EngineTask]s in the queue.
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
     /// Clears the task queue.
     pub fn clear(&mut self) {
         self.tasks.clear();
     }
 
+    /// Executes a single task directly against the [EngineState], bypassing the task queue.
+    ///
+    /// The task is attempted once, and its own result is returned. Unlike [Engine::drain], the
+    /// [EngineTask]s within the queue are neither executed nor cleared.
+    pub async fn execute<T>(&mut self, task: &T) -> Result<(), EngineTaskError>
+    where
+        T: EngineTaskExt + Sync,
+    {
+        task.execute(&mut self.state).await
+    }
+
     /// Attempts to drain the queue by executing all [EngineTask]s in-order. If any task returns an
     /// error along the way, it is not popped from the queue (in case it must be retried) and
     /// the error is returned.
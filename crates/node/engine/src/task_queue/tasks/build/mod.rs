@@ -1,7 +1,7 @@
 //! Task and its associated types for building and importing a new block.
 
 mod task;
-pub use task::BuildTask;
+pub use task::{BuildOutcome, BuildTask, BuiltPayload};
 
 mod error;
 pub use error::BuildTaskError;
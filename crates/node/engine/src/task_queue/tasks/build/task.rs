@@ -5,6 +5,7 @@ use crate::{
     EngineClient, EngineForkchoiceVersion, EngineGetPayloadVersion, EngineState, EngineTaskError,
     EngineTaskExt,
 };
+use alloy_primitives::B256;
 use alloy_provider::ext::EngineApi;
 use alloy_rpc_types_engine::{
     ExecutionPayload, ExecutionPayloadFieldV2, ExecutionPayloadInputV2, ForkchoiceState, PayloadId,
@@ -16,7 +17,30 @@ use kona_genesis::RollupConfig;
 use kona_protocol::L2BlockInfo;
 use kona_rpc::OpAttributesWithParent;
 use op_alloy_provider::ext::engine::OpEngineApi;
+use op_alloy_rpc_types_engine::OpExecutionPayload;
 use std::{sync::Arc, time::Instant};
+use tokio::sync::mpsc::UnboundedSender;
+
+/// A payload that was built and imported by a [BuildTask].
+#[derive(Debug, Clone)]
+pub struct BuiltPayload {
+    /// The [L2BlockInfo] of the imported block.
+    pub block: L2BlockInfo,
+    /// The built [OpExecutionPayload].
+    pub payload: OpExecutionPayload,
+    /// The parent beacon block root of the payload, from Ecotone onwards.
+    pub parent_beacon_block_root: Option<B256>,
+}
+
+/// The outcome of a [BuildTask], sent to its payload sender.
+#[derive(Debug, Clone)]
+pub enum BuildOutcome {
+    /// The payload was built and imported.
+    Built(BuiltPayload),
+    /// The payload failed to build or import with an error that the engine does not retry on its
+    /// own, along with the error message.
+    Failed(String),
+}
 
 /// The [BuildTask] is responsible for building new blocks and importing them via the engine API.
 #[derive(Debug, Clone)]
@@ -29,6 +53,9 @@ pub struct BuildTask {
     pub attributes: OpAttributesWithParent,
     /// Whether or not the payload was derived, or created by the sequencer.
     pub is_attributes_derived: bool,
+    /// An optional channel to send the [BuildOutcome] to once the payload is imported, or fails to
+    /// be.
+    pub payload_tx: Option<UnboundedSender<BuildOutcome>>,
 }
 
 impl BuildTask {
@@ -39,7 +66,16 @@ impl BuildTask {
         attributes: OpAttributesWithParent,
         is_attributes_derived: bool,
     ) -> Self {
-        Self { engine, cfg, attributes, is_attributes_derived }
+        Self { engine, cfg, attributes, is_attributes_derived, payload_tx: None }
+    }
+
+    /// Sets the channel that the [BuildOutcome] is sent to, such as for the sequencer to gossip the
+    /// imported payload, or to start over after a failure.
+    ///
+    /// Temporary failures are not sent, as the engine retries the task.
+    pub fn with_payload_sender(mut self, payload_tx: UnboundedSender<BuildOutcome>) -> Self {
+        self.payload_tx = Some(payload_tx);
+        self
     }
 
     /// Starts the block building process by sending an initial `engine_forkchoiceUpdate` call with
@@ -148,7 +184,7 @@ impl BuildTask {
         engine: &EngineClient,
         payload_id: PayloadId,
         payload_attrs: OpAttributesWithParent,
-    ) -> Result<L2BlockInfo, BuildTaskError> {
+    ) -> Result<BuiltPayload, BuildTaskError> {
         let payload_timestamp = payload_attrs.attributes.payload_attributes.timestamp;
 
         debug!(
@@ -159,7 +195,7 @@ impl BuildTask {
         );
 
         let get_payload_version = EngineGetPayloadVersion::from_cfg(cfg, payload_timestamp);
-        let (payload, response) = match get_payload_version {
+        let (payload, built, parent_beacon_block_root, response) = match get_payload_version {
             EngineGetPayloadVersion::V4 => {
                 let payload = engine.get_payload_v4(payload_id).await.map_err(|e| {
                     error!(target: "engine_builder", "Payload fetch failed: {e}");
@@ -176,7 +212,12 @@ impl BuildTask {
                         BuildTaskError::NewPayloadFailed(e)
                     })?;
 
-                (ExecutionPayload::V3(payload.execution_payload.payload_inner), response)
+                (
+                    ExecutionPayload::V3(payload.execution_payload.payload_inner.clone()),
+                    OpExecutionPayload::V4(payload.execution_payload),
+                    Some(payload.parent_beacon_block_root),
+                    response,
+                )
             }
             EngineGetPayloadVersion::V3 => {
                 let payload = engine.get_payload_v3(payload_id).await.map_err(|e| {
@@ -194,7 +235,12 @@ impl BuildTask {
                         BuildTaskError::NewPayloadFailed(e)
                     })?;
 
-                (ExecutionPayload::V3(payload.execution_payload), response)
+                (
+                    ExecutionPayload::V3(payload.execution_payload.clone()),
+                    OpExecutionPayload::V3(payload.execution_payload),
+                    Some(payload.parent_beacon_block_root),
+                    response,
+                )
             }
             EngineGetPayloadVersion::V2 => {
                 let payload = engine.get_payload_v2(payload_id).await.map_err(|e| {
@@ -220,7 +266,11 @@ impl BuildTask {
                     }
                 };
 
-                (payload.execution_payload.into_payload(), response)
+                let built = match &payload.execution_payload {
+                    ExecutionPayloadFieldV2::V2(payload) => OpExecutionPayload::V2(payload.clone()),
+                    ExecutionPayloadFieldV2::V1(payload) => OpExecutionPayload::V1(payload.clone()),
+                };
+                (payload.execution_payload.into_payload(), built, None, response)
             }
         };
 
@@ -236,7 +286,7 @@ impl BuildTask {
                     state.set_safe_head(imported_info);
                 }
 
-                Ok(imported_info)
+                Ok(BuiltPayload { block: imported_info, payload: built, parent_beacon_block_root })
             }
             PayloadStatusEnum::Invalid { validation_error } => {
                 if payload_attrs.is_deposits_only() {
@@ -259,6 +309,23 @@ impl BuildTask {
 #[async_trait]
 impl EngineTaskExt for BuildTask {
     async fn execute(&self, state: &mut EngineState) -> Result<(), EngineTaskError> {
+        let (outcome, res) = match self.build(state).await {
+            Ok(built) => (BuildOutcome::Built(built), Ok(())),
+            Err(e @ EngineTaskError::Temporary(_)) => return Err(e),
+            Err(e) => (BuildOutcome::Failed(e.to_string()), Err(e)),
+        };
+
+        if self.payload_tx.as_ref().is_some_and(|tx| tx.send(outcome).is_err()) {
+            warn!(target: "engine_builder", "Built payload receiver closed");
+        }
+
+        res
+    }
+}
+
+impl BuildTask {
+    /// Builds a new block from the attributes of the task, and imports it.
+    async fn build(&self, state: &mut EngineState) -> Result<BuiltPayload, EngineTaskError> {
         // Sanity check if the head is behind the finalized head. If it is, this is a critical
         // error.
         if state.unsafe_head().block_info.number < state.finalized_head().block_info.number {
@@ -282,7 +349,7 @@ impl EngineTaskExt for BuildTask {
 
         // Fetch the payload from the EL and import it into the engine.
         let block_import_start_time = Instant::now();
-        let new_block_ref = self
+        let built = self
             .fetch_and_import_payload(
                 state,
                 &self.cfg,
@@ -292,6 +359,7 @@ impl EngineTaskExt for BuildTask {
             )
             .await?;
         let block_import_duration = block_import_start_time.elapsed();
+        let new_block_ref = built.block;
 
         info!(
             target: "engine_builder",
@@ -303,6 +371,6 @@ impl EngineTaskExt for BuildTask {
             if self.is_attributes_derived { "safe" } else { "unsafe" },
         );
 
-        Ok(())
+        Ok(built)
     }
 }
@@ -10,7 +10,7 @@ mod insert;
 pub use insert::{InsertUnsafeTask, InsertUnsafeTaskError};
 
 mod build;
-pub use build::{BuildTask, BuildTaskError};
+pub use build::{BuildOutcome, BuildTask, BuildTaskError, BuiltPayload};
 
 mod consolidate;
 pub use consolidate::{ConsolidateTask, ConsolidateTaskError};
@@ -89,3 +89,51 @@ impl EngineGetPayloadVersion {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_genesis::HardForkConfig;
+
+    fn rollup_config() -> RollupConfig {
+        RollupConfig {
+            hardforks: HardForkConfig {
+                canyon_time: Some(10),
+                ecotone_time: Some(20),
+                isthmus_time: Some(30),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_forkchoice_version_at_hardfork_boundaries() {
+        let cfg = rollup_config();
+        assert_eq!(EngineForkchoiceVersion::from_cfg(&cfg, 9), EngineForkchoiceVersion::V1);
+        assert_eq!(EngineForkchoiceVersion::from_cfg(&cfg, 10), EngineForkchoiceVersion::V2);
+        assert_eq!(EngineForkchoiceVersion::from_cfg(&cfg, 19), EngineForkchoiceVersion::V2);
+        assert_eq!(EngineForkchoiceVersion::from_cfg(&cfg, 20), EngineForkchoiceVersion::V3);
+        assert_eq!(EngineForkchoiceVersion::from_cfg(&cfg, 30), EngineForkchoiceVersion::V3);
+    }
+
+    #[test]
+    fn test_new_payload_version_at_hardfork_boundaries() {
+        let cfg = rollup_config();
+        assert_eq!(EngineNewPayloadVersion::from_cfg(&cfg, 10), EngineNewPayloadVersion::V2);
+        assert_eq!(EngineNewPayloadVersion::from_cfg(&cfg, 19), EngineNewPayloadVersion::V2);
+        assert_eq!(EngineNewPayloadVersion::from_cfg(&cfg, 20), EngineNewPayloadVersion::V3);
+        assert_eq!(EngineNewPayloadVersion::from_cfg(&cfg, 29), EngineNewPayloadVersion::V3);
+        assert_eq!(EngineNewPayloadVersion::from_cfg(&cfg, 30), EngineNewPayloadVersion::V4);
+    }
+
+    #[test]
+    fn test_get_payload_version_at_hardfork_boundaries() {
+        let cfg = rollup_config();
+        assert_eq!(EngineGetPayloadVersion::from_cfg(&cfg, 10), EngineGetPayloadVersion::V2);
+        assert_eq!(EngineGetPayloadVersion::from_cfg(&cfg, 19), EngineGetPayloadVersion::V2);
+        assert_eq!(EngineGetPayloadVersion::from_cfg(&cfg, 20), EngineGetPayloadVersion::V3);
+        assert_eq!(EngineGetPayloadVersion::from_cfg(&cfg, 29), EngineGetPayloadVersion::V3);
+        assert_eq!(EngineGetPayloadVersion::from_cfg(&cfg, 30), EngineGetPayloadVersion::V4);
+    }
+}
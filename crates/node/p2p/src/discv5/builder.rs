@@ -73,6 +73,8 @@ pub struct Discv5Builder {
     bootstore: Option<PathBuf>,
     /// Additional bootnodes to manually add to the initial bootstore
     bootnodes: Vec<Enr>,
+    /// An optional signing key that overrides the key of the [`LocalNode`].
+    signing_key: Option<k256::ecdsa::SigningKey>,
 }
 
 impl Discv5Builder {
@@ -85,6 +87,7 @@ impl Discv5Builder {
             discovery_config: None,
             bootstore: None,
             bootnodes: Vec::new(),
+            signing_key: None,
         }
     }
 
@@ -106,6 +109,12 @@ impl Discv5Builder {
         self
     }
 
+    /// Sets the signing key of the local node, overriding the key of the [`LocalNode`].
+    pub fn with_signing_key(mut self, signing_key: k256::ecdsa::SigningKey) -> Self {
+        self.signing_key = Some(signing_key);
+        self
+    }
+
     /// Sets the chain ID of the network.
     pub const fn with_chain_id(mut self, chain_id: u64) -> Self {
         self.chain_id = Some(chain_id);
@@ -130,7 +139,10 @@ impl Discv5Builder {
 
         let config = self.discovery_config.ok_or(Discv5BuilderError::DiscoveryConfigNotSet)?;
 
-        let local_node = self.local_node.ok_or(Discv5BuilderError::LocalNodeNotSet)?;
+        let mut local_node = self.local_node.ok_or(Discv5BuilderError::LocalNodeNotSet)?;
+        if let Some(signing_key) = self.signing_key {
+            local_node.signing_key = signing_key;
+        }
         let key = local_node.signing_key.clone();
 
         let enr = local_node.build_enr(chain_id).map_err(|_| Discv5BuilderError::EnrBuildFailed)?;
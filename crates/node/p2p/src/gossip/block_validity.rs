@@ -166,7 +166,7 @@ impl BlockHandler {
 
         // CHECK: The signature is valid.
         let msg = envelope.payload_hash.signature_message(self.chain_id);
-        let block_signer = *self.signer_recv.borrow();
+        let block_signer = self.signer.current();
 
         // The block has a valid signature.
         let Ok(msg_signer) = envelope.signature.recover_address_from_prehash(&msg) else {
@@ -573,6 +573,31 @@ pub(crate) mod tests {
         assert!(matches!(handler.block_valid(&envelope), Err(BlockInvalidError::Signer { .. })));
     }
 
+    /// Blocks signed by a rotated-out unsafe block signer should be rejected.
+    #[test]
+    fn test_signer_rotation() {
+        let envelope = |block: Block<OpTxEnvelope>| OpNetworkPayloadEnvelope {
+            payload: OpExecutionPayload::V1(ExecutionPayloadV1::from_block_slow(&block)),
+            signature: Signature::test_signature(),
+            payload_hash: PayloadHash(B256::ZERO),
+            parent_beacon_block_root: None,
+        };
+
+        let first = envelope(v1_valid_block());
+        let msg = first.payload_hash.signature_message(10);
+        let signer = first.signature.recover_address_from_prehash(&msg).unwrap();
+        let (signer_tx, signer_rx) = tokio::sync::watch::channel(signer);
+        let mut handler = BlockHandler::new(10, signer_rx);
+
+        assert!(handler.block_valid(&first).is_ok());
+
+        // Rotate the unsafe block signer, as if a system config update was observed.
+        signer_tx.send(Address::random()).unwrap();
+
+        let second = envelope(v1_valid_block());
+        assert!(matches!(handler.block_valid(&second), Err(BlockInvalidError::Signer { .. })));
+    }
+
     /// If we specify a non empty parent beacon block root for blocks with v1/v2 payloads we
     /// get a hash mismatch error because the decoder enforces that these versions of the execution
     /// payload don't contain the parent beacon block root.
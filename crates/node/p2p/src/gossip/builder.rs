@@ -5,12 +5,12 @@ use libp2p::{
     Multiaddr, SwarmBuilder, gossipsub::Config, identity::Keypair, noise::Config as NoiseConfig,
     tcp::Config as TcpConfig, yamux::Config as YamuxConfig,
 };
-use std::time::Duration;
+use std::{num::NonZeroU32, time::Duration};
 use tokio::sync::watch::Receiver;
 
 use crate::{
-    Behaviour, BlockHandler, GossipDriver, GossipDriverBuilderError, PeerScoreLevel,
-    peers::PeerMonitoring,
+    Behaviour, BlockHandler, BlockSignerSource, GossipDriver, GossipDriverBuilderError,
+    PeerScoreLevel, peers::PeerMonitoring,
 };
 
 /// A builder for the [`GossipDriver`].
@@ -24,8 +24,8 @@ pub struct GossipDriverBuilder {
     keypair: Option<Keypair>,
     /// The [`Multiaddr`] for the gossip driver to listen on.
     gossip_addr: Option<Multiaddr>,
-    /// Unsafe block signer [`Receiver`].
-    signer: Option<Receiver<Address>>,
+    /// The unsafe [`BlockSignerSource`].
+    signer: Option<BlockSignerSource>,
     /// Sets the [`PeerScoreLevel`] for the [`Behaviour`].
     scoring: Option<PeerScoreLevel>,
     /// The [`Config`] for the [`Behaviour`].
@@ -35,10 +35,23 @@ pub struct GossipDriverBuilder {
     /// If set, the gossip layer will monitor peer scores and ban peers that are below a given
     /// threshold.
     peer_monitoring: Option<PeerMonitoring>,
+    /// The interval and factor at which the scores of banned peers decay.
+    peer_decay: Option<(Duration, f64)>,
     /// The number of times to redial a peer.
     /// If unset, peers will not be redialed.
     /// If set to `0`, peers will be redialed indefinitely.
     peer_redial: Option<u64>,
+    /// Whether inbound peers whose [`Enr`] advertises another chain id are rejected.
+    ///
+    /// [`Enr`]: discv5::Enr
+    strict_chain_filter: bool,
+    /// Whether the strict chain filter also rejects inbound peers with an unknown [`Enr`].
+    ///
+    /// [`Enr`]: discv5::Enr
+    reject_unknown_peers: bool,
+    /// The number of gossip messages per second that each peer may send.
+    /// If unset or zero, peers are not rate limited.
+    gossip_rate_limit: Option<u32>,
 }
 
 impl GossipDriverBuilder {
@@ -54,10 +67,42 @@ impl GossipDriverBuilder {
             config: None,
             block_time: None,
             peer_monitoring: None,
+            peer_decay: None,
             peer_redial: None,
+            strict_chain_filter: false,
+            reject_unknown_peers: false,
+            gossip_rate_limit: None,
         }
     }
 
+    /// Sets the number of gossip messages per second that each peer may send. Messages from
+    /// peers exceeding the rate are ignored before they are decoded.
+    /// If unset or zero, peers are not rate limited.
+    pub const fn with_gossip_rate_limit(mut self, rate: Option<u32>) -> Self {
+        self.gossip_rate_limit = rate;
+        self
+    }
+
+    /// Sets whether inbound connections are refused from peers whose discovered [`Enr`]
+    /// advertises a chain id other than the chain id of the network. Peers with an unknown
+    /// [`Enr`] are accepted unless [`Self::with_reject_unknown_peers`] is set.
+    ///
+    /// [`Enr`]: discv5::Enr
+    pub const fn with_strict_chain_filter(mut self, strict_chain_filter: bool) -> Self {
+        self.strict_chain_filter = strict_chain_filter;
+        self
+    }
+
+    /// Sets whether the strict chain filter also refuses inbound connections from peers whose
+    /// [`Enr`] is unknown. Since only the [`Enr`]s of discovered peers are known, this refuses
+    /// every inbound peer that was not found through discovery.
+    ///
+    /// [`Enr`]: discv5::Enr
+    pub const fn with_reject_unknown_peers(mut self, reject_unknown_peers: bool) -> Self {
+        self.reject_unknown_peers = reject_unknown_peers;
+        self
+    }
+
     /// Sets the number of times to redial a peer.
     /// If unset, peers will not be redialed.
     /// If set to `0`, peers will be redialed indefinitely.
@@ -90,8 +135,21 @@ impl GossipDriverBuilder {
         self
     }
 
+    /// Sets the interval at which the scores of banned peers are multiplied by `decay_factor`,
+    /// allowing them to recover. Only applies if [`PeerMonitoring`] is configured.
+    pub const fn with_peer_decay(mut self, decay_interval: Duration, decay_factor: f64) -> Self {
+        self.peer_decay = Some((decay_interval, decay_factor));
+        self
+    }
+
     /// Sets the unsafe block signer [`Address`] [`Receiver`] channel.
     pub fn with_unsafe_block_signer_receiver(mut self, signer: Receiver<Address>) -> Self {
+        self.signer = Some(signer.into());
+        self
+    }
+
+    /// Sets the [`BlockSignerSource`] that gossiped blocks are validated against.
+    pub fn with_block_signer_source(mut self, signer: BlockSignerSource) -> Self {
         self.signer = Some(signer);
         self
     }
@@ -127,10 +185,13 @@ impl GossipDriverBuilder {
         let keypair = self.keypair.take().unwrap_or(Keypair::generate_secp256k1());
         let chain_id = self.chain_id.ok_or(GossipDriverBuilderError::MissingChainID)?;
         let addr = self.gossip_addr.take().ok_or(GossipDriverBuilderError::GossipAddrNotSet)?;
-        let signer_recv = self.signer.ok_or(GossipDriverBuilderError::MissingUnsafeBlockSigner)?;
+        let signer = self.signer.ok_or(GossipDriverBuilderError::MissingUnsafeBlockSigner)?;
 
         // Block Handler setup
-        let handler = BlockHandler::new(chain_id, signer_recv);
+        let mut handler = BlockHandler::new(chain_id, signer);
+        if let Some(rate) = self.gossip_rate_limit.and_then(NonZeroU32::new) {
+            handler = handler.with_rate_limit(rate);
+        }
 
         // Construct the gossip behaviour
         let config = self.config.unwrap_or(crate::default_config());
@@ -177,6 +238,18 @@ impl GossipDriverBuilder {
 
         let redialing = self.peer_redial;
 
-        Ok(GossipDriver::new(swarm, addr, redialing, handler))
+        let mut peer_monitoring = self.peer_monitoring;
+        if let (Some(monitoring), Some((decay_interval, decay_factor))) =
+            (peer_monitoring.as_mut(), self.peer_decay)
+        {
+            monitoring.decay_interval = decay_interval;
+            monitoring.decay_factor = decay_factor;
+        }
+
+        let mut driver = GossipDriver::new(swarm, addr, redialing, handler);
+        driver.peer_monitoring = peer_monitoring;
+        driver.strict_chain_filter = self.strict_chain_filter;
+        driver.reject_unknown_peers = self.reject_unknown_peers;
+        Ok(driver)
     }
 }
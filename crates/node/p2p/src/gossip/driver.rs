@@ -3,17 +3,18 @@
 use derive_more::Debug;
 use discv5::Enr;
 use futures::stream::StreamExt;
+use kona_genesis::RollupConfig;
 use libp2p::{
     Multiaddr, PeerId, Swarm, TransportError,
     gossipsub::{IdentTopic, MessageId},
     swarm::SwarmEvent,
 };
 use op_alloy_rpc_types_engine::OpNetworkPayloadEnvelope;
-use std::collections::HashMap;
+use std::{collections::HashMap, time::Instant};
 
 use crate::{
     Behaviour, BlockHandler, EnrValidation, Event, GossipDriverBuilder, Handler, PublishError,
-    enr_to_multiaddr, peers::PeerMonitoring,
+    enr_to_multiaddr, enr_to_peer_id, peers::PeerMonitoring,
 };
 
 /// A driver for a [`Swarm`] instance.
@@ -38,11 +39,32 @@ pub struct GossipDriver {
     /// If set, the gossip layer will monitor peer scores and ban peers that are below a given
     /// threshold.
     pub peer_monitoring: Option<PeerMonitoring>,
+    /// The scores of banned peers, decayed over time as configured by the [`PeerMonitoring`],
+    /// and the [`Instant`]s at which their bans expire.
+    ///
+    /// Connections from a banned peer are refused until its score recovers or its ban expires.
+    pub banned_peers: HashMap<PeerId, (f64, Instant)>,
     /// The number of times to redial a peer.
     pub peer_redialing: Option<u64>,
+    /// The [`Enr`]s received from discovery, keyed by the [`PeerId`] of the node they describe.
+    ///
+    /// Holds at most [`Self::MAX_PEER_ENRS`] entries, and the [`Enr`] of a peer is forgotten once
+    /// it disconnects or cannot be dialed.
+    pub peer_enrs: HashMap<PeerId, Enr>,
+    /// If set, inbound connections are refused from peers whose known [`Enr`] advertises a chain
+    /// id other than the chain id of the [`BlockHandler`].
+    pub strict_chain_filter: bool,
+    /// If set together with [`Self::strict_chain_filter`], inbound connections are also refused
+    /// from peers whose [`Enr`] is unknown, such as peers that were not found through discovery.
+    pub reject_unknown_peers: bool,
+    /// The number of inbound peers rejected by the strict chain filter.
+    pub chain_filtered_peers: u64,
 }
 
 impl GossipDriver {
+    /// The maximum number of [`Enr`]s remembered in [`Self::peer_enrs`].
+    pub const MAX_PEER_ENRS: usize = 4096;
+
     /// Returns the [`GossipDriverBuilder`] that can be used to construct the [`GossipDriver`].
     pub const fn builder() -> GossipDriverBuilder {
         GossipDriverBuilder::new()
@@ -62,7 +84,12 @@ impl GossipDriver {
             dialed_peers: Default::default(),
             peerstore: Default::default(),
             peer_monitoring: None,
+            banned_peers: Default::default(),
             peer_redialing: redialing,
+            peer_enrs: Default::default(),
+            strict_chain_filter: false,
+            reject_unknown_peers: false,
+            chain_filtered_peers: 0,
         }
     }
 
@@ -93,6 +120,23 @@ impl GossipDriver {
         Ok(Some(id))
     }
 
+    /// Publishes an unsafe block to the topic of the hardfork active at the given timestamp.
+    ///
+    /// The topic is selected by [`BlockHandler::topic`] using the given [`RollupConfig`].
+    ///
+    /// ## Returns
+    ///
+    /// Returns the [`MessageId`] of the published message or a [`PublishError`]
+    /// if the message could not be published.
+    pub fn publish_all(
+        &mut self,
+        payload: OpNetworkPayloadEnvelope,
+        timestamp: u64,
+        cfg: &RollupConfig,
+    ) -> Result<Option<MessageId>, PublishError> {
+        self.publish(|handler| handler.topic(timestamp, Some(cfg)), Some(payload))
+    }
+
     /// Listens on the address.
     pub fn listen(&mut self) -> Result<(), TransportError<std::io::Error>> {
         self.swarm.listen_on(self.addr.clone())?;
@@ -142,6 +186,17 @@ impl GossipDriver {
 
     /// Dials the given [`Enr`].
     pub fn dial(&mut self, enr: Enr) {
+        // Remember the ENR, so that inbound connections from the peer can be validated.
+        if let Some(peer_id) = enr_to_peer_id(&enr) {
+            if self.peer_enrs.len() >= Self::MAX_PEER_ENRS && !self.peer_enrs.contains_key(&peer_id)
+            {
+                // Evict an arbitrary entry to keep the map bounded.
+                let evicted = self.peer_enrs.keys().next().copied();
+                evicted.map(|id| self.peer_enrs.remove(&id));
+            }
+            self.peer_enrs.insert(peer_id, enr.clone());
+        }
+
         let validation = EnrValidation::validate(&enr, self.handler.chain_id);
         if validation.is_invalid() {
             debug!(target: "gossip", "Invalid OP Stack ENR for chain id {}: {}", self.handler.chain_id, validation);
@@ -181,6 +236,72 @@ impl GossipDriver {
         }
     }
 
+    /// Bans the given [`PeerId`] with the given score for the [`PeerMonitoring::ban_duration`],
+    /// disconnecting it from the swarm and removing it from the peerstore. Returns the peer's
+    /// [`Multiaddr`], if known.
+    pub fn ban_peer(&mut self, peer_id: PeerId, score: f64) -> Option<Multiaddr> {
+        let ban_duration =
+            self.peer_monitoring.as_ref().map(|m| m.ban_duration).unwrap_or_default();
+        self.banned_peers.insert(peer_id, (score, Instant::now() + ban_duration));
+        self.peer_enrs.remove(&peer_id);
+        if self.swarm.disconnect_peer_id(peer_id).is_err() {
+            warn!(target: "gossip", peer = ?peer_id, "Trying to disconnect a non-existing peer from the gossip driver.");
+        }
+
+        let addr = self.peerstore.remove(&peer_id)?;
+        self.dialed_peers.remove(&addr);
+        Some(addr)
+    }
+
+    /// Validates the [`Enr`] of the given [`PeerId`] against the chain id of the
+    /// [`BlockHandler`]. Returns `None` if no [`Enr`] is known for the peer.
+    pub fn validate_peer_chain(&self, peer_id: &PeerId) -> Option<EnrValidation> {
+        let enr = self.peer_enrs.get(peer_id)?;
+        Some(EnrValidation::validate(enr, self.handler.chain_id))
+    }
+
+    /// Returns if the given [`PeerId`] is banned.
+    pub fn is_banned(&self, peer_id: &PeerId) -> bool {
+        self.banned_peers.get(peer_id).is_some_and(|(_, expiry)| *expiry > Instant::now())
+    }
+
+    /// Removes the bans that have expired.
+    ///
+    /// Returns the [`PeerId`]s of the peers that were unbanned.
+    pub fn prune_expired_bans(&mut self) -> Vec<PeerId> {
+        let now = Instant::now();
+        let mut expired = Vec::new();
+        self.banned_peers.retain(|peer_id, (_, expiry)| {
+            if *expiry > now {
+                return true;
+            }
+            expired.push(*peer_id);
+            false
+        });
+        expired
+    }
+
+    /// Decays the scores of all banned peers by the configured [`PeerMonitoring::decay_factor`],
+    /// unbanning the peers whose score is no longer below the ban threshold.
+    ///
+    /// Returns the [`PeerId`]s of the peers that were unbanned.
+    pub fn decay_peer_scores(&mut self) -> Vec<PeerId> {
+        let Some(monitoring) = self.peer_monitoring.as_ref().filter(|m| m.decay_enabled()) else {
+            return Vec::new();
+        };
+
+        let mut recovered = Vec::new();
+        self.banned_peers.retain(|peer_id, (score, _)| {
+            *score *= monitoring.decay_factor;
+            if *score < monitoring.ban_threshold {
+                return true;
+            }
+            recovered.push(*peer_id);
+            false
+        });
+        recovered
+    }
+
     /// Handles a [`libp2p::gossipsub::Event`].
     fn handle_gossipsub_event(
         &mut self,
@@ -194,7 +315,7 @@ impl GossipDriver {
             } => {
                 trace!(target: "gossip", "Received message with topic: {}", message.topic);
                 if self.handler.topics().contains(&message.topic) {
-                    let (status, payload) = self.handler.handle(message);
+                    let (status, payload) = self.handler.handle_from(src, message);
                     _ = self
                         .swarm
                         .behaviour_mut()
@@ -221,6 +342,25 @@ impl GossipDriver {
     /// Handles the [`SwarmEvent<Event>`].
     pub fn handle_event(&mut self, event: SwarmEvent<Event>) -> Option<OpNetworkPayloadEnvelope> {
         if let SwarmEvent::ConnectionEstablished { peer_id, endpoint, .. } = event {
+            if self.is_banned(&peer_id) {
+                debug!(target: "gossip", "Refusing connection from banned peer: {:?}", peer_id);
+                let _ = self.swarm.disconnect_peer_id(peer_id);
+                return None;
+            }
+            if self.strict_chain_filter && endpoint.is_listener() {
+                let validation = self.validate_peer_chain(&peer_id);
+                let rejected = match validation {
+                    Some(ref validation) => !validation.is_valid(),
+                    None => self.reject_unknown_peers,
+                };
+                if rejected {
+                    debug!(target: "gossip", peer = ?peer_id, ?validation, "Rejecting inbound peer not on chain id {}", self.handler.chain_id);
+                    self.chain_filtered_peers += 1;
+                    crate::set!(CHAIN_FILTERED_PEERS, self.chain_filtered_peers as i64);
+                    let _ = self.swarm.disconnect_peer_id(peer_id);
+                    return None;
+                }
+            }
             let peer_count = self.swarm.connected_peers().count();
             trace!(target: "gossip", "Connection established: {:?} | Peer Count: {}", peer_id, peer_count);
             crate::set!(PEER_COUNT, peer_count as i64);
@@ -230,11 +370,17 @@ impl GossipDriver {
         if let SwarmEvent::OutgoingConnectionError { peer_id, error, .. } = event {
             trace!(target: "gossip", "Outgoing connection error: {:?}", error);
             if let Some(id) = peer_id {
+                if !self.swarm.is_connected(&id) {
+                    self.peer_enrs.remove(&id);
+                }
                 self.redial(id);
             }
             return None;
         }
-        if let SwarmEvent::ConnectionClosed { peer_id, cause, .. } = event {
+        if let SwarmEvent::ConnectionClosed { peer_id, cause, num_established, .. } = event {
+            if num_established == 0 {
+                self.peer_enrs.remove(&peer_id);
+            }
             let peer_count = self.swarm.connected_peers().count();
             trace!(target: "gossip", "Connection closed, redialing peer: {:?} | {:?} | Peer Count: {}", peer_id, cause, peer_count);
             crate::set!(PEER_COUNT, peer_count as i64);
@@ -255,3 +401,257 @@ impl GossipDriver {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        OpStackEnr, discovery_signing_key,
+        gossip::{v2_valid_block, v3_valid_block},
+    };
+    use alloy_primitives::{Address, B256, Signature};
+    use alloy_rlp::Encodable;
+    use alloy_rpc_types_engine::{ExecutionPayloadV1, ExecutionPayloadV2, ExecutionPayloadV3};
+    use discv5::enr::CombinedKey;
+    use kona_genesis::HardForkConfig;
+    use libp2p::identity::Keypair;
+    use op_alloy_rpc_types_engine::{OpExecutionPayload, OpExecutionPayloadV4, PayloadHash};
+    use std::time::Duration;
+
+    fn builder() -> GossipDriverBuilder {
+        let mut addr = Multiaddr::from(std::net::Ipv4Addr::LOCALHOST);
+        addr.push(libp2p::multiaddr::Protocol::Tcp(0));
+        GossipDriver::builder()
+            .with_chain_id(10)
+            .with_address(addr)
+            .with_unsafe_block_signer_receiver(tokio::sync::watch::channel(Address::ZERO).1)
+    }
+
+    fn driver(peer_monitoring: PeerMonitoring) -> GossipDriver {
+        builder()
+            .with_peer_monitoring(Some(peer_monitoring))
+            .with_peer_decay(Duration::from_secs(1), 0.5)
+            .build()
+            .unwrap()
+    }
+
+    /// Returns an [`Enr`] for the given [`Keypair`] that advertises the given chain id.
+    fn op_stack_enr(keypair: &Keypair, chain_id: u64) -> Enr {
+        let key = CombinedKey::Secp256k1(discovery_signing_key(keypair).unwrap());
+        let mut enr = Enr::builder().build(&key).unwrap();
+        let mut opstack = Vec::new();
+        OpStackEnr::from_chain_id(chain_id).encode(&mut opstack);
+        enr.insert_raw_rlp(OpStackEnr::OP_CL_KEY, opstack.into(), &key).unwrap();
+        enr
+    }
+
+    #[test]
+    fn test_publish_all_topic_selection() {
+        let cfg = RollupConfig {
+            hardforks: HardForkConfig {
+                canyon_time: Some(10),
+                ecotone_time: Some(20),
+                isthmus_time: Some(30),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let mut driver = builder().build().unwrap();
+
+        // Each topic only encodes payloads of its own version, so the payload of the selected
+        // topic is the only one that fails to publish for lack of peers rather than encoding.
+        let v1 = ExecutionPayloadV1::from_block_slow(&v2_valid_block());
+        let v2 = ExecutionPayloadV2::from_block_slow(&v2_valid_block());
+        let v3 = ExecutionPayloadV3::from_block_slow(&v3_valid_block());
+        let v4 = OpExecutionPayloadV4 { payload_inner: v3.clone(), withdrawals_root: B256::ZERO };
+        let payloads = [
+            OpExecutionPayload::V1(v1),
+            OpExecutionPayload::V2(v2),
+            OpExecutionPayload::V3(v3),
+            OpExecutionPayload::V4(v4),
+        ];
+
+        // The index of the expected payload at each side of the hardfork boundaries.
+        let boundaries =
+            [(0, 0), (9, 0), (10, 1), (19, 1), (20, 2), (29, 2), (30, 3), (u64::MAX, 3)];
+        for (timestamp, expected) in boundaries {
+            for (version, payload) in payloads.iter().enumerate() {
+                let envelope = OpNetworkPayloadEnvelope {
+                    payload: payload.clone(),
+                    signature: Signature::test_signature(),
+                    payload_hash: PayloadHash(B256::ZERO),
+                    parent_beacon_block_root: Some(B256::ZERO),
+                };
+                let result = driver.publish_all(envelope, timestamp, &cfg);
+                if version == expected {
+                    assert!(
+                        matches!(result, Err(PublishError::PublishError(_))),
+                        "timestamp {timestamp}: {result:?}"
+                    );
+                } else {
+                    assert!(
+                        matches!(result, Err(PublishError::EncodeError(_))),
+                        "timestamp {timestamp}, version {version}: {result:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_strict_chain_filter_disconnects_mismatched_peer() {
+        let mut driver = builder().with_strict_chain_filter(true).build().unwrap();
+        driver.listen().unwrap();
+        let addr = loop {
+            if let SwarmEvent::NewListenAddr { address, .. } = driver.select_next_some().await {
+                break address;
+            }
+        };
+
+        // The peer advertises a different chain id. Its ENR has no socket, so it isn't dialed.
+        let keypair = Keypair::generate_secp256k1();
+        let peer_id = keypair.public().to_peer_id();
+        driver.dial(op_stack_enr(&keypair, 11));
+        assert!(driver.validate_peer_chain(&peer_id).is_some_and(|v| v.is_invalid()));
+
+        let mut peer = builder().with_keypair(keypair).build().unwrap();
+        peer.swarm.dial(addr).unwrap();
+
+        tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                tokio::select! {
+                    event = driver.select_next_some() => {
+                        let closed = matches!(
+                            event,
+                            SwarmEvent::ConnectionClosed { peer_id: id, .. } if id == peer_id
+                        );
+                        driver.handle_event(event);
+                        if closed {
+                            break;
+                        }
+                    }
+                    _ = peer.select_next_some() => {}
+                }
+            }
+        })
+        .await
+        .expect("mismatched peer was not disconnected");
+
+        assert_eq!(driver.chain_filtered_peers, 1);
+        assert!(!driver.swarm.is_connected(&peer_id));
+        assert!(!driver.peerstore.contains_key(&peer_id));
+    }
+
+    /// Connects a peer with the given [`Keypair`] to the listening `driver` and returns once the
+    /// `driver` has handled the established connection.
+    async fn connect_inbound(driver: &mut GossipDriver, keypair: Keypair) {
+        driver.listen().unwrap();
+        let addr = loop {
+            if let SwarmEvent::NewListenAddr { address, .. } = driver.select_next_some().await {
+                break address;
+            }
+        };
+        let peer_id = keypair.public().to_peer_id();
+        let mut peer = builder().with_keypair(keypair).build().unwrap();
+        peer.swarm.dial(addr).unwrap();
+
+        tokio::time::timeout(Duration::from_secs(10), async {
+            loop {
+                tokio::select! {
+                    event = driver.select_next_some() => {
+                        let established = matches!(
+                            event,
+                            SwarmEvent::ConnectionEstablished { peer_id: id, .. } if id == peer_id
+                        );
+                        driver.handle_event(event);
+                        if established {
+                            break;
+                        }
+                    }
+                    _ = peer.select_next_some() => {}
+                }
+            }
+        })
+        .await
+        .expect("peer did not connect");
+    }
+
+    #[tokio::test]
+    async fn test_strict_chain_filter_accepts_unknown_peer() {
+        let mut driver = builder().with_strict_chain_filter(true).build().unwrap();
+        let keypair = Keypair::generate_secp256k1();
+        let peer_id = keypair.public().to_peer_id();
+        connect_inbound(&mut driver, keypair).await;
+
+        assert!(driver.validate_peer_chain(&peer_id).is_none());
+        assert_eq!(driver.chain_filtered_peers, 0);
+        assert!(driver.peerstore.contains_key(&peer_id));
+    }
+
+    #[tokio::test]
+    async fn test_strict_chain_filter_rejects_unknown_peer() {
+        let mut driver = builder()
+            .with_strict_chain_filter(true)
+            .with_reject_unknown_peers(true)
+            .build()
+            .unwrap();
+        let keypair = Keypair::generate_secp256k1();
+        let peer_id = keypair.public().to_peer_id();
+        connect_inbound(&mut driver, keypair).await;
+
+        assert_eq!(driver.chain_filtered_peers, 1);
+        assert!(!driver.peerstore.contains_key(&peer_id));
+    }
+
+    #[test]
+    fn test_banned_peer_recovers_after_decay() {
+        let mut driver = driver(PeerMonitoring::new(-10.0, Duration::from_secs(60)));
+        let peer = PeerId::random();
+
+        driver.ban_peer(peer, -40.0);
+        assert!(driver.is_banned(&peer));
+
+        // -40 decays to -20, which is still below the threshold.
+        assert!(driver.decay_peer_scores().is_empty());
+        assert!(driver.is_banned(&peer));
+
+        // -20 decays to -10, which is no longer below the threshold.
+        assert_eq!(driver.decay_peer_scores(), vec![peer]);
+        assert!(!driver.is_banned(&peer));
+    }
+
+    #[test]
+    fn test_banned_peer_ban_expires() {
+        let mut driver = driver(PeerMonitoring::new(-10.0, Duration::ZERO));
+        let peer = PeerId::random();
+
+        // A ban of zero duration expires immediately, although the score has not recovered.
+        driver.ban_peer(peer, -40.0);
+        assert!(!driver.is_banned(&peer));
+        assert_eq!(driver.prune_expired_bans(), vec![peer]);
+        assert!(driver.banned_peers.is_empty());
+    }
+
+    #[test]
+    fn test_banned_peer_ban_does_not_expire_early() {
+        let mut driver = driver(PeerMonitoring::new(-10.0, Duration::from_secs(60)));
+        let peer = PeerId::random();
+
+        driver.ban_peer(peer, -40.0);
+        assert!(driver.prune_expired_bans().is_empty());
+        assert!(driver.is_banned(&peer));
+    }
+
+    #[test]
+    fn test_banned_peers_do_not_decay_without_decay() {
+        let mut driver = driver(PeerMonitoring::new(-10.0, Duration::from_secs(60)));
+        driver.peer_monitoring.as_mut().unwrap().decay_factor = 1.0;
+        let peer = PeerId::random();
+
+        driver.ban_peer(peer, -40.0);
+        for _ in 0..8 {
+            assert!(driver.decay_peer_scores().is_empty());
+        }
+        assert!(driver.is_banned(&peer));
+    }
+}
@@ -26,6 +26,23 @@ pub enum HandlerEncodeError {
     UnknownTopic(libp2p::gossipsub::TopicHash),
 }
 
+/// An error from the [`crate::PayloadSigner`].
+#[derive(Debug, Error)]
+pub enum PayloadSignerError {
+    /// The unsafe block signer key is not a valid secp256k1 secret key.
+    #[error("Invalid signer key: {0}")]
+    InvalidKey(discv5::enr::k256::ecdsa::Error),
+    /// Failed to encode the payload envelope.
+    #[error("Failed to encode payload: {0}")]
+    Encode(#[from] op_alloy_rpc_types_engine::PayloadEnvelopeEncodeError),
+    /// Failed to decompress the encoded payload envelope.
+    #[error("Failed to decompress payload: {0}")]
+    Decompress(#[from] snap::Error),
+    /// Failed to sign the payload hash.
+    #[error("Failed to sign payload: {0}")]
+    Sign(discv5::enr::k256::ecdsa::Error),
+}
+
 /// An error type for the [`crate::GossipDriverBuilder`].
 #[derive(Debug, Clone, PartialEq, Eq, From, Error)]
 pub enum GossipDriverBuilderError {
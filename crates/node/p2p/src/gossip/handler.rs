@@ -1,12 +1,17 @@
 //! Block Handler
 
-use crate::HandlerEncodeError;
-use alloy_primitives::{Address, B256};
+use crate::{BlockSignerSource, GossipRateLimiter, HandlerEncodeError};
+use alloy_primitives::B256;
 use kona_genesis::RollupConfig;
-use libp2p::gossipsub::{IdentTopic, Message, MessageAcceptance, TopicHash};
+use libp2p::{
+    PeerId,
+    gossipsub::{IdentTopic, Message, MessageAcceptance, TopicHash},
+};
 use op_alloy_rpc_types_engine::OpNetworkPayloadEnvelope;
-use std::collections::{BTreeMap, HashSet};
-use tokio::sync::watch::Receiver;
+use std::{
+    collections::{BTreeMap, HashSet},
+    num::NonZeroU32,
+};
 
 /// This trait defines the functionality required to process incoming messages
 /// and determine their acceptance within the network.
@@ -18,6 +23,18 @@ pub trait Handler: Send {
     /// This is a stateful method, because the handler needs to keep track of seen hashes.
     fn handle(&mut self, msg: Message) -> (MessageAcceptance, Option<OpNetworkPayloadEnvelope>);
 
+    /// Manages validation of a message forwarded by the given peer.
+    ///
+    /// Handlers may override this to apply per-peer policies before [`Handler::handle`]. By
+    /// default, the message is passed to [`Handler::handle`].
+    fn handle_from(
+        &mut self,
+        _peer: PeerId,
+        msg: Message,
+    ) -> (MessageAcceptance, Option<OpNetworkPayloadEnvelope>) {
+        self.handle(msg)
+    }
+
     /// Specifies which topics the handler is interested in
     fn topics(&self) -> Vec<TopicHash>;
 }
@@ -29,8 +46,8 @@ pub struct BlockHandler {
     ///
     /// Used to filter out gossip messages intended for other chains.
     pub chain_id: u64,
-    /// A [`Receiver`] to monitor changes to the unsafe block signer.
-    pub signer_recv: Receiver<Address>,
+    /// The source of the unsafe block signer.
+    pub signer: BlockSignerSource,
     /// The libp2p topic for pre Canyon/Shangai blocks.
     pub blocks_v1_topic: IdentTopic,
     /// The libp2p topic for Canyon/Delta blocks.
@@ -42,6 +59,8 @@ pub struct BlockHandler {
     /// A map of seen block height to block hash set.
     /// This map is pruned when it contains more than [`Self::SEEN_HASH_CACHE_SIZE`] entries.
     pub seen_hashes: BTreeMap<u64, HashSet<B256>>,
+    /// The optional per-peer rate limiter, applied before messages are decoded and validated.
+    pub rate_limiter: Option<GossipRateLimiter>,
 }
 
 impl Handler for BlockHandler {
@@ -76,6 +95,22 @@ impl Handler for BlockHandler {
         }
     }
 
+    /// Ignores messages from peers that exceeded the rate of the [`GossipRateLimiter`], if any,
+    /// before they are decoded and validated by [`Handler::handle`].
+    fn handle_from(
+        &mut self,
+        peer: PeerId,
+        msg: Message,
+    ) -> (MessageAcceptance, Option<OpNetworkPayloadEnvelope>) {
+        if let Some(limiter) = self.rate_limiter.as_mut() {
+            if !limiter.check(peer) {
+                debug!(target: "node::p2p::gossip", %peer, "Peer exceeded the gossip rate limit");
+                return (MessageAcceptance::Ignore, None);
+            }
+        }
+        self.handle(msg)
+    }
+
     /// The gossip topics accepted for new blocks
     fn topics(&self) -> Vec<TopicHash> {
         vec![
@@ -90,19 +125,27 @@ impl Handler for BlockHandler {
 impl BlockHandler {
     /// Creates a new [`BlockHandler`].
     ///
-    /// Requires the chain ID and a receiver channel for the unsafe block signer.
-    pub fn new(chain_id: u64, signer_recv: Receiver<Address>) -> Self {
+    /// Requires the chain ID and the [`BlockSignerSource`] for the unsafe block signer.
+    pub fn new(chain_id: u64, signer: impl Into<BlockSignerSource>) -> Self {
         Self {
             chain_id,
-            signer_recv,
+            signer: signer.into(),
             blocks_v1_topic: IdentTopic::new(format!("/optimism/{}/0/blocks", chain_id)),
             blocks_v2_topic: IdentTopic::new(format!("/optimism/{}/1/blocks", chain_id)),
             blocks_v3_topic: IdentTopic::new(format!("/optimism/{}/2/blocks", chain_id)),
             blocks_v4_topic: IdentTopic::new(format!("/optimism/{}/3/blocks", chain_id)),
             seen_hashes: BTreeMap::new(),
+            rate_limiter: None,
         }
     }
 
+    /// Limits the number of messages per second that each peer may send, see
+    /// [`GossipRateLimiter`].
+    pub fn with_rate_limit(mut self, rate: NonZeroU32) -> Self {
+        self.rate_limiter = Some(GossipRateLimiter::new(rate));
+        self
+    }
+
     /// Returns the topic using the specified timestamp and optional [`RollupConfig`].
     ///
     /// Reference: <https://github.com/ethereum-optimism/optimism/blob/0bc5fe8d16155dc68bcdf1fa5733abc58689a618/op-node/p2p/gossip.go#L604C1-L612C3>
@@ -178,7 +221,7 @@ mod tests {
         let msg = decoded.payload_hash.signature_message(10);
         let signer = decoded.signature.recover_address_from_prehash(&msg).unwrap();
         let (_, unsafe_signer) = tokio::sync::watch::channel(signer);
-        handler.signer_recv = unsafe_signer;
+        handler.signer = unsafe_signer.into();
 
         // Let's try to encode a message.
         let message = Message {
@@ -402,7 +445,7 @@ mod tests {
         let msg = decoded.payload_hash.signature_message(10);
         let signer = decoded.signature.recover_address_from_prehash(&msg).unwrap();
         let (_, unsafe_signer) = tokio::sync::watch::channel(signer);
-        handler.signer_recv = unsafe_signer;
+        handler.signer = unsafe_signer.into();
 
         // Let's try to encode a message.
         let message = Message {
@@ -445,7 +488,7 @@ mod tests {
         let msg = decoded.payload_hash.signature_message(10);
         let signer = decoded.signature.recover_address_from_prehash(&msg).unwrap();
         let (_, unsafe_signer) = tokio::sync::watch::channel(signer);
-        handler.signer_recv = unsafe_signer;
+        handler.signer = unsafe_signer.into();
 
         // Let's try to encode a message.
         let message = Message {
@@ -457,4 +500,32 @@ mod tests {
 
         assert!(matches!(handler.handle(message).0, MessageAcceptance::Accept));
     }
+
+    #[test]
+    fn test_rate_limited_peer_ignored() {
+        let (_, unsafe_signer) = tokio::sync::watch::channel(Default::default());
+        let mut handler =
+            BlockHandler::new(10, unsafe_signer).with_rate_limit(NonZeroU32::new(2).unwrap());
+        let message = Message {
+            source: None,
+            sequence_number: None,
+            topic: handler.blocks_v2_topic.clone().into(),
+            data: vec![0xFF; 32],
+        };
+        let (peer, other) = (PeerId::random(), PeerId::random());
+
+        // Messages within the rate are decoded, and rejected as invalid.
+        for _ in 0..2 {
+            let (acceptance, _) = handler.handle_from(peer, message.clone());
+            assert!(matches!(acceptance, MessageAcceptance::Reject));
+        }
+
+        // Subsequent messages are ignored without being decoded.
+        let (acceptance, _) = handler.handle_from(peer, message.clone());
+        assert!(matches!(acceptance, MessageAcceptance::Ignore));
+
+        // Other peers are not limited.
+        let (acceptance, _) = handler.handle_from(other, message);
+        assert!(matches!(acceptance, MessageAcceptance::Reject));
+    }
 }
@@ -15,7 +15,7 @@ mod builder;
 pub use builder::GossipDriverBuilder;
 
 mod error;
-pub use error::{GossipDriverBuilderError, HandlerEncodeError, PublishError};
+pub use error::{GossipDriverBuilderError, HandlerEncodeError, PayloadSignerError, PublishError};
 
 mod event;
 pub use event::Event;
@@ -23,6 +23,12 @@ pub use event::Event;
 mod handler;
 pub use handler::{BlockHandler, Handler};
 
+mod signer;
+pub use signer::{BlockSignerSource, PayloadSigner};
+
+mod rate_limit;
+pub use rate_limit::GossipRateLimiter;
+
 mod driver;
 pub use driver::GossipDriver;
 
@@ -0,0 +1,140 @@
+//! Per-peer rate limiting of gossip messages.
+
+use libp2p::PeerId;
+use std::{collections::HashMap, num::NonZeroU32, time::Instant};
+
+/// A per-peer token bucket rate limiter for gossip messages.
+///
+/// Each peer may burst up to `rate` messages, after which its bucket is refilled at `rate`
+/// messages per second.
+#[derive(Debug, Clone)]
+pub struct GossipRateLimiter {
+    /// The number of messages per second that each peer may send.
+    rate: NonZeroU32,
+    /// The token bucket of each peer, holding the number of available tokens and the time at
+    /// which the peer was last seen, when the bucket was last refilled.
+    buckets: HashMap<PeerId, (f64, Instant)>,
+}
+
+impl GossipRateLimiter {
+    /// The maximum number of tracked peers, above which full buckets are pruned and the least
+    /// recently seen peer is evicted.
+    const MAX_TRACKED_PEERS: usize = 1024;
+
+    /// Creates a new [`GossipRateLimiter`] allowing `rate` messages per second from each peer.
+    pub fn new(rate: NonZeroU32) -> Self {
+        Self { rate, buckets: HashMap::new() }
+    }
+
+    /// Returns the number of messages per second that each peer may send.
+    pub const fn rate(&self) -> NonZeroU32 {
+        self.rate
+    }
+
+    /// Consumes a token from the bucket of the given peer, returning `false` if the peer has
+    /// exceeded its rate.
+    pub fn check(&mut self, peer: PeerId) -> bool {
+        self.check_at(peer, Instant::now())
+    }
+
+    /// Consumes a token from the bucket of the given peer at the given time, returning `false` if
+    /// the peer has exceeded its rate.
+    fn check_at(&mut self, peer: PeerId, now: Instant) -> bool {
+        let capacity = self.rate.get() as f64;
+        if self.buckets.len() >= Self::MAX_TRACKED_PEERS && !self.buckets.contains_key(&peer) {
+            self.prune(now);
+        }
+        if self.buckets.len() >= Self::MAX_TRACKED_PEERS {
+            self.evict_least_recently_seen();
+        }
+
+        let (tokens, last_refill) = self.buckets.entry(peer).or_insert((capacity, now));
+        let elapsed = now.saturating_duration_since(*last_refill).as_secs_f64();
+        *tokens = (*tokens + elapsed * capacity).min(capacity);
+        *last_refill = now;
+
+        if *tokens < 1.0 {
+            return false;
+        }
+        *tokens -= 1.0;
+        true
+    }
+
+    /// Removes the buckets that would be full at the given time, as they are equivalent to the
+    /// bucket of an unseen peer.
+    fn prune(&mut self, now: Instant) {
+        let capacity = self.rate.get() as f64;
+        self.buckets.retain(|_, (tokens, last_refill)| {
+            let elapsed = now.saturating_duration_since(*last_refill).as_secs_f64();
+            *tokens + elapsed * capacity < capacity
+        });
+    }
+
+    /// Removes the bucket of the peer that was seen the longest time ago.
+    fn evict_least_recently_seen(&mut self) {
+        let oldest = self.buckets.iter().min_by_key(|(_, (_, last_seen))| *last_seen);
+        if let Some(peer) = oldest.map(|(peer, _)| *peer) {
+            self.buckets.remove(&peer);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_rate_limit_per_peer() {
+        let mut limiter = GossipRateLimiter::new(NonZeroU32::new(2).unwrap());
+        let (peer, other) = (PeerId::random(), PeerId::random());
+        let now = Instant::now();
+
+        assert!(limiter.check_at(peer, now));
+        assert!(limiter.check_at(peer, now));
+        assert!(!limiter.check_at(peer, now));
+
+        // Other peers have their own bucket.
+        assert!(limiter.check_at(other, now));
+
+        // The bucket is refilled over time, up to its capacity.
+        assert!(limiter.check_at(peer, now + Duration::from_millis(500)));
+        assert!(!limiter.check_at(peer, now + Duration::from_millis(500)));
+        let later = now + Duration::from_secs(10);
+        assert!(limiter.check_at(peer, later));
+        assert!(limiter.check_at(peer, later));
+        assert!(!limiter.check_at(peer, later));
+    }
+
+    #[test]
+    fn test_prune_full_buckets() {
+        let mut limiter = GossipRateLimiter::new(NonZeroU32::MIN);
+        let now = Instant::now();
+        for _ in 0..GossipRateLimiter::MAX_TRACKED_PEERS {
+            limiter.check_at(PeerId::random(), now);
+        }
+
+        let later = now + Duration::from_secs(1);
+        assert!(limiter.check_at(PeerId::random(), later));
+        assert_eq!(limiter.buckets.len(), 1);
+    }
+
+    #[test]
+    fn test_evict_least_recently_seen() {
+        let mut limiter = GossipRateLimiter::new(NonZeroU32::new(2).unwrap());
+        let now = Instant::now();
+        let oldest = PeerId::random();
+        assert!(limiter.check_at(oldest, now));
+        for i in 1..GossipRateLimiter::MAX_TRACKED_PEERS as u64 {
+            assert!(limiter.check_at(PeerId::random(), now + Duration::from_micros(i)));
+        }
+
+        // No bucket is full, so the least recently seen peer is evicted for the new one.
+        let newest = PeerId::random();
+        let later = now + Duration::from_micros(GossipRateLimiter::MAX_TRACKED_PEERS as u64);
+        assert!(limiter.check_at(newest, later));
+        assert_eq!(limiter.buckets.len(), GossipRateLimiter::MAX_TRACKED_PEERS);
+        assert!(!limiter.buckets.contains_key(&oldest));
+        assert!(limiter.buckets.contains_key(&newest));
+    }
+}
@@ -0,0 +1,180 @@
+//! Contains the [`BlockSignerSource`], which provides the unsafe block signer used to validate
+//! gossiped payloads, and the [`PayloadSigner`] used by the sequencer to sign them.
+
+use crate::PayloadSignerError;
+use alloy_primitives::{Address, B256, Signature};
+use discv5::enr::k256::ecdsa::SigningKey;
+use op_alloy_rpc_types_engine::{OpExecutionPayload, OpNetworkPayloadEnvelope, PayloadHash};
+use tokio::sync::watch::Receiver;
+
+/// The source of the unsafe block signer that gossiped payloads are validated against.
+///
+/// The unsafe block signer may be rotated through a system config update on L1. Nodes that follow
+/// the chain should use [`BlockSignerSource::Dynamic`] so that the [`BlockHandler`] always
+/// validates payloads against the most recent signer. [`BlockSignerSource::Static`] is suitable for
+/// tooling that only needs the genesis signer.
+///
+/// [`BlockHandler`]: crate::BlockHandler
+#[derive(Debug, Clone)]
+pub enum BlockSignerSource {
+    /// A fixed unsafe block signer.
+    Static(Address),
+    /// An unsafe block signer that is updated as system config changes are observed.
+    Dynamic(Receiver<Address>),
+}
+
+impl BlockSignerSource {
+    /// Returns the current unsafe block signer.
+    pub fn current(&self) -> Address {
+        match self {
+            Self::Static(signer) => *signer,
+            Self::Dynamic(recv) => *recv.borrow(),
+        }
+    }
+}
+
+impl From<Address> for BlockSignerSource {
+    fn from(signer: Address) -> Self {
+        Self::Static(signer)
+    }
+}
+
+impl From<Receiver<Address>> for BlockSignerSource {
+    fn from(recv: Receiver<Address>) -> Self {
+        Self::Dynamic(recv)
+    }
+}
+
+/// Signs the payloads built by the sequencer, so that they are accepted by the [`BlockHandler`] of
+/// the nodes that follow the chain.
+///
+/// [`BlockHandler`]: crate::BlockHandler
+#[derive(Clone)]
+pub struct PayloadSigner {
+    /// The unsafe block signer key.
+    key: SigningKey,
+    /// The L2 chain ID, which is part of the signed message.
+    chain_id: u64,
+}
+
+impl std::fmt::Debug for PayloadSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PayloadSigner")
+            .field("address", &self.address())
+            .field("chain_id", &self.chain_id)
+            .finish()
+    }
+}
+
+impl PayloadSigner {
+    /// Creates a new [`PayloadSigner`] from the given secret key.
+    pub fn new(key: B256, chain_id: u64) -> Result<Self, PayloadSignerError> {
+        let key = SigningKey::from_slice(key.as_slice()).map_err(PayloadSignerError::InvalidKey)?;
+        Ok(Self { key, chain_id })
+    }
+
+    /// Returns the address of the unsafe block signer.
+    pub fn address(&self) -> Address {
+        Address::from_public_key(self.key.verifying_key())
+    }
+
+    /// Signs the given payload, returning the [`OpNetworkPayloadEnvelope`] to gossip.
+    ///
+    /// The payload hash is the keccak256 hash of the SSZ-encoded payload, prefixed with the parent
+    /// beacon block root from Ecotone onwards. It is computed from the gossip encoding of the
+    /// envelope, so that it matches the hash recomputed by the receiving nodes.
+    pub fn sign(
+        &self,
+        payload: OpExecutionPayload,
+        parent_beacon_block_root: Option<B256>,
+    ) -> Result<OpNetworkPayloadEnvelope, PayloadSignerError> {
+        let mut envelope = OpNetworkPayloadEnvelope {
+            payload,
+            signature: Signature::test_signature(),
+            payload_hash: PayloadHash(B256::ZERO),
+            parent_beacon_block_root,
+        };
+
+        let encoded = match envelope.payload {
+            OpExecutionPayload::V1(_) => envelope.encode_v1()?,
+            OpExecutionPayload::V2(_) => envelope.encode_v2()?,
+            OpExecutionPayload::V3(_) => envelope.encode_v3()?,
+            OpExecutionPayload::V4(_) => envelope.encode_v4()?,
+        };
+        let decompressed = snap::raw::Decoder::new().decompress_vec(&encoded)?;
+        // The encoding is prefixed with the 65 byte signature.
+        envelope.payload_hash = PayloadHash::from(&decompressed[65..]);
+
+        let msg = envelope.payload_hash.signature_message(self.chain_id);
+        let (signature, recovery_id) =
+            self.key.sign_prehash_recoverable(msg.as_slice()).map_err(PayloadSignerError::Sign)?;
+        envelope.signature = Signature::from((signature, recovery_id));
+        Ok(envelope)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bloom, Bytes, U256};
+    use alloy_rpc_types_engine::{ExecutionPayloadV1, ExecutionPayloadV2};
+
+    #[test]
+    fn test_static_signer_source() {
+        let signer = Address::random();
+        assert_eq!(BlockSignerSource::from(signer).current(), signer);
+    }
+
+    #[test]
+    fn test_dynamic_signer_source_tracks_updates() {
+        let (tx, rx) = tokio::sync::watch::channel(Address::ZERO);
+        let source = BlockSignerSource::from(rx);
+        assert_eq!(source.current(), Address::ZERO);
+
+        let signer = Address::random();
+        tx.send(signer).unwrap();
+        assert_eq!(source.current(), signer);
+    }
+
+    #[test]
+    fn test_payload_signer_signs_decodable_envelope() {
+        let key = B256::repeat_byte(0x42);
+        let signer = PayloadSigner::new(key, 10).unwrap();
+
+        let payload = OpExecutionPayload::V2(ExecutionPayloadV2 {
+            payload_inner: ExecutionPayloadV1 {
+                parent_hash: B256::ZERO,
+                fee_recipient: Address::ZERO,
+                state_root: B256::ZERO,
+                receipts_root: B256::ZERO,
+                logs_bloom: Bloom::ZERO,
+                prev_randao: B256::ZERO,
+                block_number: 1,
+                gas_limit: 30_000_000,
+                gas_used: 0,
+                timestamp: 2,
+                extra_data: Bytes::new(),
+                base_fee_per_gas: U256::from(1),
+                block_hash: B256::repeat_byte(1),
+                transactions: vec![],
+            },
+            withdrawals: vec![],
+        });
+        let envelope = signer.sign(payload, None).unwrap();
+
+        // The envelope survives a gossip round trip, and recovers to the signer.
+        let decoded = OpNetworkPayloadEnvelope::decode_v2(&envelope.encode_v2().unwrap()).unwrap();
+        assert_eq!(decoded.payload_hash, envelope.payload_hash);
+        let msg = decoded.payload_hash.signature_message(10);
+        let recovered = decoded.signature.recover_address_from_prehash(&msg).unwrap();
+        assert_eq!(recovered, signer.address());
+    }
+
+    #[test]
+    fn test_payload_signer_rejects_invalid_key() {
+        assert!(matches!(
+            PayloadSigner::new(B256::ZERO, 10),
+            Err(PayloadSignerError::InvalidKey(_))
+        ));
+    }
+}
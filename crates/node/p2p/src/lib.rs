@@ -25,20 +25,20 @@ pub use rpc::{NetRpcRequest, NetworkRpc};
 
 mod gossip;
 pub use gossip::{
-    Behaviour, BehaviourError, BlockHandler, BlockInvalidError, DEFAULT_MESH_D, DEFAULT_MESH_DHI,
-    DEFAULT_MESH_DLAZY, DEFAULT_MESH_DLO, Event, GLOBAL_VALIDATE_THROTTLE, GOSSIP_HEARTBEAT,
-    GossipDriver, GossipDriverBuilder, GossipDriverBuilderError, Handler, HandlerEncodeError,
-    MAX_GOSSIP_SIZE, MAX_OUTBOUND_QUEUE, MAX_VALIDATE_QUEUE, MIN_GOSSIP_SIZE,
-    PEER_SCORE_INSPECT_FREQUENCY, PublishError, SEEN_MESSAGES_TTL, default_config,
-    default_config_builder,
+    Behaviour, BehaviourError, BlockHandler, BlockInvalidError, BlockSignerSource, DEFAULT_MESH_D,
+    DEFAULT_MESH_DHI, DEFAULT_MESH_DLAZY, DEFAULT_MESH_DLO, Event, GLOBAL_VALIDATE_THROTTLE,
+    GOSSIP_HEARTBEAT, GossipDriver, GossipDriverBuilder, GossipDriverBuilderError,
+    GossipRateLimiter, Handler, HandlerEncodeError, MAX_GOSSIP_SIZE, MAX_OUTBOUND_QUEUE,
+    MAX_VALIDATE_QUEUE, MIN_GOSSIP_SIZE, PEER_SCORE_INSPECT_FREQUENCY, PayloadSigner,
+    PayloadSignerError, PublishError, SEEN_MESSAGES_TTL, default_config, default_config_builder,
 };
 
 mod peers;
 pub use peers::{
     AnyNode, BootNode, BootNodes, BootStore, DialOptsError, EnrValidation, NodeRecord,
     NodeRecordParseError, OP_RAW_BOOTNODES, OP_RAW_TESTNET_BOOTNODES, OpStackEnr, PeerId,
-    PeerIdConversionError, PeerMonitoring, PeerScoreLevel, enr_to_multiaddr, local_id_to_p2p_id,
-    peer_id_to_secp256k1_pubkey,
+    PeerIdConversionError, PeerMonitoring, PeerScoreLevel, enr_to_multiaddr, enr_to_peer_id,
+    local_id_to_p2p_id, peer_id_to_secp256k1_pubkey,
 };
 
 mod discv5;
@@ -47,4 +47,4 @@ pub use discv5::{
 };
 
 mod utils;
-pub use utils::{KeypairError, ParseKeyError, get_keypair, parse_key};
+pub use utils::{KeypairError, ParseKeyError, discovery_signing_key, get_keypair, parse_key};
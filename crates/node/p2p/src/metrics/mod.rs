@@ -10,6 +10,12 @@ lazy_static! {
         "Count of currently connected p2p peers"
     ).expect("Peer count failed to register");
 
+    /// Gauge of the number of inbound peers rejected by the strict chain filter.
+    pub static ref CHAIN_FILTERED_PEERS: IntGauge = register_int_gauge!(
+        "kona_node_chain_filtered_peers",
+        "Count of inbound p2p peers rejected by the strict chain filter"
+    ).expect("Chain filtered peers failed to register");
+
     /// Histogram of currently connected peer scores.
     pub static ref PEER_SCORES: HistogramVec = register_histogram_vec!(
         "kona_node_peer_scores",
@@ -10,7 +10,8 @@ use tokio::sync::broadcast::Sender as BroadcastSender;
 
 use crate::{
     Broadcast, Config, Discv5Builder, GossipDriverBuilder, NetRpcRequest, Network,
-    NetworkBuilderError, PeerMonitoring, PeerScoreLevel, discv5::LocalNode,
+    NetworkBuilderError, PeerMonitoring, PeerScoreLevel, discovery_signing_key, discv5::LocalNode,
+    get_keypair,
 };
 
 /// Constructs a [`Network`] for the OP Stack Consensus Layer.
@@ -30,6 +31,8 @@ pub struct NetworkBuilder {
     payload_tx: Option<BroadcastSender<OpNetworkPayloadEnvelope>>,
     /// A receiver for unsafe blocks to publish.
     publish_rx: Option<tokio::sync::mpsc::Receiver<OpNetworkPayloadEnvelope>>,
+    /// The path to the node key, shared by the discovery and gossip drivers.
+    key_path: Option<PathBuf>,
 }
 
 impl From<Config> for NetworkBuilder {
@@ -44,9 +47,13 @@ impl From<Config> for NetworkBuilder {
             .with_unsafe_block_signer(config.unsafe_block_signer)
             .with_gossip_config(config.gossip_config)
             .with_peer_scoring(config.scoring)
+            .with_peer_monitoring(config.monitor_peers)
             .with_block_time(config.block_time)
             .with_keypair(config.keypair)
             .with_peer_redial(config.redial)
+            .with_gossip_rate_limit(config.gossip_rate_limit)
+            .with_strict_chain_filter(config.strict_chain_filter)
+            .with_reject_unknown_peers(config.reject_unknown_peers)
     }
 }
 
@@ -61,6 +68,7 @@ impl NetworkBuilder {
             payload_tx: None,
             publish_rx: None,
             cfg: None,
+            key_path: None,
         }
     }
 
@@ -82,6 +90,24 @@ impl NetworkBuilder {
         Self { discovery: self.discovery.with_bootnodes(bootnodes), ..self }
     }
 
+    /// Sets whether the [`crate::GossipDriver`] refuses inbound connections from peers whose
+    /// discovered ENR advertises a chain id other than the chain id of the network.
+    pub fn with_strict_chain_filter(self, strict_chain_filter: bool) -> Self {
+        Self { gossip: self.gossip.with_strict_chain_filter(strict_chain_filter), ..self }
+    }
+
+    /// Sets whether the strict chain filter of the [`crate::GossipDriver`] also refuses inbound
+    /// connections from peers whose ENR is unknown.
+    pub fn with_reject_unknown_peers(self, reject_unknown_peers: bool) -> Self {
+        Self { gossip: self.gossip.with_reject_unknown_peers(reject_unknown_peers), ..self }
+    }
+
+    /// Sets the number of gossip messages per second that each peer may send.
+    /// If unset or zero, peers are not rate limited.
+    pub fn with_gossip_rate_limit(self, rate: Option<u32>) -> Self {
+        Self { gossip: self.gossip.with_gossip_rate_limit(rate), ..self }
+    }
+
     /// Sets the block time used by peer scoring.
     pub fn with_block_time(self, block_time: u64) -> Self {
         Self { gossip: self.gossip.with_block_time(block_time), ..self }
@@ -97,6 +123,12 @@ impl NetworkBuilder {
         Self { gossip: self.gossip.with_peer_monitoring(peer_monitoring), ..self }
     }
 
+    /// Sets the interval at which the scores of banned peers are multiplied by `decay_factor`,
+    /// allowing them to recover. Only applies if [`PeerMonitoring`] is configured.
+    pub fn with_peer_decay(self, decay_interval: Duration, decay_factor: f64) -> Self {
+        Self { gossip: self.gossip.with_peer_decay(decay_interval, decay_factor), ..self }
+    }
+
     /// Sets the discovery interval for the [`crate::Discv5Driver`].
     pub fn with_discovery_interval(self, interval: tokio::time::Duration) -> Self {
         Self { discovery: self.discovery.with_interval(interval), ..self }
@@ -159,6 +191,18 @@ impl NetworkBuilder {
         Self { gossip: self.gossip.with_keypair(keypair), ..self }
     }
 
+    /// Sets the path of the node key used by both the [`crate::Discv5Driver`] and the
+    /// [`crate::GossipDriver`], taking precedence over any other configured key.
+    ///
+    /// The key is loaded from the file when the network is built. If the file does not exist, a
+    /// new key is generated and written to it, so the node id remains stable across restarts.
+    ///
+    /// The file holds the node's private key in plaintext. Anyone able to read it can impersonate
+    /// the node on the network, so it should only be readable by the node operator.
+    pub fn with_key_path(self, path: PathBuf) -> Self {
+        Self { key_path: Some(path), ..self }
+    }
+
     /// Sets the unsafe block signer for the [`crate::GossipDriver`].
     pub fn with_unsafe_block_signer(self, signer: Address) -> Self {
         Self { signer: Some(signer), ..self }
@@ -174,6 +218,14 @@ impl NetworkBuilder {
 
     /// Builds the [`Network`].
     pub fn build(mut self) -> Result<Network, NetworkBuilderError> {
+        if let Some(path) = self.key_path.take() {
+            let keypair =
+                get_keypair(&path).map_err(|e| NetworkBuilderError::NodeKey(e.to_string()))?;
+            let signing_key = discovery_signing_key(&keypair)
+                .map_err(|e| NetworkBuilderError::NodeKey(e.to_string()))?;
+            self.discovery = self.discovery.with_signing_key(signing_key);
+            self.gossip = self.gossip.with_keypair(keypair);
+        }
         let signer = self.signer.take().ok_or(NetworkBuilderError::UnsafeBlockSignerNotSet)?;
         let (signer_tx, signer_rx) = tokio::sync::watch::channel(signer);
         let unsafe_block_signer_sender = Some(signer_tx);
@@ -276,6 +328,47 @@ mod tests {
         assert_eq!(driver.gossip.handler.blocks_v4_topic.hash(), v4.hash());
     }
 
+    fn build_with_key_path(path: PathBuf) -> Network {
+        let CombinedKey::Secp256k1(secret_key) = CombinedKey::generate_secp256k1() else {
+            unreachable!()
+        };
+        let disc_listen = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 9097);
+        let disc_enr = LocalNode::new(secret_key, IpAddr::V4(Ipv4Addr::UNSPECIFIED), 9098, 9098);
+        let mut gossip_addr = Multiaddr::from(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        gossip_addr.push(libp2p::multiaddr::Protocol::Tcp(9099));
+        NetworkBuilder::new()
+            .with_unsafe_block_signer(Address::random())
+            .with_chain_id(10)
+            .with_rpc_receiver(tokio::sync::mpsc::channel(1).1)
+            .with_gossip_address(gossip_addr)
+            .with_discovery_address(disc_enr)
+            .with_discovery_config(ConfigBuilder::new(disc_listen.into()).build())
+            .with_key_path(path)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build_with_key_path_persists_node_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("p2p_priv.txt");
+
+        // A fresh run generates a new key and persists it.
+        let first = build_with_key_path(path.clone());
+        assert!(path.exists());
+        let persisted = std::fs::read_to_string(&path).unwrap();
+
+        // Loading the existing key yields the same node id.
+        let second = build_with_key_path(path.clone());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), persisted);
+        let node_id = first.discovery.disc.local_enr().node_id();
+        assert_eq!(second.discovery.disc.local_enr().node_id(), node_id);
+
+        // A different path generates a different node id.
+        let other = build_with_key_path(dir.path().join("other_priv.txt"));
+        assert_ne!(other.discovery.disc.local_enr().node_id(), node_id);
+    }
+
     #[test]
     fn test_build_network_custom_configs() {
         let id = 10;
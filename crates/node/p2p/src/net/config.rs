@@ -35,6 +35,13 @@ pub struct Config {
     pub bootstore: Option<PathBuf>,
     /// The optional number of times to redial a peer.
     pub redial: Option<u64>,
+    /// The optional number of gossip messages per second that each peer may send.
+    /// If unset or zero, peers are not rate limited.
+    pub gossip_rate_limit: Option<u32>,
+    /// Whether inbound peers whose ENR advertises another chain id are rejected.
+    pub strict_chain_filter: bool,
+    /// Whether the strict chain filter also rejects inbound peers with an unknown ENR.
+    pub reject_unknown_peers: bool,
     /// An optional list of bootnode ENRs to start the node with.
     pub bootnodes: Vec<Enr>,
 }
@@ -10,6 +10,7 @@ use tokio::{
     sync::{broadcast::Receiver as BroadcastReceiver, watch::Sender},
     time::Duration,
 };
+use tokio_util::sync::CancellationToken;
 
 use crate::{Broadcast, Discv5Driver, GossipDriver, HandlerRequest, NetRpcRequest, NetworkBuilder};
 
@@ -44,6 +45,9 @@ impl Network {
     /// The frequency at which to inspect peer scores to ban poorly performing peers.
     const PEER_SCORE_INSPECT_FREQUENCY: Duration = Duration::from_secs(1);
 
+    /// The number of unsafe blocks that may be buffered for publishing.
+    const PUBLISH_BUFFER_SIZE: usize = 256;
+
     /// Returns the [`NetworkBuilder`] that can be used to construct the [`Network`].
     pub const fn builder() -> NetworkBuilder {
         NetworkBuilder::new()
@@ -54,39 +58,79 @@ impl Network {
         self.broadcast.subscribe()
     }
 
+    /// Returns a sender for unsafe blocks to publish, replacing the publish receiver that was set
+    /// on the [`NetworkBuilder`], if any.
+    pub fn publish_sender(&mut self) -> tokio::sync::mpsc::Sender<OpNetworkPayloadEnvelope> {
+        let (tx, rx) = tokio::sync::mpsc::channel(Self::PUBLISH_BUFFER_SIZE);
+        self.publish_rx = Some(rx);
+        tx
+    }
+
     /// Take the unsafe block signer sender.
     pub fn take_unsafe_block_signer_sender(&mut self) -> Option<Sender<Address>> {
         self.unsafe_block_signer_sender.take()
     }
 
     /// Starts the Discv5 peer discovery & libp2p services
-    /// and continually listens for new peers and messages to handle
-    pub fn start(mut self) -> Result<(), TransportError<std::io::Error>> {
-        let mut rpc = self.rpc.unwrap_or_else(|| tokio::sync::mpsc::channel(1).1);
-        let mut publish = self.publish_rx.unwrap_or_else(|| tokio::sync::mpsc::channel(1).1);
+    /// and continually listens for new peers and messages to handle.
+    ///
+    /// The network stops once the [`CancellationToken`] is cancelled, or once the sender of the
+    /// rpc or publish receiver set on the [`NetworkBuilder`] is dropped. Stopping the network
+    /// closes the unsafe block receivers returned by [`Network::unsafe_block_recv`].
+    pub fn start(
+        mut self,
+        cancellation: CancellationToken,
+    ) -> Result<(), TransportError<std::io::Error>> {
+        // The senders of the default receivers are held by the network task, so that a missing
+        // receiver is never closed.
+        let (rpc_sender, rpc) = tokio::sync::mpsc::channel(1);
+        let mut rpc = self.rpc.unwrap_or(rpc);
+        let (publish_sender, publish) = tokio::sync::mpsc::channel(1);
+        let mut publish = self.publish_rx.unwrap_or(publish);
         let (handler, mut enr_receiver) = self.discovery.start();
         let mut broadcast = self.broadcast;
 
         // We are checking the peer scores every [`Self::PEER_SCORE_INSPECT_FREQUENCY`] seconds.
         let mut peer_score_inspector = tokio::time::interval(Self::PEER_SCORE_INSPECT_FREQUENCY);
 
+        // Banned peer scores are decayed every [`PeerMonitoring::decay_interval`], if enabled.
+        let decay_interval = self
+            .gossip
+            .peer_monitoring
+            .as_ref()
+            .filter(|m| m.decay_enabled())
+            .map(|m| m.decay_interval);
+        let decay_enabled = decay_interval.is_some();
+        let mut peer_score_decay =
+            tokio::time::interval(decay_interval.unwrap_or(Self::PEER_SCORE_INSPECT_FREQUENCY));
+
         // Start the libp2p Swarm
         self.gossip.listen()?;
 
         // Spawn the network handler
         tokio::spawn(async move {
+            let _senders = (rpc_sender, publish_sender);
             loop {
                 broadcast.broadcast();
                 select! {
+                    _ = cancellation.cancelled() => {
+                        info!("Received shutdown signal, stopping the network");
+                        break;
+                    }
                     block = publish.recv() => {
                         let Some(block) = block else {
-                            continue;
+                            warn!("Unsafe block publish channel closed, stopping the network");
+                            break;
                         };
                         let timestamp = block.payload.timestamp();
-                        let selector = |handler: &crate::BlockHandler| {
-                            handler.topic(timestamp, self.cfg.as_ref())
+                        let published = match self.cfg.as_ref() {
+                            Some(cfg) => self.gossip.publish_all(block, timestamp, cfg),
+                            None => self.gossip.publish(
+                                |handler| handler.topic(timestamp, None),
+                                Some(block),
+                            ),
                         };
-                        match self.gossip.publish(selector, Some(block)) {
+                        match published {
                             Ok(id) => info!("Published unsafe payload | {:?}", id),
                             Err(e) => warn!("Failed to publish unsafe payload: {:?}", e),
                         }
@@ -99,8 +143,8 @@ impl Network {
                     },
                     enr = enr_receiver.recv() => {
                         let Some(enr) = enr else {
-                            trace!("Receiver `None` peer enr");
-                            continue;
+                            warn!("Discovery service stopped, stopping the network");
+                            break;
                         };
                         self.gossip.dial(enr);
                     },
@@ -119,39 +163,41 @@ impl Network {
                                  let score = self.gossip.swarm.behaviour().gossipsub.peer_score(peer_id).unwrap_or_default();
 
                                  if score < ban_peers.ban_threshold {
-                                    return Some(*peer_id);
+                                    return Some((*peer_id, score));
                                  }
 
                                  None
                             }
                         ).collect::<Vec<_>>();
 
-                        // We remove the addresses from the gossip layer.
-                        let addrs_to_ban = peers_to_remove.into_iter().filter_map(|peer_to_remove| {
-                            // In that case, we ban the peer. This means...
-                            // 1. We remove the peer from the network gossip.
-                            // 2. We ban the peer from the discv5 service.
-                            if self.gossip.swarm.disconnect_peer_id(peer_to_remove).is_err() {
-                                warn!(peer = ?peer_to_remove, "Trying to disconnect a non-existing peer from the gossip driver.");
-                            }
-
-                            if let Some(addr) = self.gossip.peerstore.remove(&peer_to_remove){
-                                self.gossip.dialed_peers.remove(&addr);
-                                return Some(addr);
-                            }
-
-                            None
-                        }).collect::<HashSet<_>>();
+                        // In that case, we ban the peer. This means...
+                        // 1. We remove the peer from the network gossip.
+                        // 2. We ban the peer from the discv5 service.
+                        let ban_duration = ban_peers.ban_duration;
+                        let addrs_to_ban = peers_to_remove
+                            .into_iter()
+                            .filter_map(|(peer_id, score)| self.gossip.ban_peer(peer_id, score))
+                            .collect::<HashSet<_>>();
 
                         // We send a request to the discovery handler to ban the set of addresses.
-                        if let Err(send_err) = handler.sender.send(HandlerRequest::BanAddrs { addrs_to_ban: addrs_to_ban.into(), ban_duration: ban_peers.ban_duration }).await{
+                        if let Err(send_err) = handler.sender.send(HandlerRequest::BanAddrs { addrs_to_ban: addrs_to_ban.into(), ban_duration }).await{
                             warn!(err = ?send_err, "Impossible to send a request to the discovery handler. The channel connection is dropped.");
                         }
+
+                        // Lift the bans that have expired.
+                        for peer_id in self.gossip.prune_expired_bans() {
+                            debug!(peer = ?peer_id, "Peer ban expired, unbanning peer");
+                        }
+                    },
+                    _ = peer_score_decay.tick(), if decay_enabled => {
+                        for peer_id in self.gossip.decay_peer_scores() {
+                            debug!(peer = ?peer_id, "Peer score recovered, unbanning peer");
+                        }
                     },
                     req = rpc.recv() => {
                         let Some(req) = req else {
-                            trace!("Receiver `None` rpc request");
-                            continue;
+                            warn!("Network rpc channel closed, stopping the network");
+                            break;
                         };
                         req.handle(&self.gossip, &handler);
                     },
@@ -162,3 +208,72 @@ impl Network {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LocalNode, NetRpcRequest};
+    use discv5::{ConfigBuilder, ListenConfig, enr::CombinedKey};
+    use libp2p::Multiaddr;
+    use std::net::{IpAddr, Ipv4Addr};
+    use tokio::sync::{broadcast::error::RecvError, mpsc};
+
+    fn test_network(rpc: Option<mpsc::Receiver<NetRpcRequest>>) -> Network {
+        let CombinedKey::Secp256k1(secret_key) = CombinedKey::generate_secp256k1() else {
+            unreachable!()
+        };
+        let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+        let disc = LocalNode::new(secret_key, ip, 0, 0);
+        let mut gossip_addr = Multiaddr::from(ip);
+        gossip_addr.push(libp2p::multiaddr::Protocol::Tcp(0));
+        let mut builder = NetworkBuilder::new()
+            .with_unsafe_block_signer(Address::random())
+            .with_chain_id(10)
+            .with_gossip_address(gossip_addr)
+            .with_discovery_address(disc)
+            .with_discovery_config(ConfigBuilder::new(ListenConfig::from_ip(ip, 0)).build());
+        if let Some(rpc) = rpc {
+            builder = builder.with_rpc_receiver(rpc);
+        }
+        builder.build().unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_network_stops_on_cancellation() {
+        let mut network = test_network(None);
+        let mut blocks = network.unsafe_block_recv();
+        let cancellation = CancellationToken::new();
+        network.start(cancellation.clone()).unwrap();
+
+        cancellation.cancel();
+
+        let result = tokio::time::timeout(Duration::from_secs(5), blocks.recv()).await.unwrap();
+        assert_eq!(result.unwrap_err(), RecvError::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_network_stops_on_closed_rpc_channel() {
+        let (tx, rx) = mpsc::channel(1);
+        let mut network = test_network(Some(rx));
+        let mut blocks = network.unsafe_block_recv();
+        network.start(CancellationToken::new()).unwrap();
+
+        drop(tx);
+
+        let result = tokio::time::timeout(Duration::from_secs(5), blocks.recv()).await.unwrap();
+        assert_eq!(result.unwrap_err(), RecvError::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_network_runs_without_receivers() {
+        let mut network = test_network(None);
+        let mut blocks = network.unsafe_block_recv();
+        let cancellation = CancellationToken::new();
+        network.start(cancellation.clone()).unwrap();
+
+        // Without an rpc or publish receiver, the network keeps running until cancelled.
+        let result = tokio::time::timeout(Duration::from_millis(500), blocks.recv()).await;
+        assert!(result.is_err());
+        cancellation.cancel();
+    }
+}
@@ -14,6 +14,9 @@ pub enum NetworkBuilderError {
     /// The unsafe block signer is missing.
     #[error("missing unsafe block signer")]
     UnsafeBlockSignerNotSet,
+    /// Failed to load or generate the node key.
+    #[error("failed to load the node key: {0}")]
+    NodeKey(String),
     /// Missing RPC receiver.
     #[error("missing RPC receiver")]
     MissingRpcReceiver,
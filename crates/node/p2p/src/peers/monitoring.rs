@@ -9,4 +9,30 @@ pub struct PeerMonitoring {
     pub ban_threshold: f64,
     /// The duration of a peer's ban.
     pub ban_duration: Duration,
+    /// The interval at which the scores of banned peers are decayed.
+    pub decay_interval: Duration,
+    /// The factor that the scores of banned peers are multiplied by every `decay_interval`.
+    ///
+    /// Scores decay towards zero, so a banned peer recovers once its decayed score is no longer
+    /// below the `ban_threshold`. A factor of `1.0` disables decay.
+    pub decay_factor: f64,
+}
+
+impl PeerMonitoring {
+    /// Creates a new [`PeerMonitoring`] configuration without score decay.
+    pub const fn new(ban_threshold: f64, ban_duration: Duration) -> Self {
+        Self { ban_threshold, ban_duration, decay_interval: Duration::ZERO, decay_factor: 1.0 }
+    }
+
+    /// Sets the interval at which the scores of banned peers are multiplied by `decay_factor`.
+    pub const fn with_decay(mut self, decay_interval: Duration, decay_factor: f64) -> Self {
+        self.decay_interval = decay_interval;
+        self.decay_factor = decay_factor;
+        self
+    }
+
+    /// Returns if the scores of banned peers decay over time.
+    pub fn decay_enabled(&self) -> bool {
+        !self.decay_interval.is_zero() && (0.0..1.0).contains(&self.decay_factor)
+    }
 }
@@ -1,6 +1,6 @@
 //! Utilities to translate types.
 
-use discv5::{Enr, multiaddr::Protocol};
+use discv5::{Enr, enr::EnrPublicKey, multiaddr::Protocol};
 use libp2p::Multiaddr;
 
 use super::PeerId;
@@ -20,6 +20,15 @@ pub fn enr_to_multiaddr(enr: &Enr) -> Option<Multiaddr> {
     None
 }
 
+/// Returns the [`libp2p::PeerId`] of the node described by the [`Enr`], or `None` if the [`Enr`]
+/// is not signed with a secp256k1 key.
+pub fn enr_to_peer_id(enr: &Enr) -> Option<libp2p::PeerId> {
+    let pk =
+        discv5::libp2p_identity::secp256k1::PublicKey::try_from_bytes(&enr.public_key().encode())
+            .ok()?;
+    Some(discv5::libp2p_identity::PublicKey::from(pk).to_peer_id())
+}
+
 /// Converts an uncompressed [`PeerId`] to a [`secp256k1::PublicKey`] by prepending the [`PeerId`]
 /// bytes with the `SECP256K1_TAG_PUBKEY_UNCOMPRESSED` tag.
 pub fn peer_id_to_secp256k1_pubkey(id: PeerId) -> Result<secp256k1::PublicKey, secp256k1::Error> {
@@ -82,6 +91,17 @@ mod tests {
         assert_eq!(peer_id, p2p_public_key.to_peer_id());
     }
 
+    #[test]
+    fn test_enr_to_peer_id() {
+        let p2p_keypair = discv5::libp2p_identity::Keypair::generate_secp256k1();
+        let key = discv5::enr::CombinedKey::Secp256k1(
+            crate::discovery_signing_key(&p2p_keypair).unwrap(),
+        );
+        let enr = Enr::builder().build(&key).unwrap();
+
+        assert_eq!(enr_to_peer_id(&enr), Some(p2p_keypair.public().to_peer_id()));
+    }
+
     #[test]
     fn test_hardcoded_peer_id() {
         const PUB_KEY_STR: &str = "548f715f3fc388a7c917ba644a2f16270f1ede48a5d88a4d14ea287cc916068363f3092e39936f1a3e7885198bef0e5af951f1d7b1041ce8ba4010917777e71f";
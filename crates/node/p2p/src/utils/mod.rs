@@ -1,4 +1,4 @@
 //! Utility methods for P2P.
 
 mod secret_key;
-pub use secret_key::{KeypairError, ParseKeyError, get_keypair, parse_key};
+pub use secret_key::{KeypairError, ParseKeyError, discovery_signing_key, get_keypair, parse_key};
@@ -3,6 +3,7 @@
 //! This module is adapted from <https://github.com/paradigmxyz/reth/blob/aef442740c51fc00884d34931ebc3b547e41b9f4/crates/cli/util/src/load_secret_key.rs#L20>
 
 use alloy_primitives::B256;
+use discv5::enr::k256::ecdsa::SigningKey;
 use libp2p::identity::{Keypair, secp256k1::SecretKey};
 use std::{
     path::{Path, PathBuf},
@@ -25,6 +26,17 @@ pub fn parse_key(input: &mut [u8]) -> Result<Keypair, ParseKeyError> {
     Ok(Keypair::from(kp))
 }
 
+/// Converts a secp256k1 [`Keypair`] into the [`SigningKey`] used by the discovery service.
+pub fn discovery_signing_key(keypair: &Keypair) -> Result<SigningKey, ParseKeyError> {
+    let secret = keypair
+        .clone()
+        .try_into_secp256k1()
+        .map_err(|_| ParseKeyError::FailedToParseSecretKey)?
+        .secret()
+        .to_bytes();
+    SigningKey::from_bytes(&secret.into()).map_err(|_| ParseKeyError::FailedToParseSecretKey)
+}
+
 /// Errors returned by loading a [`Keypair`], including IO errors.
 #[derive(Error, Debug)]
 pub enum KeypairError {
@@ -55,6 +67,9 @@ pub enum KeypairError {
 /// If no file exists there, then it generates a secret key
 /// and stores it in the provided path. I/O errors might occur
 /// during write operations in the form of a [`KeypairError`]
+///
+/// The file holds the node's private key, so anyone with access to it can impersonate the node.
+/// On unix, newly generated key files are only readable and writable by their owner.
 pub fn get_keypair(secret_key_path: &Path) -> Result<Keypair, KeypairError> {
     let exists = secret_key_path.try_exists();
 
@@ -71,7 +86,7 @@ pub fn get_keypair(secret_key_path: &Path) -> Result<Keypair, KeypairError> {
 
             let secret = SecretKey::generate();
             let hex = alloy_primitives::hex::encode(secret.to_bytes());
-            std::fs::write(secret_key_path, hex)?;
+            write_secret_file(secret_key_path, hex.as_bytes())?;
             let kp = libp2p::identity::secp256k1::Keypair::from(secret);
             Ok(Keypair::from(kp))
         }
@@ -82,6 +97,17 @@ pub fn get_keypair(secret_key_path: &Path) -> Result<Keypair, KeypairError> {
     }
 }
 
+/// Writes the secret key file, restricting its permissions to the owner on unix.
+fn write_secret_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+    options.open(path)?.write_all(contents)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,6 +145,27 @@ mod tests {
         };
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_get_keypair_new_file_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("p2p_priv.txt");
+        get_keypair(&path).unwrap();
+
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_discovery_signing_key() {
+        let keypair = Keypair::generate_secp256k1();
+        let signing_key = discovery_signing_key(&keypair).unwrap();
+        let secret = keypair.try_into_secp256k1().unwrap().secret().to_bytes();
+        assert_eq!(signing_key.to_bytes().as_slice(), secret.as_slice());
+    }
+
     #[test]
     fn test_get_keypair_file() {
         // Create a temporary directory.
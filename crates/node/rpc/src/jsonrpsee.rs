@@ -11,7 +11,7 @@ use core::net::IpAddr;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
 use kona_genesis::RollupConfig;
 use kona_interop::{ExecutingDescriptor, SafetyLevel};
-use kona_protocol::SyncStatus;
+use kona_protocol::{BlockInfo, L2BlockInfo, SyncStatus};
 
 #[cfg_attr(all(target_arch = "wasm32", target_os = "unknown"), allow(unused_imports))]
 use getrandom as _; // required for compiling wasm32-unknown-unknown
@@ -148,6 +148,22 @@ pub trait EngineApiExt {
     async fn signal_superchain_v1(&self, signal: SuperchainSignal) -> RpcResult<ProtocolVersion>;
 }
 
+/// Kona-specific admin API for controlling the derivation pipeline.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "admin"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "admin"))]
+pub trait DerivationAdminApi {
+    /// Resets the derivation pipeline to the given L2 safe head and L1 origin.
+    ///
+    /// The heads are validated against the node's L1 and L2 providers before the reset is applied.
+    /// Returns an error if the heads are unknown to the providers or inconsistent with each other.
+    #[method(name = "resetDerivation")]
+    async fn admin_reset_derivation(
+        &self,
+        l2_safe_head: L2BlockInfo,
+        l1_origin: BlockInfo,
+    ) -> RpcResult<()>;
+}
+
 /// Supervisor API for interop.
 #[cfg_attr(not(feature = "client"), rpc(server, namespace = "supervisor"))]
 #[cfg_attr(feature = "client", rpc(server, client, namespace = "supervisor"))]
@@ -47,11 +47,13 @@ pub use jsonrpsee::SupervisorApiClient;
 pub use jsonrpsee::SupervisorApiServer;
 #[cfg(all(feature = "jsonrpsee", feature = "client"))]
 pub use jsonrpsee::{
-    EngineApiExtClient, MinerApiExtClient, OpAdminApiClient, OpP2PApiClient, RollupNodeApiClient,
+    DerivationAdminApiClient, EngineApiExtClient, MinerApiExtClient, OpAdminApiClient,
+    OpP2PApiClient, RollupNodeApiClient,
 };
 #[cfg(feature = "jsonrpsee")]
 pub use jsonrpsee::{
-    EngineApiExtServer, MinerApiExtServer, OpAdminApiServer, OpP2PApiServer, RollupNodeApiServer,
+    DerivationAdminApiServer, EngineApiExtServer, MinerApiExtServer, OpAdminApiServer,
+    OpP2PApiServer, RollupNodeApiServer,
 };
 
 #[cfg(all(feature = "reqwest", feature = "interop"))]
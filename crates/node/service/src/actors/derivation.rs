@@ -1,6 +1,9 @@
 //! [NodeActor] implementation for the derivation sub-routine.
 
-use crate::NodeActor;
+use crate::{
+    DerivationAdminRequest, NodeActor, PayloadEngine, PayloadEngineError, ResetHeadValidator,
+    metrics::DerivationMetrics,
+};
 use async_trait::async_trait;
 use kona_derive::{
     errors::{PipelineError, PipelineErrorKind, ResetError},
@@ -9,20 +12,29 @@ use kona_derive::{
 };
 use kona_protocol::{BlockInfo, L2BlockInfo};
 use kona_rpc::OpAttributesWithParent;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use tokio::{
     select,
-    sync::mpsc::{UnboundedReceiver, UnboundedSender, error::SendError},
+    sync::mpsc::{Receiver, UnboundedReceiver},
+    time,
 };
 use tokio_util::sync::CancellationToken;
 
+/// The default delay before derived attributes that failed to apply with a
+/// [PayloadEngineError::Temporary] are retried. The delay doubles with each consecutive failure.
+pub const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_millis(100);
+
+/// The maximum delay between retries of derived attributes that failed to apply.
+pub const MAX_RETRY_BACKOFF: Duration = Duration::from_secs(10);
+
 /// The [NodeActor] for the derivation sub-routine.
 ///
 /// This actor is responsible for receiving messages from [NodeActor]s and stepping the
-/// derivation pipeline forward to produce new payload attributes. The actor then sends the payload
-/// to the [NodeActor] responsible for the execution sub-routine.
+/// derivation pipeline forward to produce new payload attributes. Each set of attributes is applied
+/// to the [PayloadEngine] before the next is derived.
 #[derive(Debug)]
-pub struct DerivationActor<P>
+pub struct DerivationActor<P, E>
 where
     P: Pipeline + SignalReceiver,
 {
@@ -30,27 +42,305 @@ where
     pipeline: P,
     /// The latest L2 safe head.
     l2_safe_head: L2BlockInfo,
-    /// The sender for derived [OpAttributesWithParent]s produced by the actor.
-    attributes_out: UnboundedSender<OpAttributesWithParent>,
+    /// The [PayloadEngine] that derived [OpAttributesWithParent]s are applied to.
+    engine: E,
+    /// Attributes that failed to apply with a temporary error, retried at `retry_at`.
+    pending: Option<OpAttributesWithParent>,
+    /// The time at which the `pending` attributes are retried.
+    retry_at: Option<time::Instant>,
+    /// The number of consecutive temporary failures to apply the `pending` attributes.
+    retries: u32,
+    /// The delay before the first retry of attributes that failed to apply.
+    retry_backoff: Duration,
     /// The receiver for L1 head update notifications.
     l1_head_updates: UnboundedReceiver<BlockInfo>,
+    /// The latest observed L1 head.
+    l1_head: Option<BlockInfo>,
+    /// Derivation lag and throughput metrics.
+    metrics: DerivationMetrics,
+    /// The admin request channel, if the admin API is enabled.
+    admin: Option<DerivationAdmin>,
     /// The cancellation token, shared between all tasks.
     cancellation: CancellationToken,
 }
 
-impl<P> DerivationActor<P>
+/// The receiver for [DerivationAdminRequest]s, along with the [ResetHeadValidator] used to check
+/// requested resets.
+#[derive(derive_more::Debug)]
+struct DerivationAdmin {
+    /// The receiver for admin requests.
+    requests: Receiver<DerivationAdminRequest>,
+    /// Validates the heads of requested resets before they are applied.
+    #[debug(skip)]
+    validator: Box<dyn ResetHeadValidator + Send + Sync>,
+}
+
+impl<P, E> DerivationActor<P, E>
 where
     P: Pipeline + SignalReceiver,
+    E: PayloadEngine + Send,
 {
     /// Creates a new instance of the [DerivationActor].
     pub const fn new(
         pipeline: P,
         l2_safe_head: L2BlockInfo,
-        attributes_out: UnboundedSender<OpAttributesWithParent>,
+        engine: E,
         l1_head_updates: UnboundedReceiver<BlockInfo>,
         cancellation: CancellationToken,
     ) -> Self {
-        Self { pipeline, l2_safe_head, attributes_out, l1_head_updates, cancellation }
+        Self {
+            pipeline,
+            l2_safe_head,
+            engine,
+            pending: None,
+            retry_at: None,
+            retries: 0,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
+            l1_head_updates,
+            l1_head: None,
+            metrics: DerivationMetrics::new(),
+            admin: None,
+            cancellation,
+        }
+    }
+
+    /// Sets the delay before the first retry of attributes that failed to apply with a
+    /// [PayloadEngineError::Temporary]. Defaults to [DEFAULT_RETRY_BACKOFF].
+    pub const fn with_retry_backoff(mut self, retry_backoff: Duration) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Enables handling of [DerivationAdminRequest]s received over the given channel. Requested
+    /// resets are checked by the `validator` before being applied to the pipeline.
+    pub fn with_admin_requests(
+        mut self,
+        requests: Receiver<DerivationAdminRequest>,
+        validator: Box<dyn ResetHeadValidator + Send + Sync>,
+    ) -> Self {
+        self.admin = Some(DerivationAdmin { requests, validator });
+        self
+    }
+
+    /// Receives the next [DerivationAdminRequest], or never resolves if the admin API is disabled.
+    async fn next_admin_request(
+        admin: &mut Option<DerivationAdmin>,
+    ) -> Option<DerivationAdminRequest> {
+        match admin {
+            Some(admin) => admin.requests.recv().await,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Waits until the `pending` attributes are due to be retried, or never resolves if there are
+    /// none.
+    async fn retry_timer(retry_at: Option<time::Instant>) {
+        match retry_at {
+            Some(retry_at) => time::sleep_until(retry_at).await,
+            None => std::future::pending().await,
+        }
+    }
+
+    /// Handles a [DerivationAdminRequest], sending the result back to the requester.
+    async fn handle_admin_request(&mut self, request: DerivationAdminRequest) {
+        match request {
+            DerivationAdminRequest::ResetDerivation { l2_safe_head, l1_origin, tx } => {
+                let result = self.reset_derivation(l2_safe_head, l1_origin).await;
+                match &result {
+                    Ok(()) => info!(
+                        target: "derivation",
+                        "Reset derivation to safe head #{} and L1 origin #{}",
+                        l2_safe_head.block_info.number,
+                        l1_origin.number
+                    ),
+                    Err(e) => warn!(target: "derivation", "Rejected derivation reset: {e}"),
+                }
+                if tx.send(result).is_err() {
+                    warn!(target: "derivation", "Failed to send derivation reset response");
+                }
+            }
+        }
+    }
+
+    /// Validates the given heads and resets the pipeline to them.
+    async fn reset_derivation(
+        &mut self,
+        l2_safe_head: L2BlockInfo,
+        l1_origin: BlockInfo,
+    ) -> Result<(), String> {
+        let Some(admin) = self.admin.as_mut() else {
+            return Err("Admin requests are not enabled".to_string());
+        };
+
+        // The pipeline must not start past the L1 origin of the safe head, or the batches
+        // building on top of the safe head would be skipped.
+        if l1_origin.number > l2_safe_head.l1_origin.number {
+            return Err(format!(
+                "L1 origin #{} is ahead of the safe head's L1 origin #{}",
+                l1_origin.number, l2_safe_head.l1_origin.number
+            ));
+        }
+        admin.validator.validate(&l2_safe_head, &l1_origin).await?;
+
+        let system_config = self
+            .pipeline
+            .system_config_by_number(l2_safe_head.block_info.number)
+            .await
+            .map_err(|e| e.to_string())?;
+        self.pipeline
+            .signal(
+                ResetSignal { l2_safe_head, l1_origin, system_config: Some(system_config) }
+                    .signal(),
+            )
+            .await
+            .map_err(|e| e.to_string())?;
+        self.l2_safe_head = l2_safe_head;
+        self.metrics.reset();
+        self.record_l1_lag(l2_safe_head.l1_origin.number);
+
+        // Attributes derived on top of the previous safe head are stale after a reset.
+        self.pending = None;
+        self.retry_at = None;
+        self.retries = 0;
+        Ok(())
+    }
+
+    /// Records the number of L1 blocks that the safe head's L1 origin lags behind the latest
+    /// observed L1 head.
+    fn record_l1_lag(&self, safe_head_l1_origin: u64) {
+        if let Some(l1_head) = self.l1_head {
+            self.metrics.record_l1_lag(l1_head.number, safe_head_l1_origin);
+        }
+    }
+
+    /// Resets the pipeline to the current L2 safe head and L1 origin.
+    async fn reset_pipeline(&mut self) -> Result<(), DerivationError> {
+        let system_config =
+            self.pipeline.system_config_by_number(self.l2_safe_head.block_info.number).await?;
+        let l1_origin = self.pipeline.origin().ok_or(PipelineError::MissingOrigin.crit())?;
+        self.pipeline
+            .signal(
+                ResetSignal {
+                    l2_safe_head: self.l2_safe_head,
+                    l1_origin,
+                    system_config: Some(system_config),
+                }
+                .signal(),
+            )
+            .await?;
+        self.metrics.reset();
+        Ok(())
+    }
+
+    /// Runs the derivation loop until the actor is cancelled or the L1 head update stream closes.
+    ///
+    /// Every time the L1 head advances, the pipeline is stepped until it yields, and each set of
+    /// derived attributes is applied to the [PayloadEngine] before the next is derived. Attributes
+    /// that fail to apply with a [PayloadEngineError::Temporary] are retried with an exponential
+    /// backoff, starting at the configured retry backoff and capped at [MAX_RETRY_BACKOFF].
+    async fn run_derivation_loop(&mut self) -> Result<(), DerivationError> {
+        DerivationMetrics::describe();
+
+        loop {
+            select! {
+                _ = self.cancellation.cancelled() => {
+                    info!(
+                        target: "derivation",
+                        "Received shutdown signal. Exiting derivation task."
+                    );
+                    return Ok(());
+                }
+                msg = self.l1_head_updates.recv() => {
+                    let Some(l1_head) = msg else {
+                        error!(
+                            target: "derivation",
+                            "L1 head update stream closed without cancellation. Exiting derivation task."
+                        );
+                        return Ok(());
+                    };
+
+                    self.l1_head = Some(l1_head);
+                    self.record_l1_lag(self.l2_safe_head.l1_origin.number);
+                    self.metrics.update_throughput(Instant::now());
+                    self.apply_next_safe_payloads().await?;
+                }
+                _ = Self::retry_timer(self.retry_at) => {
+                    self.retry_at = None;
+                    self.apply_next_safe_payloads().await?;
+                }
+                Some(request) = Self::next_admin_request(&mut self.admin) => {
+                    self.handle_admin_request(request).await;
+                }
+            }
+        }
+    }
+
+    /// Returns the delay before the next retry of attributes that failed to apply, doubling with
+    /// each consecutive failure.
+    fn retry_delay(&self) -> Duration {
+        self.retry_backoff.saturating_mul(2u32.saturating_pow(self.retries)).min(MAX_RETRY_BACKOFF)
+    }
+
+    /// Derives and applies safe payloads to the [PayloadEngine] until the pipeline yields, or a
+    /// payload fails to apply with a [PayloadEngineError::Temporary].
+    async fn apply_next_safe_payloads(&mut self) -> Result<(), DerivationError> {
+        // Attributes that failed to apply are not retried before their backoff has elapsed.
+        if self.retry_at.is_some() {
+            return Ok(());
+        }
+
+        // Advance the pipeline as much as possible, new data may be available or there still may be
+        // payloads in the attributes queue.
+        loop {
+            let attributes = match self.pending.take() {
+                Some(attributes) => attributes,
+                None => match self.produce_next_safe_payload().await {
+                    Ok(attributes) => attributes,
+                    // Yield until more data is available.
+                    Err(DerivationError::Yield) => return Ok(()),
+                    Err(e) => return Err(e),
+                },
+            };
+
+            match self.engine.apply_attributes(attributes.clone()).await {
+                Ok(safe_head) => {
+                    debug!(
+                        target: "derivation",
+                        "Applied derived payload; new safe head #{}",
+                        safe_head.block_info.number
+                    );
+                    self.l2_safe_head = safe_head;
+                    self.retries = 0;
+                    self.metrics.record_derived_block(Instant::now(), attributes.is_last_in_span);
+                    self.record_l1_lag(safe_head.l1_origin.number);
+                }
+                Err(PayloadEngineError::Temporary(e)) => {
+                    let delay = self.retry_delay();
+                    warn!(
+                        target: "derivation",
+                        "Failed to apply derived payload, retrying in {delay:?}: {e}"
+                    );
+                    self.pending = Some(attributes);
+                    self.retry_at = Some(time::Instant::now() + delay);
+                    self.retries = self.retries.saturating_add(1);
+                    return Ok(());
+                }
+                Err(PayloadEngineError::Invalid(e)) => {
+                    warn!(
+                        target: "derivation",
+                        "Derived payload rejected by the engine, resetting pipeline: {e}"
+                    );
+                    // Derivation resumes on the next L1 head update, so that a payload that is
+                    // rejected again does not spin the loop.
+                    self.reset_pipeline().await?;
+                    return Ok(());
+                }
+                Err(e @ PayloadEngineError::Critical(_)) => {
+                    error!(target: "derivation", "Critical engine error: {e}");
+                    return Err(e.into());
+                }
+            }
+        }
     }
 
     /// Attempts to step the derivation pipeline forward as much as possible in order to produce the
@@ -89,12 +379,11 @@ where
                         PipelineErrorKind::Reset(e) => {
                             warn!(target: "derivation", "Derivation pipeline is being reset: {e}");
 
-                            let system_config = self
-                                .pipeline
-                                .system_config_by_number(self.l2_safe_head.block_info.number)
-                                .await?;
-
                             if matches!(e, ResetError::HoloceneActivation) {
+                                let system_config = self
+                                    .pipeline
+                                    .system_config_by_number(self.l2_safe_head.block_info.number)
+                                    .await?;
                                 let l1_origin = self
                                     .pipeline
                                     .origin()
@@ -117,22 +406,7 @@ where
                                     );
                                 }
 
-                                // Reset the pipeline to the initial L2 safe head and L1 origin,
-                                // and try again.
-                                let l1_origin = self
-                                    .pipeline
-                                    .origin()
-                                    .ok_or(PipelineError::MissingOrigin.crit())?;
-                                self.pipeline
-                                    .signal(
-                                        ResetSignal {
-                                            l2_safe_head: self.l2_safe_head,
-                                            l1_origin,
-                                            system_config: Some(system_config),
-                                        }
-                                        .signal(),
-                                    )
-                                    .await?;
+                                self.reset_pipeline().await?;
                             }
                         }
                         PipelineErrorKind::Critical(_) => {
@@ -143,7 +417,7 @@ where
                 }
             }
 
-            // If there are any new attributes, send them to the execution actor.
+            // If there are any new attributes, return them to be applied to the engine.
             if let Some(attrs) = self.pipeline.next() {
                 return Ok(attrs);
             }
@@ -152,54 +426,20 @@ where
 }
 
 #[async_trait]
-impl<P> NodeActor for DerivationActor<P>
+impl<P, E> NodeActor for DerivationActor<P, E>
 where
     P: Pipeline + SignalReceiver + Send + Sync,
+    E: PayloadEngine + Send + Sync,
 {
     type InboundEvent = InboundDerivationMessage;
     type Error = DerivationError;
 
     async fn start(mut self) -> Result<(), Self::Error> {
-        loop {
-            select! {
-                _ = self.cancellation.cancelled() => {
-                    info!(
-                        target: "derivation",
-                        "Received shutdown signal. Exiting derivation task."
-                    );
-                    return Ok(());
-                }
-                msg = self.l1_head_updates.recv() => {
-                    if msg.is_none() {
-                        error!(
-                            target: "derivation",
-                            "L1 head update stream closed without cancellation. Exiting derivation task."
-                        );
-                        return Ok(());
-                    }
-
-                    self.process(InboundDerivationMessage::NewDataAvailable).await?;
-                }
-            }
-        }
+        self.run_derivation_loop().await
     }
 
     async fn process(&mut self, _: Self::InboundEvent) -> Result<(), Self::Error> {
-        // Advance the pipeline as much as possible, new data may be available or there still may be
-        // payloads in the attributes queue.
-        let payload_attrs = match self.produce_next_safe_payload().await {
-            Ok(attrs) => attrs,
-            Err(DerivationError::Yield) => {
-                // Yield until more data is available.
-                return Ok(());
-            }
-            Err(e) => {
-                return Err(e);
-            }
-        };
-
-        self.attributes_out.send(payload_attrs).map_err(Box::new)?;
-        Ok(())
+        self.apply_next_safe_payloads().await
     }
 }
 
@@ -219,7 +459,291 @@ pub enum DerivationError {
     /// Waiting for more data to be available.
     #[error("Waiting for more data to be available")]
     Yield,
-    /// An error originating from the broadcast sender.
-    #[error("Failed to send event to broadcast sender")]
-    Sender(#[from] Box<SendError<OpAttributesWithParent>>),
+    /// An error originating from the engine.
+    #[error(transparent)]
+    Engine(#[from] PayloadEngineError),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_derive::{traits::OriginProvider, types::Signal};
+    use kona_genesis::{RollupConfig, SystemConfig};
+    use std::collections::VecDeque;
+    use tokio::sync::mpsc::{UnboundedSender, unbounded_channel};
+
+    /// A pipeline that yields a fixed set of attributes, then [PipelineError::Eof].
+    #[derive(Debug, Default)]
+    struct MockPipeline {
+        attributes: VecDeque<OpAttributesWithParent>,
+        prepared: Option<OpAttributesWithParent>,
+        signals: Vec<Signal>,
+        rollup_config: RollupConfig,
+    }
+
+    impl Iterator for MockPipeline {
+        type Item = OpAttributesWithParent;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.prepared.take()
+        }
+    }
+
+    impl OriginProvider for MockPipeline {
+        fn origin(&self) -> Option<BlockInfo> {
+            Some(BlockInfo::default())
+        }
+    }
+
+    #[async_trait]
+    impl SignalReceiver for MockPipeline {
+        async fn signal(&mut self, signal: Signal) -> Result<(), PipelineErrorKind> {
+            self.signals.push(signal);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Pipeline for MockPipeline {
+        fn peek(&self) -> Option<&OpAttributesWithParent> {
+            self.prepared.as_ref()
+        }
+
+        async fn step(&mut self, _: L2BlockInfo) -> StepResult {
+            match self.attributes.pop_front() {
+                Some(attributes) => {
+                    self.prepared = Some(attributes);
+                    StepResult::PreparedAttributes
+                }
+                None => StepResult::StepFailed(PipelineError::Eof.temp()),
+            }
+        }
+
+        fn rollup_config(&self) -> &RollupConfig {
+            &self.rollup_config
+        }
+
+        async fn system_config_by_number(
+            &mut self,
+            _: u64,
+        ) -> Result<SystemConfig, PipelineErrorKind> {
+            Ok(SystemConfig::default())
+        }
+    }
+
+    /// An engine that applies attributes on top of their parent, or fails with queued errors.
+    #[derive(Debug, Default)]
+    struct MockEngine {
+        errors: VecDeque<PayloadEngineError>,
+        applied: Vec<OpAttributesWithParent>,
+        /// Cancelled once attributes are applied, if set.
+        cancel_on_apply: Option<CancellationToken>,
+    }
+
+    #[async_trait]
+    impl PayloadEngine for MockEngine {
+        async fn apply_attributes(
+            &mut self,
+            attributes: OpAttributesWithParent,
+        ) -> Result<L2BlockInfo, PayloadEngineError> {
+            if let Some(err) = self.errors.pop_front() {
+                return Err(err);
+            }
+
+            let mut head = attributes.parent;
+            head.block_info.number += 1;
+            head.block_info.parent_hash = attributes.parent.block_info.hash;
+            self.applied.push(attributes);
+            if let Some(cancellation) = &self.cancel_on_apply {
+                cancellation.cancel();
+            }
+            Ok(head)
+        }
+    }
+
+    fn actor_with_l1_heads(
+        attributes: impl IntoIterator<Item = OpAttributesWithParent>,
+        l1_heads: impl IntoIterator<Item = BlockInfo>,
+    ) -> (DerivationActor<MockPipeline, MockEngine>, UnboundedSender<BlockInfo>) {
+        let pipeline =
+            MockPipeline { attributes: attributes.into_iter().collect(), ..Default::default() };
+        let (l1_head_tx, l1_head_rx) = unbounded_channel();
+        for l1_head in l1_heads {
+            l1_head_tx.send(l1_head).unwrap();
+        }
+
+        let actor = DerivationActor::new(
+            pipeline,
+            L2BlockInfo::default(),
+            MockEngine::default(),
+            l1_head_rx,
+            CancellationToken::new(),
+        );
+        (actor, l1_head_tx)
+    }
+
+    fn actor(
+        attributes: impl IntoIterator<Item = OpAttributesWithParent>,
+        l1_heads: impl IntoIterator<Item = BlockInfo>,
+    ) -> DerivationActor<MockPipeline, MockEngine> {
+        // Closing the L1 head stream terminates the loop once all updates are processed.
+        actor_with_l1_heads(attributes, l1_heads).0
+    }
+
+    fn attributes() -> OpAttributesWithParent {
+        OpAttributesWithParent::new(Default::default(), L2BlockInfo::default(), false)
+    }
+
+    #[tokio::test]
+    async fn test_derivation_loop_applies_block() {
+        let mut actor = actor([attributes()], [BlockInfo { number: 1, ..Default::default() }]);
+
+        actor.run_derivation_loop().await.unwrap();
+
+        assert_eq!(actor.engine.applied, [attributes()]);
+        assert_eq!(actor.l2_safe_head.block_info.number, 1);
+        assert!(actor.pipeline.signals.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_derivation_loop_invalid_payload_resets_pipeline() {
+        let mut actor = actor([attributes()], [BlockInfo { number: 1, ..Default::default() }]);
+        actor.engine.errors.push_back(PayloadEngineError::Invalid("bad block".to_string()));
+
+        actor.run_derivation_loop().await.unwrap();
+
+        assert!(actor.engine.applied.is_empty());
+        assert_eq!(actor.l2_safe_head, L2BlockInfo::default());
+        let [Signal::Reset(reset)] = actor.pipeline.signals.as_slice() else {
+            panic!("expected a single reset signal, got {:?}", actor.pipeline.signals);
+        };
+        assert_eq!(reset.l2_safe_head, L2BlockInfo::default());
+    }
+
+    #[tokio::test]
+    async fn test_derivation_loop_retries_temporary_failure() {
+        const BACKOFF: Duration = Duration::from_millis(50);
+
+        let l1_heads = [1, 2].map(|number| BlockInfo { number, ..Default::default() });
+        let (actor, _l1_head_tx) = actor_with_l1_heads([attributes()], l1_heads);
+        let mut actor = actor.with_retry_backoff(BACKOFF);
+        actor.engine.errors.push_back(PayloadEngineError::Temporary("engine syncing".to_string()));
+        actor.engine.cancel_on_apply = Some(actor.cancellation.clone());
+
+        let start = Instant::now();
+        actor.run_derivation_loop().await.unwrap();
+
+        // The attributes are retried once the backoff has elapsed, even though the L1 head
+        // advanced in the meantime.
+        assert!(start.elapsed() >= BACKOFF);
+        assert_eq!(actor.engine.applied, [attributes()]);
+        assert_eq!(actor.l2_safe_head.block_info.number, 1);
+        assert_eq!(actor.retries, 0);
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_exponentially() {
+        let mut actor = actor([], []).with_retry_backoff(Duration::from_secs(1));
+
+        let delays: Vec<_> = (0..6)
+            .map(|retries| {
+                actor.retries = retries;
+                actor.retry_delay()
+            })
+            .collect();
+        assert_eq!(delays, [1, 2, 4, 8, 10, 10].map(Duration::from_secs));
+
+        actor.retries = u32::MAX;
+        assert_eq!(actor.retry_delay(), MAX_RETRY_BACKOFF);
+    }
+
+    #[tokio::test]
+    async fn test_derivation_loop_critical_engine_error() {
+        let mut actor = actor([attributes()], [BlockInfo::default()]);
+        actor.engine.errors.push_back(PayloadEngineError::Critical("engine offline".to_string()));
+
+        let err = actor.run_derivation_loop().await.unwrap_err();
+        assert!(matches!(err, DerivationError::Engine(PayloadEngineError::Critical(_))));
+    }
+
+    /// A [ResetHeadValidator] that only accepts a single L2 safe head.
+    #[derive(Debug)]
+    struct MockValidator {
+        canonical: L2BlockInfo,
+    }
+
+    #[async_trait]
+    impl ResetHeadValidator for MockValidator {
+        async fn validate(
+            &mut self,
+            l2_safe_head: &L2BlockInfo,
+            _: &BlockInfo,
+        ) -> Result<(), String> {
+            if *l2_safe_head != self.canonical {
+                return Err("non-canonical safe head".to_string());
+            }
+            Ok(())
+        }
+    }
+
+    async fn reset_derivation(
+        actor: &mut DerivationActor<MockPipeline, MockEngine>,
+        l2_safe_head: L2BlockInfo,
+        l1_origin: BlockInfo,
+    ) -> Result<(), String> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        actor
+            .handle_admin_request(DerivationAdminRequest::ResetDerivation {
+                l2_safe_head,
+                l1_origin,
+                tx,
+            })
+            .await;
+        rx.await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_admin_reset_derivation() {
+        let mut canonical = L2BlockInfo::default();
+        canonical.block_info.number = 10;
+        canonical.l1_origin.number = 5;
+        let l1_origin = BlockInfo { number: 5, ..Default::default() };
+
+        let (_, admin_rx) = tokio::sync::mpsc::channel(1);
+        let validator = Box::new(MockValidator { canonical });
+        let mut actor = actor([], []).with_admin_requests(admin_rx, validator);
+
+        reset_derivation(&mut actor, canonical, l1_origin).await.unwrap();
+
+        assert_eq!(actor.l2_safe_head, canonical);
+        let [Signal::Reset(reset)] = actor.pipeline.signals.as_slice() else {
+            panic!("expected a single reset signal, got {:?}", actor.pipeline.signals);
+        };
+        assert_eq!(reset.l2_safe_head, canonical);
+        assert_eq!(reset.l1_origin, l1_origin);
+    }
+
+    #[tokio::test]
+    async fn test_admin_reset_derivation_rejects_invalid_heads() {
+        let mut canonical = L2BlockInfo::default();
+        canonical.block_info.number = 10;
+        canonical.l1_origin.number = 5;
+
+        let (_, admin_rx) = tokio::sync::mpsc::channel(1);
+        let validator = Box::new(MockValidator { canonical });
+        let mut actor = actor([], []).with_admin_requests(admin_rx, validator);
+
+        // A safe head that the providers do not know about.
+        let mut unknown = canonical;
+        unknown.block_info.number = 11;
+        let l1_origin = BlockInfo { number: 5, ..Default::default() };
+        assert!(reset_derivation(&mut actor, unknown, l1_origin).await.is_err());
+
+        // An L1 origin past the safe head's L1 origin.
+        let l1_origin = BlockInfo { number: 6, ..Default::default() };
+        assert!(reset_derivation(&mut actor, canonical, l1_origin).await.is_err());
+
+        assert_eq!(actor.l2_safe_head, L2BlockInfo::default());
+        assert!(actor.pipeline.signals.is_empty());
+    }
 }
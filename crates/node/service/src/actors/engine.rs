@@ -3,19 +3,27 @@
 use alloy_rpc_types_engine::JwtSecret;
 use async_trait::async_trait;
 use kona_engine::{
-    ConsolidateTask, Engine, EngineClient, EngineStateBuilder, EngineStateBuilderError, EngineTask,
-    InsertUnsafeTask, SyncConfig,
+    BuildOutcome, BuildTask, ConsolidateTask, Engine, EngineClient, EngineStateBuilder,
+    EngineStateBuilderError, EngineTask, EngineTaskError, ForkchoiceTask, InsertUnsafeTask,
+    SyncConfig,
 };
 use kona_genesis::RollupConfig;
+use kona_protocol::L2BlockInfo;
 use kona_rpc::OpAttributesWithParent;
 use op_alloy_rpc_types_engine::OpNetworkPayloadEnvelope;
-use std::sync::Arc;
-use tokio::sync::mpsc::UnboundedReceiver;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{
+    mpsc::{UnboundedReceiver, UnboundedSender},
+    oneshot,
+};
 use tokio_util::sync::CancellationToken;
 use url::Url;
 
 use crate::NodeActor;
 
+/// The maximum amount of time the [`EngineActor`] waits for its pending tasks to drain on shutdown.
+pub const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
 /// The [`EngineActor`] for the engine api sub-routine.
 ///
 /// The engine actor is essentially just a wrapper over two things.
@@ -31,12 +39,21 @@ pub struct EngineActor {
     pub client: Arc<EngineClient>,
     /// The [`Engine`].
     pub engine: Engine,
-    /// A channel to receive [`OpAttributesWithParent`] from the derivation actor.
-    attributes_rx: UnboundedReceiver<OpAttributesWithParent>,
+    /// A channel to receive [`PayloadRequest`]s from the derivation actor.
+    attributes_rx: UnboundedReceiver<PayloadRequest>,
     /// A channel to receive [`OpNetworkPayloadEnvelope`] from the network actor.
     unsafe_block_rx: UnboundedReceiver<OpNetworkPayloadEnvelope>,
     /// The cancellation token, shared between all tasks.
     cancellation: CancellationToken,
+    /// An optional alarm for the gap between the unsafe and safe heads.
+    safe_head_lag_alarm: Option<SafeHeadLagAlarm>,
+    /// An optional channel to send [`EngineEvent`]s to.
+    events_tx: Option<UnboundedSender<EngineEvent>>,
+    /// A channel to receive [`OpAttributesWithParent`] to build from the sequencer, if the node is
+    /// sequencing.
+    build_rx: Option<UnboundedReceiver<OpAttributesWithParent>>,
+    /// A channel to send the [`BuildOutcome`]s of the sequencer to, if the node is sequencing.
+    built_tx: Option<UnboundedSender<BuildOutcome>>,
 }
 
 impl EngineActor {
@@ -46,7 +63,7 @@ impl EngineActor {
         sync: SyncConfig,
         client: EngineClient,
         engine: Engine,
-        attributes_rx: UnboundedReceiver<OpAttributesWithParent>,
+        attributes_rx: UnboundedReceiver<PayloadRequest>,
         unsafe_block_rx: UnboundedReceiver<OpNetworkPayloadEnvelope>,
         cancellation: CancellationToken,
     ) -> Self {
@@ -58,10 +75,235 @@ impl EngineActor {
             attributes_rx,
             unsafe_block_rx,
             cancellation,
+            safe_head_lag_alarm: None,
+            events_tx: None,
+            build_rx: None,
+            built_tx: None,
+        }
+    }
+
+    /// Sets the channels of the sequencer. Payloads are built from the attributes received on
+    /// `build_rx`, and sent to `built_tx` once they are imported as the new unsafe head. Builds
+    /// that fail with an error the engine does not retry are reported on `built_tx` as well.
+    pub fn with_sequencer(
+        mut self,
+        build_rx: UnboundedReceiver<OpAttributesWithParent>,
+        built_tx: UnboundedSender<BuildOutcome>,
+    ) -> Self {
+        self.build_rx = Some(build_rx);
+        self.built_tx = Some(built_tx);
+        self
+    }
+
+    /// Sets the [`SafeHeadLagAlarm`], which is checked after each forkchoice update.
+    pub fn with_safe_head_lag_alarm(mut self, alarm: SafeHeadLagAlarm) -> Self {
+        self.safe_head_lag_alarm = Some(alarm);
+        self
+    }
+
+    /// Sets the channel that [`EngineEvent`]s of the actor, such as [`EngineEvent::Shutdown`], are
+    /// sent to.
+    pub fn with_event_sender(mut self, events_tx: UnboundedSender<EngineEvent>) -> Self {
+        self.events_tx = Some(events_tx);
+        self
+    }
+
+    /// Gracefully shuts down the [`EngineActor`].
+    ///
+    /// The actor stops accepting new attributes and unsafe blocks, and drains the [`Engine`] so
+    /// that the tasks that were already enqueued run to completion, for at most
+    /// [`SHUTDOWN_DRAIN_TIMEOUT`]. Tasks are never interrupted mid-execution by the actor, so no
+    /// task that was in flight is executed again.
+    ///
+    /// An [`EngineEvent::Shutdown`] is emitted whether or not the drain succeeds, and a failure
+    /// or timeout of the drain is returned.
+    pub async fn shutdown(&mut self) -> Result<(), EngineError> {
+        self.attributes_rx.close();
+        self.unsafe_block_rx.close();
+        if let Some(build_rx) = self.build_rx.as_mut() {
+            build_rx.close();
+        }
+
+        let res = match tokio::time::timeout(SHUTDOWN_DRAIN_TIMEOUT, self.engine.drain()).await {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(e)) => {
+                error!(target: "engine", "Failed to drain engine api tasks during shutdown: {e}");
+                Err(EngineError::Drain(e.to_string()))
+            }
+            Err(_) => {
+                error!(target: "engine", "Timed out draining engine api tasks during shutdown");
+                Err(EngineError::DrainTimeout(SHUTDOWN_DRAIN_TIMEOUT))
+            }
+        };
+        self.check_safe_head_lag();
+
+        if self.events_tx.as_ref().is_some_and(|tx| tx.send(EngineEvent::Shutdown).is_err()) {
+            warn!(target: "engine", "Engine event receiver closed");
+        }
+        info!(target: "engine", "EngineActor shut down");
+        res
+    }
+
+    /// Checks the gap between the unsafe and safe heads against the [`SafeHeadLagAlarm`], if set.
+    fn check_safe_head_lag(&mut self) {
+        if let Some(alarm) = self.safe_head_lag_alarm.as_mut() {
+            let state = self.engine.state();
+            alarm.check(state.unsafe_head().block_info.number, state.safe_head().block_info.number);
+        }
+    }
+}
+
+/// Receives from the optional channel, or waits forever if there is none.
+async fn recv_optional<T>(rx: &mut Option<UnboundedReceiver<T>>) -> Option<T> {
+    match rx {
+        Some(rx) => rx.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+/// An event emitted by the [`EngineActor`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineEvent {
+    /// The unsafe head got further ahead of the safe head than the configured threshold, which
+    /// signals that the batcher is not keeping up.
+    SafeHeadLagExceeded {
+        /// The number of the unsafe head.
+        unsafe_number: u64,
+        /// The number of the safe head.
+        safe_number: u64,
+    },
+    /// The [`EngineActor`] drained its pending tasks and shut down.
+    Shutdown,
+}
+
+/// Raises an [`EngineEvent::SafeHeadLagExceeded`] when the unsafe head gets too far ahead of the
+/// safe head.
+///
+/// The event fires once when the gap crosses the threshold, and is re-armed once the gap falls
+/// back within the threshold.
+#[derive(Debug, Clone)]
+pub struct SafeHeadLagAlarm {
+    /// The maximum number of blocks the unsafe head may be ahead of the safe head.
+    threshold: u64,
+    /// Whether the gap currently exceeds the threshold.
+    exceeded: bool,
+    /// A channel to send [`EngineEvent`]s to.
+    events_tx: UnboundedSender<EngineEvent>,
+}
+
+impl SafeHeadLagAlarm {
+    /// Creates a new [`SafeHeadLagAlarm`] with the given threshold, in blocks.
+    pub const fn new(threshold: u64, events_tx: UnboundedSender<EngineEvent>) -> Self {
+        Self { threshold, exceeded: false, events_tx }
+    }
+
+    /// Checks the gap between the unsafe and safe heads, emitting an
+    /// [`EngineEvent::SafeHeadLagExceeded`] if it newly exceeds the threshold.
+    pub fn check(&mut self, unsafe_number: u64, safe_number: u64) {
+        let exceeded = unsafe_number.saturating_sub(safe_number) > self.threshold;
+        if exceeded && !self.exceeded {
+            warn!(
+                target: "engine",
+                unsafe_number,
+                safe_number,
+                threshold = self.threshold,
+                "Unsafe head is too far ahead of the safe head, the batcher may not be keeping up"
+            );
+            let event = EngineEvent::SafeHeadLagExceeded { unsafe_number, safe_number };
+            if self.events_tx.send(event).is_err() {
+                warn!(target: "engine", "Engine event receiver closed");
+            }
         }
+        self.exceeded = exceeded;
     }
 }
 
+/// An engine that derived [`OpAttributesWithParent`] can be applied to.
+#[async_trait]
+pub trait PayloadEngine {
+    /// Builds a payload from the [`OpAttributesWithParent`], imports it via `engine_newPayload`,
+    /// and updates the forkchoice. Returns the new L2 safe head.
+    async fn apply_attributes(
+        &mut self,
+        attributes: OpAttributesWithParent,
+    ) -> Result<L2BlockInfo, PayloadEngineError>;
+}
+
+#[async_trait]
+impl PayloadEngine for EngineActor {
+    async fn apply_attributes(
+        &mut self,
+        attributes: OpAttributesWithParent,
+    ) -> Result<L2BlockInfo, PayloadEngineError> {
+        let task = ConsolidateTask::new(
+            Arc::clone(&self.client),
+            Arc::clone(&self.config),
+            attributes,
+            true,
+        );
+
+        // The task is executed outside of the queue, so that the errors of queued unsafe block and
+        // sequencer tasks are neither attributed to the derived attributes nor dropped.
+        let res = self.engine.execute(&task).await;
+        self.check_safe_head_lag();
+        match res {
+            Ok(()) => Ok(self.engine.state().safe_head()),
+            Err(EngineTaskError::Temporary(e)) => Err(PayloadEngineError::Temporary(e.to_string())),
+            Err(EngineTaskError::Reset(e)) => Err(PayloadEngineError::Invalid(e.to_string())),
+            Err(EngineTaskError::Critical(e)) => Err(PayloadEngineError::Critical(e.to_string())),
+        }
+    }
+}
+
+/// A request to apply derived [`OpAttributesWithParent`] on the [`EngineActor`], along with the
+/// channel that the result is sent back on.
+pub type PayloadRequest =
+    (OpAttributesWithParent, oneshot::Sender<Result<L2BlockInfo, PayloadEngineError>>);
+
+/// A [`PayloadEngine`] that applies attributes on a running [`EngineActor`], by sending it
+/// [`PayloadRequest`]s and waiting for the result.
+#[derive(Debug, Clone)]
+pub struct EngineHandle {
+    /// The channel to send [`PayloadRequest`]s to the [`EngineActor`].
+    requests_tx: UnboundedSender<PayloadRequest>,
+}
+
+impl EngineHandle {
+    /// Creates a new [`EngineHandle`] from the sender of the [`EngineActor`]'s requests channel.
+    pub const fn new(requests_tx: UnboundedSender<PayloadRequest>) -> Self {
+        Self { requests_tx }
+    }
+}
+
+#[async_trait]
+impl PayloadEngine for EngineHandle {
+    async fn apply_attributes(
+        &mut self,
+        attributes: OpAttributesWithParent,
+    ) -> Result<L2BlockInfo, PayloadEngineError> {
+        let (tx, rx) = oneshot::channel();
+        self.requests_tx
+            .send((attributes, tx))
+            .map_err(|_| PayloadEngineError::Critical("engine actor closed".to_string()))?;
+        rx.await
+            .map_err(|_| PayloadEngineError::Critical("engine actor dropped request".to_string()))?
+    }
+}
+
+/// An error from a [`PayloadEngine`].
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum PayloadEngineError {
+    /// The payload could not be applied for now, and the attributes should be retried.
+    #[error("Temporary payload engine error: {0}")]
+    Temporary(String),
+    /// The payload was rejected by the execution layer, and the derivation pipeline must be reset.
+    #[error("Invalid payload: {0}")]
+    Invalid(String),
+    /// A critical error within the engine.
+    #[error("Critical payload engine error: {0}")]
+    Critical(String),
+}
+
 /// Configuration for the Engine Actor.
 #[derive(Debug, Clone)]
 pub struct EngineLauncher {
@@ -107,31 +349,24 @@ impl NodeActor for EngineActor {
 
     async fn start(mut self) -> Result<(), Self::Error> {
         loop {
+            // Derived attributes are applied outside of the select, as the error of a drain is not
+            // `Send` and may not be held across the await. The queue is drained outside of the
+            // select as well, so that a task in flight is never interrupted by another event and
+            // then executed again by the next drain.
+            let mut request = None;
+            let mut drain = false;
             tokio::select! {
-                _ = self.cancellation.cancelled() => {
-                    warn!(target: "engine", "EngineActor received shutdown signal.");
-                    return Ok(());
-                }
-                res = self.engine.drain() => {
-                    if let Err(e) = res {
-                        warn!(target: "engine", "Encountered error draining engine api tasks: {:?}", e);
-                    }
-                }
+                // The other actors may close their channels once they are cancelled, so the
+                // cancellation is polled before receiving from them.
+                biased;
+                _ = self.cancellation.cancelled() => break,
                 attributes = self.attributes_rx.recv() => {
-                    let Some(attributes) = attributes else {
+                    if attributes.is_none() {
                         error!(target: "engine", "Attributes receiver closed unexpectedly, exiting node");
                         self.cancellation.cancel();
                         return Err(EngineError::ChannelClosed);
-                    };
-                    let task = ConsolidateTask::new(
-                        Arc::clone(&self.client),
-                        Arc::clone(&self.config),
-                        attributes,
-                        true,
-                    );
-                    let task = EngineTask::Consolidate(task);
-                    self.engine.enqueue(task);
-                    debug!(target: "engine", "Enqueued attributes consolidation task.");
+                    }
+                    request = attributes;
                 }
                 unsafe_block = self.unsafe_block_rx.recv() => {
                     let Some(envelope) = unsafe_block else {
@@ -149,8 +384,52 @@ impl NodeActor for EngineActor {
                     self.engine.enqueue(task);
                     debug!(target: "engine", "Enqueued unsafe block task.");
                 }
+                attributes = recv_optional(&mut self.build_rx) => {
+                    let Some(attributes) = attributes else {
+                        error!(target: "engine", "Sequencer receiver closed unexpectedly, exiting node");
+                        self.cancellation.cancel();
+                        return Err(EngineError::ChannelClosed);
+                    };
+                    let mut task = BuildTask::new(
+                        Arc::clone(&self.client),
+                        Arc::clone(&self.config),
+                        attributes,
+                        false,
+                    );
+                    if let Some(built_tx) = self.built_tx.clone() {
+                        task = task.with_payload_sender(built_tx);
+                    }
+                    self.engine.enqueue(EngineTask::BuildBlock(task));
+                    // The forkchoice is updated to the new unsafe head once it is imported.
+                    let task = ForkchoiceTask::new(Arc::clone(&self.client));
+                    self.engine.enqueue(EngineTask::ForkchoiceUpdate(task));
+                    debug!(target: "engine", "Enqueued sequencer block building task.");
+                }
+                // Draining an empty queue completes immediately, so it is skipped to avoid spinning.
+                // The queue is drained last, so that a task that keeps failing does not starve the
+                // channels.
+                _ = std::future::ready(()), if !self.engine.is_empty() => {
+                    drain = true;
+                }
+            }
+
+            if drain {
+                if let Err(e) = self.engine.drain().await {
+                    warn!(target: "engine", "Encountered error draining engine api tasks: {:?}", e);
+                }
+                self.check_safe_head_lag();
+            }
+
+            if let Some((attributes, tx)) = request {
+                let res = self.apply_attributes(attributes).await;
+                if tx.send(res).is_err() {
+                    warn!(target: "engine", "Derived payload requester dropped");
+                }
             }
         }
+
+        warn!(target: "engine", "EngineActor received shutdown signal.");
+        self.shutdown().await
     }
 
     async fn process(&mut self, _: Self::InboundEvent) -> Result<(), Self::Error> {
@@ -164,4 +443,264 @@ pub enum EngineError {
     /// Closed channel error.
     #[error("closed channel error")]
     ChannelClosed,
+    /// The pending engine api tasks failed to drain on shutdown.
+    #[error("failed to drain engine api tasks: {0}")]
+    Drain(String),
+    /// The pending engine api tasks did not drain within the shutdown timeout.
+    #[error("timed out draining engine api tasks after {0:?}")]
+    DrainTimeout(Duration),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_engine::{
+        ForkchoiceUpdated, INVALID_FORK_CHOICE_STATE_ERROR, PayloadStatusEnum,
+    };
+    use jsonrpsee::{
+        RpcModule,
+        server::{Server, ServerHandle},
+        types::ErrorObjectOwned,
+    };
+    use kona_engine::{EngineStateSnapshot, SyncMode};
+    use kona_protocol::BlockInfo;
+    use std::{
+        sync::atomic::{AtomicUsize, Ordering},
+        time::Duration,
+    };
+    use tokio::sync::mpsc::unbounded_channel;
+
+    /// Starts a mock execution layer that answers `engine_forkchoiceUpdatedV3` after a delay,
+    /// counting the number of calls that were answered.
+    async fn start_mock_engine(calls: Arc<AtomicUsize>) -> (Url, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap()).parse().unwrap();
+
+        let mut module = RpcModule::new(calls);
+        module
+            .register_async_method("engine_forkchoiceUpdatedV3", |_, calls, _| async move {
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                calls.fetch_add(1, Ordering::SeqCst);
+                Ok::<_, ErrorObjectOwned>(ForkchoiceUpdated::from_status(PayloadStatusEnum::Valid))
+            })
+            .unwrap();
+        (url, server.start(module))
+    }
+
+    /// Starts a mock execution layer that answers `engine_forkchoiceUpdatedV3` after a delay,
+    /// counting the number of calls that were received, including those that were abandoned by
+    /// the client before they were answered.
+    async fn start_slow_engine(received: Arc<AtomicUsize>) -> (Url, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap()).parse().unwrap();
+
+        let mut module = RpcModule::new(received);
+        module
+            .register_async_method("engine_forkchoiceUpdatedV3", |_, received, _| async move {
+                received.fetch_add(1, Ordering::SeqCst);
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                Ok::<_, ErrorObjectOwned>(ForkchoiceUpdated::from_status(PayloadStatusEnum::Valid))
+            })
+            .unwrap();
+        (url, server.start(module))
+    }
+
+    /// Starts a mock execution layer that rejects every forkchoice state, counting the number of
+    /// rejected calls, and does not know of any L2 block.
+    async fn start_rejecting_engine(calls: Arc<AtomicUsize>) -> (Url, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap()).parse().unwrap();
+
+        let mut module = RpcModule::new(calls);
+        module
+            .register_method("engine_forkchoiceUpdatedV3", |_, calls, _| {
+                calls.fetch_add(1, Ordering::SeqCst);
+                Err::<(), _>(ErrorObjectOwned::owned(
+                    INVALID_FORK_CHOICE_STATE_ERROR,
+                    "invalid forkchoice state",
+                    None::<()>,
+                ))
+            })
+            .unwrap();
+        module
+            .register_method("eth_getBlockByNumber", |_, _, _| {
+                Ok::<_, ErrorObjectOwned>(None::<()>)
+            })
+            .unwrap();
+        (url, server.start(module))
+    }
+
+    /// Creates an [`EngineActor`] against the execution layer at `url`, starting from the given
+    /// unsafe head.
+    async fn actor(url: Url, unsafe_head: L2BlockInfo) -> EngineActor {
+        let config = Arc::new(RollupConfig::default());
+        let client = EngineClient::new_http(url.clone(), url, config.clone(), JwtSecret::random());
+        let mut state = EngineStateBuilder::from_snapshot(
+            client.clone(),
+            config.genesis,
+            EngineStateSnapshot {
+                unsafe_head,
+                safe_head: L2BlockInfo::default(),
+                finalized_head: L2BlockInfo::default(),
+            },
+        )
+        .build()
+        .await
+        .unwrap();
+        state.forkchoice_update_needed = true;
+
+        // The senders are dropped, as the tests drive the actor directly.
+        let (_, attributes_rx) = unbounded_channel();
+        let (_, unsafe_block_rx) = unbounded_channel();
+        EngineActor::new(
+            config,
+            SyncConfig {
+                sync_mode: SyncMode::ExecutionLayer,
+                skip_sync_start_check: false,
+                supports_post_finalization_elsync: false,
+            },
+            client,
+            Engine::new(state),
+            attributes_rx,
+            unsafe_block_rx,
+            CancellationToken::new(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_apply_attributes_ignores_queued_tasks() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let (url, handle) = start_rejecting_engine(calls.clone()).await;
+
+        // The unsafe head is ahead of the safe head, so the attributes are consolidated against an
+        // unsafe block that the execution layer does not know of.
+        let unsafe_head = L2BlockInfo {
+            block_info: BlockInfo { number: 1, ..Default::default() },
+            ..Default::default()
+        };
+        let mut actor = actor(url, unsafe_head).await;
+
+        // A queued forkchoice update, which fails with a reset once executed.
+        let task = ForkchoiceTask::new(Arc::clone(&actor.client));
+        actor.engine.enqueue(EngineTask::ForkchoiceUpdate(task));
+
+        let attributes = OpAttributesWithParent::new(Default::default(), Default::default(), false);
+        let err = actor.apply_attributes(attributes).await.unwrap_err();
+
+        // Only the missing unsafe block is reported, and the queued task is neither executed nor
+        // dropped.
+        assert!(matches!(err, PayloadEngineError::Temporary(_)), "{err:?}");
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert!(!actor.engine.is_empty());
+
+        // The failure of the queued task is surfaced by the queue alone.
+        assert!(matches!(actor.engine.drain().await, Err(EngineTaskError::Reset(_))));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        handle.stop().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_completes_pending_task() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let (url, handle) = start_mock_engine(calls.clone()).await;
+
+        let (events_tx, mut events_rx) = unbounded_channel();
+        let mut actor = actor(url, L2BlockInfo::default()).await.with_event_sender(events_tx);
+
+        let task = ForkchoiceTask::new(Arc::clone(&actor.client));
+        actor.engine.enqueue(EngineTask::ForkchoiceUpdate(task));
+        actor.shutdown().await.unwrap();
+
+        // The pending forkchoice update was answered before the shutdown event was emitted.
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert!(!actor.engine.state().forkchoice_update_needed);
+        assert_eq!(events_rx.try_recv().unwrap(), EngineEvent::Shutdown);
+        assert!(actor.attributes_rx.recv().await.is_none());
+
+        handle.stop().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_returns_drain_error() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let (url, handle) = start_rejecting_engine(calls.clone()).await;
+
+        let (events_tx, mut events_rx) = unbounded_channel();
+        let mut actor = actor(url, L2BlockInfo::default()).await.with_event_sender(events_tx);
+
+        let task = ForkchoiceTask::new(Arc::clone(&actor.client));
+        actor.engine.enqueue(EngineTask::ForkchoiceUpdate(task));
+        assert!(matches!(actor.shutdown().await, Err(EngineError::Drain(_))));
+
+        // The shutdown event is emitted even though the drain failed.
+        assert_eq!(events_rx.try_recv().unwrap(), EngineEvent::Shutdown);
+
+        handle.stop().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_cancellation_does_not_replay_in_flight_task() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let (url, handle) = start_slow_engine(calls.clone()).await;
+
+        let mut actor = actor(url, L2BlockInfo::default()).await;
+        let (_attributes_tx, attributes_rx) = unbounded_channel();
+        let (_unsafe_block_tx, unsafe_block_rx) = unbounded_channel();
+        actor.attributes_rx = attributes_rx;
+        actor.unsafe_block_rx = unsafe_block_rx;
+        let cancellation = actor.cancellation.clone();
+
+        let task = ForkchoiceTask::new(Arc::clone(&actor.client));
+        actor.engine.enqueue(EngineTask::ForkchoiceUpdate(task));
+        let actor = tokio::spawn(actor.start());
+
+        // Cancel while the forkchoice update is in flight.
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        cancellation.cancel();
+        actor.await.unwrap().unwrap();
+
+        // The forkchoice update ran to completion once, and was not executed again on shutdown.
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        handle.stop().unwrap();
+    }
+
+    #[test]
+    fn test_safe_head_lag_alarm_fires_once_per_crossing() {
+        let (tx, mut rx) = unbounded_channel();
+        let mut alarm = SafeHeadLagAlarm::new(10, tx);
+
+        // Within the threshold, including exactly at it.
+        alarm.check(5, 0);
+        alarm.check(10, 0);
+        assert!(rx.try_recv().is_err());
+
+        // Crossing the threshold fires the event once, even as the gap keeps growing.
+        alarm.check(11, 0);
+        alarm.check(12, 0);
+        alarm.check(20, 5);
+        let event = EngineEvent::SafeHeadLagExceeded { unsafe_number: 11, safe_number: 0 };
+        assert_eq!(rx.try_recv().unwrap(), event);
+        assert!(rx.try_recv().is_err());
+
+        // Once the safe head catches up, the alarm is re-armed for the next crossing.
+        alarm.check(20, 15);
+        alarm.check(30, 15);
+        alarm.check(31, 15);
+        let event = EngineEvent::SafeHeadLagExceeded { unsafe_number: 30, safe_number: 15 };
+        assert_eq!(rx.try_recv().unwrap(), event);
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_safe_head_lag_alarm_safe_ahead_of_unsafe() {
+        let (tx, mut rx) = unbounded_channel();
+        let mut alarm = SafeHeadLagAlarm::new(0, tx);
+
+        // The safe head may briefly be reported ahead of the unsafe head.
+        alarm.check(5, 6);
+        assert!(rx.try_recv().is_err());
+    }
 }
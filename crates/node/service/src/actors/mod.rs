@@ -6,16 +6,25 @@ mod traits;
 pub use traits::NodeActor;
 
 mod engine;
-pub use engine::{EngineActor, EngineError, EngineLauncher};
+pub use engine::{
+    EngineActor, EngineError, EngineEvent, EngineHandle, EngineLauncher, PayloadEngine,
+    PayloadEngineError, PayloadRequest, SHUTDOWN_DRAIN_TIMEOUT, SafeHeadLagAlarm,
+};
 
 mod rpc;
 pub use rpc::{RpcActor, RpcActorError};
 
 mod derivation;
-pub use derivation::{DerivationActor, DerivationError, InboundDerivationMessage};
+pub use derivation::{
+    DEFAULT_RETRY_BACKOFF, DerivationActor, DerivationError, InboundDerivationMessage,
+    MAX_RETRY_BACKOFF,
+};
 
 mod l1_watcher_rpc;
 pub use l1_watcher_rpc::{L1WatcherRpc, L1WatcherRpcError};
 
 mod network;
 pub use network::{NetworkActor, NetworkActorError};
+
+mod sequencer;
+pub use sequencer::{SequencerActor, SequencerError};
@@ -0,0 +1,548 @@
+//! [NodeActor] implementation for the sequencer sub-routine.
+
+use crate::NodeActor;
+use async_trait::async_trait;
+use kona_derive::{
+    errors::PipelineErrorKind,
+    traits::{AttributesBuilder, ChainProvider},
+};
+use kona_engine::{BuildOutcome, BuiltPayload};
+use kona_genesis::RollupConfig;
+use kona_p2p::PayloadSigner;
+use kona_protocol::{BlockInfo, L2BlockInfo};
+use kona_rpc::OpAttributesWithParent;
+use op_alloy_rpc_types_engine::OpNetworkPayloadEnvelope;
+use std::{sync::Arc, time::Duration};
+use thiserror::Error;
+use tokio::{
+    select,
+    sync::mpsc::{Sender, UnboundedReceiver, UnboundedSender},
+    time::MissedTickBehavior,
+};
+use tokio_util::sync::CancellationToken;
+
+/// The [NodeActor] for the sequencer sub-routine.
+///
+/// On every tick of the block time, the sequencer selects the L1 origin of the next block,
+/// prepares its [OpAttributesWithParent] on top of the unsafe head, and sends them to the engine
+/// actor to be built. Once the engine actor has imported the built payload as the new unsafe head,
+/// the payload is signed and gossiped to the network.
+///
+/// A new block is only started once the previous one has been imported, so that each block builds
+/// on top of the last. If the engine actor fails to build a block, the next block is started over
+/// on top of the same unsafe head.
+#[derive(Debug)]
+pub struct SequencerActor<AB, L1>
+where
+    AB: AttributesBuilder,
+    L1: ChainProvider,
+{
+    /// The [RollupConfig].
+    config: Arc<RollupConfig>,
+    /// The interval at which new blocks are built.
+    block_time: Duration,
+    /// The latest unsafe head, which the next block is built on top of.
+    unsafe_head: L2BlockInfo,
+    /// The builder for the payload attributes of new blocks.
+    attributes_builder: AB,
+    /// The L1 provider used to select the L1 origin of new blocks.
+    origin_provider: L1,
+    /// The sender for the [OpAttributesWithParent] to build.
+    build_tx: UnboundedSender<OpAttributesWithParent>,
+    /// The receiver for the outcomes of the blocks built by the engine actor.
+    built_rx: UnboundedReceiver<BuildOutcome>,
+    /// The signer of built payloads, along with the sender for signed payloads to gossip.
+    publisher: Option<(PayloadSigner, Sender<OpNetworkPayloadEnvelope>)>,
+    /// Whether a block is currently being built by the engine actor.
+    building: bool,
+    /// The cancellation token, shared between all tasks.
+    cancellation: CancellationToken,
+}
+
+impl<AB, L1> SequencerActor<AB, L1>
+where
+    AB: AttributesBuilder,
+    L1: ChainProvider,
+{
+    /// Creates a new instance of the [SequencerActor], building on top of the given unsafe head
+    /// every [RollupConfig::block_time] seconds.
+    pub fn new(
+        config: Arc<RollupConfig>,
+        unsafe_head: L2BlockInfo,
+        attributes_builder: AB,
+        origin_provider: L1,
+        build_tx: UnboundedSender<OpAttributesWithParent>,
+        built_rx: UnboundedReceiver<BuildOutcome>,
+        cancellation: CancellationToken,
+    ) -> Self {
+        Self {
+            block_time: Duration::from_secs(config.block_time),
+            config,
+            unsafe_head,
+            attributes_builder,
+            origin_provider,
+            build_tx,
+            built_rx,
+            publisher: None,
+            building: false,
+            cancellation,
+        }
+    }
+
+    /// Sets the interval at which new blocks are built.
+    ///
+    /// The timestamps of the blocks always advance by [RollupConfig::block_time], so a shorter
+    /// interval lets the sequencer catch up with the wall clock, such as on devnets.
+    pub const fn with_block_time(mut self, block_time: Duration) -> Self {
+        self.block_time = block_time;
+        self
+    }
+
+    /// Sets the [PayloadSigner] of built payloads, along with the sender for the signed payloads
+    /// to gossip to the network.
+    pub fn with_publisher(
+        mut self,
+        signer: PayloadSigner,
+        publish_tx: Sender<OpNetworkPayloadEnvelope>,
+    ) -> Self {
+        self.publisher = Some((signer, publish_tx));
+        self
+    }
+
+    /// Selects the L1 origin of the next block.
+    ///
+    /// The sequencer adopts the next L1 block as soon as it is known and its timestamp is not ahead
+    /// of the next L2 block, and otherwise keeps the L1 origin of the unsafe head. If the next L2
+    /// block would be past the max sequencer drift of the current L1 origin, the next L1 block
+    /// must be known for the sequencer to make progress, and [SequencerError::PastSequencerDrift]
+    /// is returned until it is.
+    async fn select_origin(&mut self, next_l2_time: u64) -> Result<BlockInfo, SequencerError> {
+        let current = self
+            .origin_provider
+            .block_info_by_number(self.unsafe_head.l1_origin.number)
+            .await
+            .map_err(|e| SequencerError::Pipeline(e.into()))?;
+        let past_drift =
+            next_l2_time > current.timestamp + self.config.max_sequencer_drift(current.timestamp);
+
+        match self.origin_provider.block_info_by_number(current.number + 1).await {
+            Ok(next) if next.parent_hash == current.hash => {
+                Ok(if next.timestamp <= next_l2_time { next } else { current })
+            }
+            _ if past_drift => Err(SequencerError::PastSequencerDrift(current.number)),
+            _ => Ok(current),
+        }
+    }
+
+    /// Prepares the attributes of the next block on top of the unsafe head, and sends them to the
+    /// engine actor to be built.
+    async fn start_block(&mut self) -> Result<(), SequencerError> {
+        let next_l2_time = self.unsafe_head.block_info.timestamp + self.config.block_time;
+        let origin = self.select_origin(next_l2_time).await?;
+
+        let mut attributes = self
+            .attributes_builder
+            .prepare_payload_attributes(self.unsafe_head, origin.id())
+            .await?;
+
+        // Transactions from the pool are only included while the L2 time is within the sequencer
+        // drift of the L1 origin.
+        let drift = self.config.max_sequencer_drift(origin.timestamp);
+        attributes.no_tx_pool = Some(next_l2_time > origin.timestamp + drift);
+
+        debug!(
+            target: "sequencer",
+            parent = self.unsafe_head.block_info.number,
+            l1_origin = origin.number,
+            l2_time = next_l2_time,
+            "Starting new block"
+        );
+        let attributes = OpAttributesWithParent::new(attributes, self.unsafe_head, false);
+        self.build_tx.send(attributes).map_err(|_| SequencerError::ChannelClosed)?;
+        self.building = true;
+        Ok(())
+    }
+
+    /// Advances the unsafe head to the built payload, and gossips it if a publisher is set.
+    async fn seal_block(&mut self, built: BuiltPayload) {
+        self.unsafe_head = built.block;
+        self.building = false;
+        info!(
+            target: "sequencer",
+            number = built.block.block_info.number,
+            hash = %built.block.block_info.hash,
+            "Sequenced new unsafe block"
+        );
+
+        let Some((signer, publish_tx)) = self.publisher.as_ref() else {
+            return;
+        };
+        let envelope = match signer.sign(built.payload, built.parent_beacon_block_root) {
+            Ok(envelope) => envelope,
+            Err(e) => {
+                warn!(target: "sequencer", "Failed to sign unsafe payload: {e}");
+                return;
+            }
+        };
+        if publish_tx.send(envelope).await.is_err() {
+            warn!(target: "sequencer", "Failed to publish unsafe payload: network receiver closed");
+        }
+    }
+}
+
+#[async_trait]
+impl<AB, L1> NodeActor for SequencerActor<AB, L1>
+where
+    AB: AttributesBuilder + Send + 'static,
+    L1: ChainProvider + Send + 'static,
+{
+    type InboundEvent = BuildOutcome;
+    type Error = SequencerError;
+
+    async fn start(mut self) -> Result<(), Self::Error> {
+        let mut ticker = tokio::time::interval(self.block_time);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            select! {
+                _ = self.cancellation.cancelled() => {
+                    info!(target: "sequencer", "Received shutdown signal. Exiting sequencer task.");
+                    return Ok(());
+                }
+                built = self.built_rx.recv() => {
+                    let Some(built) = built else {
+                        error!(target: "sequencer", "Built payload receiver closed unexpectedly, exiting node");
+                        self.cancellation.cancel();
+                        return Err(SequencerError::ChannelClosed);
+                    };
+                    self.process(built).await?;
+                }
+                _ = ticker.tick(), if !self.building => {
+                    match self.start_block().await {
+                        Ok(()) => {}
+                        Err(SequencerError::Pipeline(PipelineErrorKind::Temporary(e))) => {
+                            warn!(target: "sequencer", "Failed to start new block, retrying: {e}");
+                        }
+                        Err(e @ SequencerError::PastSequencerDrift(_)) => {
+                            warn!(target: "sequencer", "Failed to start new block, retrying: {e}");
+                        }
+                        Err(e) => {
+                            error!(target: "sequencer", "Failed to start new block: {e}");
+                            self.cancellation.cancel();
+                            return Err(e);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    async fn process(&mut self, msg: Self::InboundEvent) -> Result<(), Self::Error> {
+        match msg {
+            BuildOutcome::Built(built) => self.seal_block(built).await,
+            BuildOutcome::Failed(e) => {
+                warn!(target: "sequencer", "Failed to build new block, starting over: {e}");
+                self.building = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An error from the [SequencerActor].
+#[derive(Error, Debug)]
+pub enum SequencerError {
+    /// An error preparing the attributes of a new block, or selecting its L1 origin.
+    #[error(transparent)]
+    Pipeline(#[from] PipelineErrorKind),
+    /// The next block would be past the max sequencer drift of its L1 origin, and the next L1
+    /// block is not yet known.
+    #[error("Next L2 block is past the sequencer drift of L1 origin {0}, and no next L1 block")]
+    PastSequencerDrift(u64),
+    /// Closed channel error.
+    #[error("closed channel error")]
+    ChannelClosed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::EngineActor;
+    use alloy_consensus::Header;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::{B256, Bloom, Bytes, U256, map::HashMap};
+    use alloy_rpc_types_engine::{
+        ExecutionPayloadEnvelopeV2, ExecutionPayloadFieldV2, ExecutionPayloadV1,
+        ExecutionPayloadV2, ForkchoiceState, ForkchoiceUpdated, JwtSecret, PayloadId,
+        PayloadStatus, PayloadStatusEnum,
+    };
+    use jsonrpsee::{
+        RpcModule,
+        server::{Server, ServerHandle},
+        types::ErrorObjectOwned,
+    };
+    use kona_derive::{
+        attributes::StatefulAttributesBuilder,
+        test_utils::{TestChainProvider, TestL2ChainProvider},
+    };
+    use kona_engine::{
+        Engine, EngineClient, EngineStateBuilder, EngineStateSnapshot, SyncConfig, SyncMode,
+    };
+    use kona_genesis::{ChainGenesis, HardForkConfig, SystemConfig};
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+    use std::sync::Mutex;
+    use tokio::sync::mpsc::{channel, unbounded_channel};
+    use url::Url;
+
+    /// The attributes of the payload that the mock execution layer is building, along with the
+    /// hash of its parent.
+    type PendingPayload = Mutex<Option<(B256, OpPayloadAttributes)>>;
+
+    /// Starts a mock execution layer that builds empty payloads from the attributes of the last
+    /// forkchoice update. The hash of block `n` is `B256::with_last_byte(n)`.
+    async fn start_mock_engine() -> (Url, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", server.local_addr().unwrap()).parse().unwrap();
+
+        let mut module = RpcModule::new(PendingPayload::default());
+        for method in ["engine_forkchoiceUpdatedV2", "engine_forkchoiceUpdatedV3"] {
+            module
+                .register_method(method, |params, pending, _| -> Result<_, ErrorObjectOwned> {
+                    let (forkchoice, attributes) =
+                        params.parse::<(ForkchoiceState, Option<OpPayloadAttributes>)>()?;
+                    let update = ForkchoiceUpdated::from_status(PayloadStatusEnum::Valid);
+                    let Some(attributes) = attributes else {
+                        return Ok(update);
+                    };
+                    *pending.lock().unwrap() = Some((forkchoice.head_block_hash, attributes));
+                    Ok(update.with_payload_id(PayloadId::new([1; 8])))
+                })
+                .unwrap();
+        }
+        module
+            .register_method("engine_getPayloadV2", |_, pending, _| {
+                let (parent_hash, attributes) = pending.lock().unwrap().take().unwrap();
+                let number = parent_hash[31] as u64 + 1;
+                let payload = ExecutionPayloadV2 {
+                    payload_inner: ExecutionPayloadV1 {
+                        parent_hash,
+                        fee_recipient: attributes.payload_attributes.suggested_fee_recipient,
+                        state_root: B256::ZERO,
+                        receipts_root: B256::ZERO,
+                        logs_bloom: Bloom::ZERO,
+                        prev_randao: attributes.payload_attributes.prev_randao,
+                        block_number: number,
+                        gas_limit: attributes.gas_limit.unwrap_or_default(),
+                        gas_used: 0,
+                        timestamp: attributes.payload_attributes.timestamp,
+                        extra_data: Bytes::new(),
+                        base_fee_per_gas: U256::from(1),
+                        block_hash: B256::with_last_byte(number as u8),
+                        transactions: attributes.transactions.unwrap_or_default(),
+                    },
+                    withdrawals: vec![],
+                };
+                Ok::<_, ErrorObjectOwned>(ExecutionPayloadEnvelopeV2 {
+                    execution_payload: ExecutionPayloadFieldV2::V2(payload),
+                    block_value: U256::ZERO,
+                })
+            })
+            .unwrap();
+        // Payloads without withdrawals are imported with `engine_newPayloadV1`.
+        for method in ["engine_newPayloadV1", "engine_newPayloadV2"] {
+            module
+                .register_method(method, |_, _, _| {
+                    Ok::<_, ErrorObjectOwned>(PayloadStatus::from_status(PayloadStatusEnum::Valid))
+                })
+                .unwrap();
+        }
+        (url, server.start(module))
+    }
+
+    #[tokio::test]
+    async fn test_sequencer_builds_consecutive_blocks() {
+        let (url, handle) = start_mock_engine().await;
+
+        let l1_header = Header::default();
+        let l1_origin = BlockInfo {
+            hash: l1_header.hash_slow(),
+            number: 0,
+            parent_hash: B256::ZERO,
+            timestamp: 0,
+        };
+        let config = Arc::new(RollupConfig {
+            genesis: ChainGenesis {
+                l1: l1_origin.id(),
+                l2: BlockNumHash { hash: B256::ZERO, number: 0 },
+                system_config: Some(SystemConfig::default()),
+                ..Default::default()
+            },
+            block_time: 2,
+            max_sequencer_drift: 600,
+            l2_chain_id: 10,
+            hardforks: HardForkConfig {
+                regolith_time: Some(0),
+                canyon_time: Some(0),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        let genesis =
+            L2BlockInfo { block_info: BlockInfo::default(), l1_origin: l1_origin.id(), seq_num: 0 };
+
+        let mut l1_provider = TestChainProvider::default();
+        l1_provider.insert_block(0, l1_origin);
+        l1_provider.insert_header(l1_origin.hash, l1_header);
+        let l2_provider = TestL2ChainProvider {
+            system_configs: HashMap::from_iter([
+                (0, SystemConfig::default()),
+                (1, SystemConfig::default()),
+            ]),
+            ..Default::default()
+        };
+        let attributes_builder =
+            StatefulAttributesBuilder::new(config.clone(), l2_provider, l1_provider.clone());
+
+        let client = EngineClient::new_http(url.clone(), url, config.clone(), JwtSecret::random());
+        let state = EngineStateBuilder::from_snapshot(
+            client.clone(),
+            config.genesis,
+            EngineStateSnapshot {
+                unsafe_head: genesis,
+                safe_head: genesis,
+                finalized_head: genesis,
+            },
+        )
+        .build()
+        .await
+        .unwrap();
+
+        let cancellation = CancellationToken::new();
+        let (_attributes_tx, attributes_rx) = unbounded_channel();
+        let (_unsafe_block_tx, unsafe_block_rx) = unbounded_channel();
+        let (build_tx, build_rx) = unbounded_channel();
+        let (built_tx, built_rx) = unbounded_channel();
+        let engine = EngineActor::new(
+            config.clone(),
+            SyncConfig {
+                sync_mode: SyncMode::ExecutionLayer,
+                skip_sync_start_check: false,
+                supports_post_finalization_elsync: false,
+            },
+            client,
+            Engine::new(state),
+            attributes_rx,
+            unsafe_block_rx,
+            cancellation.clone(),
+        )
+        .with_sequencer(build_rx, built_tx);
+
+        let signer = PayloadSigner::new(B256::repeat_byte(0x42), config.l2_chain_id).unwrap();
+        let (publish_tx, mut publish_rx) = channel(16);
+        let sequencer = SequencerActor::new(
+            config,
+            genesis,
+            attributes_builder,
+            l1_provider,
+            build_tx,
+            built_rx,
+            cancellation.clone(),
+        )
+        .with_block_time(Duration::from_millis(10))
+        .with_publisher(signer.clone(), publish_tx);
+
+        let engine = tokio::spawn(engine.start());
+        let sequencer = tokio::spawn(sequencer.start());
+
+        // Each block is built on top of the previous one, and gossiped with a valid signature.
+        let mut parent_hash = B256::ZERO;
+        for number in 1..=2 {
+            let envelope = tokio::time::timeout(Duration::from_secs(10), publish_rx.recv())
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(envelope.payload.block_number(), number);
+            assert_eq!(envelope.payload.parent_hash(), parent_hash);
+            assert_eq!(envelope.payload.timestamp(), number * 2);
+
+            let msg = envelope.payload_hash.signature_message(10);
+            let recovered = envelope.signature.recover_address_from_prehash(&msg).unwrap();
+            assert_eq!(recovered, signer.address());
+            parent_hash = envelope.payload.block_hash();
+        }
+
+        cancellation.cancel();
+        sequencer.await.unwrap().unwrap();
+        engine.await.unwrap().unwrap();
+        handle.stop().unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_sequencer_starts_over_after_failed_build() {
+        let config = Arc::new(RollupConfig::default());
+        let attributes_builder = StatefulAttributesBuilder::new(
+            config.clone(),
+            TestL2ChainProvider::default(),
+            TestChainProvider::default(),
+        );
+        let (build_tx, _build_rx) = unbounded_channel();
+        let (_built_tx, built_rx) = unbounded_channel();
+        let mut sequencer = SequencerActor::new(
+            config,
+            L2BlockInfo::default(),
+            attributes_builder,
+            TestChainProvider::default(),
+            build_tx,
+            built_rx,
+            CancellationToken::new(),
+        );
+        sequencer.building = true;
+
+        sequencer.process(BuildOutcome::Failed("invalid payload".to_string())).await.unwrap();
+        assert!(!sequencer.building);
+        assert_eq!(sequencer.unsafe_head, L2BlockInfo::default());
+    }
+
+    #[tokio::test]
+    async fn test_select_origin_at_sequencer_drift_boundary() {
+        let config = Arc::new(RollupConfig { max_sequencer_drift: 600, ..Default::default() });
+        let origin = BlockInfo { hash: B256::repeat_byte(0x01), ..Default::default() };
+        let next = BlockInfo {
+            hash: B256::repeat_byte(0x02),
+            number: 1,
+            parent_hash: origin.hash,
+            timestamp: 602,
+        };
+        let mut l1_provider = TestChainProvider::default();
+        l1_provider.insert_block(0, origin);
+
+        let attributes_builder = StatefulAttributesBuilder::new(
+            config.clone(),
+            TestL2ChainProvider::default(),
+            TestChainProvider::default(),
+        );
+        let (build_tx, _build_rx) = unbounded_channel();
+        let (_built_tx, built_rx) = unbounded_channel();
+        let mut sequencer = SequencerActor::new(
+            config,
+            L2BlockInfo { l1_origin: origin.id(), ..Default::default() },
+            attributes_builder,
+            l1_provider,
+            build_tx,
+            built_rx,
+            CancellationToken::new(),
+        );
+
+        // Up to the drift, the current origin is kept while the next L1 block is unknown.
+        assert_eq!(sequencer.select_origin(600).await.unwrap(), origin);
+        assert!(matches!(
+            sequencer.select_origin(601).await,
+            Err(SequencerError::PastSequencerDrift(0))
+        ));
+
+        // Once the next L1 block is known, it is adopted as soon as the L2 time reaches it.
+        sequencer.origin_provider.insert_block(1, next);
+        assert_eq!(sequencer.select_origin(601).await.unwrap(), origin);
+        assert_eq!(sequencer.select_origin(602).await.unwrap(), next);
+    }
+}
@@ -0,0 +1,118 @@
+//! Admin RPC requests for controlling the derivation pipeline.
+
+use async_trait::async_trait;
+use jsonrpsee::{
+    core::RpcResult,
+    types::{ErrorCode, ErrorObject, ErrorObjectOwned},
+};
+use kona_derive::traits::ChainProvider;
+use kona_protocol::{BatchValidationProvider, BlockInfo, L2BlockInfo};
+use kona_rpc::DerivationAdminApiServer;
+use tokio::sync::{mpsc, oneshot};
+
+/// A request sent from the [`DerivationAdminRpc`] to the [`crate::DerivationActor`].
+#[derive(Debug)]
+pub enum DerivationAdminRequest {
+    /// Resets the derivation pipeline to the given L2 safe head and L1 origin. The result of the
+    /// reset is sent back through the response channel.
+    ResetDerivation {
+        /// The L2 safe head to reset the pipeline to.
+        l2_safe_head: L2BlockInfo,
+        /// The L1 origin to reset the pipeline to.
+        l1_origin: BlockInfo,
+        /// The channel to send the result of the reset to.
+        tx: oneshot::Sender<Result<(), String>>,
+    },
+}
+
+/// Validates the heads of a [`DerivationAdminRequest::ResetDerivation`] before the pipeline is
+/// reset to them.
+#[async_trait]
+pub trait ResetHeadValidator {
+    /// Returns an error if the given L2 safe head or L1 origin are not canonical.
+    async fn validate(
+        &mut self,
+        l2_safe_head: &L2BlockInfo,
+        l1_origin: &BlockInfo,
+    ) -> Result<(), String>;
+}
+
+/// A [`ResetHeadValidator`] that checks the requested heads against the L1 and L2 chain
+/// providers.
+#[derive(Debug)]
+pub struct ProviderResetValidator<C, L2> {
+    /// The L1 chain provider.
+    l1_provider: C,
+    /// The L2 chain provider.
+    l2_provider: L2,
+}
+
+impl<C, L2> ProviderResetValidator<C, L2> {
+    /// Creates a new [`ProviderResetValidator`].
+    pub const fn new(l1_provider: C, l2_provider: L2) -> Self {
+        Self { l1_provider, l2_provider }
+    }
+}
+
+#[async_trait]
+impl<C, L2> ResetHeadValidator for ProviderResetValidator<C, L2>
+where
+    C: ChainProvider + Send,
+    L2: BatchValidationProvider + Send,
+{
+    async fn validate(
+        &mut self,
+        l2_safe_head: &L2BlockInfo,
+        l1_origin: &BlockInfo,
+    ) -> Result<(), String> {
+        let number = l2_safe_head.block_info.number;
+        let canonical_l2 =
+            self.l2_provider.l2_block_info_by_number(number).await.map_err(|e| e.to_string())?;
+        if canonical_l2 != *l2_safe_head {
+            return Err(format!("L2 safe head does not match canonical L2 block #{number}"));
+        }
+
+        let number = l1_origin.number;
+        let canonical_l1 =
+            self.l1_provider.block_info_by_number(number).await.map_err(|e| e.to_string())?;
+        if canonical_l1 != *l1_origin {
+            return Err(format!("L1 origin does not match canonical L1 block #{number}"));
+        }
+
+        Ok(())
+    }
+}
+
+/// A server implementation of [`DerivationAdminApiServer`], forwarding requests to the
+/// [`crate::DerivationActor`].
+#[derive(Debug)]
+pub struct DerivationAdminRpc {
+    /// The channel to send [`DerivationAdminRequest`]s.
+    pub sender: mpsc::Sender<DerivationAdminRequest>,
+}
+
+impl DerivationAdminRpc {
+    /// Constructs a new [`DerivationAdminRpc`] given a sender channel.
+    pub const fn new(sender: mpsc::Sender<DerivationAdminRequest>) -> Self {
+        Self { sender }
+    }
+}
+
+#[async_trait]
+impl DerivationAdminApiServer for DerivationAdminRpc {
+    async fn admin_reset_derivation(
+        &self,
+        l2_safe_head: L2BlockInfo,
+        l1_origin: BlockInfo,
+    ) -> RpcResult<()> {
+        let (tx, rx) = oneshot::channel();
+        self.sender
+            .send(DerivationAdminRequest::ResetDerivation { l2_safe_head, l1_origin, tx })
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?
+            .map_err(|e| ErrorObjectOwned::owned(ErrorCode::InvalidParams.code(), e, None::<()>))
+    }
+}
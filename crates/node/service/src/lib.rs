@@ -17,10 +17,19 @@ pub use service::{
 
 mod actors;
 pub use actors::{
-    DerivationActor, DerivationError, EngineActor, EngineError, EngineLauncher,
-    InboundDerivationMessage, L1WatcherRpc, L1WatcherRpcError, NetworkActor, NetworkActorError,
-    NodeActor, RpcActor, RpcActorError,
+    DEFAULT_RETRY_BACKOFF, DerivationActor, DerivationError, EngineActor, EngineError, EngineEvent,
+    EngineHandle, EngineLauncher, InboundDerivationMessage, L1WatcherRpc, L1WatcherRpcError,
+    MAX_RETRY_BACKOFF, NetworkActor, NetworkActorError, NodeActor, PayloadEngine,
+    PayloadEngineError, PayloadRequest, RpcActor, RpcActorError, SHUTDOWN_DRAIN_TIMEOUT,
+    SafeHeadLagAlarm, SequencerActor, SequencerError,
 };
 
+mod admin;
+pub use admin::{
+    DerivationAdminRequest, DerivationAdminRpc, ProviderResetValidator, ResetHeadValidator,
+};
+
+mod metrics;
+
 mod sync_start;
 pub use sync_start::{L2ForkchoiceState, SyncStartError, find_starting_forkchoice};
@@ -0,0 +1,190 @@
+//! Metrics for the node service.
+//!
+//! Metrics are recorded through the [metrics] facade, and are exported by whichever recorder is
+//! installed by the binary (e.g. the prometheus recorder enabled via `--metrics.enabled`).
+
+use std::time::{Duration, Instant};
+
+/// Gauge of the number of L1 blocks that the safe head's L1 origin lags behind the L1 head.
+pub(crate) const DERIVATION_L1_LAG_BLOCKS: &str = "kona_derivation_l1_lag_blocks";
+
+/// Gauge of the number of L2 blocks derived per second.
+pub(crate) const DERIVATION_BLOCKS_PER_SEC: &str = "kona_derivation_blocks_per_sec";
+
+/// Counter of the number of batches processed by the derivation pipeline.
+pub(crate) const BATCHES_PROCESSED_TOTAL: &str = "kona_batches_processed_total";
+
+/// The window over which the derivation throughput is averaged.
+const THROUGHPUT_WINDOW: Duration = Duration::from_secs(1);
+
+/// Records metrics for the derivation sub-routine.
+#[derive(Debug, Default)]
+pub(crate) struct DerivationMetrics {
+    /// The start of the current throughput window.
+    window_start: Option<Instant>,
+    /// The number of blocks derived within the current throughput window.
+    window_blocks: u64,
+}
+
+impl DerivationMetrics {
+    /// Creates a new [DerivationMetrics] instance.
+    pub(crate) const fn new() -> Self {
+        Self { window_start: None, window_blocks: 0 }
+    }
+
+    /// Describes the derivation metrics to the installed recorder.
+    pub(crate) fn describe() {
+        metrics::describe_gauge!(
+            DERIVATION_L1_LAG_BLOCKS,
+            "Number of L1 blocks that the safe head's L1 origin lags behind the L1 head"
+        );
+        metrics::describe_gauge!(
+            DERIVATION_BLOCKS_PER_SEC,
+            "Number of L2 blocks derived per second"
+        );
+        metrics::describe_counter!(
+            BATCHES_PROCESSED_TOTAL,
+            "Total number of batches processed by the derivation pipeline"
+        );
+    }
+
+    /// Records the derivation lag, computed as the L1 head number minus the safe head's L1 origin
+    /// number.
+    pub(crate) fn record_l1_lag(&self, l1_head: u64, safe_head_l1_origin: u64) {
+        let l1_lag = l1_head.saturating_sub(safe_head_l1_origin);
+        metrics::gauge!(DERIVATION_L1_LAG_BLOCKS).set(l1_lag as f64);
+    }
+
+    /// Records a block derived at `now`. A batch is counted as processed once its last block is
+    /// derived, so that a span batch is counted once rather than once per block.
+    pub(crate) fn record_derived_block(&mut self, now: Instant, is_last_in_batch: bool) {
+        if is_last_in_batch {
+            metrics::counter!(BATCHES_PROCESSED_TOTAL).increment(1);
+        }
+
+        self.window_start.get_or_insert(now);
+        self.window_blocks += 1;
+        self.update_throughput(now);
+    }
+
+    /// Updates the throughput gauge at `now` if the current [THROUGHPUT_WINDOW] has elapsed, so
+    /// that the gauge falls while derivation is idle rather than holding its last value.
+    pub(crate) fn update_throughput(&mut self, now: Instant) {
+        let Some(window_start) = self.window_start else {
+            return;
+        };
+
+        let elapsed = now.saturating_duration_since(window_start);
+        if elapsed >= THROUGHPUT_WINDOW {
+            let blocks_per_sec = self.window_blocks as f64 / elapsed.as_secs_f64();
+            metrics::gauge!(DERIVATION_BLOCKS_PER_SEC).set(blocks_per_sec);
+            debug!(target: "derivation", "Derived {blocks_per_sec} blocks/s");
+
+            self.window_start = Some(now);
+            self.window_blocks = 0;
+        }
+    }
+
+    /// Resets the throughput gauge after the pipeline is reset, discarding the current
+    /// [THROUGHPUT_WINDOW].
+    pub(crate) fn reset(&mut self) {
+        self.window_start = None;
+        self.window_blocks = 0;
+        metrics::gauge!(DERIVATION_BLOCKS_PER_SEC).set(0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use metrics_exporter_prometheus::PrometheusBuilder;
+
+    /// Runs `f` with a local prometheus recorder, and returns the rendered metrics.
+    fn render(f: impl FnOnce()) -> String {
+        let recorder = PrometheusBuilder::new().build_recorder();
+        let handle = recorder.handle();
+        metrics::with_local_recorder(&recorder, f);
+        handle.render()
+    }
+
+    #[test]
+    fn test_record_derived_blocks() {
+        let mut metrics = DerivationMetrics::new();
+        let start = Instant::now();
+
+        // Derive 10 blocks from single batches within the first throughput window.
+        let rendered = render(|| {
+            for i in 0..10 {
+                metrics.record_derived_block(start + Duration::from_millis(i * 50), true);
+            }
+        });
+        assert!(rendered.contains(&format!("{BATCHES_PROCESSED_TOTAL} 10\n")));
+        assert!(!rendered.contains(DERIVATION_BLOCKS_PER_SEC));
+
+        // The next block closes the two second window. The recorder only observes the metrics
+        // recorded within it.
+        let rendered =
+            render(|| metrics.record_derived_block(start + Duration::from_secs(2), true));
+        assert!(rendered.contains(&format!("{BATCHES_PROCESSED_TOTAL} 1\n")));
+        assert!(rendered.contains(&format!("{DERIVATION_BLOCKS_PER_SEC} 5.5\n")));
+    }
+
+    #[test]
+    fn test_record_span_batch_once() {
+        let mut metrics = DerivationMetrics::new();
+        let start = Instant::now();
+
+        // A span batch of 3 blocks, followed by a single batch.
+        let rendered = render(|| {
+            for (i, is_last_in_batch) in [false, false, true, true].into_iter().enumerate() {
+                metrics.record_derived_block(
+                    start + Duration::from_millis(i as u64),
+                    is_last_in_batch,
+                );
+            }
+            metrics.update_throughput(start + Duration::from_secs(1));
+        });
+        assert!(rendered.contains(&format!("{BATCHES_PROCESSED_TOTAL} 2\n")));
+        assert!(rendered.contains(&format!("{DERIVATION_BLOCKS_PER_SEC} 4\n")));
+    }
+
+    #[test]
+    fn test_throughput_falls_while_idle() {
+        let mut metrics = DerivationMetrics::new();
+        let start = Instant::now();
+
+        let rendered = render(|| {
+            metrics.record_derived_block(start, true);
+            metrics.record_derived_block(start + Duration::from_secs(1), true);
+            metrics.update_throughput(start + Duration::from_secs(2));
+        });
+        assert!(rendered.contains(&format!("{DERIVATION_BLOCKS_PER_SEC} 0\n")));
+    }
+
+    #[test]
+    fn test_reset_clears_throughput() {
+        let mut metrics = DerivationMetrics::new();
+        let start = Instant::now();
+
+        let rendered = render(|| {
+            metrics.record_derived_block(start, true);
+            metrics.record_derived_block(start + Duration::from_secs(1), true);
+            metrics.reset();
+
+            // The window restarts with the first block derived after the reset.
+            metrics.record_derived_block(start + Duration::from_secs(5), true);
+        });
+        assert!(rendered.contains(&format!("{DERIVATION_BLOCKS_PER_SEC} 0\n")));
+    }
+
+    #[test]
+    fn test_record_l1_lag() {
+        let metrics = DerivationMetrics::new();
+        let rendered = render(|| metrics.record_l1_lag(120, 100));
+        assert!(rendered.contains(&format!("{DERIVATION_L1_LAG_BLOCKS} 20\n")));
+
+        // The safe head's L1 origin may briefly be ahead of the observed L1 head.
+        let rendered = render(|| metrics.record_l1_lag(100, 101));
+        assert!(rendered.contains(&format!("{DERIVATION_L1_LAG_BLOCKS} 0\n")));
+    }
+}
@@ -15,4 +15,4 @@ mod standard;
 pub use standard::{RollupNode, RollupNodeBuilder, RollupNodeError};
 
 pub(crate) mod util;
-pub(crate) use util::spawn_and_wait;
+pub(crate) use util::{log_engine_events, spawn_and_wait};
@@ -1,13 +1,168 @@
-//! Sequencer node service.
+//! [SequencerNodeService] trait.
 
 use super::ValidatorNodeService;
+use crate::{
+    DerivationActor, DerivationAdminRpc, EngineActor, EngineHandle, NetworkActor, NodeActor,
+    RpcActor, SafeHeadLagAlarm, SequencerActor,
+    service::{log_engine_events, spawn_and_wait},
+};
 use async_trait::async_trait;
+use kona_derive::traits::{AttributesBuilder, ChainProvider};
+use kona_p2p::PayloadSigner;
+use kona_rpc::{DerivationAdminApiServer, OpP2PApiServer};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
 
-/// TODO
+/// The [SequencerNodeService] trait defines the interface for running a sequencer node service in
+/// the rollup node. On top of the actors of the [ValidatorNodeService], the sequencer node runs a
+/// [SequencerActor] that builds new unsafe blocks on top of the unsafe head, and gossips them to
+/// the network.
+///
+/// ## Actor Communication
+/// ```not_rust
+/// ┌────────────┐   ┌────────────┐   ┌────────────┐
+/// │            │──►│            │   │            │
+/// │ Sequencer  │   │ Engine API │   │  Network   │
+/// │            │◄──│            │   │            │
+/// └─────┬──────┘   └────────────┘   └────────────┘
+///       │                                 ▲
+///       └─────────────────────────────────┘
+/// ```
+///
+/// ## Types
+/// - `AttributesBuilder`: The type of [AttributesBuilder] used to prepare the payload attributes of
+///   new blocks.
+/// - `OriginProvider`: The type of [ChainProvider] used to select the L1 origin of new blocks.
 #[async_trait]
 pub trait SequencerNodeService: ValidatorNodeService {
-    /// TODO
+    /// The type of [AttributesBuilder] used to prepare the payload attributes of new blocks.
+    type AttributesBuilder: AttributesBuilder + Send + 'static;
+    /// The type of [ChainProvider] used to select the L1 origin of new blocks.
+    type OriginProvider: ChainProvider + Send + 'static;
+
+    /// Returns the interval at which new blocks are built. Defaults to the block time of the
+    /// rollup config.
+    fn block_time(&self) -> Duration {
+        Duration::from_secs(self.config().block_time)
+    }
+
+    /// Returns the [PayloadSigner] used to sign the sequenced blocks before they are gossiped, or
+    /// `None` if the blocks should not be gossiped.
+    fn payload_signer(&self) -> Option<PayloadSigner>;
+
+    /// Creates a new [AttributesBuilder] for the sequencer.
+    fn new_attributes_builder(&self) -> Self::AttributesBuilder;
+
+    /// Creates a new [ChainProvider] used to select the L1 origin of new blocks.
+    fn new_origin_provider(&self) -> Self::OriginProvider;
+
+    /// Starts the sequencer node service.
     async fn start(&self) -> Result<(), Self::Error> {
-        unimplemented!("TODO")
+        // Create a global cancellation token for graceful shutdown of tasks.
+        let cancellation = CancellationToken::new();
+
+        // Create channels for communication between actors.
+        let (new_head_tx, new_head_rx) = mpsc::unbounded_channel();
+        let (derived_payload_tx, derived_payload_rx) = mpsc::unbounded_channel();
+        let (unsafe_block_tx, unsafe_block_rx) = mpsc::unbounded_channel();
+        let (build_tx, build_rx) = mpsc::unbounded_channel();
+        let (built_tx, built_rx) = mpsc::unbounded_channel();
+
+        let (block_signer_tx, block_signer_rx) = mpsc::unbounded_channel();
+        let da_watcher =
+            Some(self.new_da_watcher(new_head_tx, block_signer_tx, cancellation.clone()));
+
+        let (l2_forkchoice_state, derivation_pipeline) = self.init_derivation().await?;
+        let mut derivation = DerivationActor::new(
+            derivation_pipeline,
+            l2_forkchoice_state.safe,
+            EngineHandle::new(derived_payload_tx),
+            new_head_rx,
+            cancellation.clone(),
+        );
+        let mut admin_module = None;
+        if let Some(validator) = self.reset_validator() {
+            let (admin_tx, admin_rx) = mpsc::channel(16);
+            derivation = derivation.with_admin_requests(admin_rx, validator);
+            admin_module = Some(DerivationAdminRpc::new(admin_tx));
+        }
+        let derivation = Some(derivation);
+
+        let config = Arc::new(self.config().clone());
+        let launcher = self.engine();
+        let client = launcher.client();
+        let sync = launcher.sync.clone();
+        let engine = launcher.launch().await?;
+        let (engine_events_tx, engine_events_rx) = mpsc::unbounded_channel();
+        tokio::spawn(log_engine_events(engine_events_rx));
+        let mut engine = EngineActor::new(
+            Arc::clone(&config),
+            sync,
+            client,
+            engine,
+            derived_payload_rx,
+            unsafe_block_rx,
+            cancellation.clone(),
+        );
+        if let Some(threshold) = self.safe_head_lag_threshold() {
+            let alarm = SafeHeadLagAlarm::new(threshold, engine_events_tx.clone());
+            engine = engine.with_safe_head_lag_alarm(alarm);
+        }
+        let engine =
+            Some(engine.with_event_sender(engine_events_tx).with_sequencer(build_rx, built_tx));
+
+        let mut sequencer = SequencerActor::new(
+            config,
+            l2_forkchoice_state.un_safe,
+            self.new_attributes_builder(),
+            self.new_origin_provider(),
+            build_tx,
+            built_rx,
+            cancellation.clone(),
+        )
+        .with_block_time(self.block_time());
+
+        let signer = self.payload_signer();
+        if signer.is_none() {
+            warn!(target: "rollup_node", "No payload signer, sequenced blocks are not gossiped");
+        }
+        // The unsafe block sender is held for the lifetime of the service even if the network is
+        // disabled, as the engine actor exits once the channel is closed.
+        let mut p2p_module = None;
+        let mut network = None;
+        if let Some((mut driver, module)) = self.init_network().await? {
+            p2p_module = Some(module);
+            if let Some(signer) = signer {
+                sequencer = sequencer.with_publisher(signer, driver.publish_sender());
+            }
+            network = Some(NetworkActor::new(
+                driver,
+                unsafe_block_tx.clone(),
+                block_signer_rx,
+                cancellation.clone(),
+            ));
+        }
+        let sequencer = Some(sequencer);
+
+        // The RPC Server should go last to let other actors register their rpc modules.
+        let rpc = if let Some(mut rpc) = self.rpc() {
+            if let Some(p2p_module) = p2p_module {
+                rpc = rpc.merge(p2p_module.into_rpc()).expect("failed to merge p2p rpc module");
+            }
+            if let Some(admin_module) = admin_module {
+                rpc = rpc.merge(admin_module.into_rpc()).expect("failed to merge admin rpc module");
+            }
+            let handle = rpc.start().await?;
+            Some(RpcActor::new(handle, cancellation.clone()))
+        } else {
+            None
+        };
+
+        spawn_and_wait!(
+            cancellation,
+            actors = [da_watcher, rpc, derivation, engine, network, sequencer]
+        );
+        Ok(())
     }
 }
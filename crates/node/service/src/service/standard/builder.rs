@@ -1,7 +1,8 @@
 //! Contains the builder for the [`RollupNode`].
 
+use super::node::DERIVATION_PROVIDER_CACHE_SIZE;
 use crate::{EngineLauncher, NodeMode, RollupNode};
-use alloy_primitives::Bytes;
+use alloy_primitives::{B256, Bytes};
 use alloy_provider::RootProvider;
 use alloy_rpc_client::RpcClient;
 use alloy_rpc_types_engine::JwtSecret;
@@ -11,13 +12,13 @@ use alloy_transport_http::{
 };
 use http_body_util::Full;
 use op_alloy_network::Optimism;
-use std::sync::Arc;
+use std::{num::NonZeroUsize, sync::Arc, time::Duration};
 use tower::ServiceBuilder;
 use url::Url;
 
 use kona_engine::SyncConfig;
 use kona_genesis::RollupConfig;
-use kona_p2p::Config;
+use kona_p2p::{Config, PayloadSigner};
 use kona_providers_alloy::OnlineBeaconClient;
 use kona_rpc::RpcConfig;
 
@@ -46,6 +47,17 @@ pub struct RollupNodeBuilder {
     mode: NodeMode,
     /// If p2p networking is entirely disabled.
     network_disabled: bool,
+    /// The size of the caches used in the L1 derivation providers.
+    l1_cache_size: Option<NonZeroUsize>,
+    /// The size of the caches used in the L2 derivation providers.
+    l2_cache_size: Option<NonZeroUsize>,
+    /// The interval at which the sequencer builds new blocks.
+    sequencer_block_time: Option<Duration>,
+    /// The secret key used to sign the sequenced blocks.
+    sequencer_key: Option<B256>,
+    /// The number of blocks the unsafe head may be ahead of the safe head before an alarm is
+    /// raised.
+    safe_head_lag_threshold: Option<u64>,
 }
 
 impl RollupNodeBuilder {
@@ -104,6 +116,36 @@ impl RollupNodeBuilder {
         Self { network_disabled, ..self }
     }
 
+    /// Sets the size of the caches used in the L1 derivation providers, including the blob
+    /// provider. Defaults to `1024` entries.
+    pub fn with_l1_cache_size(self, l1_cache_size: NonZeroUsize) -> Self {
+        Self { l1_cache_size: Some(l1_cache_size), ..self }
+    }
+
+    /// Sets the size of the caches used in the L2 derivation providers. Defaults to `1024`
+    /// entries.
+    pub fn with_l2_cache_size(self, l2_cache_size: NonZeroUsize) -> Self {
+        Self { l2_cache_size: Some(l2_cache_size), ..self }
+    }
+
+    /// Sets the interval at which the sequencer builds new blocks. Defaults to the block time of
+    /// the rollup config.
+    pub fn with_sequencer_block_time(self, block_time: Duration) -> Self {
+        Self { sequencer_block_time: Some(block_time), ..self }
+    }
+
+    /// Sets the secret key used to sign the sequenced blocks before they are gossiped. Sequenced
+    /// blocks are not gossiped if no key is set.
+    pub fn with_sequencer_key(self, key: B256) -> Self {
+        Self { sequencer_key: Some(key), ..self }
+    }
+
+    /// Sets the number of blocks the unsafe head may be ahead of the safe head before an alarm is
+    /// raised, which signals that the batcher is not keeping up. The alarm is disabled if unset.
+    pub fn with_safe_head_lag_threshold(self, threshold: u64) -> Self {
+        Self { safe_head_lag_threshold: Some(threshold), ..self }
+    }
+
     /// Assembles the [`RollupNode`] service.
     ///
     /// ## Panics
@@ -115,6 +157,7 @@ impl RollupNodeBuilder {
     /// - The L2 engine URL is not set.
     /// - The sync config is not set.
     /// - The jwt secret is not set.
+    /// - The sequencer key is not a valid secp256k1 secret key.
     pub fn build(self) -> RollupNode {
         let l1_provider =
             RootProvider::new_http(self.l1_provider_rpc_url.expect("l1 provider rpc url not set"));
@@ -134,8 +177,13 @@ impl RollupNodeBuilder {
         let rpc_client = RpcClient::new(http_hyper, true);
         let l2_provider = RootProvider::<Optimism>::new(rpc_client);
 
+        let admin_enabled = self.rpc_config.as_ref().is_some_and(|c| c.enable_admin);
         let rpc_launcher = self.rpc_config.map(|c| c.as_launcher()).unwrap_or_default();
 
+        let payload_signer = self.sequencer_key.map(|key| {
+            PayloadSigner::new(key, self.config.l2_chain_id).expect("invalid sequencer key")
+        });
+
         let config = Arc::new(self.config);
         let engine_launcher = EngineLauncher {
             config: Arc::clone(&config),
@@ -153,8 +201,54 @@ impl RollupNodeBuilder {
             l2_provider,
             engine_launcher,
             rpc_launcher,
+            admin_enabled,
             p2p_config: self.p2p_config,
             network_disabled: self.network_disabled,
+            l1_cache_size: self.l1_cache_size.unwrap_or(DERIVATION_PROVIDER_CACHE_SIZE),
+            l2_cache_size: self.l2_cache_size.unwrap_or(DERIVATION_PROVIDER_CACHE_SIZE),
+            sequencer_block_time: self.sequencer_block_time,
+            payload_signer,
+            safe_head_lag_threshold: self.safe_head_lag_threshold,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builder() -> RollupNodeBuilder {
+        let url: Url = "http://127.0.0.1:8545".parse().unwrap();
+        RollupNodeBuilder::new(RollupConfig::default())
+            .with_sync_config(SyncConfig {
+                sync_mode: kona_engine::SyncMode::ExecutionLayer,
+                skip_sync_start_check: false,
+                supports_post_finalization_elsync: false,
+            })
+            .with_l1_provider_rpc_url(url.clone())
+            .with_l1_beacon_api_url(url.clone())
+            .with_l2_engine_rpc_url(url.clone())
+            .with_l2_provider_rpc_url(url)
+            .with_jwt_secret(JwtSecret::random())
+    }
+
+    #[tokio::test]
+    async fn test_default_cache_sizes() {
+        let node = builder().build();
+        assert_eq!(node.l1_cache_size, DERIVATION_PROVIDER_CACHE_SIZE);
+        assert_eq!(node.l2_cache_size, DERIVATION_PROVIDER_CACHE_SIZE);
+    }
+
+    #[tokio::test]
+    async fn test_configured_cache_sizes() {
+        let (l1_cache_size, l2_cache_size) =
+            (NonZeroUsize::new(16).unwrap(), NonZeroUsize::new(4096).unwrap());
+        let builder = builder().with_l1_cache_size(l1_cache_size).with_l2_cache_size(l2_cache_size);
+        assert_eq!(builder.l1_cache_size, Some(l1_cache_size));
+        assert_eq!(builder.l2_cache_size, Some(l2_cache_size));
+
+        let node = builder.build();
+        assert_eq!(node.l1_cache_size, l1_cache_size);
+        assert_eq!(node.l2_cache_size, l2_cache_size);
+    }
+}
@@ -1,20 +1,21 @@
 //! Contains the [`RollupNode`] implementation.
 
 use crate::{
-    EngineLauncher, L1WatcherRpc, L2ForkchoiceState, NodeMode, RollupNodeBuilder, RollupNodeError,
-    RollupNodeService, SequencerNodeService, ValidatorNodeService, find_starting_forkchoice,
+    EngineLauncher, L1WatcherRpc, L2ForkchoiceState, NodeMode, ProviderResetValidator,
+    ResetHeadValidator, RollupNodeBuilder, RollupNodeError, RollupNodeService,
+    SequencerNodeService, ValidatorNodeService, find_starting_forkchoice,
 };
 use alloy_primitives::Address;
 use alloy_provider::RootProvider;
 use async_trait::async_trait;
-use kona_p2p::NetworkRpc;
+use kona_p2p::{NetworkRpc, PayloadSigner};
 use op_alloy_network::Optimism;
-use std::sync::Arc;
+use std::{num::NonZeroUsize, sync::Arc, time::Duration};
 use tokio::sync::mpsc::UnboundedSender;
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
-use kona_derive::traits::ChainProvider;
+use kona_derive::{attributes::StatefulAttributesBuilder, traits::ChainProvider};
 use kona_genesis::RollupConfig;
 use kona_p2p::{Config, Network, NetworkBuilder};
 use kona_protocol::BlockInfo;
@@ -24,8 +25,11 @@ use kona_providers_alloy::{
 };
 use kona_rpc::RpcLauncher;
 
-/// The size of the cache used in the derivation pipeline's providers.
-const DERIVATION_PROVIDER_CACHE_SIZE: usize = 1024;
+/// The default size of the caches used in the derivation pipeline's providers.
+pub(crate) const DERIVATION_PROVIDER_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(1024).unwrap();
+
+/// The number of verified blobs cached by the derivation pipeline's blob provider.
+pub(crate) const BLOB_CACHE_SIZE: NonZeroUsize = NonZeroUsize::new(64).unwrap();
 
 /// The standard implementation of the [RollupNode] service, using the governance approved OP Stack
 /// configuration of components.
@@ -45,10 +49,23 @@ pub struct RollupNode {
     pub(crate) engine_launcher: EngineLauncher,
     /// The [`RpcLauncher`] for the node.
     pub(crate) rpc_launcher: RpcLauncher,
+    /// Whether the admin RPC API is enabled.
+    pub(crate) admin_enabled: bool,
     /// The P2P [`Config`] for the node.
     pub(crate) p2p_config: Option<Config>,
     /// Whether p2p networking is entirely disabled.
     pub(crate) network_disabled: bool,
+    /// The size of the caches used in the L1 derivation providers.
+    pub(crate) l1_cache_size: NonZeroUsize,
+    /// The size of the caches used in the L2 derivation providers.
+    pub(crate) l2_cache_size: NonZeroUsize,
+    /// The interval at which the sequencer builds new blocks, if overridden.
+    pub(crate) sequencer_block_time: Option<Duration>,
+    /// The signer of the sequenced blocks, if they should be gossiped.
+    pub(crate) payload_signer: Option<PayloadSigner>,
+    /// The number of blocks the unsafe head may be ahead of the safe head before an alarm is
+    /// raised, if any.
+    pub(crate) safe_head_lag_threshold: Option<u64>,
 }
 
 impl RollupNode {
@@ -67,8 +84,30 @@ impl RollupNodeService for RollupNode {
 
 #[async_trait]
 impl SequencerNodeService for RollupNode {
-    async fn start(&self) -> Result<(), Self::Error> {
-        unimplemented!()
+    type AttributesBuilder = StatefulAttributesBuilder<AlloyChainProvider, AlloyL2ChainProvider>;
+    type OriginProvider = AlloyChainProvider;
+
+    fn block_time(&self) -> Duration {
+        self.sequencer_block_time.unwrap_or_else(|| Duration::from_secs(self.config.block_time))
+    }
+
+    fn payload_signer(&self) -> Option<PayloadSigner> {
+        self.payload_signer.clone()
+    }
+
+    fn new_attributes_builder(&self) -> Self::AttributesBuilder {
+        let l1_provider =
+            AlloyChainProvider::new(self.l1_provider.clone(), self.l1_cache_size.get());
+        let l2_provider = AlloyL2ChainProvider::new(
+            self.l2_provider.clone(),
+            self.config.clone(),
+            self.l2_cache_size.get(),
+        );
+        StatefulAttributesBuilder::new(self.config.clone(), l2_provider, l1_provider)
+    }
+
+    fn new_origin_provider(&self) -> Self::OriginProvider {
+        AlloyChainProvider::new(self.l1_provider.clone(), self.l1_cache_size.get())
     }
 }
 
@@ -105,6 +144,24 @@ impl ValidatorNodeService for RollupNode {
         Some(self.rpc_launcher.clone())
     }
 
+    fn safe_head_lag_threshold(&self) -> Option<u64> {
+        self.safe_head_lag_threshold
+    }
+
+    fn reset_validator(&self) -> Option<Box<dyn ResetHeadValidator + Send + Sync>> {
+        if !self.admin_enabled {
+            return None;
+        }
+        let l1_provider =
+            AlloyChainProvider::new(self.l1_provider.clone(), self.l1_cache_size.get());
+        let l2_provider = AlloyL2ChainProvider::new(
+            self.l2_provider.clone(),
+            self.config.clone(),
+            self.l2_cache_size.get(),
+        );
+        Some(Box::new(ProviderResetValidator::new(l1_provider, l2_provider)))
+    }
+
     async fn init_network(&self) -> Result<Option<(Network, NetworkRpc)>, Self::Error> {
         if self.network_disabled {
             return Ok(None);
@@ -121,6 +178,7 @@ impl ValidatorNodeService for RollupNode {
         let p2p_module = NetworkRpc::new(tx);
         let builder = NetworkBuilder::from(p2p_config.clone())
             .with_chain_id(chain_id)
+            .with_rollup_config((*self.config).clone())
             .with_rpc_receiver(rx)
             .build()
             .map_err(RollupNodeError::Network)?;
@@ -130,11 +188,11 @@ impl ValidatorNodeService for RollupNode {
     async fn init_derivation(&self) -> Result<(L2ForkchoiceState, OnlinePipeline), Self::Error> {
         // Create the caching L1/L2 EL providers for derivation.
         let mut l1_derivation_provider =
-            AlloyChainProvider::new(self.l1_provider.clone(), DERIVATION_PROVIDER_CACHE_SIZE);
+            AlloyChainProvider::new(self.l1_provider.clone(), self.l1_cache_size.get());
         let mut l2_derivation_provider = AlloyL2ChainProvider::new(
             self.l2_provider.clone(),
             self.config.clone(),
-            DERIVATION_PROVIDER_CACHE_SIZE,
+            self.l2_cache_size.get(),
         );
 
         // Find the starting forkchoice state.
@@ -165,7 +223,7 @@ impl ValidatorNodeService for RollupNode {
             self.config.clone(),
             starting_forkchoice.safe,
             starting_origin,
-            OnlineBlobProvider::init(self.l1_beacon.clone()).await,
+            OnlineBlobProvider::init(self.l1_beacon.clone(), BLOB_CACHE_SIZE).await,
             l1_derivation_provider,
             l2_derivation_provider,
         )
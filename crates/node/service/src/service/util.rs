@@ -1,5 +1,7 @@
 //! Utilities for the rollup node service, internal to the crate.
 
+use crate::EngineEvent;
+
 /// Spawns a set of parallel actors in a [JoinSet], and cancels all actors if any of them fail. The
 /// type of the error in the [NodeActor]s is erased to avoid having to specify a common error type
 /// between actors.
@@ -44,3 +46,22 @@ macro_rules! spawn_and_wait {
 
 // Export the `spawn_and_wait` macro for use in other modules.
 pub(crate) use spawn_and_wait;
+
+/// Logs the [`EngineEvent`]s emitted by the [`EngineActor`] until it shuts down.
+///
+/// [`EngineActor`]: crate::EngineActor
+pub(crate) async fn log_engine_events(
+    mut events_rx: tokio::sync::mpsc::UnboundedReceiver<EngineEvent>,
+) {
+    while let Some(event) = events_rx.recv().await {
+        match event {
+            EngineEvent::SafeHeadLagExceeded { unsafe_number, safe_number } => {
+                debug!(target: "rollup_node", unsafe_number, safe_number, "Safe head lag exceeded");
+            }
+            EngineEvent::Shutdown => {
+                info!(target: "rollup_node", "Engine drained its pending tasks and shut down");
+                break;
+            }
+        }
+    }
+}
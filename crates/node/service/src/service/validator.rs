@@ -1,8 +1,9 @@
 //! [ValidatorNodeService] trait.
 
 use crate::{
-    DerivationActor, EngineActor, EngineLauncher, L2ForkchoiceState, NetworkActor, NodeActor,
-    RpcActor, service::spawn_and_wait,
+    DerivationActor, DerivationAdminRpc, EngineActor, EngineHandle, EngineLauncher,
+    L2ForkchoiceState, NetworkActor, NodeActor, ResetHeadValidator, RpcActor, SafeHeadLagAlarm,
+    service::{log_engine_events, spawn_and_wait},
 };
 use alloy_primitives::Address;
 use async_trait::async_trait;
@@ -11,7 +12,7 @@ use kona_engine::EngineStateBuilderError;
 use kona_genesis::RollupConfig;
 use kona_p2p::{Network, NetworkRpc};
 use kona_protocol::BlockInfo;
-use kona_rpc::{OpP2PApiServer, RpcLauncher, RpcLauncherError};
+use kona_rpc::{DerivationAdminApiServer, OpP2PApiServer, RpcLauncher, RpcLauncherError};
 use std::fmt::Display;
 use tokio::sync::mpsc::{self, UnboundedSender};
 use tokio_util::sync::CancellationToken;
@@ -88,6 +89,16 @@ pub trait ValidatorNodeService {
     /// Returns the [`RpcLauncher`] for the node.
     fn rpc(&self) -> Option<RpcLauncher>;
 
+    /// Returns the number of blocks the unsafe head may be ahead of the safe head before a
+    /// [`SafeHeadLagAlarm`] is raised, or `None` if the alarm is disabled.
+    fn safe_head_lag_threshold(&self) -> Option<u64> {
+        None
+    }
+
+    /// Returns the [`ResetHeadValidator`] used to check derivation resets requested over the admin
+    /// API, or `None` if the admin API is disabled.
+    fn reset_validator(&self) -> Option<Box<dyn ResetHeadValidator + Send + Sync>>;
+
     /// Starts the rollup node service.
     async fn start(&self) -> Result<(), Self::Error> {
         // Create a global cancellation token for graceful shutdown of tasks.
@@ -103,20 +114,28 @@ pub trait ValidatorNodeService {
             Some(self.new_da_watcher(new_head_tx, block_signer_tx, cancellation.clone()));
 
         let (l2_forkchoice_state, derivation_pipeline) = self.init_derivation().await?;
-        let derivation = DerivationActor::new(
+        let mut derivation = DerivationActor::new(
             derivation_pipeline,
             l2_forkchoice_state.safe,
-            derived_payload_tx,
+            EngineHandle::new(derived_payload_tx),
             new_head_rx,
             cancellation.clone(),
         );
+        let mut admin_module = None;
+        if let Some(validator) = self.reset_validator() {
+            let (admin_tx, admin_rx) = mpsc::channel(16);
+            derivation = derivation.with_admin_requests(admin_rx, validator);
+            admin_module = Some(DerivationAdminRpc::new(admin_tx));
+        }
         let derivation = Some(derivation);
 
         let launcher = self.engine();
         let client = launcher.client();
         let sync = launcher.sync.clone();
         let engine = launcher.launch().await?;
-        let engine = EngineActor::new(
+        let (engine_events_tx, engine_events_rx) = mpsc::unbounded_channel();
+        tokio::spawn(log_engine_events(engine_events_rx));
+        let mut engine = EngineActor::new(
             std::sync::Arc::new(self.config().clone()),
             sync,
             client,
@@ -125,7 +144,11 @@ pub trait ValidatorNodeService {
             unsafe_block_rx,
             cancellation.clone(),
         );
-        let engine = Some(engine);
+        if let Some(threshold) = self.safe_head_lag_threshold() {
+            let alarm = SafeHeadLagAlarm::new(threshold, engine_events_tx.clone());
+            engine = engine.with_safe_head_lag_alarm(alarm);
+        }
+        let engine = Some(engine.with_event_sender(engine_events_tx));
 
         let mut p2p_module = None;
         let network = (self.init_network().await?).map_or_else(
@@ -146,6 +169,9 @@ pub trait ValidatorNodeService {
             if let Some(p2p_module) = p2p_module {
                 rpc = rpc.merge(p2p_module.into_rpc()).expect("failed to merge p2p rpc module");
             }
+            if let Some(admin_module) = admin_module {
+                rpc = rpc.merge(admin_module.into_rpc()).expect("failed to merge admin rpc module");
+            }
             let handle = rpc.start().await?;
             Some(RpcActor::new(handle, cancellation.clone()))
         } else {
@@ -10,13 +10,13 @@ use alloc::vec::Vec;
 use alloy_consensus::{EMPTY_OMMER_ROOT_HASH, Header, Sealed};
 use alloy_eips::Encodable2718;
 use alloy_evm::{EvmFactory, block::BlockExecutionResult};
-use alloy_primitives::{B256, Sealable, U256, keccak256, logs_bloom};
+use alloy_primitives::{Address, B256, Sealable, U256, keccak256, logs_bloom};
 use alloy_trie::EMPTY_ROOT_HASH;
 use kona_genesis::RollupConfig;
 use kona_mpt::{TrieHinter, ordered_trie_with_encoder};
 use op_alloy_consensus::OpReceiptEnvelope;
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
-use revm::{context::BlockEnv, database::BundleState};
+use revm::{Database, context::BlockEnv, database::BundleState, state::AccountInfo};
 
 impl<P, H, Evm> StatelessL2Builder<'_, P, H, Evm>
 where
@@ -151,6 +151,23 @@ where
         Ok(output_root)
     }
 
+    /// Returns the state root of the latest executed block, computed from the current state trie.
+    ///
+    /// The root is recomputed from the opened trie without modifying it, so this may be called at
+    /// any point for debugging, e.g. to compare against the state root component of
+    /// [`Self::compute_output_root`].
+    pub fn current_state_root(&self) -> B256 {
+        self.trie_db.root().blind()
+    }
+
+    /// Returns the [AccountInfo] of the account at `address` in the state of the latest executed
+    /// block, fetching it from the underlying trie if it has not been opened yet.
+    ///
+    /// The code of the account is not loaded.
+    pub fn account(&mut self, address: Address) -> ExecutorResult<Option<AccountInfo>> {
+        Ok(self.trie_db.basic(address)?)
+    }
+
     /// Fetches the L2 to L1 message passer account from the cache or underlying trie.
     fn message_passer_account(&mut self, block_number: u64) -> Result<B256, TrieDBError> {
         match self.trie_db.storage_roots().get(&L2_TO_L1_BRIDGE) {
@@ -1,20 +1,28 @@
 //! The [StatelessL2Builder] is a block builder that pulls state from a [TrieDB] during execution.
 
 use crate::{ExecutorError, ExecutorResult, TrieDB, TrieDBError, TrieDBProvider};
-use alloc::{string::ToString, vec::Vec};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
 use alloy_consensus::{Header, Sealed};
 use alloy_evm::{
     EvmFactory, FromRecoveredTx, FromTxWithEncoded,
     block::{BlockExecutionResult, BlockExecutor, BlockExecutorFactory},
 };
 use alloy_op_evm::{OpBlockExecutionCtx, OpBlockExecutorFactory, block::OpAlloyReceiptBuilder};
-use alloy_primitives::SignatureError;
+use alloy_primitives::{Bytes, SignatureError};
+use alloy_sol_types::decode_revert_reason;
 use kona_genesis::RollupConfig;
 use kona_mpt::TrieHinter;
 use op_alloy_consensus::{OpReceiptEnvelope, OpTxEnvelope};
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use op_revm::OpSpecId;
-use revm::database::{State, states::bundle_state::BundleRetention};
+use revm::{
+    context::result::{ExecutionResult, HaltReasonTr},
+    database::{State, states::bundle_state::BundleRetention},
+};
 
 /// The [`StatelessL2Builder`] is an OP Stack block builder that traverses a merkle patricia trie
 /// via the [`TrieDB`] during execution.
@@ -32,6 +40,14 @@ where
     /// The executor factory, used to create new [`op_revm::OpEvm`] instances for block building
     /// routines.
     pub(crate) factory: OpBlockExecutorFactory<OpAlloyReceiptBuilder, RollupConfig, Evm>,
+    /// An optional gas limit that replaces the gas limit of the payload attributes.
+    ///
+    /// This is **not** consensus-safe, and only exists for benchmarking and fuzzing harnesses.
+    pub(crate) gas_limit_override: Option<u64>,
+    /// Whether or not to capture the [`TxExecutionOutcome`] of each transaction.
+    pub(crate) capture_tx_outcomes: bool,
+    /// The [`TxExecutionOutcome`]s of the transactions within the most recently built block.
+    pub(crate) tx_outcomes: Vec<TxExecutionOutcome>,
 }
 
 impl<'a, P, H, Evm> StatelessL2Builder<'a, P, H, Evm>
@@ -55,15 +71,64 @@ where
             config.clone(),
             evm_factory,
         );
-        Self { config, trie_db, factory }
+        Self {
+            config,
+            trie_db,
+            factory,
+            gas_limit_override: None,
+            capture_tx_outcomes: false,
+            tx_outcomes: Vec::new(),
+        }
+    }
+
+    /// Overrides the block gas limit of all blocks built by the [StatelessL2Builder], ignoring the
+    /// gas limit derived from the system config.
+    ///
+    /// ## Warning
+    /// Blocks built with a gas limit override are not consensus-valid. This is only intended for
+    /// benchmarking and fuzzing harnesses that need to execute synthetic blocks.
+    pub const fn with_gas_limit_override(mut self, gas_limit: u64) -> Self {
+        self.gas_limit_override = Some(gas_limit);
+        self
+    }
+
+    /// Enables capturing the [`TxExecutionOutcome`] of each transaction executed by
+    /// [`Self::build_block`], including the decoded revert reason of reverted transactions.
+    ///
+    /// This is disabled by default, and is intended for debugging unexpected transaction failures.
+    pub const fn with_tx_outcome_capture(mut self) -> Self {
+        self.capture_tx_outcomes = true;
+        self
+    }
+
+    /// Returns the [`TxExecutionOutcome`]s of the transactions within the most recently built
+    /// block. Always empty unless enabled via [`Self::with_tx_outcome_capture`].
+    pub fn tx_outcomes(&self) -> &[TxExecutionOutcome] {
+        &self.tx_outcomes
+    }
+
+    /// Applies the gas limit override, if one is set, to the given [`OpPayloadAttributes`].
+    pub(crate) fn apply_gas_limit_override(&self, attrs: &mut OpPayloadAttributes) {
+        let Some(gas_limit) = self.gas_limit_override else {
+            return;
+        };
+
+        warn!(
+            target: "block_builder",
+            gas_limit,
+            system_config_gas_limit = ?attrs.gas_limit,
+            "Overriding block gas limit. The resulting block is NOT consensus-valid."
+        );
+        attrs.gas_limit = Some(gas_limit);
     }
 
     /// Builds a new block on top of the parent state, using the given [`OpPayloadAttributes`].
     pub fn build_block(
         &mut self,
-        attrs: OpPayloadAttributes,
+        mut attrs: OpPayloadAttributes,
     ) -> ExecutorResult<BlockBuildingOutcome> {
         // Step 1. Set up the execution environment.
+        self.apply_gas_limit_override(&mut attrs);
         let base_fee_params =
             Self::active_base_fee_params(self.config, self.trie_db.parent_block_header(), &attrs)?;
         let evm_env = self.evm_env(
@@ -106,14 +171,23 @@ where
             // This field is unused for individual block building jobs.
             extra_data: Default::default(),
         };
-        let executor = self.factory.create_executor(evm, ctx);
+        let mut executor = self.factory.create_executor(evm, ctx);
 
         // Step 3. Execute the block containing the transactions within the payload attributes.
         let transactions = attrs
             .recovered_transactions_with_encoded()
             .collect::<Result<Vec<_>, SignatureError>>()
             .map_err(ExecutorError::SignatureError)?;
-        let ex_result = executor.execute_block(transactions.iter())?;
+        self.tx_outcomes.clear();
+        executor.apply_pre_execution_changes()?;
+        for tx in transactions.iter() {
+            executor.execute_transaction_with_result_closure(tx, |result| {
+                if self.capture_tx_outcomes {
+                    self.tx_outcomes.push(result.into());
+                }
+            })?;
+        }
+        let ex_result = executor.apply_post_execution_changes()?;
 
         info!(
             target: "block_builder",
@@ -141,6 +215,24 @@ where
         self.trie_db.set_parent_block_header(header.clone());
         Ok((header, ex_result).into())
     }
+
+    /// Builds a sequence of blocks on top of the parent state, using the given
+    /// [`OpPayloadAttributes`], and returns the sealed [`Header`] of each block.
+    ///
+    /// The header produced by each block becomes the parent of the next, and the state of the
+    /// [`TrieDB`] is carried across blocks rather than being re-opened for each of them. The output
+    /// root of the final block may be computed afterwards with [`Self::compute_output_root`].
+    ///
+    /// ## Invariant
+    /// The payloads must be consecutive, with the first payload building on top of the current
+    /// parent header. This is not checked; executing a non-consecutive payload produces a block
+    /// that does not belong to the canonical chain.
+    pub fn build_blocks(
+        &mut self,
+        attrs: impl IntoIterator<Item = OpPayloadAttributes>,
+    ) -> ExecutorResult<Vec<Sealed<Header>>> {
+        attrs.into_iter().map(|attrs| Ok(self.build_block(attrs)?.header)).collect()
+    }
 }
 
 /// The outcome of a block building operation, returning the sealed block [`Header`] and the
@@ -161,12 +253,230 @@ impl From<(Sealed<Header>, BlockExecutionResult<OpReceiptEnvelope>)> for BlockBu
     }
 }
 
+/// The outcome of executing a single transaction within a block, captured by the
+/// [`StatelessL2Builder`] when enabled via [`StatelessL2Builder::with_tx_outcome_capture`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TxExecutionOutcome {
+    /// The transaction executed successfully.
+    Success {
+        /// The gas used by the transaction.
+        gas_used: u64,
+    },
+    /// The transaction reverted.
+    Revert {
+        /// The gas used by the transaction.
+        gas_used: u64,
+        /// The raw revert output.
+        output: Bytes,
+        /// The decoded revert reason, if the output could be decoded.
+        reason: Option<String>,
+    },
+    /// The transaction halted.
+    Halt {
+        /// The gas used by the transaction.
+        gas_used: u64,
+        /// The halt reason.
+        reason: String,
+    },
+}
+
+impl<H: HaltReasonTr> From<&ExecutionResult<H>> for TxExecutionOutcome {
+    fn from(result: &ExecutionResult<H>) -> Self {
+        match result {
+            ExecutionResult::Success { gas_used, .. } => Self::Success { gas_used: *gas_used },
+            ExecutionResult::Revert { gas_used, output } => Self::Revert {
+                gas_used: *gas_used,
+                output: output.clone(),
+                reason: decode_revert_reason(output),
+            },
+            ExecutionResult::Halt { reason, gas_used } => {
+                Self::Halt { gas_used: *gas_used, reason: format!("{reason:?}") }
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::test_utils::run_test_fixture;
+    use super::{StatelessL2Builder, TxExecutionOutcome};
+    use crate::{
+        NoopTrieDBProvider,
+        constants::{L2_TO_L1_BRIDGE, OUTPUT_ROOT_VERSION},
+        test_utils::{
+            DiskTrieNodeProvider, load_test_fixture, run_test_fixture, run_test_fixture_sequence,
+        },
+    };
+    use alloy_consensus::{Header, Sealed};
+    use alloy_eips::Encodable2718;
+    use alloy_op_evm::OpEvmFactory;
+    use alloy_primitives::{Address, Bytes, TxKind, U256, keccak256};
+    use alloy_sol_types::{Revert, SolError};
+    use kona_genesis::RollupConfig;
+    use kona_mpt::NoopTrieHinter;
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+    use op_revm::OpHaltReason;
+    use revm::{
+        context::result::{ExecutionResult, HaltReason},
+        primitives::KECCAK_EMPTY,
+    };
     use rstest::rstest;
     use std::path::PathBuf;
 
+    fn attrs_with_gas_limit(gas_limit: u64) -> OpPayloadAttributes {
+        OpPayloadAttributes { gas_limit: Some(gas_limit), ..Default::default() }
+    }
+
+    #[test]
+    fn test_gas_limit_override_unset() {
+        let cfg = RollupConfig::default();
+        let builder = StatelessL2Builder::new(
+            &cfg,
+            OpEvmFactory::default(),
+            NoopTrieDBProvider,
+            NoopTrieHinter,
+            Sealed::new(Header::default()),
+        );
+
+        let mut attrs = attrs_with_gas_limit(30_000_000);
+        builder.apply_gas_limit_override(&mut attrs);
+        assert_eq!(attrs, attrs_with_gas_limit(30_000_000));
+    }
+
+    #[test]
+    fn test_gas_limit_override_set() {
+        let cfg = RollupConfig::default();
+        let builder = StatelessL2Builder::new(
+            &cfg,
+            OpEvmFactory::default(),
+            NoopTrieDBProvider,
+            NoopTrieHinter,
+            Sealed::new(Header::default()),
+        )
+        .with_gas_limit_override(u64::MAX);
+
+        let mut attrs = attrs_with_gas_limit(30_000_000);
+        builder.apply_gas_limit_override(&mut attrs);
+        assert_eq!(attrs.gas_limit, Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_build_block_applies_gas_limit_override() {
+        let cfg = RollupConfig::default();
+        let build = |gas_limit_override: Option<u64>| {
+            let mut builder = StatelessL2Builder::new(
+                &cfg,
+                OpEvmFactory::default(),
+                NoopTrieDBProvider,
+                NoopTrieHinter,
+                Sealed::new(Header::default()),
+            );
+            if let Some(gas_limit) = gas_limit_override {
+                builder = builder.with_gas_limit_override(gas_limit);
+            }
+            let attrs = OpPayloadAttributes {
+                transactions: Some(Vec::new()),
+                ..attrs_with_gas_limit(30_000_000)
+            };
+            builder.build_block(attrs).unwrap().header
+        };
+
+        assert_eq!(build(None).gas_limit, 30_000_000);
+        assert_eq!(build(Some(u64::MAX)).gas_limit, u64::MAX);
+    }
+
+    #[test]
+    fn test_tx_outcome_capture_disabled_by_default() {
+        let cfg = RollupConfig::default();
+        let builder = StatelessL2Builder::new(
+            &cfg,
+            OpEvmFactory::default(),
+            NoopTrieDBProvider,
+            NoopTrieHinter,
+            Sealed::new(Header::default()),
+        );
+        assert!(!builder.capture_tx_outcomes);
+
+        let builder = builder.with_tx_outcome_capture();
+        assert!(builder.capture_tx_outcomes);
+        assert!(builder.tx_outcomes().is_empty());
+    }
+
+    #[test]
+    fn test_build_block_captures_revert_reason() {
+        let revert = Revert { reason: "insufficient allowance".into() }.abi_encode();
+        let len = revert.len() as u8;
+
+        // Init code that copies the revert payload appended to it into memory and reverts with it.
+        let mut init_code =
+            vec![0x60, len, 0x60, 12, 0x60, 0x00, 0x39, 0x60, len, 0x60, 0x00, 0xfd];
+        init_code.extend_from_slice(&revert);
+        let deposit = TxDeposit {
+            from: Address::with_last_byte(1),
+            to: TxKind::Create,
+            value: U256::ZERO,
+            gas_limit: 100_000,
+            input: init_code.into(),
+            ..Default::default()
+        };
+
+        let cfg = RollupConfig::default();
+        let mut builder = StatelessL2Builder::new(
+            &cfg,
+            OpEvmFactory::default(),
+            NoopTrieDBProvider,
+            NoopTrieHinter,
+            Sealed::new(Header::default()),
+        )
+        .with_tx_outcome_capture();
+        let mut attrs = attrs_with_gas_limit(30_000_000);
+        attrs.transactions =
+            Some(vec![OpTxEnvelope::Deposit(Sealed::new(deposit)).encoded_2718().into()]);
+        builder.build_block(attrs).unwrap();
+
+        let [TxExecutionOutcome::Revert { output, reason, .. }] = builder.tx_outcomes() else {
+            panic!("Expected a single reverted transaction outcome: {:?}", builder.tx_outcomes());
+        };
+        assert_eq!(output.as_ref(), revert.as_slice());
+        assert_eq!(reason.as_deref(), Some("revert: insufficient allowance"));
+    }
+
+    #[test]
+    fn test_tx_outcome_revert_reason() {
+        let output = Bytes::from(Revert { reason: "insufficient allowance".into() }.abi_encode());
+        let result = ExecutionResult::<OpHaltReason>::Revert { gas_used: 21_000, output };
+
+        let TxExecutionOutcome::Revert { gas_used, reason, .. } = (&result).into() else {
+            panic!("Expected a reverted transaction outcome");
+        };
+        assert_eq!(gas_used, 21_000);
+        assert_eq!(reason.as_deref(), Some("revert: insufficient allowance"));
+    }
+
+    #[test]
+    fn test_tx_outcome_undecodable_revert() {
+        let output = Bytes::from_static(&[0xff, 0xff]);
+        let result =
+            ExecutionResult::<OpHaltReason>::Revert { gas_used: 21_000, output: output.clone() };
+
+        assert_eq!(
+            TxExecutionOutcome::from(&result),
+            TxExecutionOutcome::Revert { gas_used: 21_000, output, reason: None }
+        );
+    }
+
+    #[test]
+    fn test_tx_outcome_halt() {
+        let result = ExecutionResult::<OpHaltReason>::Halt {
+            reason: OpHaltReason::Base(HaltReason::OutOfFunds),
+            gas_used: 30_000,
+        };
+        assert!(matches!(
+            TxExecutionOutcome::from(&result),
+            TxExecutionOutcome::Halt { gas_used: 30_000, reason } if reason.contains("OutOfFunds")
+        ));
+    }
+
     #[rstest]
     #[tokio::test]
     async fn test_statelessly_execute_block(
@@ -176,4 +486,46 @@ mod test {
     ) {
         run_test_fixture(path).await;
     }
+
+    #[tokio::test]
+    async fn test_current_state_root_matches_output_root() {
+        let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata");
+        let (_fixture_dir, kv_store, fixture) =
+            load_test_fixture(&testdata.join("block-26207960.tar.gz")).await;
+        let mut builder = StatelessL2Builder::new(
+            &fixture.rollup_config,
+            OpEvmFactory::default(),
+            DiskTrieNodeProvider::new(kv_store),
+            NoopTrieHinter,
+            fixture.parent_header.seal_slow(),
+        );
+        let header = builder.build_block(fixture.executing_payload).unwrap().header;
+
+        let state_root = builder.current_state_root();
+        assert_eq!(state_root, header.state_root);
+
+        // Rebuild the output root from the current state root and the message passer's storage.
+        let message_passer = builder.account(L2_TO_L1_BRIDGE).unwrap().unwrap();
+        assert_ne!(message_passer.code_hash, KECCAK_EMPTY);
+        let storage_root = builder.trie_db.storage_roots()[&L2_TO_L1_BRIDGE].blind();
+        let mut raw_output = [0u8; 128];
+        raw_output[31] = OUTPUT_ROOT_VERSION;
+        raw_output[32..64].copy_from_slice(state_root.as_ref());
+        raw_output[64..96].copy_from_slice(storage_root.as_ref());
+        raw_output[96..128].copy_from_slice(header.hash().as_ref());
+        assert_eq!(builder.compute_output_root().unwrap(), keccak256(raw_output));
+
+        // Neither accessor modifies the state.
+        assert_eq!(builder.current_state_root(), state_root);
+    }
+
+    #[tokio::test]
+    async fn test_statelessly_execute_consecutive_blocks() {
+        let testdata = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("testdata");
+        run_test_fixture_sequence(&[
+            testdata.join("block-26207960.tar.gz"),
+            testdata.join("block-26207961.tar.gz"),
+        ])
+        .await;
+    }
 }
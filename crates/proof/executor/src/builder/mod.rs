@@ -1,7 +1,10 @@
 //! Stateless OP Stack block builder implementation.
 
 mod core;
-pub use core::{BlockBuildingOutcome, StatelessL2Builder};
+pub use core::{BlockBuildingOutcome, StatelessL2Builder, TxExecutionOutcome};
+
+mod precompiles;
+pub use precompiles::PrecompileOverrideFactory;
 
 mod assemble;
 mod env;
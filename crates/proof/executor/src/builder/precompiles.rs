@@ -0,0 +1,208 @@
+//! Precompile overrides for the EVMs created by the [StatelessL2Builder].
+
+use super::StatelessL2Builder;
+use crate::TrieDBProvider;
+use alloy_evm::{EvmFactory, FromRecoveredTx, FromTxWithEncoded};
+use alloy_op_evm::{OpBlockExecutorFactory, block::OpAlloyReceiptBuilder};
+use alloy_primitives::Address;
+use kona_mpt::TrieHinter;
+use op_alloy_consensus::OpTxEnvelope;
+use op_revm::OpSpecId;
+use revm::{precompile::PrecompileFn, primitives::hash_map::HashMap};
+
+/// An [`EvmFactory`] that can install precompile overrides into the EVMs that it creates.
+///
+/// An override replaces the precompile at its [`Address`], or adds a precompile at an address that
+/// does not have one.
+pub trait PrecompileOverrideFactory: EvmFactory {
+    /// Sets the precompile overrides of all EVMs created by the factory.
+    fn with_precompile_overrides(self, overrides: HashMap<Address, PrecompileFn>) -> Self;
+}
+
+impl<P, H, Evm> StatelessL2Builder<'_, P, H, Evm>
+where
+    P: TrieDBProvider,
+    H: TrieHinter,
+    Evm: PrecompileOverrideFactory<Spec = OpSpecId> + Clone + 'static,
+    <Evm as EvmFactory>::Tx: FromTxWithEncoded<OpTxEnvelope> + FromRecoveredTx<OpTxEnvelope>,
+{
+    /// Installs the given precompile overrides into the EVM of all blocks built by the
+    /// [StatelessL2Builder], such as to supply an accelerated precompile.
+    ///
+    /// ## Warning
+    /// An override that does not match the canonical precompile at its address produces blocks
+    /// that are not consensus-valid.
+    pub fn with_precompile_overrides(mut self, overrides: HashMap<Address, PrecompileFn>) -> Self {
+        let evm_factory = self.factory.evm_factory().clone().with_precompile_overrides(overrides);
+        self.factory = OpBlockExecutorFactory::new(
+            OpAlloyReceiptBuilder::default(),
+            self.config.clone(),
+            evm_factory,
+        );
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{NoopTrieDBProvider, TxExecutionOutcome};
+    use alloy_consensus::{Header, Sealed};
+    use alloy_eips::Encodable2718;
+    use alloy_evm::{Database, EvmEnv};
+    use alloy_op_evm::OpEvm;
+    use alloy_primitives::{Bytes, TxKind, U256};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use kona_genesis::RollupConfig;
+    use kona_mpt::NoopTrieHinter;
+    use op_alloy_consensus::TxDeposit;
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+    use op_revm::{
+        DefaultOp, OpContext, OpEvm as RevmOpEvm, OpHaltReason, OpTransaction, OpTransactionError,
+    };
+    use revm::{
+        Context, Inspector,
+        context::{Cfg, ContextTr, Evm as RevmEvm, EvmData, TxEnv, result::EVMError},
+        handler::{PrecompileProvider, instructions::EthInstructions},
+        inspector::NoOpInspector,
+        interpreter::{Gas, InputsImpl, InstructionResult, InterpreterResult},
+        precompile::{PrecompileOutput, PrecompileResult, u64_to_address},
+    };
+
+    /// The number of times that [counting_identity] has been invoked.
+    static IDENTITY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    /// An identity precompile override that counts its invocations.
+    fn counting_identity(input: &Bytes, _gas_limit: u64) -> PrecompileResult {
+        IDENTITY_CALLS.fetch_add(1, Ordering::SeqCst);
+        Ok(PrecompileOutput::new(15, input.clone()))
+    }
+
+    /// A [PrecompileProvider] that only serves the precompile overrides.
+    #[derive(Debug, Clone, Default)]
+    struct OverridePrecompiles {
+        overrides: HashMap<Address, PrecompileFn>,
+    }
+
+    impl<CTX> PrecompileProvider<CTX> for OverridePrecompiles
+    where
+        CTX: ContextTr<Cfg: Cfg<Spec = OpSpecId>>,
+    {
+        type Output = InterpreterResult;
+
+        fn set_spec(&mut self, _spec: <CTX::Cfg as Cfg>::Spec) -> bool {
+            false
+        }
+
+        fn run(
+            &mut self,
+            _context: &mut CTX,
+            address: &Address,
+            inputs: &InputsImpl,
+            _is_static: bool,
+            gas_limit: u64,
+        ) -> Result<Option<Self::Output>, String> {
+            let Some(precompile) = self.overrides.get(address) else {
+                return Ok(None);
+            };
+            let output = (*precompile)(&inputs.input, gas_limit).map_err(|e| e.to_string())?;
+            let mut gas = Gas::new(gas_limit);
+            assert!(gas.record_cost(output.gas_used));
+            Ok(Some(InterpreterResult {
+                result: InstructionResult::Return,
+                gas,
+                output: output.bytes,
+            }))
+        }
+
+        fn warm_addresses(&self) -> Box<impl Iterator<Item = Address>> {
+            Box::new(self.overrides.keys().copied())
+        }
+
+        fn contains(&self, address: &Address) -> bool {
+            self.overrides.contains_key(address)
+        }
+    }
+
+    /// An [EvmFactory] producing [OpEvm]s with [OverridePrecompiles].
+    #[derive(Debug, Clone, Default)]
+    struct OverrideEvmFactory {
+        overrides: HashMap<Address, PrecompileFn>,
+    }
+
+    impl PrecompileOverrideFactory for OverrideEvmFactory {
+        fn with_precompile_overrides(self, overrides: HashMap<Address, PrecompileFn>) -> Self {
+            Self { overrides }
+        }
+    }
+
+    impl EvmFactory for OverrideEvmFactory {
+        type Evm<DB: Database, I: Inspector<OpContext<DB>>> = OpEvm<DB, I, OverridePrecompiles>;
+        type Context<DB: Database> = OpContext<DB>;
+        type Tx = OpTransaction<TxEnv>;
+        type Error<DBError: core::error::Error + Send + Sync + 'static> =
+            EVMError<DBError, OpTransactionError>;
+        type HaltReason = OpHaltReason;
+        type Spec = OpSpecId;
+
+        fn create_evm<DB: Database>(
+            &self,
+            db: DB,
+            input: EvmEnv<OpSpecId>,
+        ) -> Self::Evm<DB, NoOpInspector> {
+            self.create_evm_with_inspector(db, input, NoOpInspector {})
+        }
+
+        fn create_evm_with_inspector<DB: Database, I: Inspector<Self::Context<DB>>>(
+            &self,
+            db: DB,
+            input: EvmEnv<OpSpecId>,
+            inspector: I,
+        ) -> Self::Evm<DB, I> {
+            let ctx = Context::op().with_db(db).with_block(input.block_env).with_cfg(input.cfg_env);
+            let revm_evm = RevmOpEvm(RevmEvm {
+                data: EvmData { ctx, inspector },
+                instruction: EthInstructions::new_mainnet(),
+                precompiles: OverridePrecompiles { overrides: self.overrides.clone() },
+            });
+            OpEvm::new(revm_evm, false)
+        }
+    }
+
+    #[test]
+    fn test_build_block_invokes_precompile_override() {
+        let identity = u64_to_address(4);
+        let deposit = TxDeposit {
+            from: Address::with_last_byte(1),
+            to: TxKind::Call(identity),
+            value: U256::ZERO,
+            gas_limit: 100_000,
+            input: Bytes::from_static(&[1, 2, 3]),
+            ..Default::default()
+        };
+
+        let cfg = RollupConfig::default();
+        let mut builder = StatelessL2Builder::new(
+            &cfg,
+            OverrideEvmFactory::default(),
+            NoopTrieDBProvider,
+            NoopTrieHinter,
+            Sealed::new(Header::default()),
+        )
+        .with_tx_outcome_capture()
+        .with_precompile_overrides(
+            [(identity, counting_identity as PrecompileFn)].into_iter().collect(),
+        );
+        let attrs = OpPayloadAttributes {
+            gas_limit: Some(30_000_000),
+            transactions: Some(vec![
+                OpTxEnvelope::Deposit(Sealed::new(deposit)).encoded_2718().into(),
+            ]),
+            ..Default::default()
+        };
+        builder.build_block(attrs).unwrap();
+
+        assert!(matches!(builder.tx_outcomes(), [TxExecutionOutcome::Success { .. }]));
+        assert_eq!(IDENTITY_CALLS.load(Ordering::SeqCst), 1);
+    }
+}
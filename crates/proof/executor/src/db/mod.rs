@@ -11,7 +11,7 @@ use kona_mpt::{Nibbles, TrieHinter, TrieNode, TrieNodeError};
 use revm::{
     Database,
     database::{BundleState, states::StorageSlot},
-    primitives::{BLOCK_HASH_HISTORY, HashMap},
+    primitives::{BLOCK_HASH_HISTORY, HashMap, KECCAK_EMPTY},
     state::{AccountInfo, Bytecode},
 };
 
@@ -33,6 +33,10 @@ pub use traits::{NoopTrieDBProvider, TrieDBProvider};
 ///   fall through to the `PreimageFetcher` to fetch the preimages of the trie nodes on the path to
 ///   the account. After it has been fetched, the path will be cached until the next call to
 ///   [Self::state_root].
+/// - When an account's storage is queried, the account's storage trie is opened in the same manner.
+///   Opened storage tries persist across blocks executed on the same [`TrieDB`], and are only
+///   discarded once the account's storage root changes, avoiding re-fetching the storage trie nodes
+///   of frequently accessed contracts.
 /// - When querying for the code hash of an account, the [`TrieDBProvider`] is consulted to fetch
 ///   the code hash of the account.
 /// - When a [`BundleState`] changeset is committed to the parent [`State`] database, the changes
@@ -41,7 +45,8 @@ pub use traits::{NoopTrieDBProvider, TrieDBProvider};
 /// - When the block hash of a block number is needed via [Self::block_hash], the
 ///   `HeaderByHashFetcher` is consulted to walk back to the desired block number by revealing the
 ///   parent hash of block headers until the desired block number is reached, up to a maximum of
-///   [BLOCK_HASH_HISTORY] blocks back relative to the current parent block hash.
+///   [BLOCK_HASH_HISTORY] blocks back relative to the current parent block hash. Block hashes
+///   preloaded with [Self::preload_block_hashes] are served without fetching any headers.
 ///
 /// **Example Construction**:
 /// ```rust
@@ -85,10 +90,21 @@ where
 {
     /// The [`TrieNode`] representation of the root node.
     root_node: TrieNode,
-    /// Storage roots of accounts within the trie.
+    /// Storage roots of accounts within the trie. Opened storage tries are retained across blocks,
+    /// so long as the account's storage root remains unchanged.
     storage_roots: HashMap<Address, TrieNode>,
+    /// The commitments of the tries within `storage_roots`, recorded as each trie is built so that
+    /// checking for an unchanged storage root does not require re-hashing the opened trie.
+    storage_root_hashes: HashMap<Address, B256>,
     /// The parent block hash of the current block.
     parent_block_header: Sealed<Header>,
+    /// The verified hashes of ancestors of the parent block, keyed by block number.
+    block_hashes: HashMap<u64, B256>,
+    /// The accounts preloaded with [`Self::preload_accounts`], served by [`Self::basic`] without
+    /// hinting the host. Discarded once the trie or the parent block changes.
+    preloaded_accounts: HashMap<Address, Option<TrieAccount>>,
+    /// The code of the accounts preloaded with [`Self::preload_accounts`], keyed by code hash.
+    preloaded_code: HashMap<B256, Bytecode>,
     /// The [`TrieDBProvider`]
     pub fetcher: F,
     /// The [`TrieHinter`]
@@ -105,7 +121,11 @@ where
         Self {
             root_node: TrieNode::new_blinded(parent_block_header.state_root),
             storage_roots: Default::default(),
+            storage_root_hashes: Default::default(),
             parent_block_header,
+            block_hashes: Default::default(),
+            preloaded_accounts: Default::default(),
+            preloaded_code: Default::default(),
             fetcher,
             hinter,
         }
@@ -137,9 +157,47 @@ where
     /// ## Takes
     /// - `parent_block_header`: The parent block header of the current block.
     pub fn set_parent_block_header(&mut self, parent_block_header: Sealed<Header>) {
+        // Preloaded block hashes remain valid if the new parent block builds on the current one.
+        if parent_block_header.parent_hash == self.parent_block_header.hash() {
+            self.block_hashes
+                .insert(self.parent_block_header.number, self.parent_block_header.hash());
+            self.block_hashes
+                .retain(|n, _| parent_block_header.number.saturating_sub(*n) <= BLOCK_HASH_HISTORY);
+        } else {
+            self.block_hashes.clear();
+        }
+        self.preloaded_accounts.clear();
         self.parent_block_header = parent_block_header;
     }
 
+    /// Preloads the hashes of the ancestors of the parent block header, so that they can be served
+    /// by [Self::block_hash] (e.g. for the `BLOCKHASH` opcode) without fetching their headers from
+    /// the [TrieDBProvider].
+    ///
+    /// ## Takes
+    /// - `headers`: The ancestor headers, walking back from the parent of the parent block header.
+    ///   At most [BLOCK_HASH_HISTORY] headers are consumed.
+    ///
+    /// ## Returns
+    /// - `Ok(())`: If all consumed headers were preloaded.
+    /// - `Err(TrieDBError::UnlinkedHeader(_))`: If a header is not the parent of the previous
+    ///   header. The hashes of the headers preceding it remain preloaded.
+    pub fn preload_block_hashes(
+        &mut self,
+        headers: impl IntoIterator<Item = Header>,
+    ) -> TrieDBResult<()> {
+        let mut expected_hash = self.parent_block_header.parent_hash;
+        for header in headers.into_iter().take(BLOCK_HASH_HISTORY as usize) {
+            let hash = header.hash_slow();
+            if hash != expected_hash {
+                return Err(TrieDBError::UnlinkedHeader(header.number));
+            }
+            self.block_hashes.insert(header.number, hash);
+            expected_hash = header.parent_hash;
+        }
+        Ok(())
+    }
+
     /// Applies a [BundleState] changeset to the [TrieNode] and recomputes the state root hash.
     ///
     /// ## Takes
@@ -166,6 +224,54 @@ where
         Ok(root)
     }
 
+    /// Applies a [BundleState] changeset to the [TrieNode] and recomputes the state root hash,
+    /// hashing independent branch children in parallel when the `rayon` feature is enabled.
+    ///
+    /// The result is identical to that of [Self::state_root]. Without the `rayon` feature, the
+    /// root is computed serially.
+    ///
+    /// ## Takes
+    /// - `bundle`: The [BundleState] changeset to apply to the trie DB.
+    ///
+    /// ## Returns
+    /// - `Ok(B256)`: The new state root hash of the trie DB.
+    /// - `Err(_)`: If the state root hash could not be computed.
+    pub fn state_root_parallel(&mut self, bundle: &BundleState) -> TrieDBResult<B256> {
+        debug!(target: "client_executor", "Recomputing state root in parallel");
+
+        self.update_accounts(bundle)?;
+        let root = self.root_node.blind_parallel();
+
+        debug!(
+            target: "client_executor",
+            "Recomputed state root: {root}",
+        );
+
+        Ok(root)
+    }
+
+    /// Applies a [BundleState] changeset to the trie DB and computes the resulting state root,
+    /// consuming the trie DB.
+    ///
+    /// The result is equal to that of [Self::state_root], but the opened trie is re-blinded as
+    /// the root is folded up, minimizing peak memory. This is intended for tooling that verifies a
+    /// state transition and discards the state immediately afterwards.
+    ///
+    /// ## Takes
+    /// - `bundle`: The [BundleState] changeset to apply to the trie DB.
+    ///
+    /// ## Returns
+    /// - `Ok(B256)`: The new state root hash of the trie DB.
+    /// - `Err(_)`: If the state root hash could not be computed.
+    pub fn compute_root_and_discard(mut self, bundle: &BundleState) -> TrieDBResult<B256> {
+        self.update_accounts(bundle)?;
+
+        // The storage tries have been folded into the account leaves, and are no longer needed.
+        drop(core::mem::take(&mut self.storage_roots));
+
+        Ok(self.root_node.into_commitment())
+    }
+
     /// Fetches the [TrieAccount] of an account from the trie DB.
     ///
     /// ## Takes
@@ -185,7 +291,56 @@ where
             .hint_account_proof(*address, block_number)
             .map_err(|e| TrieDBError::Provider(e.to_string()))?;
 
-        // Fetch the account from the trie.
+        self.open_trie_account(address)
+    }
+
+    /// Preloads the [TrieAccount]s of the given addresses, along with their code, into the trie DB.
+    ///
+    /// Hints for all account proofs are sent before any trie nodes are fetched, allowing the host
+    /// to serve them in one batch rather than interleaving hints and fetches during execution.
+    /// The preloaded accounts and code are then served by [Self::basic] and [Self::code_by_hash]
+    /// without hinting or fetching them again, until the trie or the parent block header changes.
+    /// Preloading is purely an optimization; executing a block yields the same result whether or
+    /// not its accounts were preloaded.
+    ///
+    /// ## Takes
+    /// - `addresses`: The addresses of the accounts to preload.
+    ///
+    /// ## Returns
+    /// - `Ok(())`: If all accounts were preloaded.
+    /// - `Err(_)`: If an account or its code could not be fetched.
+    pub fn preload_accounts(&mut self, addresses: &[Address]) -> TrieDBResult<()> {
+        let block_number = self.parent_block_header.number;
+        for address in addresses {
+            self.hinter
+                .hint_account_proof(*address, block_number)
+                .map_err(|e| TrieDBError::Provider(e.to_string()))?;
+        }
+
+        for address in addresses {
+            let trie_account = self.open_trie_account(address)?;
+            self.preloaded_accounts.insert(*address, trie_account);
+            let Some(trie_account) = trie_account else {
+                continue;
+            };
+
+            if trie_account.code_hash != KECCAK_EMPTY &&
+                !self.preloaded_code.contains_key(&trie_account.code_hash)
+            {
+                let code = self
+                    .fetcher
+                    .bytecode_by_hash(trie_account.code_hash)
+                    .map_err(|e| TrieDBError::Provider(e.to_string()))?;
+                self.preloaded_code.insert(trie_account.code_hash, Bytecode::new_raw(code));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Opens the path to an account in the trie, without hinting the host, and decodes the
+    /// [TrieAccount] at its leaf.
+    fn open_trie_account(&mut self, address: &Address) -> TrieDBResult<Option<TrieAccount>> {
         let hashed_address_nibbles = Nibbles::unpack(keccak256(address.as_slice()));
         let Some(trie_account_rlp) = self.root_node.open(&hashed_address_nibbles, &self.fetcher)?
         else {
@@ -208,6 +363,9 @@ where
     /// - `Ok(())` if the accounts were successfully updated.
     /// - `Err(_)` if the accounts could not be updated.
     fn update_accounts(&mut self, bundle: &BundleState) -> TrieDBResult<()> {
+        // The preloaded accounts reflect the state prior to the changeset.
+        self.preloaded_accounts.clear();
+
         // Sort the storage keys prior to applying the changeset, to ensure that the order of
         // application is deterministic between runs.
         let mut sorted_state =
@@ -226,6 +384,7 @@ where
             if bundle_account.was_destroyed() {
                 self.root_node.delete(&account_path, &self.fetcher, &self.hinter)?;
                 self.storage_roots.remove(address);
+                self.storage_root_hashes.remove(address);
                 continue;
             }
 
@@ -267,6 +426,7 @@ where
             // Recompute the account storage root.
             let root = acc_storage_root.blind();
             trie_account.storage_root = root;
+            self.storage_root_hashes.insert(*address, root);
 
             // RLP encode the trie account for insertion.
             let mut account_buf = Vec::with_capacity(trie_account.length());
@@ -328,16 +488,24 @@ where
     type Error = TrieDBError;
 
     fn basic(&mut self, address: Address) -> Result<Option<AccountInfo>, Self::Error> {
-        // Fetch the account from the trie.
-        let Some(trie_account) =
-            self.get_trie_account(&address, self.parent_block_header.number)?
-        else {
+        // Fetch the account from the trie, unless it was preloaded.
+        let trie_account = match self.preloaded_accounts.get(&address) {
+            Some(trie_account) => *trie_account,
+            None => self.get_trie_account(&address, self.parent_block_header.number)?,
+        };
+        let Some(trie_account) = trie_account else {
             // If the account does not exist in the trie, return `Ok(None)`.
             return Ok(None);
         };
 
-        // Insert the account's storage root into the cache.
-        self.storage_roots.insert(address, TrieNode::new_blinded(trie_account.storage_root));
+        // Insert the account's storage root into the cache. If the account's storage trie was
+        // opened in a prior block and its root is unchanged, the opened trie is retained to avoid
+        // re-fetching its nodes. Otherwise, the stale trie is replaced with the blinded root.
+        let storage_root = trie_account.storage_root;
+        if self.storage_root_hashes.get(&address) != Some(&storage_root) {
+            self.storage_roots.insert(address, TrieNode::new_blinded(storage_root));
+            self.storage_root_hashes.insert(address, storage_root);
+        }
 
         // Return a partial DB account. The storage and code are not loaded out-right, and are
         // loaded optimistically in the `Database` + `DatabaseRef` trait implementations.
@@ -350,6 +518,10 @@ where
     }
 
     fn code_by_hash(&mut self, code_hash: B256) -> Result<Bytecode, Self::Error> {
+        if let Some(code) = self.preloaded_code.get(&code_hash) {
+            return Ok(code.clone());
+        }
+
         self.fetcher
             .bytecode_by_hash(code_hash)
             .map(Bytecode::new_raw)
@@ -389,6 +561,11 @@ where
         }
     }
 
+    /// Returns the hash of the given block number, which must be within [BLOCK_HASH_HISTORY]
+    /// blocks of the parent block header. Otherwise, [B256::ZERO] is returned.
+    ///
+    /// If the hash was not preloaded with [TrieDB::preload_block_hashes], the headers are fetched
+    /// from the [TrieDBProvider], failing with [TrieDBError::Provider] if one is unavailable.
     fn block_hash(&mut self, block_number: u64) -> Result<B256, Self::Error> {
         // Copy the current header
         let mut header = self.parent_block_header.inner().clone();
@@ -400,6 +577,11 @@ where
             return Ok(B256::default());
         }
 
+        // Serve the block hash from the preloaded hashes, if available.
+        if let Some(hash) = self.block_hashes.get(&block_number) {
+            return Ok(*hash);
+        }
+
         // Walk back the block headers to the desired block number.
         while header.number > block_number {
             header = self
@@ -415,9 +597,19 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::rc::Rc;
     use alloy_consensus::Sealable;
-    use alloy_primitives::b256;
-    use kona_mpt::NoopTrieHinter;
+    use alloy_evm::{Evm, EvmEnv, EvmFactory};
+    use alloy_op_evm::OpEvmFactory;
+    use alloy_primitives::{Bytes, address, b256, bytes};
+    use core::cell::Cell;
+    use kona_mpt::{NoopTrieHinter, NoopTrieProvider, TrieProvider};
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+    use revm::{
+        context::{BlockEnv, CfgEnv},
+        database::{InMemoryDB, State},
+        primitives::KECCAK_EMPTY,
+    };
 
     fn new_test_db() -> TrieDB<NoopTrieDBProvider, NoopTrieHinter> {
         TrieDB::new(Header::default().seal_slow(), NoopTrieDBProvider, NoopTrieHinter)
@@ -474,4 +666,497 @@ mod tests {
             b256!("78dec18c6d7da925bbe773c315653cdc70f6444ed6c1de9ac30bdb36cff74c3b")
         );
     }
+
+    /// Returns a chain of linked headers from genesis up to the given block number.
+    fn header_chain(tip: u64) -> Vec<Sealed<Header>> {
+        let mut chain: Vec<Sealed<Header>> = Vec::new();
+        for number in 0..=tip {
+            let parent_hash = chain.last().map(|h| h.hash()).unwrap_or_default();
+            chain.push(Header { number, parent_hash, ..Default::default() }.seal_slow());
+        }
+        chain
+    }
+
+    /// Returns the ancestors of the last header in the chain, walking back to genesis.
+    fn ancestors(chain: &[Sealed<Header>]) -> impl Iterator<Item = Header> + '_ {
+        chain[..chain.len() - 1].iter().rev().map(|h| h.inner().clone())
+    }
+
+    #[test]
+    fn test_preload_block_hashes() {
+        let chain = header_chain(10);
+        let mut db = TrieDB::new(chain[10].clone(), NoopTrieDBProvider, NoopTrieHinter);
+        db.preload_block_hashes(ancestors(&chain)).unwrap();
+
+        // The [NoopTrieDBProvider] serves default headers, so only preloaded hashes are correct.
+        for header in &chain {
+            assert_eq!(db.block_hash(header.number).unwrap(), header.hash());
+        }
+    }
+
+    #[test]
+    fn test_preload_block_hashes_unlinked_header() {
+        let chain = header_chain(10);
+        let mut db = TrieDB::new(chain[10].clone(), NoopTrieDBProvider, NoopTrieHinter);
+        let headers = ancestors(&chain).filter(|h| h.number != 7);
+        assert_eq!(db.preload_block_hashes(headers), Err(TrieDBError::UnlinkedHeader(6)));
+
+        // Hashes preceding the unlinked header remain preloaded.
+        assert_eq!(db.block_hash(8).unwrap(), chain[8].hash());
+        assert_ne!(db.block_hash(6).unwrap(), chain[6].hash());
+    }
+
+    #[test]
+    fn test_preloaded_block_hashes_retained_for_child_block() {
+        let chain = header_chain(11);
+        let mut db = TrieDB::new(chain[10].clone(), NoopTrieDBProvider, NoopTrieHinter);
+        db.preload_block_hashes(ancestors(&chain[..=10])).unwrap();
+
+        // Advancing to the child block retains the preloaded hashes.
+        db.set_parent_block_header(chain[11].clone());
+        for header in &chain {
+            assert_eq!(db.block_hash(header.number).unwrap(), header.hash());
+        }
+
+        // Switching to an unrelated parent block discards them.
+        db.set_parent_block_header(Header { number: 11, ..Default::default() }.seal_slow());
+        assert_ne!(db.block_hash(5).unwrap(), chain[5].hash());
+    }
+
+    #[test]
+    fn test_blockhash_opcode_reads_preloaded_ancestor() {
+        const BLOCKHASH_READER: Address = address!("0x00000000000000000000000000000000000b10c4");
+
+        let chain = header_chain(10);
+        let mut trie_db = TrieDB::new(chain[10].clone(), NoopTrieDBProvider, NoopTrieHinter);
+        trie_db.preload_block_hashes(ancestors(&chain)).unwrap();
+
+        // PUSH1 0x05 BLOCKHASH PUSH1 0x00 MSTORE PUSH1 0x20 PUSH1 0x00 RETURN
+        let code = bytes!("60054060005260206000f3");
+        let mut state = State::builder().with_database(trie_db).build();
+        state.insert_account(
+            BLOCKHASH_READER,
+            AccountInfo {
+                code_hash: keccak256(&code),
+                code: Some(Bytecode::new_raw(code)),
+                ..Default::default()
+            },
+        );
+
+        let block_env = BlockEnv { number: 11, ..Default::default() };
+        let mut evm =
+            OpEvmFactory::default().create_evm(&mut state, EvmEnv::new(CfgEnv::default(), block_env));
+        let result =
+            evm.transact_system_call(Address::ZERO, BLOCKHASH_READER, Bytes::new()).unwrap();
+        assert_eq!(result.result.output().map(|o| o.as_ref()), Some(chain[5].hash().as_slice()));
+    }
+
+    /// A [TrieDBProvider] that serves preimages from an in-memory fixture.
+    #[derive(Debug, Default)]
+    struct FixtureProvider {
+        nodes: HashMap<B256, TrieNode>,
+        code: HashMap<B256, Bytes>,
+        headers: HashMap<B256, Header>,
+        node_fetches: Cell<usize>,
+        /// Set by the [RoundTripHinter] when a hint is sent, and cleared by the next fetch.
+        hinted: Rc<Cell<bool>>,
+        /// The number of fetches that immediately followed a hint.
+        round_trips: Cell<usize>,
+    }
+
+    impl FixtureProvider {
+        /// Registers the preimages of all nodes within the given trie.
+        fn insert_trie(&mut self, node: &TrieNode) {
+            match node {
+                TrieNode::Empty | TrieNode::Blinded { .. } => return,
+                TrieNode::Extension { node: child, .. } => self.insert_trie(child),
+                TrieNode::Branch { stack } => {
+                    stack.iter().for_each(|child| self.insert_trie(child));
+                }
+                TrieNode::Leaf { .. } => {}
+            }
+            self.nodes.insert(node.blind(), node.clone());
+        }
+
+        /// Records a fetch, counting a round-trip if it is the first fetch after a hint.
+        fn record_fetch(&self) {
+            if self.hinted.replace(false) {
+                self.round_trips.set(self.round_trips.get() + 1);
+            }
+        }
+    }
+
+    impl TrieProvider for FixtureProvider {
+        type Error = String;
+
+        fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+            self.node_fetches.set(self.node_fetches.get() + 1);
+            self.record_fetch();
+            self.nodes.get(&key).cloned().ok_or_else(|| format!("Missing trie node {key}"))
+        }
+    }
+
+    impl TrieDBProvider for FixtureProvider {
+        fn bytecode_by_hash(&self, code_hash: B256) -> Result<Bytes, Self::Error> {
+            self.record_fetch();
+            self.code.get(&code_hash).cloned().ok_or_else(|| format!("Missing code {code_hash}"))
+        }
+
+        fn header_by_hash(&self, hash: B256) -> Result<Header, Self::Error> {
+            self.headers.get(&hash).cloned().ok_or_else(|| format!("Missing header {hash}"))
+        }
+    }
+
+    /// A [TrieHinter] that flags the [FixtureProvider] when a hint is sent, and counts the hints.
+    #[derive(Debug, Default)]
+    struct RoundTripHinter {
+        hinted: Rc<Cell<bool>>,
+        hints: Cell<usize>,
+    }
+
+    impl RoundTripHinter {
+        /// Records a hint.
+        fn record_hint(&self) {
+            self.hinted.set(true);
+            self.hints.set(self.hints.get() + 1);
+        }
+    }
+
+    impl TrieHinter for RoundTripHinter {
+        type Error = String;
+
+        fn hint_trie_node(&self, _hash: B256) -> Result<(), Self::Error> {
+            self.record_hint();
+            Ok(())
+        }
+
+        fn hint_account_proof(&self, _address: Address, _block: u64) -> Result<(), Self::Error> {
+            self.record_hint();
+            Ok(())
+        }
+
+        fn hint_storage_proof(
+            &self,
+            _address: Address,
+            _slot: U256,
+            _block: u64,
+        ) -> Result<(), Self::Error> {
+            self.record_hint();
+            Ok(())
+        }
+
+        fn hint_execution_witness(
+            &self,
+            _parent_hash: B256,
+            _op_payload_attributes: &OpPayloadAttributes,
+        ) -> Result<(), Self::Error> {
+            self.record_hint();
+            Ok(())
+        }
+    }
+
+    /// An account within the differential test fixture.
+    struct FixtureAccount {
+        address: Address,
+        balance: U256,
+        nonce: u64,
+        code: Option<Bytes>,
+        storage: Vec<(U256, U256)>,
+    }
+
+    const EOA: Address = address!("0000000000000000000000000000000000000001");
+    const CONTRACT: Address = address!("0000000000000000000000000000000000000002");
+    const EMPTY: Address = address!("0000000000000000000000000000000000000003");
+    const MISSING: Address = address!("0000000000000000000000000000000000000004");
+
+    fn fixture_accounts() -> Vec<FixtureAccount> {
+        vec![
+            FixtureAccount {
+                address: EOA,
+                balance: U256::from(1_000_000_000_000_000_000u128),
+                nonce: 7,
+                code: None,
+                storage: vec![],
+            },
+            FixtureAccount {
+                address: CONTRACT,
+                balance: U256::from(42),
+                nonce: 1,
+                code: Some(bytes!("6001600055")),
+                storage: vec![(U256::from(1), U256::from(0xdead)), (U256::from(2), U256::MAX)],
+            },
+            FixtureAccount {
+                address: EMPTY,
+                balance: U256::ZERO,
+                nonce: 0,
+                code: None,
+                storage: vec![],
+            },
+        ]
+    }
+
+    /// Builds the state trie for the fixture accounts on top of a two-block header chain, and
+    /// returns the parent header along with a provider for all preimages.
+    fn build_fixture(accounts: &[FixtureAccount]) -> (Sealed<Header>, FixtureProvider) {
+        let mut provider = FixtureProvider::default();
+        let mut root = TrieNode::Empty;
+
+        for account in accounts {
+            let mut storage_root = TrieNode::Empty;
+            for (slot, value) in &account.storage {
+                let path = Nibbles::unpack(keccak256(slot.to_be_bytes::<32>()));
+                storage_root
+                    .insert(&path, alloy_rlp::encode(value).into(), &NoopTrieProvider)
+                    .unwrap();
+            }
+            provider.insert_trie(&storage_root);
+
+            let code_hash = account.code.as_ref().map_or(KECCAK_EMPTY, keccak256);
+            if let Some(code) = &account.code {
+                provider.code.insert(code_hash, code.clone());
+            }
+
+            let trie_account = TrieAccount {
+                nonce: account.nonce,
+                balance: account.balance,
+                storage_root: storage_root.blind(),
+                code_hash,
+            };
+            let path = Nibbles::unpack(keccak256(account.address));
+            root.insert(&path, alloy_rlp::encode(trie_account).into(), &NoopTrieProvider).unwrap();
+        }
+        provider.insert_trie(&root);
+
+        let genesis = Header::default();
+        let parent = Header {
+            number: 1,
+            parent_hash: genesis.hash_slow(),
+            state_root: root.blind(),
+            ..Default::default()
+        };
+        provider.headers.insert(genesis.hash_slow(), genesis);
+
+        (parent.seal_slow(), provider)
+    }
+
+    /// Builds a reference [InMemoryDB] holding the plain fixture values.
+    fn reference_db(accounts: &[FixtureAccount]) -> InMemoryDB {
+        let mut db = InMemoryDB::default();
+        for account in accounts {
+            let code = account.code.clone().map(Bytecode::new_raw);
+            let info = AccountInfo {
+                balance: account.balance,
+                nonce: account.nonce,
+                code_hash: code.as_ref().map_or(KECCAK_EMPTY, |c| c.hash_slow()),
+                code,
+            };
+            db.insert_account_info(account.address, info);
+            for (slot, value) in &account.storage {
+                db.insert_account_storage(account.address, *slot, *value).unwrap();
+            }
+        }
+        db
+    }
+
+    /// The account fields that all state backends must agree on. Code is loaded lazily through
+    /// [Database::code_by_hash], so it is not compared here.
+    fn account_fields(info: Option<AccountInfo>) -> Option<(U256, u64, B256)> {
+        info.map(|info| (info.balance, info.nonce, info.code_hash))
+    }
+
+    #[test]
+    fn test_differential_trie_db_reads() {
+        let accounts = fixture_accounts();
+        let (parent, provider) = build_fixture(&accounts);
+        let genesis_hash = parent.parent_hash;
+        let parent_hash = parent.hash();
+
+        let mut trie_db = TrieDB::new(parent.clone(), provider, NoopTrieHinter);
+        let (_, state_provider) = build_fixture(&accounts);
+        let mut state = State::builder()
+            .with_database(TrieDB::new(parent, state_provider, NoopTrieHinter))
+            .build();
+        let mut reference = reference_db(&accounts);
+
+        // Account reads must agree for every account shape, including non-existent accounts.
+        for address in [EOA, CONTRACT, EMPTY, MISSING] {
+            let expected = account_fields(reference.basic(address).unwrap());
+            assert_eq!(account_fields(trie_db.basic(address).unwrap()), expected, "{address}");
+            assert_eq!(account_fields(state.basic(address).unwrap()), expected, "{address}");
+        }
+
+        // Storage reads must agree for both set and unset slots.
+        for address in [EOA, CONTRACT, EMPTY, MISSING] {
+            for slot in [U256::from(1), U256::from(2), U256::from(3)] {
+                let expected = reference.storage(address, slot).unwrap();
+                assert_eq!(trie_db.storage(address, slot).unwrap(), expected, "{address}");
+                assert_eq!(state.storage(address, slot).unwrap(), expected, "{address}");
+            }
+        }
+
+        // Code reads must agree.
+        let code_hash = keccak256(bytes!("6001600055"));
+        let expected = reference.code_by_hash(code_hash).unwrap();
+        assert_eq!(
+            trie_db.code_by_hash(code_hash).unwrap().original_bytes(),
+            expected.original_bytes()
+        );
+        assert_eq!(
+            state.code_by_hash(code_hash).unwrap().original_bytes(),
+            expected.original_bytes()
+        );
+
+        // Block hash reads must agree with the header chain.
+        for (number, expected) in [(0, genesis_hash), (1, parent_hash)] {
+            assert_eq!(trie_db.block_hash(number).unwrap(), expected);
+            assert_eq!(state.block_hash(number).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_storage_trie_cached_across_blocks() {
+        let accounts = fixture_accounts();
+        let (parent, provider) = build_fixture(&accounts);
+        let mut trie_db = TrieDB::new(parent.clone(), provider, NoopTrieHinter);
+
+        // Block 1: Read a storage slot of the contract, opening its storage trie.
+        trie_db.basic(CONTRACT).unwrap();
+        assert_eq!(trie_db.storage(CONTRACT, U256::from(1)).unwrap(), U256::from(0xdead));
+        trie_db.state_root(&BundleState::default()).unwrap();
+        let fetches = trie_db.fetcher.node_fetches.get();
+
+        // Block 2: The contract's storage root is unchanged, so the same read hits the cache.
+        let next = Header {
+            number: parent.number + 1,
+            parent_hash: parent.hash(),
+            state_root: parent.state_root,
+            ..Default::default()
+        };
+        trie_db.set_parent_block_header(next.seal_slow());
+        trie_db.basic(CONTRACT).unwrap();
+        assert_eq!(trie_db.storage(CONTRACT, U256::from(1)).unwrap(), U256::from(0xdead));
+        assert_eq!(trie_db.fetcher.node_fetches.get(), fetches);
+    }
+
+    #[test]
+    fn test_storage_trie_cache_invalidated_on_root_change() {
+        let accounts = fixture_accounts();
+        let (parent, provider) = build_fixture(&accounts);
+        let mut trie_db = TrieDB::new(parent, provider, NoopTrieHinter);
+
+        // Seed the cache with a storage trie whose root differs from the account's storage root.
+        let mut stale = TrieNode::Empty;
+        stale
+            .insert(&Nibbles::unpack(keccak256([0xFF; 32])), bytes!("01"), &NoopTrieProvider)
+            .unwrap();
+        trie_db.storage_root_hashes.insert(CONTRACT, stale.blind());
+        trie_db.storage_roots.insert(CONTRACT, stale);
+
+        // Loading the account must discard the stale trie in favor of the current storage root.
+        trie_db.basic(CONTRACT).unwrap();
+        let expected = trie_db.get_trie_account(&CONTRACT, 1).unwrap().unwrap().storage_root;
+        assert_eq!(trie_db.storage_roots()[&CONTRACT], TrieNode::new_blinded(expected));
+        assert_eq!(trie_db.storage(CONTRACT, U256::from(2)).unwrap(), U256::MAX);
+    }
+
+    #[test]
+    fn test_preload_accounts_batches_round_trips() {
+        let accounts = fixture_accounts();
+        let addresses = [EOA, CONTRACT, EMPTY, MISSING];
+
+        // Reads every account and the contract's code, as block execution would.
+        let execute = |preload: bool| {
+            let (parent, provider) = build_fixture(&accounts);
+            let hinter = RoundTripHinter { hinted: provider.hinted.clone(), ..Default::default() };
+            let mut trie_db = TrieDB::new(parent, provider, hinter);
+            if preload {
+                trie_db.preload_accounts(&addresses).unwrap();
+            }
+
+            let infos = addresses
+                .iter()
+                .map(|address| account_fields(trie_db.basic(*address).unwrap()))
+                .collect::<Vec<_>>();
+            let code = trie_db.code_by_hash(keccak256(bytes!("6001600055"))).unwrap();
+            let root = trie_db.state_root(&BundleState::default()).unwrap();
+            ((infos, code.original_bytes(), root), trie_db.fetcher.round_trips.get())
+        };
+
+        let (cold, cold_round_trips) = execute(false);
+        let (warm, warm_round_trips) = execute(true);
+
+        // Preloading must not change the result of execution.
+        assert_eq!(warm, cold);
+        assert!(warm_round_trips < cold_round_trips, "{warm_round_trips} >= {cold_round_trips}");
+    }
+
+    #[test]
+    fn test_preloaded_accounts_are_not_rehinted() {
+        let accounts = fixture_accounts();
+        let addresses = [EOA, CONTRACT, EMPTY, MISSING];
+        let (parent, provider) = build_fixture(&accounts);
+        let hinter = RoundTripHinter { hinted: provider.hinted.clone(), ..Default::default() };
+        let mut trie_db = TrieDB::new(parent, provider, hinter);
+
+        trie_db.preload_accounts(&addresses).unwrap();
+        let hints = trie_db.hinter.hints.get();
+        let fetches = trie_db.fetcher.node_fetches.get();
+        let round_trips = trie_db.fetcher.round_trips.get();
+        assert_eq!(hints, addresses.len());
+
+        // Reading the preloaded accounts and code neither hints nor fetches.
+        for address in addresses {
+            trie_db.basic(address).unwrap();
+        }
+        trie_db.code_by_hash(keccak256(bytes!("6001600055"))).unwrap();
+        assert_eq!(trie_db.hinter.hints.get(), hints);
+        assert_eq!(trie_db.fetcher.node_fetches.get(), fetches);
+        assert_eq!(trie_db.fetcher.round_trips.get(), round_trips);
+
+        // Once the trie is updated, the preloaded accounts are stale and are hinted again.
+        trie_db.state_root(&BundleState::default()).unwrap();
+        trie_db.basic(EOA).unwrap();
+        assert_eq!(trie_db.hinter.hints.get(), hints + 1);
+    }
+
+    #[test]
+    fn test_compute_root_and_discard_matches_state_root() {
+        let accounts = fixture_accounts();
+        let changeset = || {
+            let contract = AccountInfo {
+                balance: U256::from(43),
+                nonce: 2,
+                code_hash: keccak256(bytes!("6001600055")),
+                code: None,
+            };
+            let storage = HashMap::from_iter([
+                (U256::from(1), (U256::from(0xdead), U256::from(0xbeef))),
+                (U256::from(2), (U256::MAX, U256::ZERO)),
+                (U256::from(3), (U256::ZERO, U256::from(1))),
+            ]);
+            let created = AccountInfo { balance: U256::from(1), ..Default::default() };
+            BundleState::new(
+                [
+                    (CONTRACT, None, Some(contract), storage),
+                    (MISSING, None, Some(created), HashMap::default()),
+                ],
+                Vec::<Vec<(Address, Option<Option<AccountInfo>>, Vec<(U256, U256)>)>>::new(),
+                Vec::<(B256, Bytecode)>::new(),
+            )
+        };
+        let new_db = || {
+            let (parent, provider) = build_fixture(&accounts);
+            let mut trie_db = TrieDB::new(parent, provider, NoopTrieHinter);
+            trie_db.basic(CONTRACT).unwrap();
+            trie_db
+        };
+
+        let mut trie_db = new_db();
+        let parent_root = trie_db.parent_block_header().state_root;
+        let expected = trie_db.state_root(&changeset()).unwrap();
+        assert_ne!(expected, parent_root);
+
+        let root = new_db().compute_root_and_discard(&changeset()).unwrap();
+        assert_eq!(root, expected);
+    }
 }
@@ -72,6 +72,9 @@ pub enum TrieDBError {
     /// Trie provider error.
     #[error("Trie provider error: {0}")]
     Provider(String),
+    /// A preloaded header is not an ancestor of the parent block header.
+    #[error("Header #{0} is not an ancestor of the parent block header")]
+    UnlinkedHeader(u64),
 }
 
 impl DBErrorMarker for TrieDBError {}
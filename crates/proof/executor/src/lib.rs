@@ -16,7 +16,9 @@ mod db;
 pub use db::{NoopTrieDBProvider, TrieDB, TrieDBProvider};
 
 mod builder;
-pub use builder::{BlockBuildingOutcome, StatelessL2Builder};
+pub use builder::{
+    BlockBuildingOutcome, PrecompileOverrideFactory, StatelessL2Builder, TxExecutionOutcome,
+};
 
 mod errors;
 pub use errors::{ExecutorError, ExecutorResult, TrieDBError, TrieDBResult};
@@ -15,17 +15,93 @@ use kona_registry::ROLLUP_CONFIGS;
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use rocksdb::{DB, Options};
 use serde::{Deserialize, Serialize};
-use std::{path::PathBuf, sync::Arc};
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+use tempfile::TempDir;
 use tokio::{fs, runtime::Handle, sync::Mutex};
 
 /// Executes a [ExecutorTestFixture] stored at the passed `fixture_path` and asserts that the
 /// produced block hash matches the expected block hash.
 pub async fn run_test_fixture(fixture_path: PathBuf) {
+    let (_fixture_dir, kv_store, fixture) = load_test_fixture(&fixture_path).await;
+
+    let mut executor = StatelessL2Builder::new(
+        &fixture.rollup_config,
+        OpEvmFactory::default(),
+        DiskTrieNodeProvider::new(kv_store),
+        NoopTrieHinter,
+        fixture.parent_header.seal_slow(),
+    );
+
+    let outcome = executor.build_block(fixture.executing_payload).unwrap();
+
+    assert_eq!(
+        outcome.header.hash(),
+        fixture.expected_block_hash,
+        "Produced header does not match the expected header"
+    );
+}
+
+/// Executes a sequence of consecutive [ExecutorTestFixture]s stored at the passed
+/// `fixture_paths` with [StatelessL2Builder::build_blocks], and asserts that the produced headers
+/// match those produced by executing each fixture individually.
+pub async fn run_test_fixture_sequence(fixture_paths: &[PathBuf]) {
+    // Execute each fixture individually.
+    let mut expected_headers = Vec::with_capacity(fixture_paths.len());
+    for path in fixture_paths {
+        let (_fixture_dir, kv_store, fixture) = load_test_fixture(path).await;
+        let mut executor = StatelessL2Builder::new(
+            &fixture.rollup_config,
+            OpEvmFactory::default(),
+            DiskTrieNodeProvider::new(kv_store),
+            NoopTrieHinter,
+            fixture.parent_header.seal_slow(),
+        );
+        let outcome = executor.build_block(fixture.executing_payload).unwrap();
+        assert_eq!(outcome.header.hash(), fixture.expected_block_hash);
+        expected_headers.push(outcome.header);
+    }
+
+    // Execute the whole sequence on top of the first fixture's parent, reading from the union of
+    // the fixtures' key-value stores.
+    let mut fixture_dirs = Vec::with_capacity(fixture_paths.len());
+    let mut kv_stores = Vec::with_capacity(fixture_paths.len());
+    let mut fixtures = Vec::with_capacity(fixture_paths.len());
+    for path in fixture_paths {
+        let (fixture_dir, kv_store, fixture) = load_test_fixture(path).await;
+        fixture_dirs.push(fixture_dir);
+        kv_stores.push(kv_store);
+        fixtures.push(fixture);
+    }
+
+    let first = fixtures.first().expect("No fixtures provided");
+    let mut executor = StatelessL2Builder::new(
+        &first.rollup_config,
+        OpEvmFactory::default(),
+        DiskTrieNodeProvider::new_layered(kv_stores),
+        NoopTrieHinter,
+        first.parent_header.clone().seal_slow(),
+    );
+    let headers = executor
+        .build_blocks(fixtures.iter().map(|fixture| fixture.executing_payload.clone()))
+        .unwrap();
+
+    assert_eq!(
+        headers, expected_headers,
+        "Produced headers do not match the individually executed headers"
+    );
+}
+
+/// Untars the [ExecutorTestFixture] stored at the passed `fixture_path`, returning the temporary
+/// directory it was extracted to, its key-value store, and the fixture itself.
+pub(crate) async fn load_test_fixture(fixture_path: &Path) -> (TempDir, DB, ExecutorTestFixture) {
     // First, untar the fixture.
     let fixture_dir = tempfile::tempdir().expect("Failed to create temporary directory");
     tokio::process::Command::new("tar")
         .arg("-xvf")
-        .arg(fixture_path.as_path())
+        .arg(fixture_path)
         .arg("-C")
         .arg(fixture_dir.path())
         .arg("--strip-components=1")
@@ -38,26 +114,11 @@ pub async fn run_test_fixture(fixture_path: PathBuf) {
     options.create_if_missing(true);
     let kv_store = DB::open(&options, fixture_dir.path().join("kv"))
         .unwrap_or_else(|e| panic!("Failed to open database at {fixture_dir:?}: {e}"));
-    let provider = DiskTrieNodeProvider::new(kv_store);
     let fixture: ExecutorTestFixture =
         serde_json::from_slice(&fs::read(fixture_dir.path().join("fixture.json")).await.unwrap())
             .expect("Failed to deserialize fixture");
 
-    let mut executor = StatelessL2Builder::new(
-        &fixture.rollup_config,
-        OpEvmFactory::default(),
-        provider,
-        NoopTrieHinter,
-        fixture.parent_header.seal_slow(),
-    );
-
-    let outcome = executor.build_block(fixture.executing_payload).unwrap();
-
-    assert_eq!(
-        outcome.header.hash(),
-        fixture.expected_block_hash,
-        "Produced header does not match the expected header"
-    );
+    (fixture_dir, kv_store, fixture)
 }
 
 /// The test fixture format for the [`StatelessL2Builder`].
@@ -299,16 +360,34 @@ impl TrieDBProvider for ExecutorTestFixtureCreator {
     }
 }
 
-/// A simple [`TrieDBProvider`] that reads data from a disk-based key-value store.
+/// A simple [`TrieDBProvider`] that reads data from one or more disk-based key-value stores.
 #[derive(Debug)]
 pub struct DiskTrieNodeProvider {
-    kv_store: DB,
+    kv_stores: Vec<DB>,
 }
 
 impl DiskTrieNodeProvider {
     /// Creates a new [`DiskTrieNodeProvider`] with the given [`rocksdb`] K/V store.
-    pub const fn new(kv_store: DB) -> Self {
-        Self { kv_store }
+    pub fn new(kv_store: DB) -> Self {
+        Self { kv_stores: vec![kv_store] }
+    }
+
+    /// Creates a new [`DiskTrieNodeProvider`] that reads from the first of the given [`rocksdb`]
+    /// K/V stores that contains a key.
+    pub const fn new_layered(kv_stores: Vec<DB>) -> Self {
+        Self { kv_stores }
+    }
+
+    /// Fetches the value of the given key from the first K/V store that contains it.
+    fn get(&self, key: B256) -> Result<Vec<u8>, TestTrieNodeProviderError> {
+        for kv_store in &self.kv_stores {
+            if let Some(value) =
+                kv_store.get(key).map_err(|_| TestTrieNodeProviderError::PreimageNotFound)?
+            {
+                return Ok(value);
+            }
+        }
+        Err(TestTrieNodeProviderError::PreimageNotFound)
     }
 }
 
@@ -316,37 +395,17 @@ impl TrieProvider for DiskTrieNodeProvider {
     type Error = TestTrieNodeProviderError;
 
     fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
-        TrieNode::decode(
-            &mut self
-                .kv_store
-                .get(key)
-                .map_err(|_| TestTrieNodeProviderError::PreimageNotFound)?
-                .ok_or(TestTrieNodeProviderError::PreimageNotFound)?
-                .as_slice(),
-        )
-        .map_err(TestTrieNodeProviderError::Rlp)
+        TrieNode::decode(&mut self.get(key)?.as_slice()).map_err(TestTrieNodeProviderError::Rlp)
     }
 }
 
 impl TrieDBProvider for DiskTrieNodeProvider {
     fn bytecode_by_hash(&self, code_hash: B256) -> Result<Bytes, Self::Error> {
-        self.kv_store
-            .get(code_hash)
-            .map_err(|_| TestTrieNodeProviderError::PreimageNotFound)?
-            .map(Bytes::from)
-            .ok_or(TestTrieNodeProviderError::PreimageNotFound)
+        self.get(code_hash).map(Bytes::from)
     }
 
     fn header_by_hash(&self, hash: B256) -> Result<Header, Self::Error> {
-        Header::decode(
-            &mut self
-                .kv_store
-                .get(hash)
-                .map_err(|_| TestTrieNodeProviderError::PreimageNotFound)?
-                .ok_or(TestTrieNodeProviderError::PreimageNotFound)?
-                .as_slice(),
-        )
-        .map_err(TestTrieNodeProviderError::Rlp)
+        Header::decode(&mut self.get(hash)?.as_slice()).map_err(TestTrieNodeProviderError::Rlp)
     }
 }
 
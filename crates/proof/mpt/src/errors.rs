@@ -1,6 +1,7 @@
 //! Errors for the `kona-derive` crate.
 
 use alloc::string::String;
+use alloy_primitives::Bytes;
 use thiserror::Error;
 
 /// A [Result] type alias where the error is [TrieNodeError].
@@ -40,3 +41,24 @@ pub enum OrderedListWalkerError {
     #[error("{0}")]
     TrieNode(#[from] TrieNodeError),
 }
+
+/// A [Result] type alias where the error is [ProofError].
+pub type ProofResult<T> = Result<T, ProofError>;
+
+/// An error type for [verify_proof].
+///
+/// [verify_proof]: crate::verify_proof
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ProofError {
+    /// A node on the path is missing from the proof, or is invalid.
+    #[error("{0}")]
+    TrieNode(#[from] TrieNodeError),
+    /// The value at the key does not match the expected value.
+    #[error("Proof value mismatch: expected {expected:?}, got {actual:?}")]
+    ValueMismatch {
+        /// The expected value, or [None] if the key was expected to be absent.
+        expected: Option<Bytes>,
+        /// The value at the key, or [None] if the key is absent.
+        actual: Option<Bytes>,
+    },
+}
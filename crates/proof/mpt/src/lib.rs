@@ -10,20 +10,26 @@
 extern crate alloc;
 
 mod errors;
-pub use errors::{OrderedListWalkerError, OrderedListWalkerResult, TrieNodeError, TrieNodeResult};
+pub use errors::{
+    OrderedListWalkerError, OrderedListWalkerResult, ProofError, ProofResult, TrieNodeError,
+    TrieNodeResult,
+};
 
 mod traits;
 pub use traits::{TrieHinter, TrieProvider};
 
 mod node;
-pub use node::TrieNode;
+pub use node::{MAX_UNBLIND_SUBTREE_DEPTH, TrieNode};
 
 mod list_walker;
-pub use list_walker::OrderedListWalker;
+pub use list_walker::{OrderedListWalker, ReverseOrderedListWalker};
 
 mod noop;
 pub use noop::{NoopTrieHinter, NoopTrieProvider};
 
+mod proof;
+pub use proof::verify_proof;
+
 mod util;
 pub use util::ordered_trie_with_encoder;
 
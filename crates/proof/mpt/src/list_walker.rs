@@ -5,7 +5,7 @@ use crate::{
     TrieNode, TrieNodeError, TrieProvider,
     errors::{OrderedListWalkerError, OrderedListWalkerResult},
 };
-use alloc::{collections::VecDeque, string::ToString, vec};
+use alloc::{collections::VecDeque, string::ToString, vec, vec::Vec};
 use alloy_primitives::{B256, Bytes};
 use alloy_rlp::EMPTY_STRING_CODE;
 use core::marker::PhantomData;
@@ -14,7 +14,14 @@ use core::marker::PhantomData;
 /// ordered list.
 ///
 /// Once it has ben hydrated with [Self::hydrate], the elements in the derivable list can be
-/// iterated over using the [Iterator] implementation.
+/// iterated over using the [Iterator] implementation. The [DoubleEndedIterator] implementation
+/// allows for iterating over the elements from the highest index downward, e.g. with
+/// [Iterator::rev]. The [ExactSizeIterator] implementation reports the number of elements that
+/// have yet to be yielded, allowing collections to be pre-sized.
+///
+/// Hydration fetches every node in the trie. To walk the list from the highest index downward
+/// without fetching the nodes of elements that are never reached, use a
+/// [ReverseOrderedListWalker] instead.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct OrderedListWalker<F: TrieProvider> {
     /// The Merkle Patricia Trie root.
@@ -157,6 +164,108 @@ where
             _ => None,
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.inner.as_ref().map_or(0, VecDeque::len);
+        (len, Some(len))
+    }
+}
+
+impl<F: TrieProvider> ExactSizeIterator for OrderedListWalker<F> {}
+
+impl<F> DoubleEndedIterator for OrderedListWalker<F>
+where
+    F: TrieProvider,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self.inner {
+            Some(ref mut leaves) => {
+                let item = leaves.pop_back();
+                if leaves.is_empty() {
+                    self.inner = None;
+                }
+                item
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A [ReverseOrderedListWalker] lazily traverses a Merkle Patricia Trie containing a derivable
+/// ordered list from the highest index downward.
+///
+/// Unlike [OrderedListWalker], the trie is not hydrated up front. Each call to [Iterator::next]
+/// only fetches the nodes on the path to the next element, so taking the last `n` elements of a
+/// list does not fetch the nodes of the elements before them.
+#[derive(Debug)]
+pub struct ReverseOrderedListWalker<'a, F: TrieProvider> {
+    /// The fetcher used to unblind nodes as they are reached.
+    fetcher: &'a F,
+    /// The subtries yet to be traversed, paired with their paths, in ascending key order.
+    pending: Vec<(Vec<u8>, TrieNode)>,
+    /// The element at index 0, held back until every other element has been yielded.
+    first: Option<(Bytes, Bytes)>,
+}
+
+impl<'a, F> ReverseOrderedListWalker<'a, F>
+where
+    F: TrieProvider,
+{
+    /// The nibbles of the key of the element at index 0, which is RLP encoded as the empty string.
+    const FIRST_KEY: [u8; 2] = [EMPTY_STRING_CODE >> 4, EMPTY_STRING_CODE & 0x0F];
+
+    /// Creates a new [ReverseOrderedListWalker] over the list committed to by `root`.
+    pub fn new(root: B256, fetcher: &'a F) -> Self {
+        Self {
+            fetcher,
+            pending: vec![(Vec::new(), TrieNode::Blinded { commitment: root })],
+            first: None,
+        }
+    }
+}
+
+impl<F> Iterator for ReverseOrderedListWalker<'_, F>
+where
+    F: TrieProvider,
+{
+    type Item = OrderedListWalkerResult<(Bytes, Bytes)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Keys are ordered by their RLP encoding, so the element at index 0 (keyed by 0x80) sits
+        // between indices 0x7F and 0x80. It is held back when reached, and yielded last.
+        while let Some((path, mut node)) = self.pending.pop() {
+            match node {
+                TrieNode::Blinded { .. } => {
+                    if let Err(e) = node.unblind(self.fetcher) {
+                        self.pending.clear();
+                        self.first = None;
+                        return Some(Err(e.into()));
+                    }
+                    self.pending.push((path, node));
+                }
+                TrieNode::Branch { stack } => {
+                    for (nibble, child) in stack.into_iter().enumerate() {
+                        if !matches!(child, TrieNode::Empty) {
+                            self.pending.push(([path.as_slice(), &[nibble as u8]].concat(), child));
+                        }
+                    }
+                }
+                TrieNode::Extension { prefix, node } => {
+                    self.pending.push(([path.as_slice(), prefix.as_slice()].concat(), *node));
+                }
+                TrieNode::Leaf { prefix, value } => {
+                    let item = (prefix.to_vec().into(), value);
+                    if [path.as_slice(), prefix.as_slice()].concat() == Self::FIRST_KEY {
+                        self.first = Some(item);
+                    } else {
+                        return Some(Ok(item));
+                    }
+                }
+                TrieNode::Empty => { /* Skip over empty nodes, we're looking for values. */ }
+            }
+        }
+        self.first.take().map(Ok)
+    }
 }
 
 #[cfg(test)]
@@ -170,10 +279,37 @@ mod test {
         },
     };
     use alloc::{collections::BTreeMap, string::String, vec::Vec};
-    use alloy_consensus::{ReceiptEnvelope, TxEnvelope};
+    use alloy_consensus::{Receipt, ReceiptEnvelope, ReceiptWithBloom, TxEnvelope};
     use alloy_primitives::keccak256;
-    use alloy_provider::network::eip2718::Decodable2718;
+    use alloy_provider::network::eip2718::{Decodable2718, Encodable2718};
     use alloy_rlp::{Decodable, Encodable};
+    use core::cell::Cell;
+
+    /// A [TrieProvider] that counts the trie nodes fetched through it.
+    struct CountingTrieNodeProvider {
+        inner: TrieNodeProvider,
+        fetches: Cell<usize>,
+    }
+
+    impl CountingTrieNodeProvider {
+        const fn new(inner: TrieNodeProvider) -> Self {
+            Self { inner, fetches: Cell::new(0) }
+        }
+
+        /// Returns the number of nodes fetched since the last call, resetting the count.
+        fn take_fetches(&self) -> usize {
+            self.fetches.take()
+        }
+    }
+
+    impl TrieProvider for CountingTrieNodeProvider {
+        type Error = <TrieNodeProvider as TrieProvider>::Error;
+
+        fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+            self.fetches.set(self.fetches.get() + 1);
+            self.inner.trie_node_by_hash(key)
+        }
+    }
 
     #[tokio::test]
     async fn test_online_list_walker_receipts() {
@@ -203,6 +339,35 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn test_online_list_walker_transactions_rev() {
+        let (root, preimages, envelopes) = get_live_derivable_transactions_list().await.unwrap();
+        let fetcher = CountingTrieNodeProvider::new(TrieNodeProvider::new(preimages));
+
+        let forward =
+            OrderedListWalker::try_new_hydrated(root, &fetcher).unwrap().collect::<Vec<_>>();
+        let hydrated_fetches = fetcher.take_fetches();
+        let mut reverse = ReverseOrderedListWalker::new(root, &fetcher)
+            .collect::<OrderedListWalkerResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(fetcher.take_fetches(), hydrated_fetches);
+
+        reverse.reverse();
+        assert_eq!(reverse, forward);
+        assert_eq!(
+            forward
+                .into_iter()
+                .map(|(_, rlp)| TxEnvelope::decode(&mut rlp.as_ref()).unwrap())
+                .collect::<Vec<_>>(),
+            envelopes
+        );
+
+        // Taking the last element only fetches the nodes on the path to it.
+        let (_, last) = ReverseOrderedListWalker::new(root, &fetcher).next().unwrap().unwrap();
+        assert_eq!(TxEnvelope::decode(&mut last.as_ref()).unwrap(), *envelopes.last().unwrap());
+        assert!(fetcher.take_fetches() < hydrated_fetches);
+    }
+
     #[test]
     fn test_list_walker() {
         const VALUES: [&str; 3] = ["test one", "test two", "test three"];
@@ -239,4 +404,69 @@ mod test {
                 .is_empty()
         );
     }
+
+    #[test]
+    fn test_list_walker_mixed_ends() {
+        const VALUES: [&str; 4] = ["one", "two", "three", "four"];
+        let mut trie = ordered_trie_with_encoder(&VALUES, |v, buf| v.encode(buf));
+        let root = trie.root();
+        let preimages = trie
+            .take_proof_nodes()
+            .into_inner()
+            .into_values()
+            .map(|value| (keccak256(value.as_ref()), value))
+            .collect();
+        let fetcher = TrieNodeProvider::new(preimages);
+
+        let mut list = OrderedListWalker::try_new_hydrated(root, &fetcher)
+            .unwrap()
+            .map(|(_, v)| String::decode(&mut v.as_ref()).unwrap());
+        assert_eq!(list.next_back().as_deref(), Some("four"));
+        assert_eq!(list.next().as_deref(), Some("one"));
+        assert_eq!(list.next_back().as_deref(), Some("three"));
+        assert_eq!(list.next().as_deref(), Some("two"));
+        assert_eq!(list.next_back(), None);
+    }
+
+    #[test]
+    fn test_list_walker_len() {
+        let receipts = (0..200u64)
+            .map(|i| {
+                ReceiptEnvelope::Eip1559(ReceiptWithBloom {
+                    receipt: Receipt {
+                        status: true.into(),
+                        cumulative_gas_used: 21_000 * (i + 1),
+                        logs: Vec::new(),
+                    },
+                    logs_bloom: Default::default(),
+                })
+            })
+            .collect::<Vec<ReceiptEnvelope>>();
+        let mut trie = ordered_trie_with_encoder(&receipts, |r, buf| r.encode_2718(buf));
+        let root = trie.root();
+        let preimages = trie
+            .take_proof_nodes()
+            .into_inner()
+            .into_values()
+            .map(|value| (keccak256(value.as_ref()), value))
+            .collect();
+        let fetcher = TrieNodeProvider::new(preimages);
+
+        // The list is empty until it is hydrated.
+        assert_eq!(OrderedListWalker::<TrieNodeProvider>::new(root).len(), 0);
+
+        let list = OrderedListWalker::try_new_hydrated(root, &fetcher).unwrap();
+        let len = list.len();
+        let yielded = list
+            .map(|(_, rlp)| ReceiptEnvelope::decode_2718(&mut rlp.as_ref()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(len, yielded.len());
+        assert_eq!(yielded, receipts);
+
+        // The length shrinks as elements are yielded from either end.
+        let mut list = OrderedListWalker::try_new_hydrated(root, &fetcher).unwrap();
+        list.next();
+        list.next_back();
+        assert_eq!(list.len(), receipts.len() - 2);
+    }
 }
@@ -35,6 +35,10 @@ const PREFIX_LEAF_ODD: u8 = 3;
 /// Nibble bit width.
 const NIBBLE_WIDTH: usize = 4;
 
+/// The maximum depth that [TrieNode::unblind_subtree] will descend to. Each level of a branch-heavy
+/// trie can fan out 16x, so deeper prefetches are clamped to this bound.
+pub const MAX_UNBLIND_SUBTREE_DEPTH: usize = 3;
+
 /// A [TrieNode] is a node within a standard Ethereum Merkle Patricia Trie. In this implementation,
 /// keys are expected to be fixed-size nibble sequences, and values are arbitrary byte sequences.
 ///
@@ -56,12 +60,29 @@ const NIBBLE_WIDTH: usize = 4;
 /// implementation of these traits will implicitly blind nodes that are longer than 32 bytes in
 /// length when encoding. When decoding, the implementation will leave blinded nodes in place.
 ///
+/// ## Serialization
+/// With the `serde` feature enabled, [TrieNode]s are serialized in a stable, explicitly tagged
+/// format so that serialized witnesses remain portable across releases. The current format
+/// version is `1`:
+/// - Every node is an object with a `type` tag of `empty`, `blinded`, `leaf`, `extension`, or
+///   `branch`.
+/// - `commitment` and `value` fields are `0x`-prefixed hex strings.
+/// - `prefix` fields are unprefixed hex strings with one character per nibble, so that odd-length
+///   paths are preserved.
+///
+/// Any change to this format must bump the version and keep the existing fixtures decodable.
+///
 /// ## SAFETY
 /// As this implementation only supports uniform key sizes, the [TrieNode] data structure will fail
 /// to behave correctly if confronted with keys of varying lengths. Namely, this is because it does
 /// not support the `value` field in branch nodes, just like the Ethereum Merkle Patricia Trie.
-#[derive(Debug, Clone, Eq, PartialEq)]
+///
+/// ## Ordering
+/// [TrieNode]s are totally ordered by variant (in declaration order), and then by their fields, so
+/// that they may be stored in ordered collections such as a `BTreeSet`.
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "lowercase"))]
 pub enum TrieNode {
     /// An empty [TrieNode] is represented as an [EMPTY_STRING_CODE] (0x80).
     Empty,
@@ -73,6 +94,7 @@ pub enum TrieNode {
     /// A leaf node is a 2-item node with the encoding `rlp([encoded_path, value])`
     Leaf {
         /// The key of the leaf node
+        #[cfg_attr(feature = "serde", serde(with = "serde_nibbles"))]
         prefix: Nibbles,
         /// The value of the leaf node
         value: Bytes,
@@ -80,6 +102,7 @@ pub enum TrieNode {
     /// An extension node is a 2-item pointer node with the encoding `rlp([encoded_path, key])`
     Extension {
         /// The path prefix of the extension
+        #[cfg_attr(feature = "serde", serde(with = "serde_nibbles"))]
         prefix: Nibbles,
         /// The pointer to the child node
         node: Box<TrieNode>,
@@ -118,6 +141,75 @@ impl TrieNode {
         }
     }
 
+    /// Returns the commitment of the [TrieNode], equal to that of [TrieNode::blind].
+    ///
+    /// With the `rayon` feature enabled, the commitments of independent branch children are
+    /// computed in parallel on the global rayon thread pool. Otherwise, this falls back to
+    /// [TrieNode::blind]. The trie itself is left open.
+    pub fn blind_parallel(&self) -> B256 {
+        #[cfg(feature = "rayon")]
+        {
+            self.committed_children().blind()
+        }
+        #[cfg(not(feature = "rayon"))]
+        {
+            self.blind()
+        }
+    }
+
+    /// Returns a shallow copy of the [TrieNode], with each child replaced by the node that its
+    /// parent's encoding references: its commitment, or the child itself if it is inlined. The
+    /// children of branch nodes are processed in parallel.
+    #[cfg(feature = "rayon")]
+    fn committed_children(&self) -> Self {
+        use rayon::prelude::*;
+
+        match self {
+            Self::Branch { stack } => {
+                Self::Branch { stack: stack.par_iter().map(Self::committed_reference).collect() }
+            }
+            Self::Extension { prefix, node } => Self::Extension {
+                prefix: prefix.clone(),
+                node: Box::new(node.committed_reference()),
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Returns the node that a parent's encoding references for the [TrieNode]. Nodes whose
+    /// encoding is shorter than a [B256] are inlined, and all others are blinded.
+    #[cfg(feature = "rayon")]
+    fn committed_reference(&self) -> Self {
+        let node = self.committed_children();
+        if node.length() >= B256::ZERO.len() { Self::new_blinded(node.blind()) } else { node }
+    }
+
+    /// Consumes the [TrieNode], returning its commitment. Unlike [TrieNode::blind], subtrees are
+    /// re-blinded bottom-up as the trie is folded, so that opened nodes are released as soon as
+    /// they have been hashed rather than once the whole commitment has been computed.
+    pub fn into_commitment(mut self) -> B256 {
+        self.blind_children();
+        self.blind()
+    }
+
+    /// Re-blinds the children of the [TrieNode] that are referenced by their commitment, in
+    /// post-order. Children that are shorter than a [B256] are inlined within their parent's
+    /// encoding, and are left open.
+    fn blind_children(&mut self) {
+        let children = match self {
+            Self::Extension { node, .. } => core::slice::from_mut(node.as_mut()),
+            Self::Branch { stack } => stack.as_mut_slice(),
+            _ => return,
+        };
+
+        for child in children {
+            child.blind_children();
+            if child.length() >= B256::ZERO.len() {
+                *child = Self::new_blinded(child.blind());
+            }
+        }
+    }
+
     /// Unblinds the [TrieNode] if it is a [TrieNode::Blinded] node.
     pub fn unblind<F: TrieProvider>(&mut self, fetcher: &F) -> TrieNodeResult<()> {
         if let Self::Blinded { commitment } = self {
@@ -134,6 +226,65 @@ impl TrieNode {
         Ok(())
     }
 
+    /// Unblinds the [TrieNode] and its descendants up to `depth` levels below it.
+    ///
+    /// The subtree is unblinded level by level. Before any node within a level is fetched, hints
+    /// are sent for every blinded node within that level, allowing the host to prefetch the
+    /// preimages in a single batch rather than one round-trip per node.
+    ///
+    /// A `depth` of `0` is equivalent to [TrieNode::unblind]. `depth` is clamped to
+    /// [MAX_UNBLIND_SUBTREE_DEPTH].
+    ///
+    /// ## Takes
+    /// - `self` - The root of the subtree to unblind
+    /// - `depth` - The number of levels below `self` to unblind
+    /// - `fetcher` - The preimage fetcher for blinded nodes
+    /// - `hinter` - The hinter for blinded nodes
+    ///
+    /// ## Returns
+    /// - `Ok(())` - The subtree was successfully unblinded
+    /// - `Err(_)` - Could not unblind a node within the subtree
+    pub fn unblind_subtree<F: TrieProvider, H: TrieHinter>(
+        &mut self,
+        depth: usize,
+        fetcher: &F,
+        hinter: &H,
+    ) -> TrieNodeResult<()> {
+        let depth = depth.min(MAX_UNBLIND_SUBTREE_DEPTH);
+
+        let mut level: Vec<&mut Self> = vec![self];
+        for current_depth in 0..=depth {
+            // Hint all blinded nodes within the level before fetching any of them.
+            for node in level.iter() {
+                if let Self::Blinded { commitment } = node {
+                    if *commitment != EMPTY_ROOT_HASH {
+                        hinter
+                            .hint_trie_node(*commitment)
+                            .map_err(|e| TrieNodeError::Provider(e.to_string()))?;
+                    }
+                }
+            }
+            for node in level.iter_mut() {
+                node.unblind(fetcher)?;
+            }
+
+            if current_depth == depth {
+                break;
+            }
+            level = level.into_iter().flat_map(Self::children_mut).collect();
+        }
+        Ok(())
+    }
+
+    /// Returns mutable references to the direct children of the [TrieNode].
+    fn children_mut(&mut self) -> Vec<&mut Self> {
+        match self {
+            Self::Branch { stack } => stack.iter_mut().collect(),
+            Self::Extension { node, .. } => vec![node.as_mut()],
+            _ => Vec::new(),
+        }
+    }
+
     /// Walks down the trie to a leaf value with the given key, if it exists. Preimages for blinded
     /// nodes along the path are fetched using the `fetcher` function, and persisted in the inner
     /// [TrieNode] elements.
@@ -180,6 +331,176 @@ impl TrieNode {
         }
     }
 
+    /// Walks down the trie to a leaf value with the given key, if it exists, without mutating the
+    /// trie. Preimages for blinded nodes along the path are fetched using the `fetcher` function,
+    /// but unlike [TrieNode::open], they are discarded once the walk has completed.
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `path` - The nibbles representation of the path to the leaf node
+    /// - `fetcher` - The preimage fetcher for intermediate blinded nodes
+    ///
+    /// ## Returns
+    /// - `Err(_)` - Could not retrieve the node with the given key from the trie.
+    /// - `Ok(None)` - The node with the given key does not exist in the trie.
+    /// - `Ok(Some(_))` - The value of the node
+    pub fn get<F: TrieProvider>(
+        &self,
+        path: &Nibbles,
+        fetcher: &F,
+    ) -> TrieNodeResult<Option<Bytes>> {
+        match self {
+            Self::Branch { stack } => {
+                let branch_nibble = path[0] as usize;
+                stack
+                    .get(branch_nibble)
+                    .map(|node| node.get(&path.slice(BRANCH_NODE_NIBBLES..), fetcher))
+                    .unwrap_or(Ok(None))
+            }
+            Self::Leaf { prefix, value } => {
+                Ok((path.as_slice() == prefix.as_slice()).then(|| value.clone()))
+            }
+            Self::Extension { prefix, node } => {
+                if path.slice(..prefix.len()).as_slice() == prefix.as_slice() {
+                    // Follow extension branch
+                    node.get(&path.slice(prefix.len()..), fetcher)
+                } else {
+                    Ok(None)
+                }
+            }
+            Self::Blinded { .. } => {
+                let mut node = self.clone();
+                node.unblind(fetcher)?;
+                node.get(path, fetcher)
+            }
+            Self::Empty => Ok(None),
+        }
+    }
+
+    /// Returns every leaf key and value in the subtree whose keys begin with `prefix`, in key
+    /// order. Blinded nodes under the prefix are unblinded using the `fetcher`.
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `prefix` - The nibbles representation of the key prefix to enumerate
+    /// - `fetcher` - The preimage fetcher for blinded nodes
+    ///
+    /// ## Returns
+    /// - `Err(_)` - Could not unblind a node under the prefix
+    /// - `Ok(_)` - The full keys and values of all leaves under the prefix
+    pub fn entries_with_prefix<F: TrieProvider>(
+        &mut self,
+        prefix: &Nibbles,
+        fetcher: &F,
+    ) -> TrieNodeResult<Vec<(Nibbles, Bytes)>> {
+        let mut entries = Vec::new();
+        self.collect_entries(Nibbles::default(), prefix, fetcher, &mut entries)?;
+        Ok(entries)
+    }
+
+    /// Appends the leaves below `self` whose keys begin with `prefix` to `entries`. `path` is the
+    /// key of `self`, and `prefix` is the remainder of the prefix that is yet to be matched.
+    fn collect_entries<F: TrieProvider>(
+        &mut self,
+        path: Nibbles,
+        prefix: &Nibbles,
+        fetcher: &F,
+        entries: &mut Vec<(Nibbles, Bytes)>,
+    ) -> TrieNodeResult<()> {
+        self.unblind(fetcher)?;
+        match self {
+            Self::Branch { stack } => {
+                for (nibble, child) in stack.iter_mut().take(BRANCH_LIST_LENGTH - 1).enumerate() {
+                    let nibble = nibble as u8;
+                    if prefix.as_slice().first().is_some_and(|&n| n != nibble) {
+                        continue;
+                    }
+
+                    let child_path =
+                        Nibbles::from_nibbles_unchecked([path.as_slice(), &[nibble]].concat());
+                    let remaining = prefix.slice(prefix.len().min(BRANCH_NODE_NIBBLES)..);
+                    child.collect_entries(child_path, &remaining, fetcher, entries)?;
+                }
+            }
+            Self::Leaf { prefix: key, value } => {
+                if key.as_slice().starts_with(prefix.as_slice()) {
+                    let key = [path.as_slice(), key.as_slice()].concat();
+                    entries.push((Nibbles::from_nibbles_unchecked(key), value.clone()));
+                }
+            }
+            Self::Extension { prefix: key, node } => {
+                let shared = prefix.len().min(key.len());
+                if key.slice(..shared).as_slice() == prefix.slice(..shared).as_slice() {
+                    let path = [path.as_slice(), key.as_slice()].concat();
+                    let path = Nibbles::from_nibbles_unchecked(path);
+                    node.collect_entries(path, &prefix.slice(shared..), fetcher, entries)?;
+                }
+            }
+            Self::Blinded { .. } | Self::Empty => {}
+        }
+        Ok(())
+    }
+
+    /// Returns the Merkle proof for the given path, unblinding the nodes along it.
+    ///
+    /// The proof consists of the RLP encodings of the nodes along the path, from the root to the
+    /// leaf, in the same format as the `accountProof` and `storageProof` fields of `eth_getProof`.
+    /// Nodes that are shorter than a [B256] are inlined within their parent's encoding, and are not
+    /// included on their own. If the path does not exist within the trie, the proof ends at the
+    /// node where the path diverges, proving its exclusion.
+    ///
+    /// ## Takes
+    /// - `self` - The root trie node
+    /// - `path` - The nibbles representation of the path to prove
+    /// - `fetcher` - The preimage fetcher for intermediate blinded nodes
+    ///
+    /// ## Returns
+    /// - `Err(_)` - Could not unblind a node along the path
+    /// - `Ok(_)` - The encoded proof nodes, from the root to the leaf
+    pub fn proof<F: TrieProvider>(
+        &mut self,
+        path: &Nibbles,
+        fetcher: &F,
+    ) -> TrieNodeResult<Vec<Bytes>> {
+        let mut proof = Vec::new();
+        self.collect_proof(path, fetcher, true, &mut proof)?;
+        Ok(proof)
+    }
+
+    /// Appends the encodings of the nodes along `path`, starting at `self`, to `proof`.
+    fn collect_proof<F: TrieProvider>(
+        &mut self,
+        path: &Nibbles,
+        fetcher: &F,
+        is_root: bool,
+        proof: &mut Vec<Bytes>,
+    ) -> TrieNodeResult<()> {
+        // Only the root and nodes referenced by their commitment are part of the proof.
+        let referenced = is_root || self.length() >= B256::ZERO.len();
+        self.unblind(fetcher)?;
+        if referenced && !matches!(self, Self::Empty) {
+            let mut rlp_buf = Vec::with_capacity(self.length());
+            self.encode(&mut rlp_buf);
+            proof.push(rlp_buf.into());
+        }
+
+        match self {
+            Self::Branch { stack } => {
+                let branch_nibble = path[0] as usize;
+                if let Some(node) = stack.get_mut(branch_nibble) {
+                    node.collect_proof(&path.slice(BRANCH_NODE_NIBBLES..), fetcher, false, proof)?;
+                }
+            }
+            Self::Extension { prefix, node } => {
+                if path.slice(..prefix.len()).as_slice() == prefix.as_slice() {
+                    node.collect_proof(&path.slice(prefix.len()..), fetcher, false, proof)?;
+                }
+            }
+            Self::Leaf { .. } | Self::Blinded { .. } | Self::Empty => {}
+        }
+        Ok(())
+    }
+
     /// Inserts a [TrieNode] at the given path into the trie rooted at Self.
     ///
     /// ## Takes
@@ -390,49 +711,59 @@ impl TrieNode {
                 }
             },
             Self::Branch { stack } => {
-                // Count non-empty children
+                // A branch that holds a value cannot be collapsed, regardless of its children.
+                if stack.get(BRANCH_LIST_LENGTH - 1).is_some_and(|v| !matches!(v, Self::Empty)) {
+                    return Ok(());
+                }
+
+                // Count non-empty children, excluding the value slot.
                 let mut non_empty_children = stack
                     .iter_mut()
+                    .take(BRANCH_LIST_LENGTH - 1)
                     .enumerate()
                     .filter(|(_, node)| !matches!(node, Self::Empty))
                     .collect::<Vec<_>>();
 
-                if non_empty_children.len() == 1 {
-                    let (index, non_empty_node) = &mut non_empty_children[0];
-
-                    // If only one non-empty child and no value, convert to extension or leaf
-                    match non_empty_node {
-                        Self::Leaf { prefix, value } => {
-                            let new_prefix = Nibbles::from_nibbles_unchecked(
-                                [&[*index as u8], prefix.as_slice()].concat(),
-                            );
-                            *self = Self::Leaf { prefix: new_prefix, value: value.clone() };
-                        }
-                        Self::Extension { prefix, node } => {
-                            let new_prefix = Nibbles::from_nibbles_unchecked(
-                                [&[*index as u8], prefix.as_slice()].concat(),
-                            );
-                            *self = Self::Extension { prefix: new_prefix, node: node.clone() };
-                        }
-                        Self::Branch { .. } => {
-                            *self = Self::Extension {
-                                prefix: Nibbles::from_nibbles_unchecked([*index as u8]),
-                                node: Box::new(non_empty_node.clone()),
-                            };
-                        }
-                        Self::Blinded { commitment } => {
-                            // In this special case, we need to send a hint to fetch the preimage of
-                            // the blinded node, since it is outside of the paths that have been
-                            // traversed so far.
-                            hinter
-                                .hint_trie_node(*commitment)
-                                .map_err(|e| TrieNodeError::Provider(e.to_string()))?;
-
-                            non_empty_node.unblind(fetcher)?;
-                            self.collapse_if_possible(fetcher, hinter)?;
-                        }
-                        _ => {}
-                    };
+                match non_empty_children.as_mut_slice() {
+                    [] => {
+                        // A lone blinded child may have unblinded to an empty node, in which case
+                        // the branch is empty as well.
+                        *self = Self::Empty;
+                    }
+                    [(index, non_empty_node)] => {
+                        // If only one non-empty child and no value, convert to extension or leaf
+                        match non_empty_node {
+                            Self::Leaf { prefix, value } => {
+                                let new_prefix = Nibbles::from_nibbles_unchecked(
+                                    [&[*index as u8], prefix.as_slice()].concat(),
+                                );
+                                *self = Self::Leaf { prefix: new_prefix, value: value.clone() };
+                            }
+                            Self::Extension { prefix, node } => {
+                                let new_prefix = Nibbles::from_nibbles_unchecked(
+                                    [&[*index as u8], prefix.as_slice()].concat(),
+                                );
+                                *self = Self::Extension { prefix: new_prefix, node: node.clone() };
+                            }
+                            Self::Branch { .. } => {
+                                *self = Self::Extension {
+                                    prefix: Nibbles::from_nibbles_unchecked([*index as u8]),
+                                    node: Box::new(non_empty_node.clone()),
+                                };
+                            }
+                            Self::Blinded { .. } => {
+                                // In this special case, we need to send a hint to fetch the
+                                // preimage of the blinded node, since it is outside of the paths
+                                // that have been traversed so far. The collapsed form depends on
+                                // the type of the revealed child, so the collapse is retried once
+                                // it is unblinded.
+                                non_empty_node.unblind_subtree(0, fetcher, hinter)?;
+                                self.collapse_if_possible(fetcher, hinter)?;
+                            }
+                            Self::Empty => {}
+                        };
+                    }
+                    _ => {}
                 }
             }
             _ => {}
@@ -618,6 +949,38 @@ impl Decodable for TrieNode {
     }
 }
 
+/// Serde helpers for encoding [Nibbles] as a hex string with one character per nibble.
+#[cfg(feature = "serde")]
+mod serde_nibbles {
+    use alloc::{string::String, vec::Vec};
+    use alloy_trie::Nibbles;
+    use serde::{Deserialize, Deserializer, Serializer, de::Error};
+
+    pub(super) fn serialize<S: Serializer>(
+        nibbles: &Nibbles,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let hex = nibbles
+            .iter()
+            .map(|nibble| char::from_digit(*nibble as u32, 16))
+            .collect::<Option<String>>()
+            .ok_or_else(|| serde::ser::Error::custom("invalid nibble"))?;
+        serializer.serialize_str(&hex)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Nibbles, D::Error> {
+        let hex = String::deserialize(deserializer)?;
+        let nibbles = hex
+            .chars()
+            .map(|c| c.to_digit(16).map(|nibble| nibble as u8))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| D::Error::custom("invalid nibble"))?;
+        Ok(Nibbles::from_nibbles_unchecked(nibbles))
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -625,10 +988,16 @@ mod test {
         NoopTrieHinter, NoopTrieProvider, TrieNode, ordered_trie_with_encoder,
         test_util::TrieNodeProvider,
     };
-    use alloc::{collections::BTreeMap, vec, vec::Vec};
-    use alloy_primitives::{b256, bytes, hex, keccak256};
+    use alloc::{
+        collections::{BTreeMap, BTreeSet},
+        string::String,
+        vec,
+        vec::Vec,
+    };
+    use alloy_primitives::{Address, U256, b256, bytes, hex, keccak256};
     use alloy_rlp::{Decodable, EMPTY_STRING_CODE, Encodable};
     use alloy_trie::{HashBuilder, Nibbles};
+    use core::cell::RefCell;
     use rand::prelude::IteratorRandom;
 
     #[test]
@@ -637,6 +1006,46 @@ mod test {
         assert_eq!(trie_node.blind(), EMPTY_ROOT_HASH);
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_fixture_v1() {
+        const FIXTURE: &str = include_str!("../testdata/trie_node_v1.json");
+
+        let mut stack = vec![
+            TrieNode::new_blinded(b256!(
+                "eb08a66a94882454bec899d3e82952dcc918ba4b35a09a84acd98019aef43450"
+            )),
+            TrieNode::Leaf {
+                prefix: Nibbles::from_nibbles_unchecked([0x0, 0xa, 0x3, 0xf]),
+                value: bytes!("deadbeef"),
+            },
+            TrieNode::Extension {
+                prefix: Nibbles::from_nibbles_unchecked([0x7]),
+                node: Box::new(TrieNode::Leaf {
+                    prefix: Nibbles::from_nibbles_unchecked([0xb]),
+                    value: bytes!("01"),
+                }),
+            },
+        ];
+        stack.resize(BRANCH_LIST_LENGTH, TrieNode::Empty);
+        let expected = TrieNode::Branch { stack };
+
+        // The committed fixture must always deserialize to the same node.
+        let decoded: TrieNode = serde_json::from_str(FIXTURE).unwrap();
+        assert_eq!(decoded, expected);
+
+        // The current serialization must match the committed fixture.
+        let fixture: serde_json::Value = serde_json::from_str(FIXTURE).unwrap();
+        assert_eq!(serde_json::to_value(&expected).unwrap(), fixture);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_invalid_nibble() {
+        let raw = r#"{"type":"leaf","prefix":"0g","value":"0x01"}"#;
+        assert!(serde_json::from_str::<TrieNode>(raw).is_err());
+    }
+
     #[test]
     fn test_decode_branch() {
         const BRANCH_RLP: [u8; 83] = hex!(
@@ -762,6 +1171,94 @@ mod test {
         assert_eq!(commitment, root);
     }
 
+    #[test]
+    fn test_get_matches_open_without_unblinding() {
+        let (root, fetcher) = subtree_fixture();
+        let root_node = fetcher.trie_node_by_hash(root).unwrap();
+        let commitments = child_commitments(&root_node);
+
+        let mut opened = root_node.clone();
+        for i in [0u64, 1, 17, 128, 199, 200] {
+            let path = Nibbles::unpack(alloy_rlp::encode(i));
+            let value = root_node.get(&path, &fetcher).unwrap();
+            assert_eq!(value.as_ref(), opened.open(&path, &fetcher).unwrap().map(|v| &*v));
+            assert_eq!(value.is_some(), i < 200);
+        }
+
+        // The children of the root remain blinded after `get`, while `open` unblinds them.
+        assert_eq!(child_commitments(&root_node), commitments);
+        assert!(child_commitments(&opened).len() < commitments.len());
+        assert_eq!(root_node.blind(), root);
+    }
+
+    #[test]
+    fn test_entries_with_prefix_static() {
+        let mut node = TrieNode::Empty;
+        for (key, value) in [
+            (hex!("012345"), bytes!("01")),
+            (hex!("012346"), bytes!("02")),
+            (hex!("0124ff"), bytes!("03")),
+            (hex!("ff0000"), bytes!("04")),
+        ] {
+            node.insert(&Nibbles::unpack(key), value, &NoopTrieProvider).unwrap();
+        }
+
+        let entries = |node: &mut TrieNode, prefix: &[u8]| {
+            node.entries_with_prefix(&Nibbles::from_nibbles(prefix), &NoopTrieProvider).unwrap()
+        };
+        assert_eq!(
+            entries(&mut node, &[0, 1, 2, 3, 4]),
+            vec![
+                (Nibbles::unpack(hex!("012345")), bytes!("01")),
+                (Nibbles::unpack(hex!("012346")), bytes!("02")),
+            ]
+        );
+        assert_eq!(entries(&mut node, &[0, 1, 2]).len(), 3);
+        assert_eq!(entries(&mut node, &[0, 1, 2, 4, 0xf, 0xf]).len(), 1);
+        assert_eq!(entries(&mut node, &[]).len(), 4);
+        assert!(entries(&mut node, &[0, 1, 3]).is_empty());
+        assert!(entries(&mut node, &[0, 1, 2, 3, 4, 5, 6]).is_empty());
+    }
+
+    #[test]
+    fn test_entries_with_prefix_unblinds_subtree() {
+        let (root, fetcher) = subtree_fixture();
+        let mut root_node = fetcher.trie_node_by_hash(root).unwrap();
+
+        // Keys `0x80` (0) and `0x81..` (128..200) share the prefix nibble `0x8`.
+        let prefix = Nibbles::from_nibbles([0x8]);
+        let entries = root_node.entries_with_prefix(&prefix, &fetcher).unwrap();
+        let expected = core::iter::once(0u64)
+            .chain(128..200)
+            .map(|i| {
+                let value = alloy_rlp::encode(keccak256(i.to_be_bytes()));
+                (Nibbles::unpack(alloy_rlp::encode(i)), value.into())
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(entries, expected);
+        assert_eq!(root_node.blind(), root);
+    }
+
+    #[test]
+    fn test_into_commitment_matches_blind() {
+        let (root, fetcher) = subtree_fixture();
+        let mut root_node = fetcher.trie_node_by_hash(root).unwrap();
+
+        // Fully open the trie, then modify it.
+        for i in 0u64..200 {
+            root_node.open(&Nibbles::unpack(alloy_rlp::encode(i)), &fetcher).unwrap();
+        }
+        let path = Nibbles::unpack(alloy_rlp::encode(17u64));
+        root_node.insert(&path, bytes!("deadbeef"), &fetcher).unwrap();
+        let path = Nibbles::unpack(alloy_rlp::encode(18u64));
+        root_node.delete(&path, &fetcher, &NoopTrieHinter).unwrap();
+
+        let expected = root_node.blind();
+        assert_ne!(expected, root);
+        assert_eq!(root_node.into_commitment(), expected);
+        assert_eq!(TrieNode::Empty.into_commitment(), EMPTY_ROOT_HASH);
+    }
+
     #[test]
     fn test_insert_static() {
         let mut node = TrieNode::Empty;
@@ -797,6 +1294,219 @@ mod test {
         assert_eq!(node, expected);
     }
 
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Access {
+        Hint(B256),
+        Fetch(B256),
+    }
+
+    /// Records the order of hints and fetches issued while unblinding.
+    struct RecordingTrie<'a> {
+        provider: TrieNodeProvider,
+        log: &'a RefCell<Vec<Access>>,
+    }
+
+    impl TrieProvider for RecordingTrie<'_> {
+        type Error = <TrieNodeProvider as TrieProvider>::Error;
+
+        fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+            self.log.borrow_mut().push(Access::Fetch(key));
+            self.provider.trie_node_by_hash(key)
+        }
+    }
+
+    impl TrieHinter for RecordingTrie<'_> {
+        type Error = String;
+
+        fn hint_trie_node(&self, hash: B256) -> Result<(), Self::Error> {
+            self.log.borrow_mut().push(Access::Hint(hash));
+            Ok(())
+        }
+
+        fn hint_account_proof(&self, _: Address, _: u64) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn hint_storage_proof(&self, _: Address, _: U256, _: u64) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn hint_execution_witness(
+            &self,
+            _: B256,
+            _: &op_alloy_rpc_types_engine::OpPayloadAttributes,
+        ) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    /// Builds an ordered trie of 200 items, whose root is a branch of blinded branches of blinded
+    /// leaves, returning the root commitment and a provider for its preimages.
+    fn subtree_fixture() -> (B256, TrieNodeProvider) {
+        ordered_trie_fixture(200)
+    }
+
+    /// Builds an ordered trie of `len` items, returning the root commitment and a provider for its
+    /// preimages.
+    fn ordered_trie_fixture(len: u64) -> (B256, TrieNodeProvider) {
+        let values = (0..len).map(|i| keccak256(i.to_be_bytes())).collect::<Vec<_>>();
+        let mut trie = ordered_trie_with_encoder(&values, |v, buf| v.encode(buf));
+        let root = trie.root();
+        let preimages = trie
+            .take_proof_nodes()
+            .into_inner()
+            .into_values()
+            .map(|value| (keccak256(value.as_ref()), value))
+            .collect();
+        (root, TrieNodeProvider::new(preimages))
+    }
+
+    fn child_commitments(node: &TrieNode) -> Vec<B256> {
+        let TrieNode::Branch { stack } = node else { panic!("expected a branch") };
+        stack
+            .iter()
+            .filter_map(|n| match n {
+                TrieNode::Blinded { commitment } => Some(*commitment),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the depths, relative to `node`, of every blinded node within the subtree.
+    fn blinded_depths(node: &TrieNode) -> Vec<usize> {
+        fn walk(node: &TrieNode, depth: usize, depths: &mut Vec<usize>) {
+            match node {
+                TrieNode::Blinded { .. } => depths.push(depth),
+                TrieNode::Branch { stack } => {
+                    stack.iter().for_each(|child| walk(child, depth + 1, depths))
+                }
+                TrieNode::Extension { node, .. } => walk(node, depth + 1, depths),
+                _ => {}
+            }
+        }
+
+        let mut depths = Vec::new();
+        walk(node, 0, &mut depths);
+        depths
+    }
+
+    #[test]
+    fn test_unblind_subtree_depth_zero_matches_unblind() {
+        let (root, provider) = subtree_fixture();
+
+        let mut node = TrieNode::new_blinded(root);
+        node.unblind_subtree(0, &provider, &NoopTrieHinter).unwrap();
+
+        // Only the root is revealed; all of its children remain blinded.
+        let TrieNode::Branch { stack } = &node else { panic!("expected a branch") };
+        let children = stack.iter().filter(|n| !matches!(n, TrieNode::Empty)).count();
+        assert!(children > 1);
+        assert_eq!(blinded_depths(&node), vec![1; children]);
+
+        let mut unblinded = TrieNode::new_blinded(root);
+        unblinded.unblind(&provider).unwrap();
+        assert_eq!(node, unblinded);
+    }
+
+    #[test]
+    fn test_unblind_subtree_hints_level_before_fetching() {
+        let (root, provider) = subtree_fixture();
+        let children = child_commitments(&provider.trie_node_by_hash(root).unwrap());
+        assert!(children.len() > 1);
+
+        let log = RefCell::new(Vec::new());
+        let trie = RecordingTrie { provider, log: &log };
+        let mut node = TrieNode::new_blinded(root);
+        node.unblind_subtree(1, &trie, &trie).unwrap();
+
+        let expected = [Access::Hint(root), Access::Fetch(root)]
+            .into_iter()
+            .chain(children.iter().copied().map(Access::Hint))
+            .chain(children.iter().copied().map(Access::Fetch))
+            .collect::<Vec<_>>();
+        assert_eq!(log.into_inner(), expected);
+    }
+
+    #[test]
+    fn test_unblind_subtree_depth_two() {
+        let (root, provider) = ordered_trie_fixture(5_000);
+
+        let mut node = TrieNode::new_blinded(root);
+        node.unblind_subtree(2, &provider, &NoopTrieHinter).unwrap();
+
+        // Every node within two levels of the root is revealed, and the next level is not.
+        let depths = blinded_depths(&node);
+        assert!(!depths.is_empty());
+        assert!(depths.iter().all(|&depth| depth > 2));
+        assert!(depths.contains(&3));
+
+        // Unblinding to depth 1 leaves nodes at depth 2 blinded.
+        let mut shallow = TrieNode::new_blinded(root);
+        shallow.unblind_subtree(1, &provider, &NoopTrieHinter).unwrap();
+        let depths = blinded_depths(&shallow);
+        assert!(depths.iter().all(|&depth| depth > 1));
+        assert!(depths.contains(&2));
+    }
+
+    #[test]
+    fn test_unblind_subtree_depth_is_bounded() {
+        let (root, provider) = ordered_trie_fixture(5_000);
+
+        let mut node = TrieNode::new_blinded(root);
+        node.unblind_subtree(usize::MAX, &provider, &NoopTrieHinter).unwrap();
+
+        // Nodes below the maximum depth remain blinded.
+        let depths = blinded_depths(&node);
+        assert!(depths.iter().all(|&depth| depth > MAX_UNBLIND_SUBTREE_DEPTH));
+        assert!(depths.contains(&(MAX_UNBLIND_SUBTREE_DEPTH + 1)));
+    }
+
+    #[test]
+    fn test_delete_collapses_lone_blinded_child() {
+        let (root, provider) = subtree_fixture();
+        let TrieNode::Branch { stack } = provider.trie_node_by_hash(root).unwrap() else {
+            panic!("expected a branch")
+        };
+        // Index `0` and indices `128..` are all keyed under nibble `8`.
+        let TrieNode::Blinded { commitment: lone_child } = stack[8] else {
+            panic!("expected a blinded child")
+        };
+
+        let log = RefCell::new(Vec::new());
+        let trie = RecordingTrie { provider, log: &log };
+        let mut node = TrieNode::new_blinded(root);
+
+        // Delete every key outside of the subtree under nibble `8`, which is never traversed.
+        let mut key = Vec::new();
+        for i in 1..128u64 {
+            key.clear();
+            i.encode(&mut key);
+            node.delete(&Nibbles::unpack(&key), &trie, &trie).unwrap();
+        }
+
+        // The lone blinded child is hinted and fetched in order to collapse the root branch.
+        let log = log.into_inner();
+        let hint = log.iter().position(|a| *a == Access::Hint(lone_child)).unwrap();
+        let fetch = log.iter().position(|a| *a == Access::Fetch(lone_child)).unwrap();
+        assert!(hint < fetch);
+        let TrieNode::Extension { prefix, node: child } = &node else {
+            panic!("expected an extension")
+        };
+        assert_eq!(prefix, &Nibbles::from_nibbles([0x8]));
+        assert!(matches!(child.as_ref(), TrieNode::Branch { .. }));
+
+        let mut hb = HashBuilder::default();
+        let mut value = Vec::new();
+        for i in core::iter::once(0u64).chain(128..200) {
+            key.clear();
+            i.encode(&mut key);
+            value.clear();
+            keccak256(i.to_be_bytes()).encode(&mut value);
+            hb.add_leaf(Nibbles::unpack(&key), &value);
+        }
+        assert_eq!(node.blind(), hb.root());
+    }
+
     proptest::proptest! {
         /// Differential test for inserting an arbitrary number of keys into an empty `TrieNode` / `HashBuilder`.
         #[test]
@@ -815,6 +1525,20 @@ mod test {
             assert_eq!(node.blind(), hb.root());
         }
 
+        /// Differential test for computing the commitment of a randomly populated `TrieNode` in
+        /// parallel and serially.
+        #[test]
+        fn diff_blind_parallel(keys in proptest::collection::vec(proptest::prelude::any::<[u8; 32]>(), 0..4096)) {
+            let mut node = TrieNode::Empty;
+            for key in keys {
+                // Vary the value length, so that some subtrees are short enough to be inlined.
+                let value = Bytes::copy_from_slice(&key[..1 + key[0] as usize % 32]);
+                node.insert(&Nibbles::unpack(key), value, &NoopTrieProvider).unwrap();
+            }
+
+            assert_eq!(node.blind_parallel(), node.blind());
+        }
+
         /// Differential test for deleting an arbitrary number of keys from a `TrieNode` / `HashBuilder`.
         #[test]
         fn diff_hash_builder_delete(mut keys in proptest::collection::vec(proptest::prelude::any::<[u8; 32]>(), 1..4096)) {
@@ -851,4 +1575,66 @@ mod test {
             assert_eq!(trie_root, hb.root());
         }
     }
+
+    #[test]
+    fn test_trie_node_ordering() {
+        let leaf = |value| TrieNode::Leaf { prefix: Nibbles::from_nibbles([0x01]), value };
+        let nodes = [
+            TrieNode::Branch { stack: vec![TrieNode::Empty, leaf(bytes!("01"))] },
+            TrieNode::Extension {
+                prefix: Nibbles::from_nibbles([0x02]),
+                node: Box::new(leaf(bytes!("01"))),
+            },
+            leaf(bytes!("01")),
+            leaf(bytes!("02")),
+            TrieNode::new_blinded(B256::repeat_byte(0xFF)),
+            TrieNode::new_blinded(B256::ZERO),
+            TrieNode::Empty,
+            leaf(bytes!("01")),
+        ];
+
+        let set = nodes.iter().cloned().collect::<BTreeSet<_>>();
+        assert_eq!(set.len(), nodes.len() - 1);
+
+        let sorted = set.into_iter().collect::<Vec<_>>();
+        for pair in sorted.windows(2) {
+            assert_eq!(pair[0].partial_cmp(&pair[1]), Some(core::cmp::Ordering::Less));
+            assert_eq!(pair[0].cmp(&pair[1]), core::cmp::Ordering::Less);
+        }
+        assert_eq!(sorted.first(), Some(&TrieNode::Empty));
+        assert!(matches!(sorted.last(), Some(TrieNode::Branch { .. })));
+    }
+
+    #[test]
+    fn test_proof_verifies_against_root() {
+        let (root, fetcher) = subtree_fixture();
+        let mut root_node = TrieNode::new_blinded(root);
+
+        for i in [0u64, 1, 17, 128, 199] {
+            let path = Nibbles::unpack(alloy_rlp::encode(i));
+            let proof = root_node.proof(&path, &fetcher).unwrap();
+            let value = alloy_rlp::encode(keccak256(i.to_be_bytes()));
+            alloy_trie::proof::verify_proof(root, path, Some(value), &proof).unwrap();
+        }
+
+        // A non-existent key yields a partial proof up to the point of divergence.
+        let path = Nibbles::unpack(alloy_rlp::encode(200u64));
+        let proof = root_node.proof(&path, &fetcher).unwrap();
+        assert!(!proof.is_empty());
+        alloy_trie::proof::verify_proof(root, path, None, &proof).unwrap();
+
+        assert_eq!(root_node.blind(), root);
+    }
+
+    #[test]
+    fn test_proof_surfaces_provider_error() {
+        let (root, fetcher) = subtree_fixture();
+        let mut root_node = fetcher.trie_node_by_hash(root).unwrap();
+        let path = Nibbles::unpack(alloy_rlp::encode(1u64));
+
+        // The root is open, but its blinded children can't be fetched.
+        let empty = TrieNodeProvider::new(BTreeMap::default());
+        let err = root_node.proof(&path, &empty).unwrap_err();
+        assert!(matches!(err, TrieNodeError::Provider(_)));
+    }
 }
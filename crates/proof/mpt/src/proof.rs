@@ -0,0 +1,171 @@
+//! Contains the [verify_proof] function, which verifies a merkle proof against a trie root
+//! without a remote [TrieProvider].
+
+use crate::{ProofError, ProofResult, TrieNode, TrieNodeError, TrieProvider};
+use alloc::{collections::BTreeMap, format, string::String};
+use alloy_primitives::{B256, Bytes, keccak256};
+use alloy_rlp::Decodable;
+use alloy_trie::Nibbles;
+use core::mem;
+
+/// A [TrieProvider] that serves the nodes of a merkle proof, keyed by their hash.
+///
+/// Nodes are only served by the hash of their encoding, so a node that does not hash to the
+/// reference from its parent is never found.
+#[derive(Debug)]
+struct ProofNodes<'a> {
+    /// The RLP-encoded proof nodes, keyed by their hash.
+    nodes: BTreeMap<B256, &'a Bytes>,
+}
+
+impl<'a> ProofNodes<'a> {
+    /// Creates a new [ProofNodes] provider from the given RLP-encoded proof nodes.
+    fn new(proof: &'a [Bytes]) -> Self {
+        Self { nodes: proof.iter().map(|node| (keccak256(node), node)).collect() }
+    }
+}
+
+impl TrieProvider for ProofNodes<'_> {
+    type Error = String;
+
+    fn trie_node_by_hash(&self, key: B256) -> Result<TrieNode, Self::Error> {
+        let node = self.nodes.get(&key).ok_or_else(|| format!("Missing proof node {key}"))?;
+        TrieNode::decode(&mut &node[..]).map_err(|e| format!("Invalid proof node {key}: {e}"))
+    }
+}
+
+/// Verifies a merkle proof, such as one returned by `eth_getProof`, against the trie with the
+/// given `root`.
+///
+/// The proof nodes are walked from the root along `key`, checking that each node hashes to the
+/// reference from its parent. The walk ends at the leaf for `key`, or where the path diverges
+/// from the trie. The value found, if any, must equal `expected_value`; an `expected_value` of
+/// [None] verifies that `key` is absent from the trie.
+///
+/// ## Takes
+/// - `root` - The root of the trie
+/// - `key` - The nibbles representation of the key to verify
+/// - `expected_value` - The expected value at `key`, or [None] if the key is absent
+/// - `proof` - The RLP-encoded trie nodes on the path to `key`, in any order
+///
+/// ## Returns
+/// - `Ok(())` - The proof is valid
+/// - `Err(_)` - A node on the path is missing or invalid, or the value does not match
+pub fn verify_proof(
+    root: B256,
+    key: &Nibbles,
+    expected_value: Option<&[u8]>,
+    proof: &[Bytes],
+) -> ProofResult<()> {
+    let provider = ProofNodes::new(proof);
+
+    let mut node = TrieNode::new_blinded(root);
+    let mut path = key.clone();
+    let value = loop {
+        node.unblind(&provider)?;
+        match node {
+            TrieNode::Empty => break None,
+            TrieNode::Leaf { prefix, value } => {
+                break (path.as_slice() == prefix.as_slice()).then_some(value);
+            }
+            TrieNode::Extension { prefix, node: child } => {
+                if !path.as_slice().starts_with(prefix.as_slice()) {
+                    break None;
+                }
+                path = path.slice(prefix.len()..);
+                node = *child;
+            }
+            TrieNode::Branch { mut stack } => {
+                // Values stored within branch nodes are not supported by `TrieNode`, so a key
+                // ending at a branch is absent.
+                let Some(&nibble) = path.as_slice().first() else { break None };
+                let Some(child) = stack.get_mut(nibble as usize) else { break None };
+                path = path.slice(1..);
+                node = mem::replace(child, TrieNode::Empty);
+            }
+            // A proof node must not be a bare commitment.
+            TrieNode::Blinded { .. } => return Err(TrieNodeError::InvalidNodeType.into()),
+        }
+    };
+
+    if value.as_ref().map(|v| &v[..]) != expected_value {
+        return Err(ProofError::ValueMismatch {
+            expected: expected_value.map(Bytes::copy_from_slice),
+            actual: value,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ordered_trie_with_encoder;
+    use alloc::vec::Vec;
+    use alloy_rlp::Encodable;
+
+    /// Returns the root and the full set of proof nodes of a trie of 200 ordered values, with the
+    /// RLP-encoded index of each value as its key.
+    fn proof_fixture() -> (B256, Vec<Bytes>) {
+        let values = (0..200u64).map(|i| keccak256(i.to_be_bytes())).collect::<Vec<_>>();
+        let mut trie = ordered_trie_with_encoder(&values, |v, buf| v.encode(buf));
+        let root = trie.root();
+        (root, trie.take_proof_nodes().into_inner().into_values().collect())
+    }
+
+    fn key(index: u64) -> Nibbles {
+        Nibbles::unpack(alloy_rlp::encode(index))
+    }
+
+    fn value(index: u64) -> Vec<u8> {
+        alloy_rlp::encode(keccak256(index.to_be_bytes()))
+    }
+
+    #[test]
+    fn test_verify_inclusion_proof() {
+        let (root, proof) = proof_fixture();
+        for index in [0, 1, 127, 128, 199] {
+            verify_proof(root, &key(index), Some(&value(index)), &proof).unwrap();
+        }
+
+        // The proof does not verify a different value, or the absence of the key.
+        assert!(matches!(
+            verify_proof(root, &key(5), Some(&value(6)), &proof),
+            Err(ProofError::ValueMismatch { .. })
+        ));
+        assert_eq!(
+            verify_proof(root, &key(5), None, &proof),
+            Err(ProofError::ValueMismatch { expected: None, actual: Some(value(5).into()) })
+        );
+    }
+
+    #[test]
+    fn test_verify_exclusion_proof() {
+        let (root, proof) = proof_fixture();
+        verify_proof(root, &key(500), None, &proof).unwrap();
+        assert_eq!(
+            verify_proof(root, &key(500), Some(&value(500)), &proof),
+            Err(ProofError::ValueMismatch { expected: Some(value(500).into()), actual: None })
+        );
+    }
+
+    #[test]
+    fn test_verify_tampered_proof() {
+        let (root, mut proof) = proof_fixture();
+
+        // Tamper with the value within the leaf node for the key.
+        let expected = value(42);
+        let leaf = proof
+            .iter_mut()
+            .find(|node| node.windows(expected.len()).any(|w| w == expected.as_slice()))
+            .unwrap();
+        let mut tampered = leaf.to_vec();
+        *tampered.last_mut().unwrap() ^= 0xFF;
+        *leaf = tampered.into();
+
+        assert!(matches!(
+            verify_proof(root, &key(42), Some(&expected), &proof),
+            Err(ProofError::TrieNode(TrieNodeError::Provider(_)))
+        ));
+    }
+}
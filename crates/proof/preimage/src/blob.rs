@@ -0,0 +1,277 @@
+//! Contains utilities for blob preimages.
+//!
+//! Blobs are served by the preimage oracle as individual field elements, keyed by
+//! `keccak256(commitment ++ z)` where `z` is the root of unity that the blob polynomial is
+//! evaluated at to obtain the field element.
+
+use alloy_eips::eip4844::FIELD_ELEMENTS_PER_BLOB;
+use ark_bls12_381::Fr;
+use ark_ff::{AdditiveGroup, BigInteger256, Field};
+use core::str::FromStr;
+use spin::Lazy;
+
+#[cfg(feature = "kzg")]
+use crate::{PreimageKey, PreimageKeyType, errors::BlobVerificationError};
+#[cfg(feature = "kzg")]
+use alloc::{collections::BTreeMap, vec::Vec};
+#[cfg(feature = "kzg")]
+use alloy_primitives::{B256, keccak256};
+#[cfg(feature = "kzg")]
+use ark_ff::PrimeField;
+
+/// The 4096th bit-reversed roots of unity used in EIP-4844 as predefined evaluation points.
+///
+/// See `generate_roots_of_unity` for details on how these roots of unity are generated.
+pub static ROOTS_OF_UNITY: Lazy<[Fr; FIELD_ELEMENTS_PER_BLOB as usize]> =
+    Lazy::new(generate_roots_of_unity);
+
+/// Generates the 4096th bit-reversed roots of unity used in EIP-4844 as predefined evaluation
+/// points. To compute the field element at index i in a blob, the blob polynomial is evaluated at
+/// the i'th root of unity. Based on go-kzg-4844: <https://github.com/crate-crypto/go-kzg-4844/blob/8bcf6163d3987313a3194595cf1f33fd45d7301a/internal/kzg/domain.go#L44-L98>
+/// Also, see the consensus specs:
+///   - compute_roots_of_unity <https://github.com/ethereum/consensus-specs/blob/bf09edef17e2900258f7e37631e9452941c26e86/specs/deneb/polynomial-commitments.md#compute_roots_of_unity>
+///   - bit-reversal permutation: <https://github.com/ethereum/consensus-specs/blob/bf09edef17e2900258f7e37631e9452941c26e86/specs/deneb/polynomial-commitments.md#bit-reversal-permutation>
+fn generate_roots_of_unity() -> [Fr; FIELD_ELEMENTS_PER_BLOB as usize] {
+    const MAX_ORDER_ROOT: u64 = 32;
+
+    let mut roots_of_unity = [Fr::ZERO; FIELD_ELEMENTS_PER_BLOB as usize];
+
+    // Generator of the largest 2-adic subgroup of order 2^32.
+    let root_of_unity = Fr::new(
+        BigInteger256::from_str(
+            "10238227357739495823651030575849232062558860180284477541189508159991286009131",
+        )
+        .expect("Failed to initialize root of unity"),
+    );
+
+    // Find generator subgroup of order x.
+    // This can be constructed by powering a generator of the largest 2-adic subgroup of order 2^32
+    // by an exponent of (2^32)/x, provided x is <= 2^32.
+    let log_x = FIELD_ELEMENTS_PER_BLOB.trailing_zeros() as u64;
+    let expo = 1u64 << (MAX_ORDER_ROOT - log_x);
+
+    // Generator has order x now
+    let generator = root_of_unity.pow([expo]);
+
+    // Compute all relevant roots of unity, i.e. the multiplicative subgroup of size x
+    let mut current = Fr::ONE;
+    (0..FIELD_ELEMENTS_PER_BLOB).for_each(|i| {
+        roots_of_unity[i as usize] = current;
+        current *= generator;
+    });
+
+    let shift_correction = 64 - FIELD_ELEMENTS_PER_BLOB.trailing_zeros();
+    (0..FIELD_ELEMENTS_PER_BLOB).for_each(|i| {
+        // Find index irev, such that i and irev get swapped
+        let irev = i.reverse_bits() >> shift_correction;
+        if irev > i {
+            roots_of_unity.swap(i as usize, irev as usize);
+        }
+    });
+
+    roots_of_unity
+}
+
+/// The length of a blob key preimage, `commitment ++ z`.
+#[cfg(feature = "kzg")]
+const BLOB_KEY_LENGTH: usize = 80;
+
+/// The field elements and KZG proof of a single blob, collected from the preimages.
+#[cfg(feature = "kzg")]
+#[derive(Debug)]
+struct BlobPreimages<'a> {
+    /// The KZG commitment to the blob.
+    commitment: &'a [u8],
+    /// The field elements of the blob, keyed by their index.
+    field_elements: BTreeMap<usize, &'a [u8]>,
+    /// The KZG proof for the blob.
+    proof: Option<&'a [u8]>,
+}
+
+/// Verifies all [PreimageKeyType::Blob] preimages within a set of `(key, preimage)` entries.
+///
+/// The commitment and evaluation point of each field element are recovered from the
+/// [PreimageKeyType::Keccak256] preimage of its blob key, which must be present in `entries`. The
+/// field elements are then grouped by the versioned hash of their commitment, reassembled into
+/// full blobs, and verified against the commitment with the KZG proof that is stored under the
+/// evaluation point `FIELD_ELEMENTS_PER_BLOB`.
+///
+/// Entries of other key types are ignored.
+#[cfg(feature = "kzg")]
+pub fn verify_blob_preimages(
+    entries: &[(PreimageKey, Vec<u8>)],
+) -> Result<(), BlobVerificationError> {
+    use alloy_eips::eip4844::{env_settings::EnvKzgSettings, kzg_to_versioned_hash};
+    use ark_ff::BigInteger;
+    use c_kzg::{Blob, Bytes48};
+
+    // Recover the `commitment ++ z` blob keys from the keccak256 preimages. The blob key is
+    // re-hashed, so that a blob key can only be paired with the field element it commits to.
+    let blob_keys = entries
+        .iter()
+        .filter(|(key, value)| {
+            key.key_type() == PreimageKeyType::Keccak256 && value.len() == BLOB_KEY_LENGTH
+        })
+        .map(|(_, value)| {
+            (PreimageKey::new(*keccak256(value), PreimageKeyType::Blob), value.as_slice())
+        })
+        .collect::<BTreeMap<_, _>>();
+    let root_indices = ROOTS_OF_UNITY
+        .iter()
+        .enumerate()
+        .map(|(i, root)| (root.into_bigint().to_bytes_be(), i))
+        .collect::<BTreeMap<_, _>>();
+
+    // Group the field elements and proofs by the versioned hash of their blob.
+    let mut blobs = BTreeMap::<B256, BlobPreimages<'_>>::new();
+    for (key, value) in entries.iter().filter(|(key, _)| key.key_type() == PreimageKeyType::Blob) {
+        let blob_key =
+            blob_keys.get(key).ok_or(BlobVerificationError::MissingBlobKey((*key).into()))?;
+        let (commitment, z) = blob_key.split_at(48);
+
+        let versioned_hash = kzg_to_versioned_hash(commitment);
+        let blob = blobs.entry(versioned_hash).or_insert_with(|| BlobPreimages {
+            commitment,
+            field_elements: BTreeMap::new(),
+            proof: None,
+        });
+
+        if let Some(&index) = root_indices.get(z) {
+            if value.len() != 32 {
+                return Err(BlobVerificationError::InvalidLength((*key).into(), value.len()));
+            }
+            blob.field_elements.insert(index, value.as_slice());
+        } else if z[24..] == FIELD_ELEMENTS_PER_BLOB.to_be_bytes() {
+            if value.len() != 48 {
+                return Err(BlobVerificationError::InvalidLength((*key).into(), value.len()));
+            }
+            blob.proof = Some(value.as_slice());
+        } else {
+            return Err(BlobVerificationError::InvalidEvaluationPoint(B256::from_slice(z)));
+        }
+    }
+
+    let kzg_settings = EnvKzgSettings::Default;
+    for (versioned_hash, blob) in blobs {
+        if blob.field_elements.len() != FIELD_ELEMENTS_PER_BLOB as usize {
+            return Err(BlobVerificationError::IncompleteBlob(
+                versioned_hash,
+                blob.field_elements.len(),
+            ));
+        }
+        let proof = blob.proof.ok_or(BlobVerificationError::MissingProof(versioned_hash))?;
+
+        let blob_data = blob.field_elements.into_values().flatten().copied().collect::<Vec<_>>();
+        let valid = kzg_settings
+            .get()
+            .verify_blob_kzg_proof(
+                &Blob::from_bytes(&blob_data).map_err(BlobVerificationError::Kzg)?,
+                &Bytes48::from_bytes(blob.commitment).map_err(BlobVerificationError::Kzg)?,
+                &Bytes48::from_bytes(proof).map_err(BlobVerificationError::Kzg)?,
+            )
+            .map_err(BlobVerificationError::Kzg)?;
+        if !valid {
+            return Err(BlobVerificationError::InvalidProof(versioned_hash));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "kzg"))]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use alloy_eips::eip4844::{
+        BYTES_PER_BLOB, env_settings::EnvKzgSettings, kzg_to_versioned_hash,
+    };
+    use ark_ff::BigInteger;
+    use c_kzg::Blob;
+
+    /// Returns a blob whose field elements are derived from their index, alongside its KZG
+    /// commitment and proof.
+    fn blob_commitment_proof() -> (Vec<u8>, [u8; 48], [u8; 48]) {
+        let mut data = vec![0u8; BYTES_PER_BLOB];
+        for (i, element) in data.chunks_exact_mut(32).enumerate() {
+            // Keep the leading byte zero so that each field element is within the field modulus.
+            element[1..].copy_from_slice(&keccak256(i.to_be_bytes())[1..]);
+        }
+
+        let kzg = EnvKzgSettings::Default;
+        let blob = Blob::from_bytes(&data).unwrap();
+        let commitment = kzg.get().blob_to_kzg_commitment(&blob).unwrap().to_bytes();
+        let proof = kzg.get().compute_blob_kzg_proof(&blob, &commitment).unwrap().to_bytes();
+
+        (data, commitment.into_inner(), proof.into_inner())
+    }
+
+    /// Returns the preimage entries for a blob, in the same layout the host serves them in.
+    fn blob_entries(
+        data: &[u8],
+        commitment: &[u8; 48],
+        proof: &[u8; 48],
+    ) -> Vec<(PreimageKey, Vec<u8>)> {
+        let mut entries = Vec::new();
+        let mut blob_key = [0u8; BLOB_KEY_LENGTH];
+        blob_key[..48].copy_from_slice(commitment);
+        for (root, element) in ROOTS_OF_UNITY.iter().zip(data.chunks_exact(32)) {
+            blob_key[48..].copy_from_slice(&root.into_bigint().to_bytes_be());
+            let hash = keccak256(blob_key);
+            entries.push((PreimageKey::new_keccak256(*hash), blob_key.to_vec()));
+            entries.push((PreimageKey::new(*hash, PreimageKeyType::Blob), element.to_vec()));
+        }
+
+        // The proof is stored as the 4096th element.
+        blob_key[72..].copy_from_slice(&FIELD_ELEMENTS_PER_BLOB.to_be_bytes());
+        let hash = keccak256(blob_key);
+        entries.push((PreimageKey::new_keccak256(*hash), blob_key.to_vec()));
+        entries.push((PreimageKey::new(*hash, PreimageKeyType::Blob), proof.to_vec()));
+
+        entries
+    }
+
+    #[test]
+    fn test_verify_blob_preimages() {
+        let (data, commitment, proof) = blob_commitment_proof();
+        let entries = blob_entries(&data, &commitment, &proof);
+
+        verify_blob_preimages(&entries).unwrap();
+    }
+
+    #[test]
+    fn test_verify_blob_preimages_invalid_proof() {
+        let (mut data, commitment, proof) = blob_commitment_proof();
+        data[31] ^= 1;
+        let entries = blob_entries(&data, &commitment, &proof);
+
+        let err = verify_blob_preimages(&entries).unwrap_err();
+        assert!(matches!(
+            err,
+            BlobVerificationError::InvalidProof(hash) if hash == kzg_to_versioned_hash(&commitment)
+        ));
+    }
+
+    #[test]
+    fn test_verify_blob_preimages_incomplete_blob() {
+        let (data, commitment, proof) = blob_commitment_proof();
+        let mut entries = blob_entries(&data, &commitment, &proof);
+        entries.remove(1);
+
+        let err = verify_blob_preimages(&entries).unwrap_err();
+        let expected_len = FIELD_ELEMENTS_PER_BLOB as usize - 1;
+        assert!(matches!(
+            err,
+            BlobVerificationError::IncompleteBlob(_, len) if len == expected_len
+        ));
+    }
+
+    #[test]
+    fn test_verify_blob_preimages_missing_blob_key() {
+        let (data, commitment, proof) = blob_commitment_proof();
+        let mut entries = blob_entries(&data, &commitment, &proof);
+        entries.remove(0);
+
+        let err = verify_blob_preimages(&entries).unwrap_err();
+        assert!(matches!(err, BlobVerificationError::MissingBlobKey(_)));
+    }
+}
@@ -1,6 +1,9 @@
 //! Errors for the `kona-preimage` crate.
 
+use crate::PreimageKey;
 use alloc::string::String;
+use alloy_primitives::B256;
+use core::time::Duration;
 use thiserror::Error;
 
 /// A [PreimageOracleError] is an enum that differentiates pipe-related errors from other errors
@@ -16,12 +19,37 @@ pub enum PreimageOracleError {
     /// The preimage key is invalid.
     #[error("Invalid preimage key.")]
     InvalidPreimageKey,
+    /// The type byte of a preimage key does not correspond to a known [PreimageKeyType].
+    ///
+    /// [PreimageKeyType]: crate::PreimageKeyType
+    #[error("Invalid preimage key type byte: {0:#04x}.")]
+    InvalidPreimageKeyType(u8),
     /// Key not found.
     #[error("Key not found.")]
     KeyNotFound,
     /// Buffer length mismatch.
     #[error("Buffer length mismatch. Expected {0}, got {1}.")]
     BufferLengthMismatch(usize, usize),
+    /// The length prefix of a preimage exceeds the maximum allowed length.
+    #[error("Preimage length {0} exceeds the maximum of {1} bytes.")]
+    LengthTooLarge(usize, usize),
+    /// The preimage served for a [PreimageKeyType::Keccak256] or [PreimageKeyType::Sha256] key
+    /// does not hash to the key.
+    ///
+    /// [PreimageKeyType::Keccak256]: crate::PreimageKeyType::Keccak256
+    /// [PreimageKeyType::Sha256]: crate::PreimageKeyType::Sha256
+    #[error("Preimage for key {key} hashes to {got_hash}.")]
+    Mismatch {
+        /// The requested key.
+        key: PreimageKey,
+        /// The hash of the preimage served for the key.
+        got_hash: B256,
+    },
+    /// A previous request left the channel in an unknown state, e.g. by timing out before the
+    /// host's response was fully read. All further requests are rejected, as their responses
+    /// could no longer be matched to their keys.
+    #[error("Preimage oracle channel is poisoned.")]
+    Poisoned,
     /// Other errors.
     #[error("Error in preimage server: {0}")]
     Other(String),
@@ -41,7 +69,38 @@ pub enum ChannelError {
     /// Unexpected EOF.
     #[error("Unexpected EOF in channel read operation.")]
     UnexpectedEOF,
+    /// The operation did not complete before its deadline.
+    #[error("Channel operation timed out after {0:?}.")]
+    Timeout(Duration),
 }
 
 /// A [Result] type for the [ChannelError] enum.
 pub type ChannelResult<T> = Result<T, ChannelError>;
+
+/// A [BlobVerificationError] is an enum that describes the error cases of
+/// [crate::verify_blob_preimages].
+#[cfg(feature = "kzg")]
+#[derive(Error, Debug)]
+pub enum BlobVerificationError {
+    /// The keccak256 preimage of a blob key is missing.
+    #[error("Missing blob key preimage for key {0}.")]
+    MissingBlobKey(B256),
+    /// A blob key commits to an evaluation point that is not a root of unity.
+    #[error("Invalid blob evaluation point {0}.")]
+    InvalidEvaluationPoint(B256),
+    /// A blob preimage has an invalid length.
+    #[error("Invalid length for blob preimage {0}: {1} bytes.")]
+    InvalidLength(B256, usize),
+    /// A blob is missing field elements.
+    #[error("Blob {0} is incomplete: {1} field elements.")]
+    IncompleteBlob(B256, usize),
+    /// The KZG proof of a blob is missing.
+    #[error("Missing KZG proof for blob {0}.")]
+    MissingProof(B256),
+    /// The KZG proof of a blob is invalid.
+    #[error("Invalid KZG proof for blob {0}.")]
+    InvalidProof(B256),
+    /// A KZG error.
+    #[error("KZG error: {0:?}")]
+    Kzg(c_kzg::Error),
+}
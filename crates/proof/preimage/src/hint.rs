@@ -1,22 +1,81 @@
 use crate::{
     Channel, HintReaderServer,
-    errors::{PreimageOracleError, PreimageOracleResult},
+    errors::{ChannelError, PreimageOracleError, PreimageOracleResult},
     traits::{HintRouter, HintWriterClient},
 };
-use alloc::{boxed::Box, format, string::String, vec};
+use alloc::{boxed::Box, format, string::String, sync::Arc, vec};
 use async_trait::async_trait;
+use core::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
 
 /// A [HintWriter] is a high-level interface to the hint channel. It provides a way to write hints
 /// to the host.
-#[derive(Debug, Clone, Copy)]
+///
+/// Clones of a [HintWriter] share its poisoned state, so that a hint which leaves the channel in
+/// an unknown state fails all further hints written over the same channel.
+#[derive(Debug, Clone)]
 pub struct HintWriter<C> {
     channel: C,
+    /// The deadline for each read and write on the channel, if any.
+    timeout: Option<Duration>,
+    /// Whether a previous hint left the channel in an unknown state.
+    poisoned: Arc<AtomicBool>,
 }
 
-impl<C> HintWriter<C> {
+impl<C> HintWriter<C>
+where
+    C: Channel + Send + Sync,
+{
     /// Create a new [HintWriter] from a [Channel].
-    pub const fn new(channel: C) -> Self {
-        Self { channel }
+    pub fn new(channel: C) -> Self {
+        Self { channel, timeout: None, poisoned: Default::default() }
+    }
+
+    /// Sets the deadline for each read and write on the channel. Hints that time out fail with
+    /// [ChannelError::Timeout] and poison the writer, as a partially written hint or a late
+    /// acknowledgement would otherwise be attributed to the next hint.
+    ///
+    /// The deadline is only enforced by channels that override [Channel::read_with_timeout]
+    /// and [Channel::write_with_timeout], such as the native `FileChannel`. Defaults to `None`.
+    pub const fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Returns whether a previous hint left the channel in an unknown state. A poisoned writer
+    /// rejects all hints with [PreimageOracleError::Poisoned].
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
+    /// Reads exactly `buf.len()` bytes from the channel, within the deadline if one is set. The
+    /// writer is poisoned if the deadline passes.
+    async fn read_exact(&self, buf: &mut [u8]) -> PreimageOracleResult<()> {
+        let result = match self.timeout {
+            Some(timeout) => self.channel.read_with_timeout(buf, timeout).await,
+            None => self.channel.read_exact(buf).await,
+        };
+        if matches!(result, Err(ChannelError::Timeout(_))) {
+            self.poisoned.store(true, Ordering::Release);
+        }
+        result?;
+        Ok(())
+    }
+
+    /// Writes `buf` to the channel, within the deadline if one is set. The writer is poisoned if
+    /// the deadline passes.
+    async fn write_all(&self, buf: &[u8]) -> PreimageOracleResult<()> {
+        let result = match self.timeout {
+            Some(timeout) => self.channel.write_with_timeout(buf, timeout).await,
+            None => self.channel.write(buf).await,
+        };
+        if matches!(result, Err(ChannelError::Timeout(_))) {
+            self.poisoned.store(true, Ordering::Release);
+        }
+        result?;
+        Ok(())
     }
 }
 
@@ -29,17 +88,20 @@ where
     /// until all data has been written.
     async fn write(&self, hint: &str) -> PreimageOracleResult<()> {
         trace!(target: "hint_writer", "Writing hint \"{hint}\"");
+        if self.is_poisoned() {
+            return Err(PreimageOracleError::Poisoned);
+        }
 
         // Form the hint into a byte buffer. The format is a 4-byte big-endian length prefix
         // followed by the hint string.
-        self.channel.write(u32::to_be_bytes(hint.len() as u32).as_ref()).await?;
-        self.channel.write(hint.as_bytes()).await?;
+        self.write_all(u32::to_be_bytes(hint.len() as u32).as_ref()).await?;
+        self.write_all(hint.as_bytes()).await?;
 
         trace!(target: "hint_writer", "Successfully wrote hint");
 
         // Read the hint acknowledgement from the host.
         let mut hint_ack = [0u8; 1];
-        self.channel.read_exact(&mut hint_ack).await?;
+        self.read_exact(&mut hint_ack).await?;
 
         trace!(target: "hint_writer", "Received hint acknowledgement");
 
@@ -47,6 +109,30 @@ where
     }
 }
 
+/// A [HintWriterClient] that is selected at runtime, allowing a single client binary to either
+/// send hints to the host over a [HintWriter] or ignore them, e.g. when running within a zkVM
+/// where all preimages are provided up-front.
+#[derive(Debug, Clone)]
+pub enum AnyHintWriter<C> {
+    /// Sends hints to the host over the [HintWriter].
+    Pipe(HintWriter<C>),
+    /// Discards all hints.
+    Noop,
+}
+
+#[async_trait]
+impl<C> HintWriterClient for AnyHintWriter<C>
+where
+    C: Channel + Send + Sync,
+{
+    async fn write(&self, hint: &str) -> PreimageOracleResult<()> {
+        match self {
+            Self::Pipe(writer) => writer.write(hint).await,
+            Self::Noop => Ok(()),
+        }
+    }
+}
+
 /// A [HintReader] is a router for hints sent by the [HintWriter] from the client program. It
 /// provides a way for the host to prepare preimages for reading.
 #[derive(Debug, Clone, Copy)]
@@ -116,7 +202,7 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::native_channel::BidirectionalChannel;
+    use crate::native_channel::{BidirectionalChannel, NativeChannel};
     use alloc::{sync::Arc, vec::Vec};
     use tokio::sync::Mutex;
 
@@ -141,6 +227,31 @@ mod test {
         }
     }
 
+    #[tokio::test]
+    async fn test_any_hint_writer_noop() {
+        // The no-op writer never waits on an acknowledgement from a host.
+        let hint_writer = AnyHintWriter::<NativeChannel>::Noop;
+        hint_writer.write("test-hint 0xfacade").await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_any_hint_writer_pipe() {
+        const MOCK_DATA: &str = "test-hint 0xfacade";
+
+        let incoming_hints = Arc::new(Mutex::new(Vec::new()));
+        let hint_channel = BidirectionalChannel::new().unwrap();
+
+        let client = tokio::task::spawn(async move {
+            let hint_writer = AnyHintWriter::Pipe(HintWriter::new(hint_channel.client));
+            hint_writer.write(MOCK_DATA).await
+        });
+        let router = TestRouter { incoming_hints: Arc::clone(&incoming_hints) };
+        HintReader::new(hint_channel.host).next_hint(&router).await.unwrap();
+
+        client.await.unwrap().unwrap();
+        assert_eq!(*incoming_hints.lock().await, [MOCK_DATA]);
+    }
+
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
     async fn test_unblock_on_bad_utf8() {
         let mock_data = [0xf0, 0x90, 0x28, 0xbc];
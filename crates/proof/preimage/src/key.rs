@@ -2,6 +2,7 @@
 //! the preimage oracle.
 
 use alloy_primitives::{B256, Keccak256, U256};
+use core::str::FromStr;
 #[cfg(feature = "rkyv")]
 use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
 #[cfg(feature = "serde")]
@@ -52,7 +53,7 @@ impl TryFrom<u8> for PreimageKeyType {
             4 => Self::Sha256,
             5 => Self::Blob,
             6 => Self::Precompile,
-            _ => return Err(PreimageOracleError::InvalidPreimageKey),
+            _ => return Err(PreimageOracleError::InvalidPreimageKeyType(value)),
         };
         Ok(key_type)
     }
@@ -158,6 +159,17 @@ impl core::fmt::Display for PreimageKey {
     }
 }
 
+impl FromStr for PreimageKey {
+    type Err = PreimageOracleError;
+
+    /// Parses a [PreimageKey] from its 32-byte hex representation, as rendered by its
+    /// [core::fmt::Display] implementation. The `0x` prefix is optional.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw = B256::from_str(s).map_err(|_| PreimageOracleError::InvalidPreimageKey)?;
+        Self::try_from(raw.0)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -209,6 +221,53 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_preimage_key_try_from_bytes() {
+        let types = [
+            PreimageKeyType::Local,
+            PreimageKeyType::Keccak256,
+            PreimageKeyType::GlobalGeneric,
+            PreimageKeyType::Sha256,
+            PreimageKeyType::Blob,
+            PreimageKeyType::Precompile,
+        ];
+
+        for key_type in types {
+            let mut raw = [0xABu8; 32];
+            raw[0] = key_type as u8;
+            let key = PreimageKey::try_from(raw).unwrap();
+            assert_eq!(key.key_type(), key_type);
+            assert_eq!(<[u8; 32]>::from(key), raw);
+        }
+    }
+
+    #[test]
+    fn test_preimage_key_try_from_invalid_type() {
+        let mut raw = [0xABu8; 32];
+        raw[0] = 0x07;
+        let err = PreimageKey::try_from(raw).unwrap_err();
+        assert!(matches!(err, PreimageOracleError::InvalidPreimageKeyType(0x07)));
+        assert_eq!(err.to_string(), "Invalid preimage key type byte: 0x07.");
+    }
+
+    #[test]
+    fn test_preimage_key_display_roundtrip() {
+        let key = PreimageKey::new([0xFFu8; 32], PreimageKeyType::Blob);
+        let rendered = key.to_string();
+        assert_eq!(rendered.parse::<PreimageKey>().unwrap(), key);
+        assert_eq!(rendered.trim_start_matches("0x").parse::<PreimageKey>().unwrap(), key);
+
+        assert!(matches!(
+            "0x07ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+                .parse::<PreimageKey>(),
+            Err(PreimageOracleError::InvalidPreimageKeyType(0x07))
+        ));
+        assert!(matches!(
+            "not a key".parse::<PreimageKey>(),
+            Err(PreimageOracleError::InvalidPreimageKey)
+        ));
+    }
+
     #[test]
     fn test_preimage_keys() {
         let types = [
@@ -18,10 +18,16 @@ mod key;
 pub use key::{PreimageKey, PreimageKeyType};
 
 mod oracle;
-pub use oracle::{OracleReader, OracleServer};
+pub use oracle::{
+    DEFAULT_MAX_PREIMAGE_LENGTH, MAX_PIPELINED_REQUESTS, OracleReader, OracleServer,
+    PREIMAGE_NOT_FOUND,
+};
 
 mod hint;
-pub use hint::{HintReader, HintWriter};
+pub use hint::{AnyHintWriter, HintReader, HintWriter};
+
+mod router;
+pub use router::CompositeHintRouter;
 
 mod traits;
 pub use traits::{
@@ -29,6 +35,13 @@ pub use traits::{
     PreimageOracleClient, PreimageOracleServer, PreimageServerBackend,
 };
 
+#[cfg(feature = "blob")]
+mod blob;
+#[cfg(feature = "blob")]
+pub use blob::ROOTS_OF_UNITY;
+#[cfg(feature = "kzg")]
+pub use blob::verify_blob_preimages;
+
 #[cfg(feature = "std")]
 mod native_channel;
 #[cfg(feature = "std")]
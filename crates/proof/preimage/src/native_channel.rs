@@ -56,6 +56,11 @@ impl Channel for NativeChannel {
     }
 
     async fn write(&self, buf: &[u8]) -> ChannelResult<usize> {
+        // Writing nothing to a byte stream is a no-op, so no empty message is queued for the
+        // reader, which would otherwise never read it.
+        if buf.is_empty() {
+            return Ok(0);
+        }
         self.write.send(buf.to_vec()).await.map_err(|_| ChannelError::Closed)?;
         Ok(buf.len())
     }
@@ -1,37 +1,226 @@
 use crate::{
-    PreimageKey, PreimageOracleClient, PreimageOracleServer,
-    errors::{PreimageOracleError, PreimageOracleResult},
+    PreimageKey, PreimageKeyType, PreimageOracleClient, PreimageOracleServer,
+    errors::{ChannelError, PreimageOracleError, PreimageOracleResult},
     traits::{Channel, PreimageFetcher},
 };
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use alloy_primitives::{B256, keccak256};
+use core::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use sha2::{Digest, Sha256};
+
+/// The default maximum length of a preimage that the [OracleReader] will accept, in bytes.
+pub const DEFAULT_MAX_PREIMAGE_LENGTH: usize = 16 * 1024 * 1024;
+
+/// The length prefix sent by the [OracleServer] in place of a preimage that the host definitively
+/// does not have, if the not-found sentinel is enabled with
+/// [OracleServer::with_not_found_sentinel].
+///
+/// This is an extension of the preimage oracle protocol, see the [crate-level docs](crate).
+pub const PREIMAGE_NOT_FOUND: u64 = u64::MAX;
+
+/// The maximum number of requests that the [OracleReader] writes to the channel before reading
+/// their responses, when pipelining is enabled. The outstanding keys must fit within the channel's
+/// buffer, or the client and host could block on each other's writes.
+pub const MAX_PIPELINED_REQUESTS: usize = 64;
 
 /// An [OracleReader] is a high-level interface to the preimage oracle channel.
-#[derive(Debug, Clone, Copy)]
+///
+/// Clones of an [OracleReader] share its poisoned state, so that a request which leaves the
+/// channel in an unknown state fails all further requests made over the same channel.
+#[derive(Debug, Clone)]
 pub struct OracleReader<C> {
     channel: C,
+    /// The maximum length of a preimage that the reader will allocate a buffer for.
+    max_length: usize,
+    /// Whether [PreimageOracleClient::get_many] pipelines its requests on the channel.
+    pipelined: bool,
+    /// Whether hash-keyed preimages are verified against their key before being returned.
+    verify: bool,
+    /// The deadline for each read and write on the channel, if any.
+    timeout: Option<Duration>,
+    /// Whether a [PREIMAGE_NOT_FOUND] length prefix is read as a missing preimage.
+    not_found_sentinel: bool,
+    /// Whether a previous request left the channel in an unknown state.
+    poisoned: Arc<AtomicBool>,
 }
 
 impl<C> OracleReader<C>
 where
-    C: Channel,
+    C: Channel + Send + Sync,
 {
     /// Create a new [OracleReader] from a [Channel].
-    pub const fn new(channel: C) -> Self {
-        Self { channel }
+    pub fn new(channel: C) -> Self {
+        Self {
+            channel,
+            max_length: DEFAULT_MAX_PREIMAGE_LENGTH,
+            pipelined: false,
+            verify: false,
+            timeout: None,
+            not_found_sentinel: false,
+            poisoned: Default::default(),
+        }
+    }
+
+    /// Sets the maximum length of a preimage that the [OracleReader] will accept. Length prefixes
+    /// received from the host that exceed this length are rejected before allocating.
+    ///
+    /// Defaults to [DEFAULT_MAX_PREIMAGE_LENGTH].
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = max_length;
+        self
+    }
+
+    /// Enables pipelining of the requests issued by [PreimageOracleClient::get_many]. Up to
+    /// [MAX_PIPELINED_REQUESTS] keys are written to the channel before their responses are read,
+    /// saving a round trip per preimage.
+    ///
+    /// This must only be enabled when the host serves requests in order from a buffered channel,
+    /// such as the native host. Within the FPVM, writing a key replaces the previously requested
+    /// key, so pipelined requests would be answered incorrectly.
+    pub const fn with_pipelining(mut self, pipelined: bool) -> Self {
+        self.pipelined = pipelined;
+        self
+    }
+
+    /// Enables verification of the preimages served for [PreimageKeyType::Keccak256] and
+    /// [PreimageKeyType::Sha256] keys. Preimages that do not hash to their key are rejected with
+    /// [PreimageOracleError::Mismatch], catching faulty hosts at the source.
+    ///
+    /// Defaults to `false`.
+    pub const fn with_verification(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Sets the deadline for each read and write on the channel. Requests that time out fail with
+    /// [ChannelError::Timeout] and poison the reader, as the host's response may still arrive and
+    /// would otherwise be read as the response to the next request.
+    ///
+    /// The deadline is only enforced by channels that override [Channel::read_with_timeout]
+    /// and [Channel::write_with_timeout], such as the native `FileChannel`. Defaults to `None`.
+    pub const fn with_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Enables reading a [PREIMAGE_NOT_FOUND] length prefix as a missing preimage, failing the
+    /// request with [PreimageOracleError::KeyNotFound].
+    ///
+    /// This must only be enabled when the host's [OracleServer] has the sentinel enabled as well,
+    /// see the [crate-level docs](crate). Defaults to `false`.
+    pub const fn with_not_found_sentinel(mut self, enabled: bool) -> Self {
+        self.not_found_sentinel = enabled;
+        self
+    }
+
+    /// Returns whether a previous request left the channel in an unknown state. A poisoned reader
+    /// rejects all requests with [PreimageOracleError::Poisoned].
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Acquire)
+    }
+
+    /// Marks the channel as being in an unknown state.
+    fn poison(&self) {
+        self.poisoned.store(true, Ordering::Release);
+    }
+
+    /// Fails with [PreimageOracleError::Poisoned] if the reader has been poisoned.
+    fn ensure_not_poisoned(&self) -> PreimageOracleResult<()> {
+        if self.is_poisoned() {
+            return Err(PreimageOracleError::Poisoned);
+        }
+        Ok(())
+    }
+
+    /// Reads exactly `buf.len()` bytes from the channel, within the deadline if one is set. The
+    /// reader is poisoned if the deadline passes.
+    async fn read_exact(&self, buf: &mut [u8]) -> PreimageOracleResult<()> {
+        let result = match self.timeout {
+            Some(timeout) => self.channel.read_with_timeout(buf, timeout).await,
+            None => self.channel.read_exact(buf).await,
+        };
+        if matches!(result, Err(ChannelError::Timeout(_))) {
+            self.poison();
+        }
+        result?;
+        Ok(())
+    }
+
+    /// Writes `buf` to the channel, within the deadline if one is set. The reader is poisoned if
+    /// the deadline passes.
+    async fn write(&self, buf: &[u8]) -> PreimageOracleResult<()> {
+        let result = match self.timeout {
+            Some(timeout) => self.channel.write_with_timeout(buf, timeout).await,
+            None => self.channel.write(buf).await,
+        };
+        if matches!(result, Err(ChannelError::Timeout(_))) {
+            self.poison();
+        }
+        result?;
+        Ok(())
     }
 
     /// Set the preimage key for the global oracle reader. This will overwrite any existing key, and
     /// block until the host has prepared the preimage and responded with the length of the
     /// preimage.
     async fn write_key(&self, key: PreimageKey) -> PreimageOracleResult<usize> {
-        // Write the key to the host so that it can prepare the preimage.
+        self.send_key(key).await?;
+        self.read_length().await
+    }
+
+    /// Writes the key to the host so that it can prepare the preimage.
+    async fn send_key(&self, key: PreimageKey) -> PreimageOracleResult<()> {
         let key_bytes: [u8; 32] = key.into();
-        self.channel.write(&key_bytes).await?;
+        self.write(&key_bytes).await
+    }
 
-        // Read the length prefix and reset the cursor.
+    /// Reads the length prefix of the next preimage sent by the host.
+    async fn read_length(&self) -> PreimageOracleResult<usize> {
         let mut length_buffer = [0u8; 8];
-        self.channel.read_exact(&mut length_buffer).await?;
-        Ok(u64::from_be_bytes(length_buffer) as usize)
+        self.read_exact(&mut length_buffer).await?;
+        match u64::from_be_bytes(length_buffer) {
+            PREIMAGE_NOT_FOUND if self.not_found_sentinel => Err(PreimageOracleError::KeyNotFound),
+            length => Ok(length as usize),
+        }
+    }
+
+    /// Reads a preimage of the given length from the host into a new heap allocated `Vec<u8>`.
+    async fn read_preimage(&self, length: usize) -> PreimageOracleResult<Vec<u8>> {
+        if length == 0 {
+            return Ok(Default::default());
+        }
+
+        // Guard against a faulty or hostile host causing an unbounded allocation. The preimage is
+        // left unread on the channel, so the reader can no longer match responses to requests.
+        if length > self.max_length {
+            self.poison();
+            return Err(PreimageOracleError::LengthTooLarge(length, self.max_length));
+        }
+
+        let mut data_buffer = alloc::vec![0; length];
+        self.read_exact(&mut data_buffer).await?;
+        Ok(data_buffer)
+    }
+
+    /// Verifies that the preimage hashes to its key, if verification is enabled. Only
+    /// [PreimageKeyType::Keccak256] and [PreimageKeyType::Sha256] keys are checked.
+    fn verify_preimage(&self, key: PreimageKey, data: &[u8]) -> PreimageOracleResult<()> {
+        if !self.verify {
+            return Ok(());
+        }
+
+        let got_hash = match key.key_type() {
+            PreimageKeyType::Keccak256 => keccak256(data),
+            PreimageKeyType::Sha256 => B256::from_slice(&Sha256::digest(data)),
+            _ => return Ok(()),
+        };
+        if PreimageKey::new(*got_hash, key.key_type()) != key {
+            return Err(PreimageOracleError::Mismatch { key, got_hash });
+        }
+        Ok(())
     }
 }
 
@@ -44,56 +233,101 @@ where
     /// heap allocated `Vec<u8>`
     async fn get(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
         trace!(target: "oracle_client", "Requesting data from preimage oracle. Key {key}");
+        self.ensure_not_poisoned()?;
 
         let length = self.write_key(key).await?;
 
-        if length == 0 {
-            return Ok(Default::default());
-        }
-
-        let mut data_buffer = alloc::vec![0; length];
-
         trace!(target: "oracle_client", "Reading data from preimage oracle. Key {key}");
 
-        // Grab a read lock on the preimage channel to read the data.
-        self.channel.read_exact(&mut data_buffer).await?;
+        let data = self.read_preimage(length).await?;
+        self.verify_preimage(key, &data)?;
 
         trace!(target: "oracle_client", "Successfully read data from preimage oracle. Key: {key}");
 
-        Ok(data_buffer)
+        Ok(data)
     }
 
     /// Get the data corresponding to the currently set key from the host. Write the data into the
     /// provided buffer
     async fn get_exact(&self, key: PreimageKey, buf: &mut [u8]) -> PreimageOracleResult<()> {
         trace!(target: "oracle_client", "Requesting data from preimage oracle. Key {key}");
+        self.ensure_not_poisoned()?;
 
         // Write the key to the host and read the length of the preimage.
         let length = self.write_key(key).await?;
 
         trace!(target: "oracle_client", "Reading data from preimage oracle. Key {key}");
 
-        // Ensure the buffer is the correct size.
+        // Ensure the buffer is the correct size. The preimage is still consumed from the channel,
+        // so that the response to the next request is not read in its place.
         if buf.len() != length {
+            self.read_preimage(length).await?;
             return Err(PreimageOracleError::BufferLengthMismatch(length, buf.len()));
         }
 
-        if length == 0 {
-            return Ok(());
+        if length != 0 {
+            self.read_exact(buf).await?;
         }
-
-        self.channel.read_exact(buf).await?;
+        self.verify_preimage(key, buf)?;
 
         trace!(target: "oracle_client", "Successfully read data from preimage oracle. Key: {key}");
 
         Ok(())
     }
+
+    /// Get the data corresponding to each of the given keys from the host. If pipelining is
+    /// enabled, the keys are written to the host in batches of [MAX_PIPELINED_REQUESTS] before the
+    /// responses are read back in order.
+    async fn get_many(&self, keys: &[PreimageKey]) -> PreimageOracleResult<Vec<Vec<u8>>> {
+        if !self.pipelined {
+            let mut values = Vec::with_capacity(keys.len());
+            for key in keys {
+                values.push(self.get(*key).await?);
+            }
+            return Ok(values);
+        }
+
+        trace!(target: "oracle_client", "Requesting {} preimages from preimage oracle", keys.len());
+        self.ensure_not_poisoned()?;
+
+        let mut values = Vec::with_capacity(keys.len());
+        for batch in keys.chunks(MAX_PIPELINED_REQUESTS) {
+            for key in batch {
+                self.send_key(*key).await?;
+            }
+
+            // Read every response in the batch, even after a request fails, so that the responses
+            // to later requests are not read in place of the remaining ones.
+            let mut error = None;
+            for key in batch {
+                let result = match self.read_length().await {
+                    Ok(length) => self.read_preimage(length).await,
+                    Err(e) => Err(e),
+                };
+                match result.and_then(|data| self.verify_preimage(*key, &data).map(|_| data)) {
+                    Ok(data) => values.push(data),
+                    // The remaining responses can't be located within a poisoned channel.
+                    Err(e) if self.is_poisoned() => return Err(error.unwrap_or(e)),
+                    Err(e) => {
+                        error.get_or_insert(e);
+                    }
+                }
+            }
+            if let Some(e) = error {
+                return Err(e);
+            }
+        }
+        Ok(values)
+    }
 }
 
 /// An [OracleServer] is a router for the host to serve data back to the client [OracleReader].
 #[derive(Debug, Clone, Copy)]
 pub struct OracleServer<C> {
     channel: C,
+    /// Whether keys that the [PreimageFetcher] does not contain are answered with
+    /// [PREIMAGE_NOT_FOUND].
+    not_found_sentinel: bool,
 }
 
 impl<C> OracleServer<C>
@@ -102,7 +336,17 @@ where
 {
     /// Create a new [OracleServer] from a [Channel].
     pub const fn new(chanel: C) -> Self {
-        Self { channel: chanel }
+        Self { channel: chanel, not_found_sentinel: false }
+    }
+
+    /// Enables answering requests for keys that the [PreimageFetcher] definitively does not
+    /// contain with a [PREIMAGE_NOT_FOUND] length prefix, rather than attempting a full fetch.
+    ///
+    /// This must only be enabled when the client's [OracleReader] has the sentinel enabled as
+    /// well, see the [crate-level docs](crate). Defaults to `false`.
+    pub const fn with_not_found_sentinel(mut self, enabled: bool) -> Self {
+        self.not_found_sentinel = enabled;
+        self
     }
 }
 
@@ -120,6 +364,13 @@ where
         self.channel.read_exact(&mut buf).await?;
         let preimage_key = PreimageKey::try_from(buf)?;
 
+        // Fail fast on keys that the fetcher definitively doesn't have.
+        if self.not_found_sentinel && !fetcher.contains(preimage_key).await {
+            trace!(target: "oracle_server", "Preimage for key {preimage_key} not found");
+            self.channel.write(PREIMAGE_NOT_FOUND.to_be_bytes().as_ref()).await?;
+            return Ok(());
+        }
+
         trace!(target: "oracle_server", "Fetching preimage for key {preimage_key}");
 
         // Fetch the preimage value from the preimage getter.
@@ -138,7 +389,10 @@ where
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{PreimageKeyType, native_channel::BidirectionalChannel};
+    use crate::{
+        PreimageKeyType,
+        native_channel::{BidirectionalChannel, NativeChannel},
+    };
     use alloc::sync::Arc;
     use alloy_primitives::keccak256;
     use std::collections::HashMap;
@@ -154,6 +408,52 @@ mod test {
             let read_lock = self.preimages.lock().await;
             read_lock.get(&key).cloned().ok_or(PreimageOracleError::KeyNotFound)
         }
+
+        async fn contains(&self, key: PreimageKey) -> bool {
+            self.preimages.lock().await.contains_key(&key)
+        }
+    }
+
+    /// Serves a single preimage request by responding with the given length prefix, and no data.
+    async fn respond_with_length(host: NativeChannel, length: u64) {
+        let mut key = [0u8; 32];
+        host.read_exact(&mut key).await.unwrap();
+        host.write(&length.to_be_bytes()).await.unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_oracle_reader_rejects_oversized_length() {
+        let preimage_channel = BidirectionalChannel::new().unwrap();
+        let key = PreimageKey::new([0xFF; 32], PreimageKeyType::Keccak256);
+
+        let length = u64::MAX - 1;
+        let host = tokio::task::spawn(respond_with_length(preimage_channel.host, length));
+        let oracle_reader = OracleReader::new(preimage_channel.client);
+        let res = oracle_reader.get(key).await;
+        host.await.unwrap();
+
+        assert!(matches!(
+            res,
+            Err(PreimageOracleError::LengthTooLarge(l, DEFAULT_MAX_PREIMAGE_LENGTH))
+                if l == length as usize
+        ));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_oracle_reader_custom_max_length() {
+        let preimage_channel = BidirectionalChannel::new().unwrap();
+        let key = PreimageKey::new([0xFF; 32], PreimageKeyType::Keccak256);
+
+        let host = tokio::task::spawn(respond_with_length(preimage_channel.host, 33));
+        let oracle_reader = OracleReader::new(preimage_channel.client).with_max_length(32);
+        let res = oracle_reader.get(key).await;
+        host.await.unwrap();
+
+        assert!(matches!(res, Err(PreimageOracleError::LengthTooLarge(33, 32))));
+
+        // The unread preimage leaves the channel in an unknown state.
+        assert!(oracle_reader.is_poisoned());
+        assert!(matches!(oracle_reader.get(key).await, Err(PreimageOracleError::Poisoned)));
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
@@ -245,4 +545,223 @@ mod test {
         assert_eq!(contents_a, MOCK_DATA_A);
         assert_eq!(contents_b, MOCK_DATA_B);
     }
+
+    /// Serves the given preimages to a new [OracleReader] with verification enabled, returning the
+    /// result of requesting each key.
+    async fn get_verified(
+        preimages: HashMap<PreimageKey, Vec<u8>>,
+        keys: &[PreimageKey],
+    ) -> Vec<PreimageOracleResult<Vec<u8>>> {
+        let preimage_channel = BidirectionalChannel::new().unwrap();
+        tokio::task::spawn(async move {
+            let oracle_server = OracleServer::new(preimage_channel.host);
+            let test_fetcher = TestFetcher { preimages: Arc::new(Mutex::new(preimages)) };
+            while oracle_server.next_preimage_request(&test_fetcher).await.is_ok() {}
+        });
+
+        let oracle_reader = OracleReader::new(preimage_channel.client).with_verification(true);
+        let mut results = Vec::with_capacity(keys.len());
+        for key in keys {
+            results.push(oracle_reader.get(*key).await);
+        }
+        results
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_oracle_reader_verification() {
+        const MOCK_DATA: &[u8] = b"preimage";
+        let keccak_key = PreimageKey::new(*keccak256(MOCK_DATA), PreimageKeyType::Keccak256);
+        let sha256_key =
+            PreimageKey::new(Sha256::digest(MOCK_DATA).into(), PreimageKeyType::Sha256);
+        let local_key = PreimageKey::new_local(1);
+
+        let preimages = [keccak_key, sha256_key, local_key]
+            .into_iter()
+            .map(|key| (key, MOCK_DATA.to_vec()))
+            .collect::<HashMap<_, _>>();
+        let results = get_verified(preimages, &[keccak_key, sha256_key, local_key]).await;
+        for res in results {
+            assert_eq!(res.unwrap(), MOCK_DATA);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_oracle_reader_verification_mismatch() {
+        const MOCK_DATA: &[u8] = b"preimage";
+        const BAD_DATA: &[u8] = b"not the preimage";
+        let keccak_key = PreimageKey::new(*keccak256(MOCK_DATA), PreimageKeyType::Keccak256);
+        let sha256_key =
+            PreimageKey::new(Sha256::digest(MOCK_DATA).into(), PreimageKeyType::Sha256);
+        let local_key = PreimageKey::new_local(1);
+
+        let preimages = [keccak_key, sha256_key, local_key]
+            .into_iter()
+            .map(|key| (key, BAD_DATA.to_vec()))
+            .collect::<HashMap<_, _>>();
+        let results = get_verified(preimages, &[keccak_key, sha256_key, local_key]).await;
+        assert!(matches!(
+            &results[0],
+            Err(PreimageOracleError::Mismatch { key, got_hash })
+                if *key == keccak_key && *got_hash == keccak256(BAD_DATA)
+        ));
+        assert!(matches!(
+            &results[1],
+            Err(PreimageOracleError::Mismatch { key, got_hash })
+                if *key == sha256_key && got_hash[..] == Sha256::digest(BAD_DATA)[..]
+        ));
+
+        // Local keys are not hash-keyed, so their preimages cannot be verified.
+        assert_eq!(results[2].as_ref().unwrap(), BAD_DATA);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_oracle_server_not_found() {
+        let key = PreimageKey::new([0xFF; 32], PreimageKeyType::Keccak256);
+        let preimage_channel = BidirectionalChannel::new().unwrap();
+
+        let server = tokio::task::spawn(async move {
+            let oracle_server =
+                OracleServer::new(preimage_channel.host).with_not_found_sentinel(true);
+            let test_fetcher = TestFetcher { preimages: Default::default() };
+            oracle_server.next_preimage_request(&test_fetcher).await
+        });
+
+        let oracle_reader =
+            OracleReader::new(preimage_channel.client).with_not_found_sentinel(true);
+        let res = oracle_reader.get(key).await;
+        assert!(matches!(res, Err(PreimageOracleError::KeyNotFound)));
+
+        // The request is served without an error on the host side.
+        server.await.unwrap().unwrap();
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_oracle_not_found_sentinel_disabled() {
+        let key = PreimageKey::new([0xFF; 32], PreimageKeyType::Keccak256);
+        let preimage_channel = BidirectionalChannel::new().unwrap();
+
+        // By default, the server attempts the fetch rather than answering with the sentinel.
+        let oracle_server = OracleServer::new(preimage_channel.host.clone());
+        let test_fetcher = TestFetcher { preimages: Default::default() };
+        preimage_channel.client.write(&<[u8; 32]>::from(key)).await.unwrap();
+        let res = oracle_server.next_preimage_request(&test_fetcher).await;
+        assert!(matches!(res, Err(PreimageOracleError::KeyNotFound)));
+
+        // By default, the reader treats the sentinel as a regular length prefix.
+        let host =
+            tokio::task::spawn(respond_with_length(preimage_channel.host, PREIMAGE_NOT_FOUND));
+        let res = OracleReader::new(preimage_channel.client).get(key).await;
+        host.await.unwrap();
+        assert!(matches!(res, Err(PreimageOracleError::LengthTooLarge(_, _))));
+    }
+
+    /// A [PreimageOracleClient] that serves preimages from memory, using the default
+    /// [PreimageOracleClient::get_many] implementation.
+    struct MockOracle {
+        preimages: HashMap<PreimageKey, Vec<u8>>,
+    }
+
+    #[async_trait::async_trait]
+    impl PreimageOracleClient for MockOracle {
+        async fn get(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
+            self.preimages.get(&key).cloned().ok_or(PreimageOracleError::KeyNotFound)
+        }
+
+        async fn get_exact(&self, key: PreimageKey, buf: &mut [u8]) -> PreimageOracleResult<()> {
+            buf.copy_from_slice(&self.get(key).await?);
+            Ok(())
+        }
+    }
+
+    fn keyed_preimages(count: u64) -> (Vec<PreimageKey>, HashMap<PreimageKey, Vec<u8>>) {
+        let data = (0..count).map(|i| i.to_be_bytes().repeat(i as usize % 5)).collect::<Vec<_>>();
+        let keys = data
+            .iter()
+            .map(|d| PreimageKey::new(*keccak256(d), PreimageKeyType::Keccak256))
+            .collect::<Vec<_>>();
+        (keys.clone(), keys.into_iter().zip(data).collect())
+    }
+
+    #[tokio::test]
+    async fn test_get_many_default_preserves_order() {
+        let (mut keys, preimages) = keyed_preimages(8);
+        keys.reverse();
+        let oracle = MockOracle { preimages };
+
+        let values = oracle.get_many(&keys).await.unwrap();
+        for (key, value) in keys.iter().zip(values) {
+            assert_eq!(oracle.preimages[key], value);
+        }
+
+        let missing = PreimageKey::new([0xFF; 32], PreimageKeyType::Keccak256);
+        let res = oracle.get_many(&[keys[0], missing]).await;
+        assert!(matches!(res, Err(PreimageOracleError::KeyNotFound)));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_get_many_pipelined() {
+        // Span multiple pipelined batches, with a partial final batch.
+        let (keys, preimages) = keyed_preimages(MAX_PIPELINED_REQUESTS as u64 * 2 + 3);
+        let expected = keys.iter().map(|k| preimages[k].clone()).collect::<Vec<_>>();
+        let preimages = Arc::new(Mutex::new(preimages));
+
+        let preimage_channel = BidirectionalChannel::new().unwrap();
+        tokio::task::spawn(async move {
+            let oracle_server = OracleServer::new(preimage_channel.host);
+            let test_fetcher = TestFetcher { preimages };
+            while oracle_server.next_preimage_request(&test_fetcher).await.is_ok() {}
+        });
+
+        let oracle_reader = OracleReader::new(preimage_channel.client).with_pipelining(true);
+        assert_eq!(oracle_reader.get_many(&keys).await.unwrap(), expected);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_get_many_pipelined_failure_mid_batch() {
+        let (keys, mut preimages) = keyed_preimages(8);
+        let missing = PreimageKey::new([0xFF; 32], PreimageKeyType::Keccak256);
+        preimages.insert(keys[4], b"not the preimage".to_vec());
+        let expected = preimages[&keys[7]].clone();
+
+        let preimage_channel = BidirectionalChannel::new().unwrap();
+        tokio::task::spawn(async move {
+            let oracle_server =
+                OracleServer::new(preimage_channel.host).with_not_found_sentinel(true);
+            let test_fetcher = TestFetcher { preimages: Arc::new(Mutex::new(preimages)) };
+            while oracle_server.next_preimage_request(&test_fetcher).await.is_ok() {}
+        });
+
+        // Fail one request with a missing key, and a later one with a mismatched preimage.
+        let oracle_reader = OracleReader::new(preimage_channel.client)
+            .with_pipelining(true)
+            .with_verification(true)
+            .with_not_found_sentinel(true);
+        let batch = [&keys[..2], &[missing], &keys[2..]].concat();
+        let res = oracle_reader.get_many(&batch).await;
+        assert!(matches!(res, Err(PreimageOracleError::KeyNotFound)));
+
+        // The remaining responses of the batch were consumed, so later requests stay in sync.
+        assert!(!oracle_reader.is_poisoned());
+        assert_eq!(oracle_reader.get(keys[7]).await.unwrap(), expected);
+        assert_eq!(oracle_reader.get_many(&keys[6..]).await.unwrap()[1], expected);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_oracle_reader_get_exact_length_mismatch() {
+        let (keys, preimages) = keyed_preimages(4);
+        let expected = preimages[&keys[3]].clone();
+
+        let preimage_channel = BidirectionalChannel::new().unwrap();
+        tokio::task::spawn(async move {
+            let oracle_server = OracleServer::new(preimage_channel.host);
+            let test_fetcher = TestFetcher { preimages: Arc::new(Mutex::new(preimages)) };
+            while oracle_server.next_preimage_request(&test_fetcher).await.is_ok() {}
+        });
+
+        let oracle_reader = OracleReader::new(preimage_channel.client);
+        let mut buf = [0u8; 1];
+        let res = oracle_reader.get_exact(keys[3], &mut buf).await;
+        assert!(matches!(res, Err(PreimageOracleError::BufferLengthMismatch(24, 1))));
+        assert_eq!(oracle_reader.get(keys[3]).await.unwrap(), expected);
+    }
 }
@@ -0,0 +1,184 @@
+//! Contains the [CompositeHintRouter], a [HintRouter] that fans hints out to multiple routers.
+
+use crate::{
+    HintRouter,
+    errors::{PreimageOracleError, PreimageOracleResult},
+};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use async_trait::async_trait;
+
+/// A [CompositeHintRouter] dispatches every hint to each of its inner [HintRouter]s.
+///
+/// This enables tiered host architectures, where a single hint should populate several caches
+/// (e.g. a local disk cache and a remote cache) at once. Each hint is routed to every inner router,
+/// in insertion order, regardless of whether previous routers failed.
+///
+/// By default, routing only succeeds if all inner routers succeed. A quorum may be configured via
+/// [CompositeHintRouter::with_quorum], in which case routing succeeds once at least `quorum` inner
+/// routers have succeeded.
+#[derive(Default)]
+pub struct CompositeHintRouter {
+    /// The inner routers that each hint is dispatched to.
+    routers: Vec<Box<dyn HintRouter + Send + Sync>>,
+    /// The minimum number of inner routers that must succeed. If `None`, all must succeed.
+    quorum: Option<usize>,
+}
+
+impl core::fmt::Debug for CompositeHintRouter {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("CompositeHintRouter")
+            .field("routers", &self.routers.len())
+            .field("quorum", &self.quorum)
+            .finish()
+    }
+}
+
+impl CompositeHintRouter {
+    /// Creates a new, empty [CompositeHintRouter].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a [HintRouter] to the set of routers that hints are dispatched to.
+    pub fn with_router<R>(mut self, router: R) -> Self
+    where
+        R: HintRouter + Send + Sync + 'static,
+    {
+        self.routers.push(Box::new(router));
+        self
+    }
+
+    /// Sets the minimum number of inner routers that must succeed for a hint to be considered
+    /// routed.
+    pub const fn with_quorum(mut self, quorum: usize) -> Self {
+        self.quorum = Some(quorum);
+        self
+    }
+
+    /// Returns the number of inner routers.
+    pub fn len(&self) -> usize {
+        self.routers.len()
+    }
+
+    /// Returns `true` if there are no inner routers.
+    pub fn is_empty(&self) -> bool {
+        self.routers.is_empty()
+    }
+
+    /// Returns the number of inner routers that must succeed for a hint to be considered routed.
+    pub fn required_successes(&self) -> usize {
+        self.quorum.unwrap_or(self.routers.len())
+    }
+}
+
+#[async_trait]
+impl HintRouter for CompositeHintRouter {
+    async fn route_hint(&self, hint: String) -> PreimageOracleResult<()> {
+        let mut successes = 0;
+        let mut last_err = None;
+
+        for router in self.routers.iter() {
+            match router.route_hint(hint.clone()).await {
+                Ok(()) => successes += 1,
+                Err(e) => {
+                    warn!(target: "hint_router", "Inner hint router failed: {e}");
+                    last_err = Some(e);
+                }
+            }
+        }
+
+        let required = self.required_successes();
+        if successes >= required {
+            return Ok(());
+        }
+
+        Err(last_err.unwrap_or_else(|| {
+            PreimageOracleError::Other(format!(
+                "Hint routing quorum not met: {successes}/{required} routers succeeded"
+            ))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{string::ToString, sync::Arc};
+    use tokio::sync::Mutex;
+
+    #[derive(Default, Clone)]
+    struct RecordingRouter {
+        hints: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl HintRouter for RecordingRouter {
+        async fn route_hint(&self, hint: String) -> PreimageOracleResult<()> {
+            self.hints.lock().await.push(hint);
+            Ok(())
+        }
+    }
+
+    struct FailRouter;
+
+    #[async_trait]
+    impl HintRouter for FailRouter {
+        async fn route_hint(&self, _hint: String) -> PreimageOracleResult<()> {
+            Err(PreimageOracleError::KeyNotFound)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_composite_router_fans_out() {
+        let a = RecordingRouter::default();
+        let b = RecordingRouter::default();
+        let router = CompositeHintRouter::new().with_router(a.clone()).with_router(b.clone());
+
+        router.route_hint("hint-a 0xfacade".to_string()).await.unwrap();
+        router.route_hint("hint-b 0xbeef".to_string()).await.unwrap();
+
+        let expected = ["hint-a 0xfacade", "hint-b 0xbeef"];
+        assert_eq!(*a.hints.lock().await, expected);
+        assert_eq!(*b.hints.lock().await, expected);
+    }
+
+    #[tokio::test]
+    async fn test_composite_router_requires_all_by_default() {
+        let a = RecordingRouter::default();
+        let router = CompositeHintRouter::new().with_router(FailRouter).with_router(a.clone());
+
+        let res = router.route_hint("hint 0xfacade".to_string()).await;
+        assert!(matches!(res, Err(PreimageOracleError::KeyNotFound)));
+
+        // The hint is still dispatched to routers after the failing one.
+        assert_eq!(*a.hints.lock().await, ["hint 0xfacade"]);
+    }
+
+    #[tokio::test]
+    async fn test_composite_router_quorum() {
+        let a = RecordingRouter::default();
+        let router = CompositeHintRouter::new()
+            .with_router(FailRouter)
+            .with_router(a.clone())
+            .with_quorum(1);
+        router.route_hint("hint 0xfacade".to_string()).await.unwrap();
+
+        let router = CompositeHintRouter::new()
+            .with_router(FailRouter)
+            .with_router(FailRouter)
+            .with_router(a)
+            .with_quorum(2);
+        assert!(router.route_hint("hint 0xfacade".to_string()).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_composite_router_empty() {
+        let router = CompositeHintRouter::new();
+        assert!(router.is_empty());
+        router.route_hint("hint 0xfacade".to_string()).await.unwrap();
+
+        let router = CompositeHintRouter::new().with_quorum(1);
+        let res = router.route_hint("hint 0xfacade".to_string()).await;
+        assert!(matches!(res, Err(PreimageOracleError::Other(_))));
+    }
+}
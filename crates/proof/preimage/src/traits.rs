@@ -4,6 +4,7 @@ use crate::{
 };
 use alloc::{boxed::Box, string::String, vec::Vec};
 use async_trait::async_trait;
+use core::time::Duration;
 
 /// A [PreimageOracleClient] is a high-level interface to read data from the host, keyed by a
 /// [PreimageKey].
@@ -24,6 +25,26 @@ pub trait PreimageOracleClient {
     /// - `Ok(())` if the data was successfully written into the buffer.
     /// - `Err(_)` if the data could not be written into the buffer.
     async fn get_exact(&self, key: PreimageKey, buf: &mut [u8]) -> PreimageOracleResult<()>;
+
+    /// Get the data corresponding to each of the given keys from the host. The returned preimages
+    /// are in the same order as the keys.
+    ///
+    /// By default, each preimage is fetched with a separate call to [PreimageOracleClient::get].
+    /// Implementations may override this to batch the requests.
+    ///
+    /// # Returns
+    /// - `Ok(Vec<Vec<u8>>)` if all of the data was successfully fetched from the host.
+    /// - `Err(_)` if any of the data could not be fetched from the host.
+    async fn get_many(&self, keys: &[PreimageKey]) -> PreimageOracleResult<Vec<Vec<u8>>>
+    where
+        Self: Sync,
+    {
+        let mut values = Vec::with_capacity(keys.len());
+        for key in keys {
+            values.push(self.get(*key).await?);
+        }
+        Ok(values)
+    }
 }
 
 /// A [HintWriterClient] is a high-level interface to the hint pipe. It provides a way to write
@@ -100,6 +121,18 @@ pub trait PreimageFetcher {
     /// - `Ok(Vec<u8>)` if the preimage was successfully fetched.
     /// - `Err(_)` if the preimage could not be fetched.
     async fn get_preimage(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>>;
+
+    /// Returns whether the fetcher may be able to serve the preimage for the given key.
+    ///
+    /// Returning `false` signals that the preimage is definitively unavailable, allowing an
+    /// [OracleServer] with the not-found sentinel enabled to respond with [PREIMAGE_NOT_FOUND]
+    /// without attempting a full fetch. By default, all keys are assumed to be available.
+    ///
+    /// [OracleServer]: crate::OracleServer
+    /// [PREIMAGE_NOT_FOUND]: crate::PREIMAGE_NOT_FOUND
+    async fn contains(&self, _key: PreimageKey) -> bool {
+        true
+    }
 }
 
 /// A [PreimageServerBackend] is a trait that combines the [PreimageFetcher] and [HintRouter]
@@ -141,4 +174,28 @@ pub trait Channel {
     /// - `Ok(usize)`: The number of bytes written.
     /// - `Err(_)` if the data could not be written.
     async fn write(&self, buf: &[u8]) -> ChannelResult<usize>;
+
+    /// Asynchronously read exactly `buf.len()` bytes into `buf` from the channel, failing with
+    /// [ChannelError::Timeout] if they are not received within `timeout`.
+    ///
+    /// By default, the deadline is not enforced and this is equivalent to [Channel::read_exact].
+    /// Implementations that are able to wait on their transport with a deadline should override
+    /// it.
+    ///
+    /// [ChannelError::Timeout]: crate::errors::ChannelError::Timeout
+    async fn read_with_timeout(&self, buf: &mut [u8], _timeout: Duration) -> ChannelResult<usize> {
+        self.read_exact(buf).await
+    }
+
+    /// Asynchronously write the provided buffer to the channel, failing with
+    /// [ChannelError::Timeout] if it is not written within `timeout`.
+    ///
+    /// By default, the deadline is not enforced and this is equivalent to [Channel::write].
+    /// Implementations that are able to wait on their transport with a deadline should override
+    /// it.
+    ///
+    /// [ChannelError::Timeout]: crate::errors::ChannelError::Timeout
+    async fn write_with_timeout(&self, buf: &[u8], _timeout: Duration) -> ChannelResult<usize> {
+        self.write(buf).await
+    }
 }
@@ -106,14 +106,14 @@ impl BootInfo {
         let agreed_pre_state =
             PreState::decode(&mut raw_pre_state.as_ref()).map_err(OracleProviderError::Rlp)?;
 
-        let chain_ids: Vec<_> = match agreed_pre_state {
-            PreState::SuperRoot(ref super_root) => {
-                super_root.output_roots.iter().map(|r| r.chain_id).collect()
-            }
-            PreState::TransitionState(ref transition_state) => {
-                transition_state.pre_state.output_roots.iter().map(|r| r.chain_id).collect()
-            }
-        };
+        // The active sub-problem is selected by the position of the chain within the pre-state's
+        // output roots, so they must be canonically ordered for the selection to be deterministic.
+        if !agreed_pre_state.is_canonically_ordered() {
+            return Err(BootstrapError::NonCanonicalPreState);
+        }
+
+        let chain_ids: Vec<_> =
+            agreed_pre_state.super_root().output_roots.iter().map(|r| r.chain_id).collect();
 
         // Attempt to load the rollup config from the chain ID. If there is no config for the chain,
         // fall back to loading the config from the preimage oracle.
@@ -166,6 +166,9 @@ pub enum BootstrapError {
     /// The pre-state is invalid and the post-state claim is also invalid.
     #[error("No-op state transition detected; both pre and post states are `INVALID`.")]
     InvalidToInvalid,
+    /// The output roots of the pre-state are not sorted by ascending chain ID.
+    #[error("Pre-state output roots are not in canonical chain ID order.")]
+    NonCanonicalPreState,
 }
 
 /// Reads the raw pre-state from the preimage oracle.
@@ -5,7 +5,7 @@ use alloc::{boxed::Box, vec::Vec};
 use alloy_op_evm::OpEvmFactory;
 use alloy_primitives::Sealable;
 use alloy_rpc_types_engine::PayloadAttributes;
-use kona_executor::{ExecutorError, StatelessL2Builder};
+use kona_executor::{ExecutorError, StatelessL2Builder, TrieDBError};
 use kona_interop::{MessageGraph, MessageGraphError};
 use kona_mpt::OrderedListWalker;
 use kona_preimage::CommsClient;
@@ -211,21 +211,146 @@ where
 #[derive(Debug, Error)]
 pub enum ConsolidationError {
     /// An invalid pre-state variant was passed to the consolidator.
+    ///
+    /// Fatal: the program was invoked with an inconsistent pre-state.
     #[error("Invalid PreState variant")]
     InvalidPreStateVariant,
     /// Missing a rollup configuration.
+    ///
+    /// Fatal: the program is misconfigured.
     #[error("Missing rollup configuration for chain ID {0}")]
     MissingRollupConfig(u64),
     /// Missing a local L2 chain provider.
+    ///
+    /// Fatal: the program is misconfigured.
     #[error("Missing local L2 chain provider for chain ID {0}")]
     MissingLocalProvider(u64),
     /// An error occurred during consolidation.
+    ///
+    /// Recoverable if the interop provider failed to fetch data, fatal otherwise.
     #[error(transparent)]
     MessageGraph(#[from] MessageGraphError<OracleProviderError>),
     /// An error occurred during execution.
+    ///
+    /// Recoverable if the trie provider failed to fetch data, fatal otherwise.
     #[error(transparent)]
     Executor(#[from] ExecutorError),
-    /// An error occurred during RLP decoding.
+    /// An error occurred while fetching data from the oracle.
+    ///
+    /// Recoverable: the data may be served once the oracle is retried.
     #[error(transparent)]
     OracleProvider(#[from] OracleProviderError),
 }
+
+impl ConsolidationError {
+    /// Returns `true` if the error cannot be recovered from by retrying consolidation.
+    ///
+    /// Errors from fetching data through the oracle or the providers backed by it are
+    /// recoverable, as the data may be served on a retry. All other errors, including those for
+    /// which [Self::is_claim_fault] holds, are fatal.
+    pub const fn is_fatal(&self) -> bool {
+        !matches!(
+            self,
+            Self::OracleProvider(_) |
+                Self::MessageGraph(MessageGraphError::InteropProviderError(_)) |
+                Self::Executor(ExecutorError::TrieDBError(TrieDBError::Provider(_)))
+        )
+    }
+
+    /// Returns `true` if the error indicates a fault in the claimed transition, i.e. the
+    /// superchain's cross-chain dependencies are invalid per the [message validity rules].
+    ///
+    /// All other errors indicate missing or malformed witness data, or a misconfiguration of the
+    /// program, and do not attribute a fault to the claim.
+    ///
+    /// [message validity rules]: https://specs.optimism.io/interop/messaging.html#invalid-messages
+    pub const fn is_claim_fault(&self) -> bool {
+        matches!(
+            self,
+            Self::MessageGraph(
+                MessageGraphError::RemoteMessageNotFound(_, _) |
+                    MessageGraphError::InvalidMessageOrigin(_, _) |
+                    MessageGraphError::InvalidMessageHash(_, _) |
+                    MessageGraphError::InvalidMessageTimestamp(_, _) |
+                    MessageGraphError::MessageInFuture(_, _) |
+                    MessageGraphError::MessageExpired(_) |
+                    MessageGraphError::InvalidMessages(_)
+            )
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+    use alloy_primitives::{Address, B256};
+    use kona_preimage::errors::PreimageOracleError;
+
+    #[test]
+    fn test_claim_fault_classification() {
+        let faults = [
+            MessageGraphError::RemoteMessageNotFound(10, B256::ZERO),
+            MessageGraphError::InvalidMessageOrigin(Address::ZERO, Address::repeat_byte(1)),
+            MessageGraphError::InvalidMessageHash(B256::ZERO, B256::repeat_byte(1)),
+            MessageGraphError::InvalidMessageTimestamp(1, 2),
+            MessageGraphError::MessageInFuture(1, 2),
+            MessageGraphError::MessageExpired(1),
+            MessageGraphError::InvalidMessages(vec![10]),
+        ];
+        for err in faults {
+            assert!(ConsolidationError::MessageGraph(err).is_claim_fault());
+        }
+
+        let non_faults = [
+            ConsolidationError::InvalidPreStateVariant,
+            ConsolidationError::MissingRollupConfig(10),
+            ConsolidationError::MissingLocalProvider(10),
+            ConsolidationError::MessageGraph(MessageGraphError::EmptyDependencySet),
+            ConsolidationError::MessageGraph(MessageGraphError::MissingRollupConfig(10)),
+            ConsolidationError::MessageGraph(MessageGraphError::InteropProviderError(
+                OracleProviderError::Preimage(PreimageOracleError::KeyNotFound),
+            )),
+            ConsolidationError::Executor(ExecutorError::MissingExecutor),
+            ConsolidationError::OracleProvider(OracleProviderError::Preimage(
+                PreimageOracleError::KeyNotFound,
+            )),
+        ];
+        for err in non_faults {
+            assert!(!err.is_claim_fault());
+        }
+    }
+
+    #[test]
+    fn test_fatal_classification() {
+        let missing_preimage = || OracleProviderError::Preimage(PreimageOracleError::KeyNotFound);
+
+        let fatal = [
+            ConsolidationError::InvalidPreStateVariant,
+            ConsolidationError::MissingRollupConfig(10),
+            ConsolidationError::MissingLocalProvider(10),
+            ConsolidationError::MessageGraph(MessageGraphError::EmptyDependencySet),
+            ConsolidationError::MessageGraph(MessageGraphError::MissingRollupConfig(10)),
+            ConsolidationError::MessageGraph(MessageGraphError::InvalidMessages(vec![10])),
+            ConsolidationError::MessageGraph(MessageGraphError::MessageExpired(1)),
+            ConsolidationError::Executor(ExecutorError::MissingExecutor),
+            ConsolidationError::Executor(ExecutorError::TrieDBError(TrieDBError::RootNotBlinded)),
+        ];
+        for err in fatal {
+            assert!(err.is_fatal(), "{err:?} should be fatal");
+        }
+
+        let recoverable = [
+            ConsolidationError::OracleProvider(missing_preimage()),
+            ConsolidationError::MessageGraph(MessageGraphError::InteropProviderError(
+                missing_preimage(),
+            )),
+            ConsolidationError::Executor(ExecutorError::TrieDBError(TrieDBError::Provider(
+                "missing node".into(),
+            ))),
+        ];
+        for err in recoverable {
+            assert!(!err.is_fatal(), "{err:?} should be recoverable");
+        }
+    }
+}
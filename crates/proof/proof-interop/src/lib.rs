@@ -11,7 +11,7 @@ extern crate alloc;
 
 mod pre_state;
 pub use pre_state::{
-    INVALID_TRANSITION, INVALID_TRANSITION_HASH, OptimisticBlock, PreState,
+    INVALID_TRANSITION, INVALID_TRANSITION_HASH, OptimisticBlock, PreState, SubProblem,
     TRANSITION_STATE_MAX_STEPS, TransitionState,
 };
 
@@ -23,6 +23,15 @@ pub const INVALID_TRANSITION_HASH: B256 =
 /// [TransitionState]. The [SuperRoot] is the canonical state of the superchain, while the
 /// [TransitionState] is a super-structure of the [SuperRoot] that represents the progress of a
 /// pending superchain state transition from one [SuperRoot] to the next.
+///
+/// ## Sub-problem selection
+///
+/// Each step of a superchain state transition targets exactly one chain. The chain targeted at
+/// step `n` is the chain of the `n`th output root in the pre-state [SuperRoot], whose output roots
+/// are canonically ordered by ascending chain ID. A [PreState::SuperRoot] is always at step `0`,
+/// and a [PreState::TransitionState] is at its `step`. Pre-states that do not respect the canonical
+/// ordering are rejected by [PreState::is_canonically_ordered], so that the same pre-state always
+/// selects the same chain.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 pub enum PreState {
@@ -48,16 +57,35 @@ impl PreState {
         }
     }
 
+    /// Returns the [SuperRoot] that the [PreState] is transitioning from.
+    pub const fn super_root(&self) -> &SuperRoot {
+        match self {
+            Self::SuperRoot(super_root) => super_root,
+            Self::TransitionState(transition_state) => &transition_state.pre_state,
+        }
+    }
+
+    /// Returns the current step of the [PreState]. A [PreState::SuperRoot] is always at step `0`.
+    pub const fn active_step(&self) -> u64 {
+        match self {
+            Self::SuperRoot(_) => 0,
+            Self::TransitionState(transition_state) => transition_state.step,
+        }
+    }
+
+    /// Returns `true` if the output roots of the pre-state [SuperRoot] are in canonical order,
+    /// i.e. strictly ascending by chain ID.
+    pub fn is_canonically_ordered(&self) -> bool {
+        self.super_root().output_roots.windows(2).all(|w| w[0].chain_id < w[1].chain_id)
+    }
+
     /// Returns the active L2 output root hash of the [PreState]. This is the output root that
     /// represents the pre-state of the chain that is to be committed to in the next transition
     /// step, or [None] if the [PreState] has already been fully saturated.
+    ///
+    /// See the [PreState] documentation for the sub-problem selection rule.
     pub fn active_l2_output_root(&self) -> Option<&OutputRootWithChain> {
-        match self {
-            Self::SuperRoot(super_root) => super_root.output_roots.first(),
-            Self::TransitionState(transition_state) => {
-                transition_state.pre_state.output_roots.get(transition_state.step as usize)
-            }
-        }
+        self.super_root().output_roots.get(self.active_step() as usize)
     }
 
     /// Returns the active L2 chain ID of the [PreState]. This is the chain ID of the output root
@@ -67,6 +95,37 @@ impl PreState {
         self.active_l2_output_root().map(|output_root| output_root.chain_id)
     }
 
+    /// Selects the [SubProblem] that the [PreState] poses for the given claimed L2 timestamp.
+    ///
+    /// Once the pre-state has reached the claimed timestamp, no further progress may be made. A
+    /// [PreState::SuperRoot] at or past the claimed timestamp is a trace extension, while a
+    /// [PreState::TransitionState] at or past the claimed timestamp can never be committed to.
+    pub const fn sub_problem(&self, claimed_l2_timestamp: u64) -> SubProblem {
+        match self {
+            Self::SuperRoot(super_root) => {
+                if super_root.timestamp >= claimed_l2_timestamp {
+                    SubProblem::TraceExtension
+                } else {
+                    // If the pre-state is a super root, the first sub-problem is always selected.
+                    SubProblem::Transition
+                }
+            }
+            Self::TransitionState(transition_state) => {
+                if transition_state.pre_state.timestamp >= claimed_l2_timestamp {
+                    return SubProblem::InvalidTransition;
+                }
+
+                if transition_state.is_consolidation_step() {
+                    SubProblem::Consolidation
+                } else if transition_state.remaining_steps() > 0 {
+                    SubProblem::Transition
+                } else {
+                    SubProblem::InvalidStep
+                }
+            }
+        }
+    }
+
     /// Transitions to the next state, appending the [OptimisticBlock] to the pending progress.
     pub fn transition(self, optimistic_block: Option<OptimisticBlock>) -> Option<Self> {
         match self {
@@ -82,7 +141,7 @@ impl PreState {
                 if transition_state.pending_progress.len() ==
                     transition_state.pre_state.output_roots.len()
                 {
-                    if transition_state.step == TRANSITION_STATE_MAX_STEPS {
+                    if transition_state.is_consolidation_step() {
                         let super_root = SuperRoot::new(
                             transition_state.pre_state.timestamp + 1,
                             transition_state
@@ -146,6 +205,23 @@ impl Decodable for PreState {
     }
 }
 
+/// The sub-problem posed by a [PreState], selected by [PreState::sub_problem].
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SubProblem {
+    /// The pre-state super root has already reached the claimed timestamp. To accommodate trace
+    /// extension, the claimed post-state must equal the agreed pre-state.
+    TraceExtension,
+    /// The pre-state transition state has already reached the claimed timestamp, so the claim
+    /// must be invalid.
+    InvalidTransition,
+    /// Derive and execute the next block of the active L2 chain.
+    Transition,
+    /// Consolidate the pending progress of the transition state.
+    Consolidation,
+    /// The transition state's step exceeds [TRANSITION_STATE_MAX_STEPS].
+    InvalidStep,
+}
+
 /// The [TransitionState] is a super-structure of the [SuperRoot] that represents the progress of a
 /// pending superchain state transition from one [SuperRoot] to the next.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -169,6 +245,18 @@ impl TransitionState {
         Self { pre_state, pending_progress, step }
     }
 
+    /// Returns `true` if the [TransitionState] is at the final step, where its pending progress
+    /// is consolidated into the next [SuperRoot].
+    pub const fn is_consolidation_step(&self) -> bool {
+        self.step == TRANSITION_STATE_MAX_STEPS
+    }
+
+    /// Returns the number of steps remaining before the [TransitionState] reaches the
+    /// consolidation step, or `0` if it is at or past it.
+    pub const fn remaining_steps(&self) -> usize {
+        TRANSITION_STATE_MAX_STEPS.saturating_sub(self.step) as usize
+    }
+
     /// Hashes the encoded [TransitionState] using [keccak256].
     pub fn hash(&self) -> B256 {
         let mut rlp_buf = Vec::with_capacity(self.length());
@@ -255,7 +343,10 @@ impl OptimisticBlock {
 
 #[cfg(test)]
 mod test {
-    use super::{OptimisticBlock, SuperRoot, TransitionState};
+    use super::{
+        OptimisticBlock, PreState, SubProblem, SuperRoot, TRANSITION_STATE_MAX_STEPS,
+        TransitionState,
+    };
     use alloc::{vec, vec::Vec};
     use alloy_primitives::B256;
     use alloy_rlp::{Decodable, Encodable};
@@ -281,6 +372,123 @@ mod test {
         assert_eq!(transition_state, TransitionState::decode(&mut rlp_buf.as_slice()).unwrap());
     }
 
+    #[test]
+    fn test_active_chain_selection_is_deterministic() {
+        let roots = vec![
+            OutputRootWithChain::new(10, B256::default()),
+            OutputRootWithChain::new(2, B256::default()),
+            OutputRootWithChain::new(5, B256::default()),
+        ];
+        let mut reversed = roots.clone();
+        reversed.reverse();
+
+        // The selected chain must not depend on the order the output roots were provided in.
+        let super_root = SuperRoot::new(10, roots);
+        assert_eq!(super_root, SuperRoot::new(10, reversed));
+
+        let pre_state = PreState::SuperRoot(super_root.clone());
+        assert!(pre_state.is_canonically_ordered());
+        assert_eq!(pre_state.active_step(), 0);
+        assert_eq!(pre_state.active_l2_chain_id(), Some(2));
+
+        for (step, expected) in [(0, Some(2)), (1, Some(5)), (2, Some(10)), (3, None)] {
+            let pre_state = PreState::TransitionState(TransitionState::new(
+                super_root.clone(),
+                vec![OptimisticBlock::default(); step as usize],
+                step,
+            ));
+            for _ in 0..4 {
+                assert_eq!(pre_state.active_l2_chain_id(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_non_canonical_ordering_detected() {
+        let super_root = SuperRoot {
+            timestamp: 10,
+            output_roots: vec![
+                OutputRootWithChain::new(5, B256::default()),
+                OutputRootWithChain::new(2, B256::default()),
+            ],
+        };
+        assert!(!PreState::SuperRoot(super_root).is_canonically_ordered());
+
+        let duplicate = SuperRoot::new(
+            10,
+            vec![
+                OutputRootWithChain::new(2, B256::default()),
+                OutputRootWithChain::new(2, B256::default()),
+            ],
+        );
+        assert!(!PreState::SuperRoot(duplicate).is_canonically_ordered());
+    }
+
+    fn transition_state(timestamp: u64, step: u64) -> PreState {
+        PreState::TransitionState(TransitionState::new(
+            SuperRoot::new(timestamp, vec![OutputRootWithChain::new(1, B256::default())]),
+            vec![OptimisticBlock::default()],
+            step,
+        ))
+    }
+
+    #[test]
+    fn test_super_root_trace_extension_boundary() {
+        let super_root = |timestamp| {
+            PreState::SuperRoot(SuperRoot::new(
+                timestamp,
+                vec![OutputRootWithChain::new(1, B256::default())],
+            ))
+        };
+
+        // Strictly greater: the pre-state is past the claim, so the transition is a no-op.
+        assert_eq!(super_root(11).sub_problem(10), SubProblem::TraceExtension);
+
+        // Exactly equal: the claimed timestamp has been reached, so no progress may be made.
+        assert_eq!(super_root(10).sub_problem(10), SubProblem::TraceExtension);
+
+        // Strictly less: the first sub-transition is selected.
+        assert_eq!(super_root(9).sub_problem(10), SubProblem::Transition);
+        assert_eq!(super_root(0).sub_problem(u64::MAX), SubProblem::Transition);
+    }
+
+    #[test]
+    fn test_transition_state_step_introspection() {
+        let state = |step| TransitionState::new(SuperRoot::new(10, vec![]), vec![], step);
+        let max = TRANSITION_STATE_MAX_STEPS;
+
+        assert!(!state(0).is_consolidation_step());
+        assert_eq!(state(0).remaining_steps(), max as usize);
+
+        assert!(!state(max / 2).is_consolidation_step());
+        assert_eq!(state(max / 2).remaining_steps(), (max - max / 2) as usize);
+
+        assert!(!state(max - 1).is_consolidation_step());
+        assert_eq!(state(max - 1).remaining_steps(), 1);
+        assert!(state(max).is_consolidation_step());
+        assert_eq!(state(max).remaining_steps(), 0);
+        assert!(!state(max + 1).is_consolidation_step());
+        assert_eq!(state(max + 1).remaining_steps(), 0);
+    }
+
+    #[test]
+    fn test_transition_state_trace_extension_boundary() {
+        // Strictly greater and exactly equal: a transition state can never be committed to.
+        assert_eq!(transition_state(11, 1).sub_problem(10), SubProblem::InvalidTransition);
+        assert_eq!(transition_state(10, 1).sub_problem(10), SubProblem::InvalidTransition);
+
+        // The timestamp check takes precedence over the step.
+        let max = TRANSITION_STATE_MAX_STEPS;
+        assert_eq!(transition_state(10, max).sub_problem(10), SubProblem::InvalidTransition);
+        assert_eq!(transition_state(10, max + 1).sub_problem(10), SubProblem::InvalidTransition);
+
+        // Strictly less: the sub-problem is selected by the step.
+        assert_eq!(transition_state(9, 1).sub_problem(10), SubProblem::Transition);
+        assert_eq!(transition_state(9, max - 1).sub_problem(10), SubProblem::Transition);
+        assert_eq!(transition_state(9, max).sub_problem(10), SubProblem::Consolidation);
+        assert_eq!(transition_state(9, max + 1).sub_problem(10), SubProblem::InvalidStep);
+    }
+
     #[test]
     #[cfg(feature = "arbitrary")]
     fn test_arbitrary_pre_state_roundtrip() {
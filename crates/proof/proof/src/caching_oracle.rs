@@ -100,6 +100,28 @@ where
             Ok(())
         }
     }
+
+    async fn get_many(&self, keys: &[PreimageKey]) -> PreimageOracleResult<Vec<Vec<u8>>> {
+        let mut cache_lock = self.cache.lock();
+        let mut values = keys.iter().map(|key| cache_lock.get(key).cloned()).collect::<Vec<_>>();
+
+        // Fetch all preimages that missed the cache in a single batch.
+        let missing = keys
+            .iter()
+            .zip(values.iter())
+            .filter_map(|(key, value)| value.is_none().then_some(*key))
+            .collect::<Vec<_>>();
+        let mut fetched = self.oracle_reader.get_many(&missing).await?.into_iter();
+        for (key, value) in keys.iter().zip(values.iter_mut()) {
+            if value.is_none() {
+                let preimage = fetched.next().expect("one preimage per missing key");
+                cache_lock.put(*key, preimage.clone());
+                *value = Some(preimage);
+            }
+        }
+
+        Ok(values.into_iter().flatten().collect())
+    }
 }
 
 #[async_trait]
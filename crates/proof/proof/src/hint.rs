@@ -108,12 +108,47 @@ pub enum HintType {
     /// A hint that specifies the proof on the path to a storage slot in an account within in the
     /// L2 state trie.
     L2AccountStorageProof,
+    /// A hint that specifies the proofs on the paths to a contiguous range of storage slots in an
+    /// account within the L2 state trie, along with the proof on the path to the account.
+    ///
+    /// The hint data is `block_number (u64 BE) ++ address (20 bytes) ++ start_slot (32 bytes) ++
+    /// count (u64 BE)`, requesting the slots `start_slot..start_slot + count`.
+    L2AccountStorageRange,
     /// A hint that specifies bulk storage of all the code, state and keys generated by an
     /// execution witness.
     L2PayloadWitness,
 }
 
 impl HintType {
+    /// All [HintType] variants.
+    pub const ALL: [Self; 14] = [
+        Self::L1BlockHeader,
+        Self::L1Transactions,
+        Self::L1Receipts,
+        Self::L1Blob,
+        Self::L1Precompile,
+        Self::L2BlockHeader,
+        Self::L2Transactions,
+        Self::L2Code,
+        Self::StartingL2Output,
+        Self::L2StateNode,
+        Self::L2AccountProof,
+        Self::L2AccountStorageProof,
+        Self::L2AccountStorageRange,
+        Self::L2PayloadWitness,
+    ];
+
+    /// Encodes a hint of this type with the given data in the `<hint_type> <hint_data>` wire
+    /// format.
+    pub fn encode(&self, data: &[u8]) -> String {
+        Hint::new(*self, data.to_vec()).encode()
+    }
+
+    /// Parses a hint in the `<hint_type> <hint_data>` wire format into its type and data.
+    pub fn parse(s: &str) -> Result<(Self, Bytes), HintParsingError> {
+        s.parse::<Hint<Self>>().map(Hint::split)
+    }
+
     /// Creates a new [Hint] from `self` and the specified data. The data passed will be
     /// concatenated into a single byte array before being stored in the resulting [Hint].
     pub fn with_data(self, data: &[&[u8]]) -> Hint<Self> {
@@ -143,6 +178,7 @@ impl FromStr for HintType {
             "l2-state-node" => Ok(Self::L2StateNode),
             "l2-account-proof" => Ok(Self::L2AccountProof),
             "l2-account-storage-proof" => Ok(Self::L2AccountStorageProof),
+            "l2-account-storage-range" => Ok(Self::L2AccountStorageRange),
             "l2-payload-witness" => Ok(Self::L2PayloadWitness),
             _ => Err(HintParsingError(value.to_string())),
         }
@@ -164,6 +200,7 @@ impl From<HintType> for &str {
             HintType::L2StateNode => "l2-state-node",
             HintType::L2AccountProof => "l2-account-proof",
             HintType::L2AccountStorageProof => "l2-account-storage-proof",
+            HintType::L2AccountStorageRange => "l2-account-storage-range",
             HintType::L2PayloadWitness => "l2-payload-witness",
         }
     }
@@ -175,3 +212,40 @@ impl Display for HintType {
         write!(f, "{}", s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hint_type_round_trip() {
+        let data = [0xde, 0xad, 0xbe, 0xef];
+        for ty in HintType::ALL {
+            let encoded = ty.encode(&data);
+            assert_eq!(encoded, alloc::format!("{ty} 0xdeadbeef"));
+            assert_eq!(HintType::parse(&encoded).unwrap(), (ty, Bytes::from(data)));
+        }
+    }
+
+    #[test]
+    fn test_hint_type_round_trip_empty_data() {
+        for ty in HintType::ALL {
+            assert_eq!(HintType::parse(&ty.encode(&[])).unwrap(), (ty, Bytes::new()));
+        }
+    }
+
+    #[test]
+    fn test_hint_type_from_str_round_trip() {
+        for ty in HintType::ALL {
+            assert_eq!(ty.to_string().parse::<HintType>().unwrap(), ty);
+        }
+    }
+
+    #[test]
+    fn test_parse_malformed_hints() {
+        assert!(HintType::parse("l1-transactions").is_err());
+        assert!(HintType::parse("l1-transactions 0xdead beef").is_err());
+        assert!(HintType::parse("l1-transactions 0xnothex").is_err());
+        assert!(HintType::parse("l1-unknown 0xdeadbeef").is_err());
+    }
+}
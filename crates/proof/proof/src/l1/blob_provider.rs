@@ -5,14 +5,11 @@ use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use alloy_consensus::Blob;
 use alloy_eips::eip4844::{FIELD_ELEMENTS_PER_BLOB, IndexedBlobHash};
 use alloy_primitives::keccak256;
-use ark_bls12_381::Fr;
-use ark_ff::{AdditiveGroup, BigInteger, BigInteger256, Field, PrimeField};
+use ark_ff::{BigInteger, PrimeField};
 use async_trait::async_trait;
-use core::str::FromStr;
 use kona_derive::traits::BlobProvider;
-use kona_preimage::{CommsClient, PreimageKey, PreimageKeyType};
+use kona_preimage::{CommsClient, PreimageKey, PreimageKeyType, ROOTS_OF_UNITY};
 use kona_protocol::BlockInfo;
-use spin::Lazy;
 
 /// An oracle-backed blob provider.
 #[derive(Debug, Clone)]
@@ -102,59 +99,6 @@ impl<T: CommsClient + Sync + Send> BlobProvider for OracleBlobProvider<T> {
     }
 }
 
-/// The 4096th bit-reversed roots of unity used in EIP-4844 as predefined evaluation points.
-///
-/// See `generate_roots_of_unity` for details on how these roots of unity are generated.
-pub static ROOTS_OF_UNITY: Lazy<[Fr; FIELD_ELEMENTS_PER_BLOB as usize]> =
-    Lazy::new(generate_roots_of_unity);
-
-/// Generates the 4096th bit-reversed roots of unity used in EIP-4844 as predefined evaluation
-/// points. To compute the field element at index i in a blob, the blob polynomial is evaluated at
-/// the i'th root of unity. Based on go-kzg-4844: <https://github.com/crate-crypto/go-kzg-4844/blob/8bcf6163d3987313a3194595cf1f33fd45d7301a/internal/kzg/domain.go#L44-L98>
-/// Also, see the consensus specs:
-///   - compute_roots_of_unity <https://github.com/ethereum/consensus-specs/blob/bf09edef17e2900258f7e37631e9452941c26e86/specs/deneb/polynomial-commitments.md#compute_roots_of_unity>
-///   - bit-reversal permutation: <https://github.com/ethereum/consensus-specs/blob/bf09edef17e2900258f7e37631e9452941c26e86/specs/deneb/polynomial-commitments.md#bit-reversal-permutation>
-fn generate_roots_of_unity() -> [Fr; FIELD_ELEMENTS_PER_BLOB as usize] {
-    const MAX_ORDER_ROOT: u64 = 32;
-
-    let mut roots_of_unity = [Fr::ZERO; FIELD_ELEMENTS_PER_BLOB as usize];
-
-    // Generator of the largest 2-adic subgroup of order 2^32.
-    let root_of_unity = Fr::new(
-        BigInteger256::from_str(
-            "10238227357739495823651030575849232062558860180284477541189508159991286009131",
-        )
-        .expect("Failed to initialize root of unity"),
-    );
-
-    // Find generator subgroup of order x.
-    // This can be constructed by powering a generator of the largest 2-adic subgroup of order 2^32
-    // by an exponent of (2^32)/x, provided x is <= 2^32.
-    let log_x = FIELD_ELEMENTS_PER_BLOB.trailing_zeros() as u64;
-    let expo = 1u64 << (MAX_ORDER_ROOT - log_x);
-
-    // Generator has order x now
-    let generator = root_of_unity.pow([expo]);
-
-    // Compute all relevant roots of unity, i.e. the multiplicative subgroup of size x
-    let mut current = Fr::ONE;
-    (0..FIELD_ELEMENTS_PER_BLOB).for_each(|i| {
-        roots_of_unity[i as usize] = current;
-        current *= generator;
-    });
-
-    let shift_correction = 64 - FIELD_ELEMENTS_PER_BLOB.trailing_zeros();
-    (0..FIELD_ELEMENTS_PER_BLOB).for_each(|i| {
-        // Find index irev, such that i and irev get swapped
-        let irev = i.reverse_bits() >> shift_correction;
-        if irev > i {
-            roots_of_unity.swap(i as usize, irev as usize);
-        }
-    });
-
-    roots_of_unity
-}
-
 #[cfg(test)]
 mod test {
     use super::ROOTS_OF_UNITY;
@@ -3,7 +3,7 @@
 use crate::{HintType, eip2935::eip_2935_history_lookup, errors::OracleProviderError};
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use alloy_consensus::{BlockBody, Header};
-use alloy_eips::eip2718::Decodable2718;
+use alloy_eips::{eip2718::Decodable2718, eip4895::Withdrawals};
 use alloy_primitives::{Address, B256, Bytes};
 use alloy_rlp::Decodable;
 use async_trait::async_trait;
@@ -58,6 +58,17 @@ impl<T: CommsClient> OracleL2ChainProvider<T> {
 }
 
 impl<T: CommsClient> OracleL2ChainProvider<T> {
+    /// Returns the withdrawals list of the L2 block with the given timestamp.
+    ///
+    /// The OP Stack does not support L1-style withdrawals, so the withdrawals list of an L2 block
+    /// is always empty once Canyon is active, and absent before. The list itself is not committed
+    /// to by the block hash; the header's `withdrawals_root` is either the root of the empty list
+    /// (Canyon) or the storage root of the `L2ToL1MessagePasser` (Isthmus), and is preserved
+    /// as-is from the header preimage.
+    fn block_withdrawals(&self, timestamp: u64) -> Option<Withdrawals> {
+        self.rollup_config.is_canyon_active(timestamp).then(|| Withdrawals::new(Vec::new()))
+    }
+
     /// Returns a [Header] corresponding to the given L2 block number, by walking back from the
     /// L2 safe head.
     async fn header_by_number(&mut self, block_number: u64) -> Result<Header, OracleProviderError> {
@@ -140,10 +151,7 @@ impl<T: CommsClient + Send + Sync> BatchValidationProvider for OracleL2ChainProv
             body: BlockBody {
                 transactions,
                 ommers: Vec::new(),
-                withdrawals: self
-                    .rollup_config
-                    .is_canyon_active(timestamp)
-                    .then(|| alloy_eips::eip4895::Withdrawals::new(Vec::new())),
+                withdrawals: self.block_withdrawals(timestamp),
             },
         };
         Ok(optimism_block)
@@ -279,3 +287,113 @@ impl<T: CommsClient> TrieHinter for OracleL2ChainProvider<T> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+    use alloy_consensus::proofs::calculate_withdrawals_root;
+    use alloy_primitives::keccak256;
+    use alloy_rlp::Encodable;
+    use alloy_trie::EMPTY_ROOT_HASH;
+    use kona_genesis::HardForkConfig;
+    use kona_preimage::{
+        HintWriterClient, PreimageOracleClient,
+        errors::{PreimageOracleError, PreimageOracleResult},
+    };
+    use kona_registry::HashMap;
+
+    /// An in-memory preimage oracle serving keccak256 preimages.
+    #[derive(Debug, Default, Clone)]
+    struct MockOracle {
+        preimages: HashMap<PreimageKey, Vec<u8>>,
+    }
+
+    impl MockOracle {
+        fn insert(&mut self, preimage: Vec<u8>) {
+            self.preimages.insert(PreimageKey::new_keccak256(*keccak256(&preimage)), preimage);
+        }
+    }
+
+    #[async_trait]
+    impl PreimageOracleClient for MockOracle {
+        async fn get(&self, key: PreimageKey) -> PreimageOracleResult<Vec<u8>> {
+            self.preimages.get(&key).cloned().ok_or(PreimageOracleError::KeyNotFound)
+        }
+
+        async fn get_exact(&self, key: PreimageKey, buf: &mut [u8]) -> PreimageOracleResult<()> {
+            buf.copy_from_slice(&self.get(key).await?);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl HintWriterClient for MockOracle {
+        async fn write(&self, _hint: &str) -> PreimageOracleResult<()> {
+            Ok(())
+        }
+    }
+
+    /// Reconstructs the block for `header` through an [OracleL2ChainProvider] headed at it.
+    async fn reconstruct_block(header: &Header, hardforks: HardForkConfig) -> OpBlock {
+        let mut oracle = MockOracle::default();
+        let mut encoded_header = Vec::new();
+        header.encode(&mut encoded_header);
+        oracle.insert(encoded_header);
+        // The empty transactions trie.
+        oracle.insert(vec![alloy_rlp::EMPTY_STRING_CODE]);
+
+        let cfg = RollupConfig { hardforks, ..Default::default() };
+        let mut provider =
+            OracleL2ChainProvider::new(header.hash_slow(), Arc::new(cfg), Arc::new(oracle));
+        provider.block_by_number(header.number).await.unwrap()
+    }
+
+    fn test_header(withdrawals_root: Option<B256>) -> Header {
+        Header {
+            number: 10,
+            timestamp: 100,
+            transactions_root: EMPTY_ROOT_HASH,
+            // The withdrawals root is only encoded after the base fee.
+            base_fee_per_gas: Some(0),
+            withdrawals_root,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_block_by_number_pre_canyon_withdrawals() {
+        let header = test_header(None);
+        let block = reconstruct_block(&header, HardForkConfig::default()).await;
+
+        assert!(block.body.withdrawals.is_none());
+        assert_eq!(block.header.hash_slow(), header.hash_slow());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_block_by_number_canyon_withdrawals() {
+        let header = test_header(Some(EMPTY_ROOT_HASH));
+        let hardforks = HardForkConfig { canyon_time: Some(0), ..Default::default() };
+        let block = reconstruct_block(&header, hardforks).await;
+
+        // The empty withdrawals list is exactly what the header commits to.
+        let withdrawals = block.body.withdrawals.as_ref().unwrap();
+        assert!(withdrawals.is_empty());
+        assert_eq!(Some(calculate_withdrawals_root(withdrawals)), header.withdrawals_root);
+        assert_eq!(block.header.hash_slow(), header.hash_slow());
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_block_by_number_isthmus_withdrawals_root() {
+        // Post-Isthmus, the withdrawals root commits to the `L2ToL1MessagePasser` storage root.
+        let message_passer_root = B256::repeat_byte(0xaa);
+        let header = test_header(Some(message_passer_root));
+        let hardforks =
+            HardForkConfig { canyon_time: Some(0), isthmus_time: Some(0), ..Default::default() };
+        let block = reconstruct_block(&header, hardforks).await;
+
+        assert!(block.body.withdrawals.as_ref().unwrap().is_empty());
+        assert_eq!(block.header.withdrawals_root, Some(message_passer_root));
+        assert_eq!(block.header.hash_slow(), header.hash_slow());
+    }
+}
@@ -4,6 +4,8 @@
 use crate::{FileDescriptor, io};
 use alloc::boxed::Box;
 use async_trait::async_trait;
+#[cfg(not(any(target_arch = "mips64", target_arch = "riscv64")))]
+use core::time::Duration;
 use core::{
     cell::RefCell,
     cmp::Ordering,
@@ -15,6 +17,8 @@ use kona_preimage::{
     Channel,
     errors::{ChannelError, ChannelResult},
 };
+#[cfg(not(any(target_arch = "mips64", target_arch = "riscv64")))]
+use std::time::Instant;
 
 /// [FileChannel] is a handle for one end of a bidirectional channel.
 #[derive(Debug, Clone, Copy)]
@@ -44,17 +48,110 @@ impl FileChannel {
 
 #[async_trait]
 impl Channel for FileChannel {
+    /// Reads available data into `buf`. If the read end is in non-blocking mode and no data is
+    /// available yet, `Ok(0)` is returned.
+    ///
+    /// Returns [ChannelError::Closed] if the write end of the channel has been closed.
     async fn read(&self, buf: &mut [u8]) -> ChannelResult<usize> {
-        io::read(self.read_handle, buf).map_err(|_| ChannelError::Closed)
+        match io::read(self.read_handle, buf) {
+            Ok(0) if !buf.is_empty() => Err(ChannelError::Closed),
+            Ok(n) => Ok(n),
+            Err(e) if e.is_would_block() => Ok(0),
+            Err(_) => Err(ChannelError::Closed),
+        }
     }
 
+    /// Reads until `buf` is full, waiting for data if the read end is in non-blocking mode.
+    ///
+    /// Returns [ChannelError::Closed] if the write end of the channel is closed before `buf` is
+    /// full.
     async fn read_exact(&self, buf: &mut [u8]) -> ChannelResult<usize> {
-        ReadFuture::new(*self, buf).await.map_err(|_| ChannelError::Closed)
+        ReadFuture::new(*self, buf).await
     }
 
     async fn write(&self, buf: &[u8]) -> ChannelResult<usize> {
         WriteFuture::new(*self, buf).await.map_err(|_| ChannelError::Closed)
     }
+
+    /// Reads until `buf` is full, polling the read end for data until `timeout` has elapsed.
+    ///
+    /// Returns [ChannelError::Timeout] if `buf` is not full by the deadline, in which case the
+    /// bytes read so far are lost.
+    #[cfg(not(any(target_arch = "mips64", target_arch = "riscv64")))]
+    async fn read_with_timeout(&self, buf: &mut [u8], timeout: Duration) -> ChannelResult<usize> {
+        let deadline = Instant::now() + timeout;
+        let mut read = 0;
+        while read < buf.len() {
+            poll_until(self.read_handle, libc::POLLIN, deadline, timeout)?;
+            match io::read(self.read_handle, &mut buf[read..]) {
+                Ok(0) => return Err(ChannelError::Closed),
+                Ok(n) => read += n,
+                Err(e) if e.is_would_block() => {}
+                Err(_) => return Err(ChannelError::Closed),
+            }
+        }
+        Ok(read)
+    }
+
+    /// Writes the full buffer, polling the write end for capacity until `timeout` has elapsed.
+    ///
+    /// Returns [ChannelError::Timeout] if the buffer is not fully written by the deadline.
+    #[cfg(not(any(target_arch = "mips64", target_arch = "riscv64")))]
+    async fn write_with_timeout(&self, buf: &[u8], timeout: Duration) -> ChannelResult<usize> {
+        let deadline = Instant::now() + timeout;
+        let mut written = 0;
+        while written < buf.len() {
+            poll_until(self.write_handle, libc::POLLOUT, deadline, timeout)?;
+
+            // A ready write end accepts at least `PIPE_BUF` bytes without blocking.
+            let chunk = &buf[written..buf.len().min(written + libc::PIPE_BUF)];
+            // SAFETY: `chunk` is a valid, initialized buffer of `chunk.len()` bytes.
+            let n = unsafe {
+                libc::write(i32::from(self.write_handle), chunk.as_ptr().cast(), chunk.len())
+            };
+            if n < 0 {
+                match std::io::Error::last_os_error().kind() {
+                    std::io::ErrorKind::Interrupted | std::io::ErrorKind::WouldBlock => continue,
+                    _ => return Err(ChannelError::Closed),
+                }
+            }
+            written += n as usize;
+        }
+        Ok(written)
+    }
+}
+
+/// Blocks until the file descriptor is ready for the given poll `events`, or fails with
+/// [ChannelError::Timeout] once the `deadline` has passed.
+///
+/// Hangups and errors on the file descriptor are reported as ready, leaving the subsequent read or
+/// write to surface them.
+#[cfg(not(any(target_arch = "mips64", target_arch = "riscv64")))]
+fn poll_until(
+    fd: FileDescriptor,
+    events: i16,
+    deadline: Instant,
+    timeout: Duration,
+) -> ChannelResult<()> {
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(ChannelError::Timeout(timeout));
+        }
+
+        // Round up, so that a sub-millisecond remainder doesn't busy-loop until the deadline.
+        let millis = remaining.as_nanos().div_ceil(1_000_000).min(i32::MAX as u128) as i32;
+        let mut poll_fd = libc::pollfd { fd: i32::from(fd), events, revents: 0 };
+        // SAFETY: `poll_fd` is a valid `pollfd`, and the count of `1` matches.
+        match unsafe { libc::poll(&mut poll_fd, 1, millis) } {
+            0 => continue,
+            n if n > 0 => return Ok(()),
+            _ if std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted => {
+                continue
+            }
+            _ => return Err(ChannelError::Closed),
+        }
+    }
 }
 
 /// A future that reads from a channel, returning [Poll::Ready] when the buffer is full.
@@ -81,8 +178,18 @@ impl Future for ReadFuture<'_> {
     fn poll(mut self: Pin<&mut Self>, ctx: &mut Context<'_>) -> Poll<Self::Output> {
         let mut buf = self.buf.borrow_mut();
         let buf_len = buf.len();
-        let chunk_read = io::read(self.channel.read_handle, &mut buf[self.read..])
-            .map_err(|_| ChannelError::Closed)?;
+        if self.read >= buf_len {
+            return Poll::Ready(Ok(self.read));
+        }
+
+        let chunk_read = match io::read(self.channel.read_handle, &mut buf[self.read..]) {
+            // A zero-length read into a non-empty buffer signals that the write end is closed.
+            Ok(0) => return Poll::Ready(Err(ChannelError::Closed)),
+            Ok(n) => n,
+            // The read end is in non-blocking mode, and no data is available yet.
+            Err(e) if e.is_would_block() => 0,
+            Err(_) => return Poll::Ready(Err(ChannelError::Closed)),
+        };
 
         // Drop the borrow on self.
         drop(buf);
@@ -152,4 +259,92 @@ mod tests {
         let ref_read_handle = chan.read_handle();
         assert_eq!(read_handle, ref_read_handle);
     }
+
+    #[tokio::test]
+    async fn test_read_closed_write_end() {
+        use std::{
+            io::Write,
+            os::{fd::AsRawFd, unix::net::UnixStream},
+        };
+
+        let (reader, mut writer) = UnixStream::pair().unwrap();
+        let read_handle = FileDescriptor::Raw(reader.as_raw_fd() as usize);
+        let chan = FileChannel::new(read_handle, FileDescriptor::StdOut);
+
+        writer.write_all(&[0xFF; 4]).unwrap();
+        drop(writer);
+
+        // The buffered data is still readable, but the channel reports that it was closed
+        // rather than waiting for more data.
+        let mut buf = [0u8; 8];
+        assert!(matches!(chan.read_exact(&mut buf).await, Err(ChannelError::Closed)));
+        assert_eq!(buf[..4], [0xFF; 4]);
+        assert!(matches!(chan.read(&mut buf).await, Err(ChannelError::Closed)));
+    }
+
+    #[tokio::test]
+    async fn test_read_non_blocking() {
+        use std::{
+            io::Write,
+            os::{fd::AsRawFd, unix::net::UnixStream},
+        };
+
+        let (reader, mut writer) = UnixStream::pair().unwrap();
+        reader.set_nonblocking(true).unwrap();
+        let read_handle = FileDescriptor::Raw(reader.as_raw_fd() as usize);
+        let chan = FileChannel::new(read_handle, FileDescriptor::StdOut);
+
+        // No data is available yet, but the channel is still open.
+        let mut buf = [0u8; 4];
+        assert_eq!(chan.read(&mut buf).await.unwrap(), 0);
+
+        writer.write_all(&[0xFF; 4]).unwrap();
+        assert_eq!(chan.read_exact(&mut buf).await.unwrap(), 4);
+        assert_eq!(buf, [0xFF; 4]);
+    }
+
+    #[tokio::test]
+    async fn test_read_with_timeout() {
+        use std::{
+            io::Write,
+            os::{fd::AsRawFd, unix::net::UnixStream},
+        };
+
+        let (reader, mut writer) = UnixStream::pair().unwrap();
+        let read_handle = FileDescriptor::Raw(reader.as_raw_fd() as usize);
+        let chan = FileChannel::new(read_handle, FileDescriptor::StdOut);
+        let timeout = Duration::from_millis(50);
+
+        writer.write_all(&[0xFF; 4]).unwrap();
+        let mut buf = [0u8; 4];
+        assert_eq!(chan.read_with_timeout(&mut buf, timeout).await.unwrap(), 4);
+        assert_eq!(buf, [0xFF; 4]);
+
+        // The write end is still open, but nothing more is written.
+        let res = chan.read_with_timeout(&mut buf, timeout).await;
+        assert!(matches!(res, Err(ChannelError::Timeout(t)) if t == timeout));
+    }
+
+    #[tokio::test]
+    async fn test_oracle_reader_poisoned_on_timeout() {
+        use kona_preimage::{
+            OracleReader, PreimageKey, PreimageOracleClient, errors::PreimageOracleError,
+        };
+        use std::os::{fd::AsRawFd, unix::net::UnixStream};
+
+        // The host end of the channel reads the requests, but never responds.
+        let (client, _host) = UnixStream::pair().unwrap();
+        let handle = FileDescriptor::Raw(client.as_raw_fd() as usize);
+        let reader = OracleReader::new(FileChannel::new(handle, handle))
+            .with_timeout(Some(Duration::from_millis(50)));
+        let key = PreimageKey::new_keccak256([0xFF; 32]);
+
+        let res = reader.get(key).await;
+        assert!(matches!(res, Err(PreimageOracleError::IOError(ChannelError::Timeout(_)))));
+        assert!(reader.is_poisoned());
+
+        // A late response to the timed out request can't be mistaken for the next one's.
+        let res = reader.clone().get(key).await;
+        assert!(matches!(res, Err(PreimageOracleError::Poisoned)));
+    }
 }
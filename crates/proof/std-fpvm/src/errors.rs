@@ -7,5 +7,16 @@ use thiserror::Error;
 #[error("IO error (errno: {_0})")]
 pub struct IOError(pub i32);
 
+impl IOError {
+    /// The error number reported when an operation on a non-blocking file descriptor would block.
+    pub const EAGAIN: i32 = 11;
+
+    /// Returns `true` if the operation failed because the file descriptor is in non-blocking mode
+    /// and is not ready yet.
+    pub const fn is_would_block(&self) -> bool {
+        self.0 == Self::EAGAIN
+    }
+}
+
 /// A [Result] type for the [IOError].
 pub type IOResult<T> = Result<T, IOError>;
@@ -11,7 +11,7 @@ cfg_if! {
         #[doc = "Concrete implementation of the [BasicKernelInterface] trait for the `riscv64` target architecture."]
         pub(crate) type ClientIO = crate::riscv64::io::RiscV64IO;
     } else {
-        use std::{fs::File, os::fd::FromRawFd, io::{Read, Write}};
+        use std::{fs::File, os::fd::FromRawFd, io::{ErrorKind, Read, Write}};
         use crate::errors::IOError;
 
         #[doc = "Native implementation of the [BasicKernelInterface] trait."]
@@ -20,7 +20,7 @@ cfg_if! {
         impl BasicKernelInterface for NativeClientIO {
             fn write(fd: FileDescriptor, buf: &[u8]) -> IOResult<usize> {
                 unsafe {
-                    let mut file = File::from_raw_fd(fd as i32);
+                    let mut file = File::from_raw_fd(i32::from(fd));
                     file.write_all(buf).map_err(|_| IOError(-9))?;
                     std::mem::forget(file);
                     Ok(buf.len())
@@ -29,10 +29,13 @@ cfg_if! {
 
             fn read(fd: FileDescriptor, buf: &mut [u8]) -> IOResult<usize> {
                 unsafe {
-                    let mut file = File::from_raw_fd(fd as i32);
-                    file.read(buf).map_err(|_| IOError(-9))?;
+                    let mut file = File::from_raw_fd(i32::from(fd));
+                    let res = file.read(buf).map_err(|e| match e.kind() {
+                        ErrorKind::WouldBlock => IOError(IOError::EAGAIN),
+                        _ => IOError(-9),
+                    });
                     std::mem::forget(file);
-                    Ok(buf.len())
+                    res
                 }
             }
 
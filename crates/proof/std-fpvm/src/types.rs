@@ -17,6 +17,9 @@ pub enum FileDescriptor {
     PreimageRead,
     /// Write-only. Used to request pre-images.
     PreimageWrite,
+    /// A raw file descriptor, used to test channels over pipes opened by the tests.
+    #[cfg(test)]
+    Raw(usize),
 }
 
 impl From<FileDescriptor> for usize {
@@ -29,6 +32,8 @@ impl From<FileDescriptor> for usize {
             FileDescriptor::HintWrite => 4,
             FileDescriptor::PreimageRead => 5,
             FileDescriptor::PreimageWrite => 6,
+            #[cfg(test)]
+            FileDescriptor::Raw(fd) => fd,
         }
     }
 }
@@ -95,6 +95,12 @@ pub enum PipelineError {
     /// The data source can no longer provide any more data.
     #[error("Data source exhausted")]
     EndOfSource,
+    /// The target L2 block could not be reached before the data source was exhausted.
+    #[error("Target L2 block #{0} unreachable; data source exhausted")]
+    TargetUnreachable(u64),
+    /// The starting L1 origin of the pipeline is ahead of the L1 origin of the L2 safe head.
+    #[error("Starting L1 origin #{0} is ahead of the safe head's L1 origin #{1}")]
+    OriginAheadOfSafeHead(u64, u64),
     /// Provider error variant.
     #[error("Provider error: {0}")]
     Provider(String),
@@ -133,6 +139,12 @@ pub enum ResetError {
     /// The second argument is the parent_hash of the next l1 origin block.
     #[error("L1 reorg detected: expected {0}, got {1}")]
     ReorgDetected(B256, B256),
+    /// The L1 blocks that batches are validated against are not strictly increasing in number
+    /// and timestamp.
+    /// The first argument is the number of the earlier L1 block.
+    /// The second argument is the number of the L1 block that does not follow it.
+    #[error("L1 block {1} does not follow L1 block {0}")]
+    NonMonotonicL1Blocks(u64, u64),
     /// Attributes builder error variant, with [BuilderError].
     #[error("Attributes builder error: {0}")]
     AttributesBuilder(#[from] BuilderError),
@@ -221,6 +233,7 @@ mod tests {
             ResetError::BadTimestamp(0, 0),
             ResetError::L1OriginMismatch(0, 0),
             ResetError::ReorgDetected(Default::default(), Default::default()),
+            ResetError::NonMonotonicL1Blocks(0, 0),
             ResetError::AttributesBuilder(BuilderError::BlockMismatch(
                 Default::default(),
                 Default::default(),
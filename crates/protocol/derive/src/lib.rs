@@ -9,6 +9,10 @@
 
 extern crate alloc;
 
+// The `metrics` macros expand to paths within `std`.
+#[cfg(feature = "metrics")]
+extern crate std;
+
 #[macro_use]
 extern crate tracing;
 
@@ -27,5 +31,8 @@ pub mod stages;
 pub mod traits;
 pub mod types;
 
+#[cfg(feature = "metrics")]
+pub mod metrics;
+
 #[cfg(feature = "test-utils")]
 pub mod test_utils;
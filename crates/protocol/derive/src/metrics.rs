@@ -0,0 +1,32 @@
+//! Metrics for the derivation pipeline.
+//!
+//! Metrics are recorded through the [metrics] facade, and are exported by whichever recorder is
+//! installed by the binary (e.g. the prometheus recorder installed by
+//! `kona_cli::init_prometheus_server`).
+
+/// Gauge of the number of channels buffered in the channel bank.
+pub const CHANNEL_BANK_BUFFERED_CHANNELS: &str = "kona_derive_channel_bank_buffered_channels";
+
+/// Gauge of the total size of the frames buffered in the channel bank, in bytes.
+pub const CHANNEL_BANK_BUFFERED_BYTES: &str = "kona_derive_channel_bank_buffered_bytes";
+
+/// Counter of the number of channels dropped by the channel bank, either because they timed out
+/// or because the channel bank was pruned.
+pub const CHANNEL_BANK_DROPPED_CHANNELS: &str = "kona_derive_channel_bank_dropped_channels_total";
+
+/// Describes the channel bank metrics to the installed recorder.
+pub fn describe_channel_bank() {
+    metrics::describe_gauge!(
+        CHANNEL_BANK_BUFFERED_CHANNELS,
+        "Number of channels buffered in the channel bank"
+    );
+    metrics::describe_gauge!(
+        CHANNEL_BANK_BUFFERED_BYTES,
+        metrics::Unit::Bytes,
+        "Total size of the frames buffered in the channel bank"
+    );
+    metrics::describe_counter!(
+        CHANNEL_BANK_DROPPED_CHANNELS,
+        "Number of channels dropped by the channel bank after timing out or being pruned"
+    );
+}
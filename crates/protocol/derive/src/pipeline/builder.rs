@@ -11,7 +11,7 @@ use crate::{
 use alloc::sync::Arc;
 use core::fmt::Debug;
 use kona_genesis::RollupConfig;
-use kona_protocol::BlockInfo;
+use kona_protocol::{BlockInfo, SpanBatchLimits};
 
 /// Type alias for the [L1Traversal] stage.
 pub type L1TraversalStage<P> = L1Traversal<P>;
@@ -52,6 +52,7 @@ where
     builder: Option<B>,
     origin: Option<BlockInfo>,
     rollup_config: Option<Arc<RollupConfig>>,
+    span_batch_limits: SpanBatchLimits,
 }
 
 impl<B, P, T, D> Default for PipelineBuilder<B, P, T, D>
@@ -69,6 +70,7 @@ where
             builder: None,
             origin: None,
             rollup_config: None,
+            span_batch_limits: SpanBatchLimits::PROTOCOL,
         }
     }
 }
@@ -121,6 +123,17 @@ where
         self
     }
 
+    /// Sets the bounds on the sizes declared by span batches, which default to the protocol's
+    /// [SpanBatchLimits::PROTOCOL].
+    ///
+    /// ## Warning
+    /// Limits below the protocol's reject span batches that are valid by consensus, causing the
+    /// pipeline to derive a different chain than the rest of the network.
+    pub const fn span_batch_limits(mut self, span_batch_limits: SpanBatchLimits) -> Self {
+        self.span_batch_limits = span_batch_limits;
+        self
+    }
+
     /// Builds the pipeline.
     pub fn build(self) -> DerivationPipeline<AttributesQueueStage<D, P, T, B>, T> {
         self.into()
@@ -149,7 +162,8 @@ where
         let l1_retrieval = L1Retrieval::new(l1_traversal, dap_source);
         let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config));
         let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue);
-        let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config));
+        let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config))
+            .with_span_batch_limits(builder.span_batch_limits);
         let batch_stream =
             BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone());
         let batch_provider =
@@ -7,7 +7,7 @@ use crate::{
     },
     types::{ActivationSignal, PipelineResult, ResetSignal, Signal, StepResult},
 };
-use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
+use alloc::{boxed::Box, collections::VecDeque, string::ToString, sync::Arc, vec::Vec};
 use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_genesis::{RollupConfig, SystemConfig};
@@ -48,6 +48,89 @@ where
     }
 }
 
+impl<S, P> DerivationPipeline<S, P>
+where
+    S: NextAttributes + SignalReceiver + OriginProvider + OriginAdvancer + Debug + Send + Sync,
+    P: L2ChainProvider + Send + Sync + Debug,
+{
+    /// Drives the pipeline from the given L2 safe head, collecting all [OpAttributesWithParent]
+    /// up to and including the L2 block with number `target_l2_block`.
+    ///
+    /// As the attributes are not executed, the cursor for each subsequent step is fetched from the
+    /// L2 chain provider, making this suitable for re-deriving a range of an existing L2 chain.
+    ///
+    /// ## Returns
+    /// - `Ok(Vec<OpAttributesWithParent>)` - The attributes for blocks `l2_safe_head + 1` through
+    ///   `target_l2_block`. Empty if the target is not ahead of the safe head.
+    /// - `Err(PipelineError::TargetUnreachable)` - The L1 data was exhausted before the target.
+    /// - `Err(_)` - Any other error encountered while stepping, such as a temporary provider error
+    ///   when advancing the L1 origin.
+    pub async fn produce_until(
+        &mut self,
+        l2_safe_head: L2BlockInfo,
+        target_l2_block: u64,
+    ) -> PipelineResult<Vec<OpAttributesWithParent>> {
+        let mut produced = Vec::new();
+        let mut cursor = l2_safe_head;
+        if target_l2_block <= cursor.block_info.number {
+            return Ok(produced);
+        }
+
+        loop {
+            while let Some(attributes) = self.prepared.pop_front() {
+                let number = attributes.parent.block_info.number + 1;
+                produced.push(attributes);
+                if number >= target_l2_block {
+                    return Ok(produced);
+                }
+
+                cursor = self
+                    .l2_chain_provider
+                    .l2_block_info_by_number(number)
+                    .await
+                    .map_err(|e| PipelineError::Provider(e.to_string()).temp())?;
+            }
+
+            match self.step(cursor).await {
+                StepResult::PreparedAttributes | StepResult::AdvancedOrigin => {}
+                StepResult::StepFailed(PipelineErrorKind::Temporary(e)) => {
+                    trace!(target: "pipeline", "Retrying step after temporary error: {:?}", e);
+                }
+                StepResult::OriginAdvanceErr(PipelineErrorKind::Temporary(PipelineError::Eof)) |
+                StepResult::OriginAdvanceErr(PipelineErrorKind::Critical(
+                    PipelineError::EndOfSource,
+                )) |
+                StepResult::StepFailed(PipelineErrorKind::Critical(PipelineError::EndOfSource)) => {
+                    warn!(
+                        target: "pipeline",
+                        "L1 data exhausted at L2 block #{} before reaching target #{}",
+                        cursor.block_info.number,
+                        target_l2_block
+                    );
+                    return Err(PipelineError::TargetUnreachable(target_l2_block).crit());
+                }
+                StepResult::OriginAdvanceErr(e) | StepResult::StepFailed(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Resets the pipeline to the given L1 origin and L2 safe head, e.g. after an L1 reorg.
+    ///
+    /// All prepared attributes are discarded, and a [`Signal::Reset`] is sent through the stages,
+    /// flushing their buffered data and re-seeding the L1 traversal at `origin`. The system config
+    /// is re-fetched at the L2 safe head.
+    pub async fn reset_to(
+        &mut self,
+        origin: BlockInfo,
+        safe_head: L2BlockInfo,
+    ) -> PipelineResult<()> {
+        self.prepared.clear();
+        let signal =
+            ResetSignal { l2_safe_head: safe_head, l1_origin: origin, system_config: None };
+        self.signal(signal.signal()).await
+    }
+}
+
 impl<S, P> OriginProvider for DerivationPipeline<S, P>
 where
     S: NextAttributes + SignalReceiver + OriginProvider + OriginAdvancer + Debug + Send,
@@ -193,7 +276,7 @@ where
 mod tests {
     use super::*;
     use crate::{pipeline::DerivationPipeline, test_utils::*};
-    use alloc::{string::ToString, sync::Arc};
+    use alloc::{string::ToString, sync::Arc, vec::Vec};
     use alloy_rpc_types_engine::PayloadAttributes;
     use kona_genesis::{RollupConfig, SystemConfig};
     use kona_protocol::L2BlockInfo;
@@ -329,4 +412,181 @@ mod tests {
         let result = pipeline.signal(ResetSignal::default().signal()).await;
         assert!(result.is_ok());
     }
+
+    /// A [NextAttributes] stage that buffers frames until it is reset.
+    #[derive(Debug, Default)]
+    struct BufferedAttributes {
+        frames: Vec<u64>,
+        origin: BlockInfo,
+    }
+
+    #[async_trait]
+    impl SignalReceiver for BufferedAttributes {
+        async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
+            if let Signal::Reset(ResetSignal { l1_origin, .. }) = signal {
+                self.frames.clear();
+                self.origin = l1_origin;
+            }
+            Ok(())
+        }
+    }
+
+    impl OriginProvider for BufferedAttributes {
+        fn origin(&self) -> Option<BlockInfo> {
+            Some(self.origin)
+        }
+    }
+
+    #[async_trait]
+    impl OriginAdvancer for BufferedAttributes {
+        async fn advance_origin(&mut self) -> PipelineResult<()> {
+            self.frames.push(self.origin.number);
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl NextAttributes for BufferedAttributes {
+        async fn next_attributes(
+            &mut self,
+            cursor: L2BlockInfo,
+        ) -> PipelineResult<OpAttributesWithParent> {
+            if self.frames.is_empty() {
+                return Err(PipelineError::Eof.temp());
+            }
+            Ok(OpAttributesWithParent { parent: cursor, ..default_test_payload_attributes() })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_derivation_pipeline_reset_to() {
+        let mut l2_chain_provider = TestL2ChainProvider::default();
+        l2_chain_provider.system_configs.insert(5, SystemConfig::default());
+        let mut pipeline = DerivationPipeline::new(
+            BufferedAttributes::default(),
+            Arc::new(RollupConfig::default()),
+            l2_chain_provider,
+        );
+
+        // Buffer a frame from the stale origin and prepare attributes from it.
+        let cursor = L2BlockInfo::default();
+        assert_eq!(pipeline.step(cursor).await, StepResult::AdvancedOrigin);
+        assert_eq!(pipeline.step(cursor).await, StepResult::PreparedAttributes);
+        assert_eq!(pipeline.prepared.len(), 1);
+
+        let origin = BlockInfo { number: 10, ..Default::default() };
+        let safe_head = L2BlockInfo {
+            block_info: BlockInfo { number: 5, ..Default::default() },
+            ..Default::default()
+        };
+        pipeline.reset_to(origin, safe_head).await.unwrap();
+
+        // The stale frames and attributes are discarded, and the origin is re-seeded.
+        assert!(pipeline.prepared.is_empty());
+        assert!(pipeline.attributes.frames.is_empty());
+        assert_eq!(pipeline.origin(), Some(origin));
+        assert_eq!(pipeline.step(safe_head).await, StepResult::AdvancedOrigin);
+        assert_eq!(pipeline.attributes.frames, [10]);
+    }
+
+    /// A [NextAttributes] stage that derives `remaining` consecutive attributes on top of the
+    /// cursor before exhausting its L1 data.
+    #[derive(Debug, Default)]
+    struct RangeAttributes {
+        remaining: u64,
+        /// Whether advancing the origin fails with a temporary provider error, instead of
+        /// exhausting the L1 data.
+        provider_down: bool,
+    }
+
+    #[async_trait]
+    impl SignalReceiver for RangeAttributes {
+        async fn signal(&mut self, _: Signal) -> PipelineResult<()> {
+            Ok(())
+        }
+    }
+
+    impl OriginProvider for RangeAttributes {
+        fn origin(&self) -> Option<BlockInfo> {
+            Some(BlockInfo::default())
+        }
+    }
+
+    #[async_trait]
+    impl OriginAdvancer for RangeAttributes {
+        async fn advance_origin(&mut self) -> PipelineResult<()> {
+            if self.provider_down {
+                return Err(PipelineError::Provider("L1 block not found".to_string()).temp());
+            }
+            Err(PipelineError::EndOfSource.crit())
+        }
+    }
+
+    #[async_trait]
+    impl NextAttributes for RangeAttributes {
+        async fn next_attributes(
+            &mut self,
+            cursor: L2BlockInfo,
+        ) -> PipelineResult<OpAttributesWithParent> {
+            if self.remaining == 0 {
+                return Err(PipelineError::Eof.temp());
+            }
+            self.remaining -= 1;
+            Ok(OpAttributesWithParent { parent: cursor, ..default_test_payload_attributes() })
+        }
+    }
+
+    fn range_pipeline(remaining: u64) -> DerivationPipeline<RangeAttributes, TestL2ChainProvider> {
+        let blocks = (0..=10)
+            .map(|number| L2BlockInfo {
+                block_info: BlockInfo { number, ..Default::default() },
+                ..Default::default()
+            })
+            .collect();
+        let l2_chain_provider = TestL2ChainProvider { blocks, ..Default::default() };
+        DerivationPipeline::new(
+            RangeAttributes { remaining, provider_down: false },
+            Arc::new(RollupConfig::default()),
+            l2_chain_provider,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_produce_until_target() {
+        let mut pipeline = range_pipeline(10);
+        let safe_head = pipeline.l2_chain_provider.blocks[2];
+
+        let produced = pipeline.produce_until(safe_head, 5).await.unwrap();
+        let parents = produced.iter().map(|a| a.parent.block_info.number).collect::<Vec<_>>();
+        assert_eq!(parents, [2, 3, 4]);
+
+        // Derivation short-circuits once the target is reached.
+        assert_eq!(pipeline.attributes.remaining, 7);
+    }
+
+    #[tokio::test]
+    async fn test_produce_until_target_behind_safe_head() {
+        let mut pipeline = range_pipeline(10);
+        let safe_head = pipeline.l2_chain_provider.blocks[5];
+        assert!(pipeline.produce_until(safe_head, 5).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_produce_until_target_unreachable() {
+        let mut pipeline = range_pipeline(2);
+        let safe_head = pipeline.l2_chain_provider.blocks[0];
+
+        let err = pipeline.produce_until(safe_head, 5).await.unwrap_err();
+        assert_eq!(err, PipelineError::TargetUnreachable(5).crit());
+    }
+
+    #[tokio::test]
+    async fn test_produce_until_temporary_provider_error() {
+        let mut pipeline = range_pipeline(2);
+        pipeline.attributes.provider_down = true;
+        let safe_head = pipeline.l2_chain_provider.blocks[0];
+
+        let err = pipeline.produce_until(safe_head, 5).await.unwrap_err();
+        assert_eq!(err, PipelineError::Provider("L1 block not found".to_string()).temp());
+    }
 }
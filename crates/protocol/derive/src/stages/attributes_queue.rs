@@ -158,7 +158,7 @@ where
         &mut self,
         parent: L2BlockInfo,
     ) -> PipelineResult<OpAttributesWithParent> {
-        self.next_attributes(parent).await
+        trace_stage!("attributes_queue", self.origin(), self.next_attributes(parent))
     }
 }
 
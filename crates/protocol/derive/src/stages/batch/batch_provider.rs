@@ -164,9 +164,13 @@ where
         self.attempt_update()?;
 
         if let Some(batch_validator) = self.batch_validator.as_mut() {
-            batch_validator.next_batch(parent).await
+            trace_stage!(
+                "batch_validator",
+                batch_validator.origin(),
+                batch_validator.next_batch(parent)
+            )
         } else if let Some(batch_queue) = self.batch_queue.as_mut() {
-            batch_queue.next_batch(parent).await
+            trace_stage!("batch_queue", batch_queue.origin(), batch_queue.next_batch(parent))
         } else {
             Err(PipelineError::NotEnoughData.temp())
         }
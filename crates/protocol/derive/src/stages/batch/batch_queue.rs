@@ -1,8 +1,8 @@
 //! This module contains the `BatchQueue` stage implementation.
 
-use super::NextBatchProvider;
 use crate::{
     errors::{PipelineEncodingError, PipelineError, PipelineErrorKind, ResetError},
+    stages::{NextBatchProvider, record_blocked_reason},
     traits::{AttributesProvider, L2ChainProvider, OriginAdvancer, OriginProvider, SignalReceiver},
     types::{PipelineResult, ResetSignal, Signal},
 };
@@ -11,7 +11,7 @@ use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_genesis::RollupConfig;
 use kona_protocol::{
-    Batch, BatchValidity, BatchWithInclusionBlock, BlockInfo, L2BlockInfo, SingleBatch,
+    Batch, BatchValidity, BatchWithInclusionBlock, BlockInfo, DropReason, L2BlockInfo, SingleBatch,
 };
 
 /// [BatchQueue] is responsible for ordering unordered batches
@@ -124,6 +124,9 @@ where
 
         let origin = self.origin.ok_or(PipelineError::MissingOrigin.crit())?;
 
+        // Batches cannot be validated against an inconsistent view of L1.
+        self.check_l1_blocks_monotonic()?;
+
         // Go over all batches, in order of inclusion, and find the first batch we can accept.
         // Filter in-place by only remembering the batches that may be processed in the future, or
         // any undecided ones.
@@ -144,11 +147,16 @@ where
                         warn!(target: "batch_queue", "[HOLOCENE] Dropping future batch with parent: {}", parent.block_info.number);
                     }
                 }
-                BatchValidity::Drop => {
+                BatchValidity::Drop(reason) => {
                     // If we drop a batch, flush previous batches buffered in the BatchStream
                     // stage.
                     self.prev.flush();
-                    warn!(target: "batch_queue", "Dropping batch with parent: {}", parent.block_info);
+                    warn!(
+                        target: "batch_queue",
+                        %reason,
+                        "Dropping batch with parent: {}",
+                        parent.block_info
+                    );
                     continue;
                 }
                 BatchValidity::Accept => {
@@ -159,6 +167,29 @@ where
                     break;
                 }
                 BatchValidity::Undecided => {
+                    // A batch is only valid within the sequence window of its epoch, which
+                    // op-node's `checkSingleBatch` enforces as
+                    // `batch.EpochNum + SeqWindowSize < l1InclusionBlock.Number -> drop`. Once the
+                    // origin has moved past the window, the batch can no longer be decided valid,
+                    // so it is dropped under the same rule rather than retried indefinitely.
+                    //
+                    // See: <https://specs.optimism.io/protocol/derivation.html#batch-queue>
+                    let epoch_num = match &batch.batch {
+                        Batch::Single(single) => single.epoch_num,
+                        Batch::Span(span) => span.starting_epoch_num(),
+                    };
+                    if epoch_num + self.cfg.seq_window_size < origin.number {
+                        self.prev.flush();
+                        warn!(
+                            target: "batch_queue",
+                            reason = %DropReason::SeqWindowExpired,
+                            "Dropping undecided batch with parent: {}",
+                            parent.block_info
+                        );
+                        continue;
+                    }
+
+                    record_blocked_reason(&validity);
                     remaining.extend_from_slice(&self.batches[i..]);
                     self.batches = remaining;
                     return Err(PipelineError::Eof.temp());
@@ -233,6 +264,26 @@ where
         Err(PipelineError::Eof.temp())
     }
 
+    /// Checks that the L1 blocks are strictly increasing in both number and timestamp. A repeated
+    /// L1 block is not considered inconsistent.
+    ///
+    /// Batch validation assumes that the L1 blocks are monotonic. An L1 reorg or malformed L1 data
+    /// may violate this, in which case the pipeline must be reset onto a consistent view of L1.
+    fn check_l1_blocks_monotonic(&self) -> PipelineResult<()> {
+        let Some(w) = self.l1_blocks.windows(2).find(|w| {
+            w[0] != w[1] && (w[0].number >= w[1].number || w[0].timestamp >= w[1].timestamp)
+        }) else {
+            return Ok(());
+        };
+        error!(
+            target: "batch_queue",
+            "L1 blocks are not strictly increasing, resetting: {} does not follow {}",
+            w[1],
+            w[0]
+        );
+        Err(ResetError::NonMonotonicL1Blocks(w[0].number, w[1].number).reset())
+    }
+
     /// Adds a batch to the queue.
     pub async fn add_batch(&mut self, batch: Batch, parent: L2BlockInfo) -> PipelineResult<()> {
         if self.l1_blocks.is_empty() {
@@ -241,6 +292,8 @@ where
         }
         let origin = self.origin.ok_or(PipelineError::MissingOrigin.crit())?;
         let data = BatchWithInclusionBlock { inclusion_block: origin, batch };
+        // Batches cannot be validated against an inconsistent view of L1.
+        self.check_l1_blocks_monotonic()?;
         // If we drop the batch, validation logs the drop reason with WARN level.
         let validity =
             data.check_batch(&self.cfg, &self.l1_blocks, parent, &mut self.fetcher).await;
@@ -483,6 +536,11 @@ mod tests {
         BatchReader::new(bytes, MAX_RLP_BYTES_PER_CHANNEL_FJORD as usize)
     }
 
+    /// Returns the L1 block following the default origin.
+    fn next_l1_block() -> BlockInfo {
+        BlockInfo { number: 1, timestamp: 1, ..Default::default() }
+    }
+
     #[test]
     fn test_pop_next_batch() {
         let cfg = Arc::new(RollupConfig::default());
@@ -824,6 +882,38 @@ mod tests {
         assert_eq!(bq.batches.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_derive_next_batch_drops_expired_undecided_batch() {
+        let cfg = Arc::new(RollupConfig { seq_window_size: 10, ..Default::default() });
+
+        // A batch that advances the epoch is undecided while only the current epoch is known.
+        let batch = SingleBatch { epoch_num: 1, ..Default::default() };
+        let mut bq = BatchQueue::new(
+            cfg,
+            TestNextBatchProvider::new(vec![]),
+            TestL2ChainProvider::default(),
+        );
+        bq.l1_blocks.push(BlockInfo::default());
+        bq.batches.push(BatchWithInclusionBlock {
+            inclusion_block: BlockInfo::default(),
+            batch: Batch::Single(batch),
+        });
+
+        // Within the sequence window of the batch's epoch, the undecided batch is retained.
+        bq.origin = Some(BlockInfo { number: 11, ..Default::default() });
+        let result = bq.derive_next_batch(false, L2BlockInfo::default()).await.unwrap_err();
+        assert_eq!(result, PipelineError::Eof.temp());
+        assert_eq!(bq.batches.len(), 1);
+        assert!(!bq.prev.flushed);
+
+        // Once the origin moves past the sequence window, the batch is dropped.
+        bq.origin = Some(BlockInfo { number: 12, ..Default::default() });
+        let result = bq.derive_next_batch(false, L2BlockInfo::default()).await.unwrap_err();
+        assert_eq!(result, PipelineError::Eof.temp());
+        assert!(bq.batches.is_empty());
+        assert!(bq.prev.flushed);
+    }
+
     #[tokio::test]
     async fn test_holocene_derive_next_batch_future() {
         let trace_store: TraceStorage = Default::default();
@@ -879,6 +969,93 @@ mod tests {
         assert!(logs[0].contains(warn_str));
     }
 
+    #[tokio::test]
+    async fn test_add_batch_non_monotonic_l1_blocks() {
+        // A batch that would otherwise be dropped, as it does not build on the parent.
+        let batch = SingleBatch { timestamp: 100, ..Default::default() };
+        let parent = L2BlockInfo {
+            block_info: BlockInfo { timestamp: 101, ..Default::default() },
+            ..Default::default()
+        };
+
+        let mut bq = BatchQueue::new(
+            Arc::new(RollupConfig::default()),
+            TestNextBatchProvider::new(vec![]),
+            TestL2ChainProvider::default(),
+        );
+        bq.origin = Some(BlockInfo { number: 2, ..Default::default() });
+        bq.l1_blocks.push(BlockInfo { number: 1, timestamp: 12, ..Default::default() });
+        bq.l1_blocks.push(BlockInfo { number: 2, timestamp: 10, ..Default::default() });
+
+        // The batch is not validated against the inconsistent L1 view, and the pipeline is reset.
+        let result = bq.add_batch(Batch::Single(batch), parent).await.unwrap_err();
+        assert_eq!(result, ResetError::NonMonotonicL1Blocks(1, 2).reset());
+        assert!(bq.batches.is_empty());
+        assert!(!bq.prev.flushed);
+    }
+
+    #[tokio::test]
+    async fn test_derive_next_batch_non_monotonic_l1_blocks() {
+        let mut bq = BatchQueue::new(
+            Arc::new(RollupConfig::default()),
+            TestNextBatchProvider::new(vec![]),
+            TestL2ChainProvider::default(),
+        );
+        bq.origin = Some(BlockInfo { number: 2, ..Default::default() });
+        bq.l1_blocks.push(BlockInfo { number: 1, timestamp: 12, ..Default::default() });
+        bq.l1_blocks.push(BlockInfo { number: 1, timestamp: 24, ..Default::default() });
+        bq.batches.push(BatchWithInclusionBlock {
+            inclusion_block: BlockInfo { number: 2, ..Default::default() },
+            batch: Batch::Single(SingleBatch { timestamp: 100, ..Default::default() }),
+        });
+
+        // No batch is validated, dropped, or generated against the inconsistent L1 view.
+        let result = bq.derive_next_batch(true, L2BlockInfo::default()).await.unwrap_err();
+        assert_eq!(result, ResetError::NonMonotonicL1Blocks(1, 1).reset());
+        assert_eq!(bq.batches.len(), 1);
+        assert_eq!(bq.l1_blocks.len(), 2);
+        assert!(!bq.prev.flushed);
+    }
+
+    #[tokio::test]
+    async fn test_derive_next_batch_undecided_trace() {
+        let trace_store: TraceStorage = Default::default();
+        let layer = CollectingLayer::new(trace_store.clone());
+        let subscriber = tracing_subscriber::Registry::default().with(layer);
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let mut provider = TestNextBatchProvider::new(vec![]);
+        provider.origin = Some(next_l1_block());
+        let mut bq = BatchQueue::new(
+            Arc::new(RollupConfig::default()),
+            provider,
+            TestL2ChainProvider::default(),
+        );
+        bq.origin = Some(next_l1_block());
+        bq.l1_blocks.push(BlockInfo::default());
+        // The batch advances the epoch, but the next L1 block is not yet known.
+        bq.batches.push(BatchWithInclusionBlock {
+            inclusion_block: next_l1_block(),
+            batch: Batch::Single(SingleBatch { epoch_num: 1, ..Default::default() }),
+        });
+
+        let result = trace_stage!(
+            "batch_queue",
+            bq.origin(),
+            bq.derive_next_batch(false, L2BlockInfo::default())
+        );
+        assert_eq!(result.unwrap_err(), PipelineError::Eof.temp());
+        assert_eq!(bq.batches.len(), 1);
+
+        // A single event is emitted within the stage's span, which records the undecided batch.
+        let traces = trace_store.get_by_level(Level::TRACE);
+        assert_eq!(traces.len(), 1);
+        assert!(traces[0].contains("derive::batch_queue"));
+        assert!(traces[0].contains("stage: \"batch_queue\""));
+        assert!(traces[0].contains("origin: 1"));
+        assert!(traces[0].contains("reason: Undecided"));
+    }
+
     #[tokio::test]
     async fn test_next_batch_cached_single_batch() {
         let mut reader = new_batch_reader();
@@ -2,7 +2,7 @@
 
 use crate::{
     errors::{PipelineEncodingError, PipelineError},
-    stages::NextBatchProvider,
+    stages::{NextBatchProvider, record_blocked_reason},
     traits::{L2ChainProvider, OriginAdvancer, OriginProvider, SignalReceiver},
     types::{PipelineResult, Signal},
 };
@@ -121,6 +121,22 @@ where
         &mut self,
         parent: L2BlockInfo,
         l1_origins: &[BlockInfo],
+    ) -> PipelineResult<Batch> {
+        trace_stage!("batch_stream", self.origin(), self.next_batch_inner(parent, l1_origins))
+    }
+}
+
+impl<P, BF> BatchStream<P, BF>
+where
+    P: BatchStreamProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
+    BF: L2ChainProvider + Send + Debug,
+{
+    /// Returns the next [Batch], passing it through if the stage is not active. Otherwise, span
+    /// batches are buffered and validated, and their [SingleBatch]es are returned one at a time.
+    async fn next_batch_inner(
+        &mut self,
+        parent: L2BlockInfo,
+        l1_origins: &[BlockInfo],
     ) -> PipelineResult<Batch> {
         // If the stage is not active, "pass" the next batch
         // through this stage to the BatchQueue stage.
@@ -155,7 +171,13 @@ where
 
                     match validity {
                         BatchValidity::Accept => self.span = Some(b),
-                        BatchValidity::Drop => {
+                        BatchValidity::Drop(reason) => {
+                            warn!(
+                                target: "batch_stream",
+                                %reason,
+                                "Invalid span batch prefix, flushing stage."
+                            );
+
                             // Flush the stage.
                             self.flush();
 
@@ -170,6 +192,7 @@ where
                             return Err(PipelineError::NotEnoughData.temp());
                         }
                         BatchValidity::Undecided | BatchValidity::Future => {
+                            record_blocked_reason(&validity);
                             return Err(PipelineError::NotEnoughData.temp());
                         }
                     }
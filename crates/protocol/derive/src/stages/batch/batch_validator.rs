@@ -1,9 +1,9 @@
 //! Contains the [BatchValidator] stage.
 
-use super::NextBatchProvider;
 use crate::{
     errors::ResetError,
     prelude::{OriginProvider, PipelineError, PipelineErrorKind},
+    stages::{NextBatchProvider, record_blocked_reason},
     traits::{AttributesProvider, OriginAdvancer, SignalReceiver},
     types::{PipelineResult, ResetSignal, Signal},
 };
@@ -246,12 +246,19 @@ where
                 warn!(target: "batch_validator", "Dropping old batch");
                 Err(PipelineError::NotEnoughData.temp())
             }
-            BatchValidity::Drop => {
-                warn!(target: "batch_validator", "Invalid singular batch, flushing current channel.");
+            BatchValidity::Drop(reason) => {
+                warn!(
+                    target: "batch_validator",
+                    %reason,
+                    "Invalid singular batch, flushing current channel."
+                );
                 self.prev.flush();
                 Err(PipelineError::NotEnoughData.temp())
             }
-            BatchValidity::Undecided => Err(PipelineError::NotEnoughData.temp()),
+            BatchValidity::Undecided => {
+                record_blocked_reason(&BatchValidity::Undecided);
+                Err(PipelineError::NotEnoughData.temp())
+            }
             BatchValidity::Future => {
                 error!(target: "batch_validator", "Future batch detected in BatchValidator.");
                 Err(PipelineError::InvalidBatchValidity.crit())
@@ -20,6 +20,17 @@ pub(crate) const MAX_CHANNEL_BANK_SIZE: usize = 100_000_000;
 /// The maximum size of a channel bank after the Fjord Hardfork.
 pub(crate) const FJORD_MAX_CHANNEL_BANK_SIZE: usize = 1_000_000_000;
 
+/// A snapshot of the occupancy of a [ChannelBank].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ChannelBankMetrics {
+    /// The number of buffered channels.
+    pub buffered_channels: usize,
+    /// The total size of the buffered frames, in bytes.
+    pub buffered_bytes: usize,
+    /// The number of channels that were dropped after timing out or being pruned.
+    pub dropped_channels: u64,
+}
+
 /// [ChannelBank] is a stateful stage that does the following:
 /// 1. Unmarshalls frames from L1 transaction data
 /// 2. Applies those frames to a channel
@@ -42,6 +53,8 @@ where
     pub(crate) channels: HashMap<ChannelId, Channel>,
     /// Channels in FIFO order.
     pub(crate) channel_queue: VecDeque<ChannelId>,
+    /// The number of channels that were dropped after timing out or being pruned.
+    pub(crate) dropped_channels: u64,
     /// The previous stage of the derivation pipeline.
     pub(crate) prev: P,
 }
@@ -52,7 +65,16 @@ where
 {
     /// Create a new [ChannelBank] stage.
     pub fn new(cfg: Arc<RollupConfig>, prev: P) -> Self {
-        Self { cfg, channels: HashMap::default(), channel_queue: VecDeque::new(), prev }
+        #[cfg(feature = "metrics")]
+        crate::metrics::describe_channel_bank();
+
+        Self {
+            cfg,
+            channels: HashMap::default(),
+            channel_queue: VecDeque::new(),
+            dropped_channels: 0,
+            prev,
+        }
     }
 
     /// Returns the size of the channel bank by accumulating over all channels.
@@ -60,6 +82,37 @@ where
         self.channels.iter().fold(0, |acc, (_, c)| acc + c.size())
     }
 
+    /// Returns a [ChannelBankMetrics] snapshot of the channel bank's occupancy.
+    pub fn metrics(&self) -> ChannelBankMetrics {
+        ChannelBankMetrics {
+            buffered_channels: self.channel_queue.len(),
+            buffered_bytes: self.size(),
+            dropped_channels: self.dropped_channels,
+        }
+    }
+
+    /// Records the channel bank's occupancy to the installed metrics recorder.
+    #[cfg(feature = "metrics")]
+    fn record_metrics(&self) {
+        use crate::metrics::{CHANNEL_BANK_BUFFERED_BYTES, CHANNEL_BANK_BUFFERED_CHANNELS};
+
+        let ChannelBankMetrics { buffered_channels, buffered_bytes, .. } = self.metrics();
+        metrics::gauge!(CHANNEL_BANK_BUFFERED_CHANNELS).set(buffered_channels as f64);
+        metrics::gauge!(CHANNEL_BANK_BUFFERED_BYTES).set(buffered_bytes as f64);
+    }
+
+    /// Records the channel bank's occupancy to the installed metrics recorder, which is a no-op
+    /// without the `metrics` feature.
+    #[cfg(not(feature = "metrics"))]
+    const fn record_metrics(&self) {}
+
+    /// Records that a channel was dropped after timing out or being pruned.
+    fn record_dropped_channel(&mut self) {
+        self.dropped_channels += 1;
+        #[cfg(feature = "metrics")]
+        metrics::counter!(crate::metrics::CHANNEL_BANK_DROPPED_CHANNELS).increment(1);
+    }
+
     /// Prunes the Channel bank, until it is below the max channel bank size.
     /// Prunes from the high-priority channel since it failed to be read.
     pub fn prune(&mut self) -> PipelineResult<()> {
@@ -75,6 +128,7 @@ where
                 self.channel_queue.pop_front().ok_or(PipelineError::ChannelProviderEmpty.crit())?;
             let channel = self.channels.remove(&id).ok_or(PipelineError::ChannelNotFound.crit())?;
             total_size -= channel.size();
+            self.record_dropped_channel();
         }
         Ok(())
     }
@@ -139,6 +193,7 @@ where
             );
             self.channels.remove(&first);
             self.channel_queue.pop_front();
+            self.record_dropped_channel();
             return Ok(None);
         }
 
@@ -202,7 +257,10 @@ where
                     return Err(PipelineError::ChannelProviderEmpty.crit());
                 }
             }
-            data => return data,
+            data => {
+                self.record_metrics();
+                return data;
+            }
         };
 
         // Load the data into the channel bank
@@ -213,6 +271,7 @@ where
             }
         };
         let res = self.ingest_frame(frame);
+        self.record_metrics();
         res?;
         Err(PipelineError::NotEnoughData.temp())
     }
@@ -236,6 +295,7 @@ where
         self.prev.signal(signal).await?;
         self.channels.clear();
         self.channel_queue = VecDeque::with_capacity(10);
+        self.record_metrics();
         Ok(())
     }
 }
@@ -244,11 +304,22 @@ where
 mod tests {
     use super::*;
     use crate::{
+        metrics::{
+            CHANNEL_BANK_BUFFERED_BYTES, CHANNEL_BANK_BUFFERED_CHANNELS,
+            CHANNEL_BANK_DROPPED_CHANNELS,
+        },
         test_utils::{CollectingLayer, TestNextFrameProvider, TraceStorage},
         types::ResetSignal,
     };
-    use alloc::{vec, vec::Vec};
+    use alloc::{
+        string::{String, ToString},
+        vec,
+        vec::Vec,
+    };
     use kona_genesis::HardForkConfig;
+    use metrics::{
+        Counter, CounterFn, Gauge, GaugeFn, Histogram, KeyName, Metadata, SharedString, Unit,
+    };
     use tracing::Level;
     use tracing_subscriber::layer::SubscriberExt;
 
@@ -495,6 +566,133 @@ mod tests {
         assert_eq!(channel_bank.size(), current_size);
     }
 
+    /// A [metrics::Recorder] that keeps the latest value of each counter and gauge recorded
+    /// through it.
+    #[derive(Debug, Default, Clone)]
+    struct TestRecorder(Arc<spin::Mutex<HashMap<String, f64>>>);
+
+    impl TestRecorder {
+        fn value(&self, name: &str) -> Option<f64> {
+            self.0.lock().get(name).copied()
+        }
+
+        fn metric(&self, key: &metrics::Key) -> Arc<TestMetric> {
+            Arc::new(TestMetric { name: key.name().to_string(), values: self.0.clone() })
+        }
+    }
+
+    impl metrics::Recorder for TestRecorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &metrics::Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.metric(key))
+        }
+
+        fn register_gauge(&self, key: &metrics::Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::from_arc(self.metric(key))
+        }
+
+        fn register_histogram(&self, _: &metrics::Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::noop()
+        }
+    }
+
+    /// A counter or gauge of a [TestRecorder].
+    #[derive(Debug)]
+    struct TestMetric {
+        name: String,
+        values: Arc<spin::Mutex<HashMap<String, f64>>>,
+    }
+
+    impl TestMetric {
+        fn update(&self, f: impl FnOnce(f64) -> f64) {
+            let mut values = self.values.lock();
+            let value = values.entry(self.name.clone()).or_default();
+            *value = f(*value);
+        }
+    }
+
+    impl CounterFn for TestMetric {
+        fn increment(&self, value: u64) {
+            self.update(|v| v + value as f64);
+        }
+
+        fn absolute(&self, value: u64) {
+            self.update(|_| value as f64);
+        }
+    }
+
+    impl GaugeFn for TestMetric {
+        fn increment(&self, value: f64) {
+            self.update(|v| v + value);
+        }
+
+        fn decrement(&self, value: f64) {
+            self.update(|v| v - value);
+        }
+
+        fn set(&self, value: f64) {
+            self.update(|_| value);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_channel_bank_metrics() {
+        let recorder = TestRecorder::default();
+        let _guard = metrics::set_default_local_recorder(&recorder);
+
+        let frames = [
+            crate::frame!(0xFF, 0, vec![0xDD; 50], false),
+            crate::frame!(0xEE, 0, vec![0xDD; 50], false),
+        ];
+        let mock = TestNextFrameProvider::new(frames.into_iter().map(Ok).collect::<Vec<_>>());
+        let cfg = Arc::new(RollupConfig::default());
+        let mut channel_bank = ChannelBank::new(cfg.clone(), mock);
+        assert_eq!(channel_bank.metrics(), ChannelBankMetrics::default());
+
+        // Ingest a frame for each of two channels.
+        for _ in 0..2 {
+            let err = channel_bank.next_data().await.unwrap_err();
+            assert_eq!(err, PipelineError::NotEnoughData.temp());
+        }
+        let frame_size = kona_protocol::FRAME_OVERHEAD + 50;
+        assert_eq!(
+            channel_bank.metrics(),
+            ChannelBankMetrics {
+                buffered_channels: 2,
+                buffered_bytes: 2 * frame_size,
+                dropped_channels: 0
+            }
+        );
+        assert_eq!(recorder.value(CHANNEL_BANK_BUFFERED_CHANNELS), Some(2.0));
+        assert_eq!(recorder.value(CHANNEL_BANK_BUFFERED_BYTES), Some((2 * frame_size) as f64));
+        assert_eq!(recorder.value(CHANNEL_BANK_DROPPED_CHANNELS), None);
+
+        // Time out both channels.
+        for _ in 0..cfg.channel_timeout + 1 {
+            channel_bank.advance_origin().await.unwrap();
+        }
+        assert_eq!(channel_bank.next_data().await, Ok(None));
+        assert_eq!(
+            channel_bank.metrics(),
+            ChannelBankMetrics {
+                buffered_channels: 1,
+                buffered_bytes: frame_size,
+                dropped_channels: 1
+            }
+        );
+        assert_eq!(channel_bank.next_data().await, Ok(None));
+        assert_eq!(
+            channel_bank.metrics(),
+            ChannelBankMetrics { buffered_channels: 0, buffered_bytes: 0, dropped_channels: 2 }
+        );
+        assert_eq!(recorder.value(CHANNEL_BANK_BUFFERED_CHANNELS), Some(0.0));
+        assert_eq!(recorder.value(CHANNEL_BANK_BUFFERED_BYTES), Some(0.0));
+        assert_eq!(recorder.value(CHANNEL_BANK_DROPPED_CHANNELS), Some(2.0));
+    }
+
     #[tokio::test]
     async fn test_read_empty_channel_bank() {
         let frames = [crate::frame!(0xFF, 0, vec![0xDD; 50], true)];
@@ -143,9 +143,13 @@ where
         self.attempt_update()?;
 
         if let Some(channel_assembler) = self.channel_assembler.as_mut() {
-            channel_assembler.next_data().await
+            trace_stage!(
+                "channel_assembler",
+                channel_assembler.origin(),
+                channel_assembler.next_data()
+            )
         } else if let Some(channel_bank) = self.channel_bank.as_mut() {
-            channel_bank.next_data().await
+            trace_stage!("channel_bank", channel_bank.origin(), channel_bank.next_data())
         } else {
             Err(PipelineError::NotEnoughData.temp())
         }
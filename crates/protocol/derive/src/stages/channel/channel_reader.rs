@@ -13,7 +13,7 @@ use core::fmt::Debug;
 use kona_genesis::{
     MAX_RLP_BYTES_PER_CHANNEL_BEDROCK, MAX_RLP_BYTES_PER_CHANNEL_FJORD, RollupConfig,
 };
-use kona_protocol::{Batch, BatchReader, BlockInfo};
+use kona_protocol::{Batch, BatchReader, BlockInfo, SpanBatchLimits};
 use tracing::{debug, warn};
 
 /// The [ChannelReader] provider trait.
@@ -46,6 +46,8 @@ where
     next_batch: Option<BatchReader>,
     /// The rollup coonfiguration.
     cfg: Arc<RollupConfig>,
+    /// The bounds on the sizes declared by span batches.
+    span_batch_limits: SpanBatchLimits,
 }
 
 impl<P> ChannelReader<P>
@@ -54,7 +56,18 @@ where
 {
     /// Create a new [ChannelReader] stage.
     pub const fn new(prev: P, cfg: Arc<RollupConfig>) -> Self {
-        Self { prev, next_batch: None, cfg }
+        Self { prev, next_batch: None, cfg, span_batch_limits: SpanBatchLimits::PROTOCOL }
+    }
+
+    /// Bounds the sizes declared by span batches by the given [SpanBatchLimits], rather than the
+    /// protocol's maximum.
+    ///
+    /// ## Warning
+    /// Limits below the protocol's maximum reject span batches that are valid by consensus,
+    /// causing the pipeline to derive a different chain than the rest of the network.
+    pub const fn with_span_batch_limits(mut self, span_batch_limits: SpanBatchLimits) -> Self {
+        self.span_batch_limits = span_batch_limits;
+        self
     }
 
     /// Creates the batch reader from available channel data.
@@ -70,8 +83,10 @@ where
                 MAX_RLP_BYTES_PER_CHANNEL_BEDROCK
             };
 
-            self.next_batch =
-                Some(BatchReader::new(&channel[..], max_rlp_bytes_per_channel as usize));
+            self.next_batch = Some(
+                BatchReader::new(&channel[..], max_rlp_bytes_per_channel as usize)
+                    .with_span_batch_limits(self.span_batch_limits),
+            );
         }
         Ok(())
     }
@@ -110,6 +125,17 @@ where
     }
 
     async fn next_batch(&mut self) -> PipelineResult<Batch> {
+        trace_stage!("channel_reader", self.origin(), self.next_batch_inner())
+    }
+}
+
+impl<P> ChannelReader<P>
+where
+    P: ChannelReaderProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
+{
+    /// Reads the next [Batch] from the current channel, moving on to the next channel if the
+    /// current one is exhausted or cannot be read.
+    async fn next_batch_inner(&mut self) -> PipelineResult<Batch> {
         if let Err(e) = self.set_batch_reader().await {
             debug!(target: "channel_reader", "Failed to set batch reader: {:?}", e);
             self.next_channel();
@@ -243,6 +269,17 @@ mod test {
         assert!(reader.next_batch.is_some());
     }
 
+    #[tokio::test]
+    async fn test_next_batch_exceeds_span_batch_limits() {
+        let raw = new_compressed_batch_data();
+        let mock = TestChannelReaderProvider::new(vec![Ok(Some(raw))]);
+        let limits = SpanBatchLimits { max_block_count: 0, ..Default::default() };
+        let mut reader = ChannelReader::new(mock, Arc::new(RollupConfig::default()))
+            .with_span_batch_limits(limits);
+        assert_eq!(reader.next_batch().await, Err(PipelineError::NotEnoughData.temp()));
+        assert!(reader.next_batch.is_none());
+    }
+
     #[tokio::test]
     async fn test_flush_post_holocene() {
         let raw = new_compressed_batch_data();
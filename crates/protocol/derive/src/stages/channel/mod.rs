@@ -20,7 +20,7 @@ pub(crate) mod channel_provider;
 pub use channel_provider::ChannelProvider;
 
 pub(crate) mod channel_bank;
-pub use channel_bank::ChannelBank;
+pub use channel_bank::{ChannelBank, ChannelBankMetrics};
 
 pub(crate) mod channel_assembler;
 pub use channel_assembler::ChannelAssembler;
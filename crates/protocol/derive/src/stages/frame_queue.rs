@@ -157,6 +157,16 @@ where
     P: FrameQueueProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
 {
     async fn next_frame(&mut self) -> PipelineResult<Frame> {
+        trace_stage!("frame_queue", self.origin(), self.next_frame_inner())
+    }
+}
+
+impl<P> FrameQueue<P>
+where
+    P: FrameQueueProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send + Debug,
+{
+    /// Loads more frames if the queue is empty, and pops the next [Frame] off of the queue.
+    async fn next_frame_inner(&mut self) -> PipelineResult<Frame> {
         self.load_frames().await?;
 
         // If we did not add more frames but still have more data, retry this function.
@@ -81,6 +81,18 @@ where
     type Item = DAP::Item;
 
     async fn next_data(&mut self) -> PipelineResult<Self::Item> {
+        trace_stage!("l1_retrieval", self.origin(), self.next_data_inner())
+    }
+}
+
+impl<DAP, P> L1Retrieval<DAP, P>
+where
+    DAP: DataAvailabilityProvider + Send,
+    P: L1RetrievalProvider + OriginAdvancer + OriginProvider + SignalReceiver + Send,
+{
+    /// Retrieves the next data item from the [DataAvailabilityProvider], for the next L1 block
+    /// of the previous stage.
+    async fn next_data_inner(&mut self) -> PipelineResult<DAP::Item> {
         if self.next.is_none() {
             self.next = Some(
                 self.prev
@@ -40,6 +40,13 @@ impl<F: ChainProvider + Send> L1RetrievalProvider for L1Traversal<F> {
     }
 
     async fn next_l1_block(&mut self) -> PipelineResult<Option<BlockInfo>> {
+        trace_stage!("l1_traversal", self.origin(), self.next_l1_block_inner())
+    }
+}
+
+impl<F: ChainProvider + Send> L1Traversal<F> {
+    /// Returns the current L1 block, if it has not been returned yet.
+    async fn next_l1_block_inner(&mut self) -> PipelineResult<Option<BlockInfo>> {
         if !self.done {
             self.done = true;
             Ok(self.block)
@@ -68,6 +75,14 @@ impl<F: ChainProvider + Send> OriginAdvancer for L1Traversal<F> {
     /// This function fetches the next L1 [BlockInfo] from the data source and updates the
     /// [SystemConfig] with the receipts from the block.
     async fn advance_origin(&mut self) -> PipelineResult<()> {
+        trace_stage!("l1_traversal", self.origin(), self.advance_origin_inner())
+    }
+}
+
+impl<F: ChainProvider + Send> L1Traversal<F> {
+    /// Fetches the next L1 [BlockInfo] from the data source, and updates the [SystemConfig] with
+    /// the receipts from the block.
+    async fn advance_origin_inner(&mut self) -> PipelineResult<()> {
         // Pull the next block or return EOF.
         // PipelineError::EOF has special handling further up the pipeline.
         let block = match self.block {
@@ -140,11 +155,16 @@ impl<F: ChainProvider + Send> SignalReceiver for L1Traversal<F> {
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
-    use crate::{errors::PipelineErrorKind, test_utils::TestChainProvider};
+    use crate::{
+        errors::PipelineErrorKind,
+        test_utils::{CollectingLayer, TestChainProvider, TraceStorage},
+    };
     use alloc::vec;
     use alloy_consensus::Receipt;
     use alloy_primitives::{B256, Bytes, Log, LogData, address, b256, hex};
-    use kona_genesis::{CONFIG_UPDATE_EVENT_VERSION_0, CONFIG_UPDATE_TOPIC};
+    use kona_genesis::{CONFIG_UPDATE_EVENT_VERSION_0, CONFIG_UPDATE_TOPIC, HardForkConfig};
+    use tracing::Level;
+    use tracing_subscriber::layer::SubscriberExt;
 
     const L1_SYS_CONFIG_ADDR: Address = address!("1337000000000000000000000000000000000000");
 
@@ -331,4 +351,36 @@ pub(crate) mod tests {
         let expected = address!("000000000000000000000000000000000000bEEF");
         assert_eq!(traversal.system_config.batcher_address, expected);
     }
+
+    #[tokio::test]
+    async fn test_l1_traversal_trace_records_origin_before_advancing() {
+        let trace_store: TraceStorage = Default::default();
+        let layer = CollectingLayer::new(trace_store.clone());
+        let subscriber = tracing_subscriber::Registry::default().with(layer);
+        let _guard = tracing::subscriber::set_default(subscriber);
+
+        let next = BlockInfo {
+            number: 1,
+            timestamp: 10,
+            hash: B256::repeat_byte(1),
+            ..Default::default()
+        };
+        let mut traversal = new_test_traversal(vec![BlockInfo::default(), next], vec![]);
+        traversal.data_source.insert_receipts(next.hash, vec![]);
+        traversal.rollup_config = Arc::new(RollupConfig {
+            hardforks: HardForkConfig { holocene_time: Some(5), ..Default::default() },
+            ..RollupConfig::default()
+        });
+
+        // The origin advances to the first Holocene block before the activation is signaled.
+        let err = traversal.advance_origin().await.unwrap_err();
+        assert_eq!(err, ResetError::HoloceneActivation.reset());
+        assert_eq!(traversal.origin(), Some(next));
+
+        let traces = trace_store.get_by_level(Level::DEBUG);
+        assert_eq!(traces.len(), 1);
+        assert!(traces[0].contains("derive::l1_traversal"));
+        assert!(traces[0].contains("stage: \"l1_traversal\""));
+        assert!(traces[0].contains("origin: 0"));
+    }
 }
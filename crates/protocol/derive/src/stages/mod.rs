@@ -14,6 +14,47 @@
 //! 7. Batch Queue
 //! 8. Payload Attributes Derivation
 //! 9. (Omitted) Engine Queue
+//!
+//! Each stage's `next_*` and `advance_origin` methods run within an `INFO` span under the
+//! `derive::<stage>` target, carrying the `stage` name and the stage's L1 `origin` number. When a
+//! stage cannot advance, an event is emitted within the span, and stages that are waiting on an
+//! undecided batch record the `reason` on the span.
+
+/// Awaits the future of a stage's `next_*` or `advance_origin` method within the stage's span,
+/// recording the stage name and the L1 origin of the stage before it advances. If the stage cannot
+/// advance, an event is emitted within the span with the [PipelineErrorKind] that it returned.
+/// Temporary errors are recorded at the `TRACE` level, as they are expected during normal
+/// operation, while all others are recorded at the `DEBUG` level.
+///
+/// [PipelineErrorKind]: crate::errors::PipelineErrorKind
+macro_rules! trace_stage {
+    ($stage:literal, $origin:expr, $body:expr) => {{
+        let origin = $origin.map(|origin| origin.number);
+        let span = info_span!(
+            target: concat!("derive::", $stage),
+            "stage",
+            stage = $stage,
+            origin,
+            reason = tracing::field::Empty,
+        );
+        let result = tracing::Instrument::instrument($body, span.clone()).await;
+        if let Err(err) = &result {
+            span.in_scope(|| {
+                if matches!(err, $crate::errors::PipelineErrorKind::Temporary(_)) {
+                    trace!(target: concat!("derive::", $stage), error = %err, "Stage cannot advance");
+                } else {
+                    debug!(target: concat!("derive::", $stage), error = %err, "Stage cannot advance");
+                }
+            });
+        }
+        result
+    }};
+}
+
+/// Records the `reason` that the current stage cannot advance on the stage's span.
+pub(crate) fn record_blocked_reason(reason: &dyn core::fmt::Debug) {
+    tracing::Span::current().record("reason", tracing::field::debug(reason));
+}
 
 mod l1_traversal;
 pub use l1_traversal::L1Traversal;
@@ -26,8 +67,8 @@ pub use frame_queue::{FrameQueue, FrameQueueProvider};
 
 mod channel;
 pub use channel::{
-    ChannelAssembler, ChannelBank, ChannelProvider, ChannelReader, ChannelReaderProvider,
-    NextFrameProvider,
+    ChannelAssembler, ChannelBank, ChannelBankMetrics, ChannelProvider, ChannelReader,
+    ChannelReaderProvider, NextFrameProvider,
 };
 
 mod batch;
@@ -2,9 +2,14 @@
 //! log levels.
 
 use alloc::{format, string::String, sync::Arc, vec::Vec};
+use core::fmt::{Debug, Write};
 use spin::Mutex;
-use tracing::{Event, Level, Subscriber};
-use tracing_subscriber::{Layer, layer::Context};
+use tracing::{
+    Event, Level, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+};
+use tracing_subscriber::{Layer, layer::Context, registry::LookupSpan};
 
 /// The storage for the collected traces.
 #[derive(Debug, Default, Clone)]
@@ -31,7 +36,18 @@ impl TraceStorage {
     }
 }
 
-/// A subscriber layer that collects traces and their log levels.
+/// The formatted fields of a span, stored in the span's extensions.
+#[derive(Debug, Default)]
+struct SpanFields(String);
+
+impl Visit for SpanFields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        let _ = write!(self.0, "{}: {:?}, ", field.name(), value);
+    }
+}
+
+/// A subscriber layer that collects traces and their log levels. Each trace is followed by the
+/// name and fields of the spans that it was emitted within.
 #[derive(Debug, Default)]
 pub struct CollectingLayer {
     /// The storage for the collected traces.
@@ -45,11 +61,33 @@ impl CollectingLayer {
     }
 }
 
-impl<S: Subscriber> Layer<S> for CollectingLayer {
-    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+impl<S> Layer<S> for CollectingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        let mut fields = SpanFields::default();
+        attrs.record(&mut fields);
+        span.extensions_mut().insert(fields);
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, ctx: Context<'_, S>) {
+        let Some(span) = ctx.span(id) else { return };
+        if let Some(fields) = span.extensions_mut().get_mut::<SpanFields>() {
+            values.record(fields);
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
         let metadata = event.metadata();
         let level = *metadata.level();
-        let message = format!("{:?}", event);
+        let mut message = format!("{:?}", event);
+        for span in ctx.event_scope(event).into_iter().flatten() {
+            if let Some(fields) = span.extensions().get::<SpanFields>() {
+                let _ = write!(message, " {}{{ {}}}", span.name(), fields.0);
+            }
+        }
 
         let mut storage = self.storage.0.lock();
         storage.push((level, message));
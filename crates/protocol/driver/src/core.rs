@@ -9,15 +9,31 @@ use core::fmt::Debug;
 use kona_derive::{
     errors::{PipelineError, PipelineErrorKind},
     traits::{Pipeline, SignalReceiver},
-    types::Signal,
+    types::{Signal, StepResult},
 };
 use kona_executor::BlockBuildingOutcome;
 use kona_genesis::RollupConfig;
-use kona_protocol::L2BlockInfo;
+use kona_protocol::{BlockInfo, L2BlockInfo};
 use kona_rpc::OpAttributesWithParent;
 use op_alloy_consensus::{OpBlock, OpTxEnvelope, OpTxType};
+use op_alloy_rpc_types_engine::OpPayloadAttributes;
 use spin::RwLock;
 
+/// The outcome of a single [Driver::step].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DriverStepOutcome {
+    /// A payload was derived and executed, advancing the safe head to the given block.
+    AdvancedSafeHead(L2BlockInfo),
+    /// A payload was derived, but failed to execute prior to Holocene and was discarded.
+    DroppedPayload,
+    /// The pipeline advanced its L1 origin to the given block.
+    AdvancedOrigin(BlockInfo),
+    /// The pipeline was reset, or signalled to activate a hardfork.
+    Reset,
+    /// The pipeline needs more data before it can make progress.
+    NeedsMoreData,
+}
+
 /// The Rollup Driver entrypoint.
 #[derive(Debug)]
 pub struct Driver<E, DP, P>
@@ -77,120 +93,402 @@ where
     ) -> DriverResult<(L2BlockInfo, B256), E::Error> {
         loop {
             // Check if we have reached the target block number.
-            let pipeline_cursor = self.cursor.read();
-            let tip_cursor = pipeline_cursor.tip();
-            if let Some(tb) = target {
-                if tip_cursor.l2_safe_head.block_info.number >= tb {
-                    info!(target: "client", "Derivation complete, reached L2 safe head.");
-                    return Ok((tip_cursor.l2_safe_head, tip_cursor.l2_safe_head_output_root));
+            {
+                let pipeline_cursor = self.cursor.read();
+                let tip_cursor = pipeline_cursor.tip();
+                if let Some(tb) = target {
+                    if tip_cursor.l2_safe_head.block_info.number >= tb {
+                        info!(target: "client", "Derivation complete, reached L2 safe head.");
+                        return Ok((tip_cursor.l2_safe_head, tip_cursor.l2_safe_head_output_root));
+                    }
                 }
             }
 
-            let OpAttributesWithParent { mut attributes, .. } = match self
-                .pipeline
-                .produce_payload(tip_cursor.l2_safe_head)
-                .await
-            {
-                Ok(attrs) => attrs,
-                Err(PipelineErrorKind::Critical(PipelineError::EndOfSource)) => {
+            match self.step(cfg).await {
+                Ok(_) => {}
+                Err(DriverError::Pipeline(PipelineErrorKind::Critical(
+                    PipelineError::EndOfSource,
+                ))) => {
                     warn!(target: "client", "Exhausted data source; Halting derivation and using current safe head.");
 
                     // Adjust the target block number to the current safe head, as no more blocks
                     // can be produced.
+                    let l2_safe_head_number = self.cursor.read().l2_safe_head().block_info.number;
                     if target.is_some() {
-                        target = Some(tip_cursor.l2_safe_head.block_info.number);
+                        target = Some(l2_safe_head_number);
                     };
 
                     // If we are in interop mode, this error must be handled by the caller.
                     // Otherwise, we continue the loop to halt derivation on the next iteration.
-                    if cfg.is_interop_active(self.cursor.read().l2_safe_head().block_info.number) {
+                    if cfg.is_interop_active(l2_safe_head_number) {
                         return Err(PipelineError::EndOfSource.crit().into());
-                    } else {
-                        continue;
                     }
                 }
                 Err(e) => {
-                    error!(target: "client", "Failed to produce payload: {:?}", e);
-                    return Err(DriverError::Pipeline(e));
+                    error!(target: "client", "Failed to advance derivation: {:?}", e);
+                    return Err(e);
                 }
-            };
+            }
+        }
+    }
 
-            self.executor.update_safe_head(tip_cursor.l2_safe_head_header.clone());
-            let outcome = match self.executor.execute_payload(attributes.clone()).await {
-                Ok(outcome) => outcome,
-                Err(e) => {
-                    error!(target: "client", "Failed to execute L2 block: {}", e);
-
-                    if cfg.is_holocene_active(attributes.payload_attributes.timestamp) {
-                        // Retry with a deposit-only block.
-                        warn!(target: "client", "Flushing current channel and retrying deposit only block");
-
-                        // Flush the current batch and channel - if a block was replaced with a
-                        // deposit-only block due to execution failure, the
-                        // batch and channel it is contained in is forwards
-                        // invalidated.
-                        self.pipeline.signal(Signal::FlushChannel).await?;
-
-                        // Strip out all transactions that are not deposits.
-                        attributes.transactions = attributes.transactions.map(|txs| {
-                            txs.into_iter()
-                                .filter(|tx| (!tx.is_empty() && tx[0] == OpTxType::Deposit as u8))
-                                .collect::<Vec<_>>()
-                        });
-
-                        // Retry the execution.
-                        self.executor.update_safe_head(tip_cursor.l2_safe_head_header.clone());
-                        match self.executor.execute_payload(attributes.clone()).await {
-                            Ok(header) => header,
-                            Err(e) => {
-                                error!(
-                                    target: "client",
-                                    "Critical - Failed to execute deposit-only block: {e}",
-                                );
-                                return Err(DriverError::Executor(e));
-                            }
+    /// Advances the derivation pipeline by exactly one step from the current L2 safe head.
+    ///
+    /// If the step produces a payload, it is executed and the safe head is advanced to the new
+    /// block. This allows derivation to be observed incrementally, rather than running it to the
+    /// target block with [Self::advance_to_target].
+    ///
+    /// ## Takes
+    /// - `cfg`: The rollup configuration.
+    ///
+    /// ## Returns
+    /// - `Ok(outcome)` - The [DriverStepOutcome] of the step.
+    /// - `Err(e)` - A critical pipeline error, or an error executing the produced payload.
+    pub async fn step(&mut self, cfg: &RollupConfig) -> DriverResult<DriverStepOutcome, E::Error> {
+        let l2_safe_head = *self.cursor.read().l2_safe_head();
+        match self.pipeline.step(l2_safe_head).await {
+            StepResult::PreparedAttributes => {
+                info!(target: "client_derivation_driver", "Stepped derivation pipeline")
+            }
+            StepResult::AdvancedOrigin => {
+                let origin = self.pipeline.origin().ok_or(PipelineError::MissingOrigin.crit())?;
+                info!(
+                    target: "client_derivation_driver",
+                    l1_block_number = origin.number,
+                    "Advanced origin"
+                );
+                return Ok(DriverStepOutcome::AdvancedOrigin(origin));
+            }
+            StepResult::OriginAdvanceErr(e) | StepResult::StepFailed(e) => {
+                let is_reset = matches!(e, PipelineErrorKind::Reset(_));
+                self.pipeline.handle_step_error(l2_safe_head, e).await?;
+                return Ok(if is_reset {
+                    DriverStepOutcome::Reset
+                } else {
+                    DriverStepOutcome::NeedsMoreData
+                });
+            }
+        }
+
+        let Some(OpAttributesWithParent { attributes, .. }) = self.pipeline.next() else {
+            return Ok(DriverStepOutcome::NeedsMoreData);
+        };
+        Ok(self
+            .execute_attributes(cfg, attributes)
+            .await?
+            .map_or(DriverStepOutcome::DroppedPayload, DriverStepOutcome::AdvancedSafeHead))
+    }
+
+    /// Executes the given [OpPayloadAttributes] on top of the current L2 safe head, and advances
+    /// the safe head to the produced block.
+    ///
+    /// Returns [None] if the payload failed to execute prior to Holocene, in which case it is
+    /// discarded.
+    async fn execute_attributes(
+        &mut self,
+        cfg: &RollupConfig,
+        mut attributes: OpPayloadAttributes,
+    ) -> DriverResult<Option<L2BlockInfo>, E::Error> {
+        let l2_safe_head_header = self.cursor.read().l2_safe_head_header().clone();
+        self.executor.update_safe_head(l2_safe_head_header.clone());
+        let outcome = match self.executor.execute_payload(attributes.clone()).await {
+            Ok(outcome) => outcome,
+            Err(e) => {
+                error!(target: "client", "Failed to execute L2 block: {}", e);
+
+                if cfg.is_holocene_active(attributes.payload_attributes.timestamp) {
+                    // Retry with a deposit-only block.
+                    warn!(target: "client", "Flushing current channel and retrying deposit only block");
+
+                    // Flush the current batch and channel - if a block was replaced with a
+                    // deposit-only block due to execution failure, the
+                    // batch and channel it is contained in is forwards
+                    // invalidated.
+                    self.pipeline.signal(Signal::FlushChannel).await?;
+
+                    // Strip out all transactions that are not deposits.
+                    attributes.transactions = attributes.transactions.map(|txs| {
+                        txs.into_iter()
+                            .filter(|tx| (!tx.is_empty() && tx[0] == OpTxType::Deposit as u8))
+                            .collect::<Vec<_>>()
+                    });
+
+                    // Retry the execution.
+                    self.executor.update_safe_head(l2_safe_head_header);
+                    match self.executor.execute_payload(attributes.clone()).await {
+                        Ok(header) => header,
+                        Err(e) => {
+                            error!(
+                                target: "client",
+                                "Critical - Failed to execute deposit-only block: {e}",
+                            );
+                            return Err(DriverError::Executor(e));
                         }
-                    } else {
-                        // Pre-Holocene, discard the block if execution fails.
-                        continue;
                     }
+                } else {
+                    // Pre-Holocene, discard the block if execution fails.
+                    return Ok(None);
                 }
-            };
+            }
+        };
+
+        // Construct the block.
+        let block = OpBlock {
+            header: outcome.header.inner().clone(),
+            body: BlockBody {
+                transactions: attributes
+                    .transactions
+                    .as_ref()
+                    .unwrap_or(&Vec::new())
+                    .iter()
+                    .map(|tx| OpTxEnvelope::decode(&mut tx.as_ref()).map_err(DriverError::Rlp))
+                    .collect::<DriverResult<Vec<OpTxEnvelope>, E::Error>>()?,
+                ommers: Vec::new(),
+                withdrawals: None,
+            },
+        };
+
+        // Get the pipeline origin and update the tip cursor.
+        let origin = self.pipeline.origin().ok_or(PipelineError::MissingOrigin.crit())?;
+        let l2_info =
+            L2BlockInfo::from_block_and_genesis(&block, &self.pipeline.rollup_config().genesis)?;
+        let tip_cursor = TipCursor::new(
+            l2_info,
+            outcome.header.clone(),
+            self.executor.compute_output_root().map_err(DriverError::Executor)?,
+        );
+
+        // Advance the derivation pipeline cursor
+        self.cursor.write().advance(origin, tip_cursor);
+
+        // Update the latest safe head artifacts.
+        self.safe_head_artifacts = Some((outcome, attributes.transactions.unwrap_or_default()));
+
+        Ok(Some(l2_info))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::collections::VecDeque;
+    use alloy_consensus::{Header, Sealed};
+    use alloy_eips::eip2718::Encodable2718;
+    use alloy_evm::block::BlockExecutionResult;
+    use async_trait::async_trait;
+    use kona_derive::{traits::OriginProvider, types::PipelineResult};
+    use kona_genesis::SystemConfig;
+    use kona_protocol::{L1BlockInfoBedrock, L1BlockInfoTx};
+    use op_alloy_consensus::TxDeposit;
+
+    /// A scripted event returned by a [ScriptedPipeline] step.
+    #[derive(Debug, Clone, Copy)]
+    enum Event {
+        /// Prepares the attributes of the next L2 block.
+        Attributes,
+        /// Advances the L1 origin.
+        Origin,
+        /// Fails the step with a temporary error.
+        Temporary,
+    }
+
+    /// A pipeline that replays a fixed script of [Event]s, and is exhausted once the script ends.
+    #[derive(Debug)]
+    struct ScriptedPipeline {
+        script: VecDeque<Event>,
+        prepared: VecDeque<OpAttributesWithParent>,
+        origin: BlockInfo,
+        rollup_config: RollupConfig,
+    }
+
+    impl Iterator for ScriptedPipeline {
+        type Item = OpAttributesWithParent;
 
-            // Construct the block.
-            let block = OpBlock {
-                header: outcome.header.inner().clone(),
-                body: BlockBody {
-                    transactions: attributes
-                        .transactions
-                        .as_ref()
-                        .unwrap_or(&Vec::new())
-                        .iter()
-                        .map(|tx| OpTxEnvelope::decode(&mut tx.as_ref()).map_err(DriverError::Rlp))
-                        .collect::<DriverResult<Vec<OpTxEnvelope>, E::Error>>()?,
-                    ommers: Vec::new(),
-                    withdrawals: None,
-                },
+        fn next(&mut self) -> Option<Self::Item> {
+            self.prepared.pop_front()
+        }
+    }
+
+    impl OriginProvider for ScriptedPipeline {
+        fn origin(&self) -> Option<BlockInfo> {
+            Some(self.origin)
+        }
+    }
+
+    #[async_trait]
+    impl SignalReceiver for ScriptedPipeline {
+        async fn signal(&mut self, _: Signal) -> PipelineResult<()> {
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl Pipeline for ScriptedPipeline {
+        fn peek(&self) -> Option<&OpAttributesWithParent> {
+            self.prepared.front()
+        }
+
+        async fn step(&mut self, cursor: L2BlockInfo) -> StepResult {
+            match self.script.pop_front() {
+                Some(Event::Attributes) => {
+                    let l1_info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+                        number: self.origin.number,
+                        ..Default::default()
+                    });
+                    let deposit = OpTxEnvelope::Deposit(Sealed::new(TxDeposit {
+                        input: l1_info.encode_calldata(),
+                        ..Default::default()
+                    }));
+                    let mut attributes = OpPayloadAttributes {
+                        transactions: Some(vec![deposit.encoded_2718().into()]),
+                        ..Default::default()
+                    };
+                    attributes.payload_attributes.timestamp = (cursor.block_info.number + 1) * 2;
+                    self.prepared.push_back(OpAttributesWithParent::new(attributes, cursor, true));
+                    StepResult::PreparedAttributes
+                }
+                Some(Event::Origin) => {
+                    self.origin.number += 1;
+                    StepResult::AdvancedOrigin
+                }
+                Some(Event::Temporary) => StepResult::StepFailed(PipelineError::Eof.temp()),
+                None => StepResult::StepFailed(PipelineError::EndOfSource.crit()),
+            }
+        }
+
+        fn rollup_config(&self) -> &RollupConfig {
+            &self.rollup_config
+        }
+
+        async fn system_config_by_number(
+            &mut self,
+            _: u64,
+        ) -> Result<SystemConfig, PipelineErrorKind> {
+            Ok(SystemConfig::default())
+        }
+    }
+
+    #[async_trait]
+    impl DriverPipeline<Self> for ScriptedPipeline {
+        fn flush(&mut self) {}
+    }
+
+    #[derive(Debug, thiserror::Error)]
+    #[error("Mock executor error")]
+    struct MockExecutorError;
+
+    /// An [Executor] that builds empty child blocks of the safe head.
+    #[derive(Debug)]
+    struct MockExecutor {
+        safe_head: Sealed<Header>,
+    }
+
+    #[async_trait]
+    impl Executor for MockExecutor {
+        type Error = MockExecutorError;
+
+        async fn wait_until_ready(&mut self) {}
+
+        fn update_safe_head(&mut self, header: Sealed<Header>) {
+            self.safe_head = header;
+        }
+
+        async fn execute_payload(
+            &mut self,
+            attributes: OpPayloadAttributes,
+        ) -> Result<BlockBuildingOutcome, Self::Error> {
+            let header = Sealed::new(Header {
+                number: self.safe_head.number + 1,
+                parent_hash: self.safe_head.hash(),
+                timestamp: attributes.payload_attributes.timestamp,
+                ..Default::default()
+            });
+            self.safe_head = header.clone();
+            let execution_result = BlockExecutionResult {
+                receipts: Vec::new(),
+                requests: Default::default(),
+                gas_used: 0,
             };
+            Ok((header, execution_result).into())
+        }
+
+        fn compute_output_root(&mut self) -> Result<B256, Self::Error> {
+            Ok(self.safe_head.hash())
+        }
+    }
+
+    type TestDriver = Driver<MockExecutor, ScriptedPipeline, ScriptedPipeline>;
+
+    const SCRIPT: [Event; 6] = [
+        Event::Temporary,
+        Event::Attributes,
+        Event::Origin,
+        Event::Attributes,
+        Event::Temporary,
+        Event::Attributes,
+    ];
+
+    fn test_driver() -> TestDriver {
+        let genesis = Sealed::new(Header::default());
+        let rollup_config = RollupConfig::default();
+        let mut cursor = PipelineCursor::new(0, BlockInfo::default());
+        cursor.advance(
+            BlockInfo::default(),
+            TipCursor::new(L2BlockInfo::default(), genesis.clone(), B256::ZERO),
+        );
+        let pipeline = ScriptedPipeline {
+            script: SCRIPT.into_iter().collect(),
+            prepared: VecDeque::new(),
+            origin: BlockInfo::default(),
+            rollup_config,
+        };
+        Driver::new(Arc::new(RwLock::new(cursor)), MockExecutor { safe_head: genesis }, pipeline)
+    }
+
+    #[tokio::test]
+    async fn test_step_matches_advance_to_target() {
+        let cfg = RollupConfig::default();
+        let (batch_head, batch_root) =
+            test_driver().advance_to_target(&cfg, Some(3)).await.unwrap();
+        assert_eq!(batch_head.block_info.number, 3);
+        assert_eq!(batch_head.l1_origin.number, 1);
+
+        let mut driver = test_driver();
+        let mut outcomes = Vec::new();
+        while driver.cursor.read().l2_safe_head().block_info.number < 3 {
+            outcomes.push(driver.step(&cfg).await.unwrap());
+        }
+
+        let heads = outcomes
+            .iter()
+            .filter_map(|o| match o {
+                DriverStepOutcome::AdvancedSafeHead(head) => Some(head.block_info.number),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(heads, vec![1, 2, 3]);
+        assert_eq!(outcomes[0], DriverStepOutcome::NeedsMoreData);
+        assert_eq!(
+            outcomes[2],
+            DriverStepOutcome::AdvancedOrigin(BlockInfo { number: 1, ..Default::default() })
+        );
+        assert_eq!(outcomes.last(), Some(&DriverStepOutcome::AdvancedSafeHead(batch_head)));
+
+        let cursor = driver.cursor.read();
+        assert_eq!(*cursor.l2_safe_head(), batch_head);
+        assert_eq!(*cursor.l2_safe_head_output_root(), batch_root);
+    }
 
-            // Get the pipeline origin and update the tip cursor.
-            let origin = self.pipeline.origin().ok_or(PipelineError::MissingOrigin.crit())?;
-            let l2_info = L2BlockInfo::from_block_and_genesis(
-                &block,
-                &self.pipeline.rollup_config().genesis,
-            )?;
-            let tip_cursor = TipCursor::new(
-                l2_info,
-                outcome.header.clone(),
-                self.executor.compute_output_root().map_err(DriverError::Executor)?,
-            );
-
-            // Advance the derivation pipeline cursor
-            drop(pipeline_cursor);
-            self.cursor.write().advance(origin, tip_cursor);
-
-            // Update the latest safe head artifacts.
-            self.safe_head_artifacts = Some((outcome, attributes.transactions.unwrap_or_default()));
+    #[tokio::test]
+    async fn test_step_exhausted_source() {
+        let cfg = RollupConfig::default();
+        let mut driver = test_driver();
+        for _ in 0..SCRIPT.len() {
+            driver.step(&cfg).await.unwrap();
         }
+        assert!(matches!(
+            driver.step(&cfg).await,
+            Err(DriverError::Pipeline(PipelineErrorKind::Critical(PipelineError::EndOfSource)))
+        ));
     }
 }
@@ -22,7 +22,7 @@ mod executor;
 pub use executor::Executor;
 
 mod core;
-pub use core::Driver;
+pub use core::{Driver, DriverStepOutcome};
 
 mod cursor;
 pub use cursor::PipelineCursor;
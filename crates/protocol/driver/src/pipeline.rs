@@ -22,6 +22,62 @@ where
     /// Flushes any cache on re-org.
     fn flush(&mut self);
 
+    /// Handles an error returned by stepping the pipeline from the given L2 safe head.
+    ///
+    /// Temporary errors are ignored, as the step can be retried once more data is available. Reset
+    /// errors signal the pipeline to reset or activate a hardfork. Critical errors are returned.
+    async fn handle_step_error(
+        &mut self,
+        l2_safe_head: L2BlockInfo,
+        e: PipelineErrorKind,
+    ) -> Result<(), PipelineErrorKind> {
+        match e {
+            PipelineErrorKind::Temporary(_) => {
+                trace!(target: "client_derivation_driver", "Failed to step derivation pipeline temporarily: {:?}", e);
+            }
+            PipelineErrorKind::Reset(e) => {
+                warn!(target: "client_derivation_driver", "Failed to step derivation pipeline due to reset: {:?}", e);
+                let system_config =
+                    self.system_config_by_number(l2_safe_head.block_info.number).await?;
+
+                if matches!(e, ResetError::HoloceneActivation) {
+                    let l1_origin = self.origin().ok_or(PipelineError::MissingOrigin.crit())?;
+                    self.signal(
+                        ActivationSignal {
+                            l2_safe_head,
+                            l1_origin,
+                            system_config: Some(system_config),
+                        }
+                        .signal(),
+                    )
+                    .await?;
+                } else {
+                    // Flushes cache if a reorg or an inconsistent view of L1 is detected.
+                    if matches!(
+                        e,
+                        ResetError::ReorgDetected(_, _) | ResetError::NonMonotonicL1Blocks(_, _)
+                    ) {
+                        self.flush();
+                    }
+
+                    // Reset the pipeline to the initial L2 safe head and L1 origin,
+                    // and try again.
+                    let l1_origin = self.origin().ok_or(PipelineError::MissingOrigin.crit())?;
+                    self.signal(
+                        ResetSignal { l2_safe_head, l1_origin, system_config: Some(system_config) }
+                            .signal(),
+                    )
+                    .await?;
+                }
+            }
+            PipelineErrorKind::Critical(_) => {
+                warn!(target: "client_derivation_driver", "Failed to step derivation pipeline: {:?}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
     /// Produces the disputed [OpAttributesWithParent] payload, directly after the given
     /// starting l2 safe head.
     async fn produce_payload(
@@ -45,57 +101,9 @@ where
                 }
                 StepResult::OriginAdvanceErr(e) | StepResult::StepFailed(e) => {
                     // Break the loop unless the error signifies that there is not enough data to
-                    // complete the current step. In this case, we retry the step to see if other
-                    // stages can make progress.
-                    match e {
-                        PipelineErrorKind::Temporary(_) => {
-                            trace!(target: "client_derivation_driver", "Failed to step derivation pipeline temporarily: {:?}", e);
-                            continue;
-                        }
-                        PipelineErrorKind::Reset(e) => {
-                            warn!(target: "client_derivation_driver", "Failed to step derivation pipeline due to reset: {:?}", e);
-                            let system_config = self
-                                .system_config_by_number(l2_safe_head.block_info.number)
-                                .await?;
-
-                            if matches!(e, ResetError::HoloceneActivation) {
-                                let l1_origin =
-                                    self.origin().ok_or(PipelineError::MissingOrigin.crit())?;
-                                self.signal(
-                                    ActivationSignal {
-                                        l2_safe_head,
-                                        l1_origin,
-                                        system_config: Some(system_config),
-                                    }
-                                    .signal(),
-                                )
-                                .await?;
-                            } else {
-                                // Flushes cache if a reorg is detected.
-                                if matches!(e, ResetError::ReorgDetected(_, _)) {
-                                    self.flush();
-                                }
-
-                                // Reset the pipeline to the initial L2 safe head and L1 origin,
-                                // and try again.
-                                let l1_origin =
-                                    self.origin().ok_or(PipelineError::MissingOrigin.crit())?;
-                                self.signal(
-                                    ResetSignal {
-                                        l2_safe_head,
-                                        l1_origin,
-                                        system_config: Some(system_config),
-                                    }
-                                    .signal(),
-                                )
-                                .await?;
-                            }
-                        }
-                        PipelineErrorKind::Critical(_) => {
-                            warn!(target: "client_derivation_driver", "Failed to step derivation pipeline: {:?}", e);
-                            return Err(e);
-                        }
-                    }
+                    // complete the current step, or the pipeline was reset. In these cases, we
+                    // retry the step to see if other stages can make progress.
+                    self.handle_step_error(l2_safe_head, e).await?;
                 }
             }
 
@@ -581,6 +581,19 @@ mod tests {
         assert_eq!(config.channel_timeout(10), 100);
     }
 
+    #[test]
+    fn test_granite_channel_timeout_override() {
+        let config = RollupConfig {
+            channel_timeout: 300,
+            granite_channel_timeout: 25,
+            hardforks: HardForkConfig { holocene_time: Some(20), ..Default::default() },
+            ..Default::default()
+        };
+        assert_eq!(config.channel_timeout(19), 300);
+        // Granite is implied by later hardforks, so its timeout applies from their activation.
+        assert_eq!(config.channel_timeout(20), 25);
+    }
+
     #[test]
     fn test_max_sequencer_drift() {
         let mut config = RollupConfig { max_sequencer_drift: 100, ..Default::default() };
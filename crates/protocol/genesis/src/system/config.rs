@@ -4,9 +4,13 @@ use crate::{
     CONFIG_UPDATE_TOPIC, RollupConfig, SystemConfigLog, SystemConfigUpdateError,
     SystemConfigUpdateKind,
 };
+use alloc::vec::Vec;
 use alloy_consensus::{Eip658Value, Receipt};
 use alloy_primitives::{Address, B64, Log, U256};
 
+/// The divisor applied to the operator fee scalar, which has 6 decimals of precision.
+const OPERATOR_FEE_SCALAR_DECIMALS: u64 = 1_000_000;
+
 /// System configuration.
 #[derive(Debug, Copy, Clone, Default, Hash, Eq, PartialEq)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
@@ -108,6 +112,24 @@ impl SystemConfig {
         receipts: &[Receipt],
         l1_system_config_address: Address,
         ecotone_active: bool,
+    ) -> Result<(), SystemConfigUpdateError> {
+        self.update_with_receipts_observed(
+            receipts,
+            l1_system_config_address,
+            ecotone_active,
+            |_| {},
+        )
+    }
+
+    /// Filters all L1 receipts to find config updates and applies the config updates, invoking
+    /// `on_update` with the [SystemConfigUpdateKind] of each update log, in order, as it is
+    /// applied.
+    pub fn update_with_receipts_observed(
+        &mut self,
+        receipts: &[Receipt],
+        l1_system_config_address: Address,
+        ecotone_active: bool,
+        mut on_update: impl FnMut(SystemConfigUpdateKind),
     ) -> Result<(), SystemConfigUpdateError> {
         for receipt in receipts {
             if Eip658Value::Eip658(false) == receipt.status {
@@ -121,7 +143,7 @@ impl SystemConfig {
                     topics[0] == CONFIG_UPDATE_TOPIC
                 {
                     // Safety: Error is bubbled up by the trailing `?`
-                    self.process_config_update_log(log, ecotone_active)?;
+                    on_update(self.apply_update(log, ecotone_active)?);
                 }
                 Ok::<(), SystemConfigUpdateError>(())
             })?;
@@ -129,6 +151,38 @@ impl SystemConfig {
         Ok(())
     }
 
+    /// Returns the [SystemConfigUpdateKind]s whose fields differ between `self` and `other`, in
+    /// the order of their update type.
+    ///
+    /// The unsafe block signer is not part of the [SystemConfig], and is never reported.
+    pub fn diff(&self, other: &Self) -> Vec<SystemConfigUpdateKind> {
+        let mut kinds = Vec::new();
+        if self.batcher_address != other.batcher_address {
+            kinds.push(SystemConfigUpdateKind::Batcher);
+        }
+        if self.overhead != other.overhead ||
+            self.scalar != other.scalar ||
+            self.base_fee_scalar != other.base_fee_scalar ||
+            self.blob_base_fee_scalar != other.blob_base_fee_scalar
+        {
+            kinds.push(SystemConfigUpdateKind::GasConfig);
+        }
+        if self.gas_limit != other.gas_limit {
+            kinds.push(SystemConfigUpdateKind::GasLimit);
+        }
+        if self.eip1559_denominator != other.eip1559_denominator ||
+            self.eip1559_elasticity != other.eip1559_elasticity
+        {
+            kinds.push(SystemConfigUpdateKind::Eip1559);
+        }
+        if self.operator_fee_scalar != other.operator_fee_scalar ||
+            self.operator_fee_constant != other.operator_fee_constant
+        {
+            kinds.push(SystemConfigUpdateKind::OperatorFee);
+        }
+        kinds
+    }
+
     /// Returns the eip1559 parameters from a [SystemConfig] encoded as a [B64].
     pub fn eip_1559_params(
         &self,
@@ -154,6 +208,50 @@ impl SystemConfig {
         }
     }
 
+    /// Returns the operator fee scalar and constant from a [SystemConfig] if Isthmus is active at
+    /// the given timestamp. Prior to Isthmus, the operator fee parameters are not honored and
+    /// [None] is returned.
+    pub fn operator_fee_params(
+        &self,
+        rollup_config: &RollupConfig,
+        timestamp: u64,
+    ) -> Option<(u32, u64)> {
+        rollup_config.is_isthmus_active(timestamp).then(|| {
+            (
+                self.operator_fee_scalar.unwrap_or_default(),
+                self.operator_fee_constant.unwrap_or_default(),
+            )
+        })
+    }
+
+    /// Returns the operator fee charged for a transaction that used `gas_used` gas, as specified
+    /// by Isthmus:
+    ///
+    /// ```text
+    /// operator_fee = gas_used * operator_fee_scalar / 1e6 + operator_fee_constant
+    /// ```
+    ///
+    /// Unset operator fee parameters are treated as zero.
+    pub fn operator_fee(&self, gas_used: u64) -> U256 {
+        let scalar = U256::from(self.operator_fee_scalar.unwrap_or_default());
+        let constant = U256::from(self.operator_fee_constant.unwrap_or_default());
+        (U256::from(gas_used) * scalar / U256::from(OPERATOR_FEE_SCALAR_DECIMALS)) + constant
+    }
+
+    /// Decodes an EVM log entry emitted by the system config contract and applies it as a
+    /// [SystemConfig] change, returning the [SystemConfigUpdateKind] that was applied.
+    ///
+    /// The log is not checked against the system config address or the
+    /// [CONFIG_UPDATE_TOPIC]; callers filtering raw receipts should prefer
+    /// [SystemConfig::update_with_receipts].
+    pub fn apply_update(
+        &mut self,
+        log: &Log,
+        ecotone_active: bool,
+    ) -> Result<SystemConfigUpdateKind, SystemConfigUpdateError> {
+        self.process_config_update_log(log, ecotone_active)
+    }
+
     /// Decodes an EVM log entry emitted by the system config contract and applies it as a
     /// [SystemConfig] change.
     ///
@@ -289,6 +387,85 @@ mod test {
         assert_eq!(sys_config.eip_1559_params(&rollup_config, 0, 0), expected);
     }
 
+    #[test]
+    fn test_operator_fee_params_pre_isthmus() {
+        let rollup_config = RollupConfig {
+            hardforks: HardForkConfig { isthmus_time: Some(10), ..Default::default() },
+            ..Default::default()
+        };
+        let sys_config = SystemConfig {
+            operator_fee_scalar: Some(1),
+            operator_fee_constant: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(sys_config.operator_fee_params(&rollup_config, 0), None);
+        assert_eq!(sys_config.operator_fee_params(&rollup_config, 9), None);
+        assert_eq!(sys_config.operator_fee_params(&rollup_config, 10), Some((1, 2)));
+    }
+
+    #[test]
+    fn test_operator_fee_params_isthmus_unset() {
+        let rollup_config = RollupConfig::default();
+        let sys_config = SystemConfig {
+            operator_fee_scalar: Some(1),
+            operator_fee_constant: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(sys_config.operator_fee_params(&rollup_config, u64::MAX), None);
+    }
+
+    #[test]
+    fn test_default_operator_fee_params() {
+        let rollup_config = RollupConfig {
+            hardforks: HardForkConfig { isthmus_time: Some(0), ..Default::default() },
+            ..Default::default()
+        };
+        let sys_config = SystemConfig::default();
+        assert_eq!(sys_config.operator_fee_params(&rollup_config, 0), Some((0, 0)));
+    }
+
+    #[test]
+    fn test_operator_fee_zero() {
+        let sys_config = SystemConfig::default();
+        assert_eq!(sys_config.operator_fee(21_000), U256::ZERO);
+
+        let sys_config = SystemConfig {
+            operator_fee_scalar: Some(0),
+            operator_fee_constant: Some(0),
+            ..Default::default()
+        };
+        assert_eq!(sys_config.operator_fee(21_000), U256::ZERO);
+    }
+
+    #[test]
+    fn test_operator_fee_scalar_only() {
+        let sys_config =
+            SystemConfig { operator_fee_scalar: Some(2_500_000), ..Default::default() };
+        assert_eq!(sys_config.operator_fee(21_000), U256::from(52_500));
+
+        // The fee is rounded down.
+        assert_eq!(sys_config.operator_fee(1), U256::from(2));
+    }
+
+    #[test]
+    fn test_operator_fee_scalar_and_constant() {
+        let sys_config = SystemConfig {
+            operator_fee_scalar: Some(u32::MAX),
+            operator_fee_constant: Some(u64::MAX),
+            ..Default::default()
+        };
+        let expected = U256::from(u64::MAX) * U256::from(u32::MAX) / U256::from(1_000_000) +
+            U256::from(u64::MAX);
+        assert_eq!(sys_config.operator_fee(u64::MAX), expected);
+
+        let sys_config = SystemConfig {
+            operator_fee_scalar: Some(1_000_000),
+            operator_fee_constant: Some(500),
+            ..Default::default()
+        };
+        assert_eq!(sys_config.operator_fee(21_000), U256::from(21_500));
+    }
+
     #[test]
     fn test_default_eip_1559_params_from_system_config() {
         let rollup_config = RollupConfig {
@@ -379,6 +556,70 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_system_config_update_with_receipts_observed() {
+        let l1_system_config_address = Address::ZERO;
+        let data = hex!(
+            "00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef"
+        );
+        let update_log = |address: Address, update_type: u8| Log {
+            address,
+            data: LogData::new_unchecked(
+                vec![
+                    CONFIG_UPDATE_TOPIC,
+                    CONFIG_UPDATE_EVENT_VERSION_0,
+                    B256::with_last_byte(update_type),
+                ],
+                data.into(),
+            ),
+        };
+
+        let receipts = [
+            Receipt {
+                logs: vec![
+                    update_log(l1_system_config_address, 2),
+                    update_log(address!("000000000000000000000000000000000000dEaD"), 0),
+                    update_log(l1_system_config_address, 0),
+                ],
+                status: Eip658Value::Eip658(true),
+                cumulative_gas_used: 0,
+            },
+            Receipt {
+                logs: vec![update_log(l1_system_config_address, 0)],
+                status: Eip658Value::Eip658(false),
+                cumulative_gas_used: 0,
+            },
+            Receipt {
+                logs: vec![update_log(l1_system_config_address, 2)],
+                status: Eip658Value::Eip658(true),
+                cumulative_gas_used: 0,
+            },
+        ];
+
+        // Only the update logs of successful receipts from the system config address are observed.
+        let mut observed = vec![];
+        let mut system_config = SystemConfig::default();
+        system_config
+            .update_with_receipts_observed(&receipts, l1_system_config_address, false, |kind| {
+                observed.push(kind)
+            })
+            .unwrap();
+
+        assert_eq!(
+            observed,
+            vec![
+                SystemConfigUpdateKind::GasLimit,
+                SystemConfigUpdateKind::Batcher,
+                SystemConfigUpdateKind::GasLimit,
+            ]
+        );
+        assert_eq!(system_config.gas_limit, 0xbeef);
+        assert_eq!(
+            system_config.batcher_address,
+            address!("000000000000000000000000000000000000bEEF"),
+        );
+    }
+
     #[test]
     fn test_system_config_update_batcher_log() {
         const UPDATE_TYPE: B256 =
@@ -533,4 +774,57 @@ mod test {
         assert_eq!(system_config.operator_fee_scalar, Some(0xbabe_u32));
         assert_eq!(system_config.operator_fee_constant, Some(0xbeef_u64));
     }
+
+    #[test]
+    fn test_system_config_apply_update_matches_receipts() {
+        const UPDATE_TYPE: B256 =
+            b256!("0000000000000000000000000000000000000000000000000000000000000002");
+
+        let l1_system_config_address = Address::ZERO;
+        let update_log = Log {
+            address: l1_system_config_address,
+            data: LogData::new_unchecked(
+                vec![
+                    CONFIG_UPDATE_TOPIC,
+                    CONFIG_UPDATE_EVENT_VERSION_0,
+                    UPDATE_TYPE,
+                ],
+                hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef").into()
+            )
+        };
+
+        let mut applied = SystemConfig::default();
+        let kind = applied.apply_update(&update_log, false).unwrap();
+        assert_eq!(kind, SystemConfigUpdateKind::GasLimit);
+
+        let receipt = Receipt {
+            logs: vec![update_log],
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 0,
+        };
+        let mut from_receipts = SystemConfig::default();
+        from_receipts.update_with_receipts(&[receipt], l1_system_config_address, false).unwrap();
+
+        assert_eq!(applied, from_receipts);
+        assert_eq!(applied.gas_limit, 0xbeef);
+    }
+
+    #[test]
+    fn test_system_config_diff() {
+        let before = SystemConfig {
+            batcher_address: address!("6887246668a3b87f54deb3b94ba47a6f63f32985"),
+            gas_limit: 30_000_000,
+            eip1559_denominator: Some(250),
+            eip1559_elasticity: Some(6),
+            ..Default::default()
+        };
+        assert!(before.diff(&before).is_empty());
+
+        let after = SystemConfig { gas_limit: 60_000_000, eip1559_elasticity: Some(4), ..before };
+        assert_eq!(
+            before.diff(&after),
+            [SystemConfigUpdateKind::GasLimit, SystemConfigUpdateKind::Eip1559]
+        );
+        assert_eq!(after.diff(&before), before.diff(&after));
+    }
 }
@@ -23,7 +23,9 @@ pub use errors::{
 };
 
 mod root;
-pub use root::{ChainRootInfo, OutputRootWithChain, SuperRoot, SuperRootResponse};
+pub use root::{
+    ChainRootInfo, OutputRootWithChain, SuperRoot, SuperRootBuilder, SuperRootResponse,
+};
 
 mod message;
 pub use message::{
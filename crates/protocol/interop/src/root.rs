@@ -3,7 +3,7 @@
 //! Represents a snapshot of the state of the superchain at a given integer timestamp.
 
 use crate::{SUPER_ROOT_VERSION, SuperRootError, SuperRootResult};
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use alloy_primitives::{B256, Bytes, U256, keccak256};
 use alloy_rlp::{Buf, BufMut};
 
@@ -84,6 +84,51 @@ impl SuperRoot {
     }
 }
 
+/// A builder for a [SuperRoot], collecting the output roots of the chains within the dependency
+/// set one at a time.
+///
+/// Output roots are kept sorted by chain ID, so the commitment of the built [SuperRoot] does not
+/// depend on the order in which the chains were added.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct SuperRootBuilder {
+    /// The output roots of the added chains, keyed by chain ID.
+    output_roots: BTreeMap<u64, B256>,
+}
+
+impl SuperRootBuilder {
+    /// Creates a new, empty [SuperRootBuilder].
+    pub const fn new() -> Self {
+        Self { output_roots: BTreeMap::new() }
+    }
+
+    /// Adds the output root of the chain with the given ID, replacing any output root previously
+    /// added for that chain.
+    pub fn add_chain(&mut self, chain_id: u64, output_root: B256) -> &mut Self {
+        self.output_roots.insert(chain_id, output_root);
+        self
+    }
+
+    /// Returns the number of chains added to the builder.
+    pub fn len(&self) -> usize {
+        self.output_roots.len()
+    }
+
+    /// Returns `true` if no chains have been added to the builder.
+    pub fn is_empty(&self) -> bool {
+        self.output_roots.is_empty()
+    }
+
+    /// Builds the [SuperRoot] of the added chains at the given timestamp.
+    pub fn build(&self, timestamp: u64) -> SuperRoot {
+        let output_roots = self
+            .output_roots
+            .iter()
+            .map(|(chain_id, output_root)| OutputRootWithChain::new(*chain_id, *output_root))
+            .collect();
+        SuperRoot { timestamp, output_roots }
+    }
+}
+
 /// Chain Root Info
 #[derive(Debug, Clone, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -140,7 +185,7 @@ impl OutputRootWithChain {
 mod test {
     use crate::{SUPER_ROOT_VERSION, errors::SuperRootError};
 
-    use super::{OutputRootWithChain, SuperRoot};
+    use super::{OutputRootWithChain, SuperRoot, SuperRootBuilder};
     use alloy_primitives::{B256, b256};
 
     #[test]
@@ -208,6 +253,52 @@ mod test {
         assert_eq!(super_root.hash(), EXPECTED);
     }
 
+    #[test]
+    fn test_super_root_builder_static_hash() {
+        const EXPECTED: B256 =
+            b256!("0980033cbf4337f614a2401ab7efbfdc66ab647812f1c98d891d92ddfb376541");
+
+        let mut builder = SuperRootBuilder::new();
+        builder.add_chain(2, B256::default()).add_chain(1, B256::default());
+        assert_eq!(builder.len(), 2);
+        assert_eq!(builder.build(10).hash(), EXPECTED);
+    }
+
+    #[test]
+    fn test_super_root_builder_insertion_order_independent() {
+        let chains =
+            (1..=5u64).map(|id| (id * 10, B256::repeat_byte(id as u8))).collect::<Vec<_>>();
+
+        let (mut forward, mut reverse) = (SuperRootBuilder::new(), SuperRootBuilder::new());
+        for (id, root) in &chains {
+            forward.add_chain(*id, *root);
+        }
+        for (id, root) in chains.iter().rev() {
+            reverse.add_chain(*id, *root);
+        }
+
+        let super_root = forward.build(42);
+        assert_eq!(super_root, reverse.build(42));
+        assert_eq!(super_root.hash(), reverse.build(42).hash());
+        assert_eq!(
+            super_root,
+            SuperRoot::new(
+                42,
+                chains.iter().map(|(id, root)| OutputRootWithChain::new(*id, *root)).collect()
+            )
+        );
+    }
+
+    #[test]
+    fn test_super_root_builder_replaces_chain() {
+        let mut builder = SuperRootBuilder::new();
+        builder.add_chain(1, B256::repeat_byte(0x01)).add_chain(1, B256::repeat_byte(0x02));
+        assert_eq!(
+            builder.build(10).output_roots,
+            vec![OutputRootWithChain::new(1, B256::repeat_byte(0x02))]
+        );
+    }
+
     #[test]
     fn test_static_super_root_roundtrip() {
         let super_root = SuperRoot::new(
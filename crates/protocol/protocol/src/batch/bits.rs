@@ -1,6 +1,6 @@
 //! Module for working with span batch bits.
 
-use crate::SpanBatchError;
+use crate::{SpanBatchError, SpanDecodingError};
 use alloc::{vec, vec::Vec};
 use alloy_primitives::bytes;
 use alloy_rlp::Buf;
@@ -10,6 +10,27 @@ use core::cmp::Ordering;
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct SpanBatchBits(Vec<u8>);
 
+/// The encoding of a bitlist in the compact format written by [SpanBatchBits::encode_compact].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanBatchBitsEncoding {
+    /// The standard span-batch bitlist encoding, see [SpanBatchBits::encode].
+    Dense = 0,
+    /// The run-length encoding, see [SpanBatchBits::encode_rle].
+    Rle = 1,
+}
+
+impl TryFrom<u8> for SpanBatchBitsEncoding {
+    type Error = SpanBatchError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Dense),
+            1 => Ok(Self::Rle),
+            _ => Err(SpanBatchError::Decoding(SpanDecodingError::CompactBits)),
+        }
+    }
+}
+
 impl AsRef<[u8]> for SpanBatchBits {
     fn as_ref(&self) -> &[u8] {
         &self.0
@@ -67,6 +88,107 @@ impl SpanBatchBits {
         Ok(())
     }
 
+    /// Decodes a run-length encoded span-batch bitlist from a reader, as written by
+    /// [SpanBatchBits::encode_rle]. The encoded bitlist cannot be longer than `bit_length`.
+    pub fn decode_rle(b: &mut &[u8], bit_length: usize) -> Result<Self, SpanBatchError> {
+        let buffer_len = bit_length / 8 + if bit_length % 8 != 0 { 1 } else { 0 };
+        let mut bits = Self(vec![0; buffer_len]);
+
+        // Runs alternate between `0`s and `1`s, beginning with a run of `0`s.
+        let (mut index, mut value) = (0usize, false);
+        while index < bit_length {
+            let (run, remaining) = unsigned_varint::decode::u64(b)
+                .map_err(|_| SpanBatchError::Decoding(SpanDecodingError::CompactBits))?;
+            *b = remaining;
+
+            let end = usize::try_from(run)
+                .ok()
+                .and_then(|run| index.checked_add(run))
+                .filter(|end| *end <= bit_length)
+                .ok_or(SpanBatchError::BitfieldTooLong)?;
+            if value {
+                (index..end).for_each(|i| bits.set_bit(i, true));
+            }
+            index = end;
+            value = !value;
+        }
+
+        Ok(bits)
+    }
+
+    /// Encodes a span-batch bitlist as a sequence of run lengths.
+    ///
+    /// The first `bit_length` bits, starting from the least significant bit, are encoded as
+    /// alternating runs of `0`s and `1`s, beginning with a (possibly empty) run of `0`s. Each run
+    /// length is encoded as an unsigned varint. For sparse bitlists, such as the origin bits of a
+    /// long span in which the L1 origin rarely changes, this is much smaller than the standard
+    /// encoding. The encoded bitlist cannot be longer than `bit_length`.
+    pub fn encode_rle(
+        w: &mut dyn bytes::BufMut,
+        bit_length: usize,
+        bits: &Self,
+    ) -> Result<(), SpanBatchError> {
+        if bits.bit_len() > bit_length {
+            return Err(SpanBatchError::BitfieldTooLong);
+        }
+
+        let mut u64_varint_buf = [0u8; 10];
+        let (mut value, mut run) = (0, 0u64);
+        for index in 0..bit_length {
+            let bit = bits.get_bit(index).unwrap_or_default();
+            if bit != value {
+                w.put_slice(unsigned_varint::encode::u64(run, &mut u64_varint_buf));
+                value = bit;
+                run = 0;
+            }
+            run += 1;
+        }
+        if run > 0 {
+            w.put_slice(unsigned_varint::encode::u64(run, &mut u64_varint_buf));
+        }
+        Ok(())
+    }
+
+    /// Decodes a span-batch bitlist in the compact format from a reader, as written by
+    /// [SpanBatchBits::encode_compact]. The encoded bitlist cannot be longer than `bit_length`.
+    pub fn decode_compact(b: &mut &[u8], bit_length: usize) -> Result<Self, SpanBatchError> {
+        let Some((&encoding, rest)) = b.split_first() else {
+            return Err(SpanBatchError::Decoding(SpanDecodingError::CompactBits));
+        };
+        *b = rest;
+
+        match SpanBatchBitsEncoding::try_from(encoding)? {
+            SpanBatchBitsEncoding::Dense => Self::decode(b, bit_length),
+            SpanBatchBitsEncoding::Rle => Self::decode_rle(b, bit_length),
+        }
+    }
+
+    /// Encodes a span-batch bitlist in the compact format: a [SpanBatchBitsEncoding] byte,
+    /// followed by the run-length encoding of the bitlist if it is smaller than the standard
+    /// encoding, or the standard encoding otherwise. The encoded bitlist cannot be longer than
+    /// `bit_length`.
+    ///
+    /// Returns the [SpanBatchBitsEncoding] that was selected.
+    pub fn encode_compact(
+        w: &mut dyn bytes::BufMut,
+        bit_length: usize,
+        bits: &Self,
+    ) -> Result<SpanBatchBitsEncoding, SpanBatchError> {
+        let mut rle = Vec::new();
+        Self::encode_rle(&mut rle, bit_length, bits)?;
+
+        let dense_len = bit_length / 8 + if bit_length % 8 != 0 { 1 } else { 0 };
+        if rle.len() < dense_len {
+            w.put_u8(SpanBatchBitsEncoding::Rle as u8);
+            w.put_slice(&rle);
+            Ok(SpanBatchBitsEncoding::Rle)
+        } else {
+            w.put_u8(SpanBatchBitsEncoding::Dense as u8);
+            Self::encode(w, bit_length, bits)?;
+            Ok(SpanBatchBitsEncoding::Dense)
+        }
+    }
+
     /// Get a bit from the [SpanBatchBits] bitlist.
     pub fn get_bit(&self, index: usize) -> Option<u8> {
         let byte_index = index / 8;
@@ -156,7 +278,39 @@ mod test {
     use super::*;
     use proptest::{collection::vec, prelude::any, proptest};
 
+    /// Builds a [SpanBatchBits] bitlist in its decoded representation from the given bits.
+    fn bits_from(values: &[bool]) -> SpanBatchBits {
+        let mut bits = SpanBatchBits(vec![0; values.len().div_ceil(8)]);
+        for (index, value) in values.iter().enumerate() {
+            bits.set_bit(index, *value);
+        }
+        bits
+    }
+
     proptest! {
+        #[test]
+        fn test_rle_roundtrip_span_bitlist(values in vec(any::<bool>(), 0..4096)) {
+            let bits = bits_from(&values);
+
+            let mut encoded = Vec::new();
+            SpanBatchBits::encode_rle(&mut encoded, values.len(), &bits).unwrap();
+            let mut reader = encoded.as_slice();
+            assert_eq!(SpanBatchBits::decode_rle(&mut reader, values.len()).unwrap(), bits);
+            assert!(reader.is_empty());
+        }
+
+        #[test]
+        fn test_compact_roundtrip_span_bitlist(values in vec(any::<bool>(), 0..4096)) {
+            let bits = bits_from(&values);
+
+            let mut encoded = Vec::new();
+            SpanBatchBits::encode_compact(&mut encoded, values.len(), &bits).unwrap();
+            assert!(encoded.len() <= values.len().div_ceil(8) + 1);
+            let mut reader = encoded.as_slice();
+            assert_eq!(SpanBatchBits::decode_compact(&mut reader, values.len()).unwrap(), bits);
+            assert!(reader.is_empty());
+        }
+
         #[test]
         fn test_encode_decode_roundtrip_span_bitlist(vec in vec(any::<u8>(), 0..5096)) {
             let bits = SpanBatchBits(vec);
@@ -195,6 +349,49 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_rle_sparse_bitlist_is_smaller() {
+        const BIT_LENGTH: usize = 10_000;
+        let mut values = vec![false; BIT_LENGTH];
+        for index in [0, 5_000, BIT_LENGTH - 1] {
+            values[index] = true;
+        }
+        let bits = bits_from(&values);
+
+        let mut dense = Vec::new();
+        SpanBatchBits::encode(&mut dense, BIT_LENGTH, &bits).unwrap();
+        let mut rle = Vec::new();
+        SpanBatchBits::encode_rle(&mut rle, BIT_LENGTH, &bits).unwrap();
+        assert_eq!(dense.len(), BIT_LENGTH / 8);
+        assert!(rle.len() * 100 < dense.len());
+
+        let mut compact = Vec::new();
+        let encoding = SpanBatchBits::encode_compact(&mut compact, BIT_LENGTH, &bits).unwrap();
+        assert_eq!(encoding, SpanBatchBitsEncoding::Rle);
+        assert_eq!(compact.len(), rle.len() + 1);
+    }
+
+    #[test]
+    fn test_compact_dense_bitlist_falls_back() {
+        let values = (0..1024).map(|i| i % 2 == 0).collect::<Vec<_>>();
+        let bits = bits_from(&values);
+
+        let mut compact = Vec::new();
+        let encoding = SpanBatchBits::encode_compact(&mut compact, values.len(), &bits).unwrap();
+        assert_eq!(encoding, SpanBatchBitsEncoding::Dense);
+        assert_eq!(compact.len(), values.len() / 8 + 1);
+    }
+
+    #[test]
+    fn test_decode_rle_too_long() {
+        let mut u64_varint_buf = [0u8; 10];
+        let encoded = unsigned_varint::encode::u64(9, &mut u64_varint_buf);
+        assert_eq!(
+            SpanBatchBits::decode_rle(&mut &encoded[..], 8),
+            Err(SpanBatchError::BitfieldTooLong)
+        );
+    }
+
     #[test]
     fn bitlist_big_endian_zero_extended() {
         let mut bits = SpanBatchBits::default();
@@ -2,6 +2,7 @@
 
 use crate::{
     BatchDecodingError, BatchEncodingError, BatchType, RawSpanBatch, SingleBatch, SpanBatch,
+    SpanBatchLimits,
 };
 use alloy_primitives::bytes;
 use alloy_rlp::{Buf, Decodable, Encodable};
@@ -28,6 +29,16 @@ impl Batch {
 
     /// Attempts to decode a batch from a reader.
     pub fn decode(r: &mut &[u8], cfg: &RollupConfig) -> Result<Self, BatchDecodingError> {
+        Self::decode_with_limits(r, cfg, SpanBatchLimits::default())
+    }
+
+    /// Attempts to decode a batch from a reader, bounding the sizes declared by a span batch by
+    /// the given [SpanBatchLimits].
+    pub fn decode_with_limits(
+        r: &mut &[u8],
+        cfg: &RollupConfig,
+        limits: SpanBatchLimits,
+    ) -> Result<Self, BatchDecodingError> {
         if r.is_empty() {
             return Err(BatchDecodingError::EmptyBuffer);
         }
@@ -43,7 +54,7 @@ impl Batch {
                 Ok(Self::Single(single_batch))
             }
             BatchType::Span => {
-                let mut raw_span_batch = RawSpanBatch::decode(r)?;
+                let mut raw_span_batch = RawSpanBatch::decode_with_limits(r, limits)?;
                 let span_batch = raw_span_batch
                     .derive(cfg.block_time, cfg.genesis.l2_time, cfg.l2_chain_id)
                     .map_err(BatchDecodingError::SpanBatchError)?;
@@ -6,6 +6,16 @@ pub enum SpanBatchError {
     /// The span batch is too big
     #[error("The span batch is too big.")]
     TooBigSpanBatchSize,
+    /// The span batch declares more elements than the configured [SpanBatchLimits] allow.
+    ///
+    /// [SpanBatchLimits]: crate::SpanBatchLimits
+    #[error("The span batch declares {declared} elements, exceeding the limit of {limit}")]
+    TooLarge {
+        /// The number of elements declared by the span batch.
+        declared: u64,
+        /// The maximum number of elements allowed.
+        limit: u64,
+    },
     /// The bit field is too long
     #[error("The bit field is too long")]
     BitfieldTooLong,
@@ -15,6 +25,14 @@ pub enum SpanBatchError {
     /// Missing L1 origin
     #[error("Missing L1 origin")]
     MissingL1Origin,
+    /// The number of parent hashes does not match the number of singular batches.
+    #[error("Expected {expected} parent hashes, got {actual}")]
+    ParentHashCountMismatch {
+        /// The number of singular batches.
+        expected: usize,
+        /// The number of parent hashes supplied.
+        actual: usize,
+    },
     /// Decoding errors
     #[error("Span batch decoding error: {0}")]
     Decoding(#[from] SpanDecodingError),
@@ -82,4 +100,7 @@ pub enum SpanDecodingError {
     /// Invalid transaction signature
     #[error("Invalid transaction signature")]
     InvalidTransactionSignature,
+    /// Failed to decode compact span batch bits
+    #[error("Failed to decode compact span batch bits")]
+    CompactBits,
 }
@@ -16,7 +16,7 @@ mod raw;
 pub use raw::RawSpanBatch;
 
 mod payload;
-pub use payload::SpanBatchPayload;
+pub use payload::{SpanBatchLimits, SpanBatchPayload};
 
 mod prefix;
 pub use prefix::SpanBatchPrefix;
@@ -28,7 +28,7 @@ mod errors;
 pub use errors::{BatchDecodingError, BatchEncodingError, SpanBatchError, SpanDecodingError};
 
 mod bits;
-pub use bits::SpanBatchBits;
+pub use bits::{SpanBatchBits, SpanBatchBitsEncoding};
 
 mod span;
 pub use span::SpanBatch;
@@ -40,7 +40,7 @@ mod element;
 pub use element::{MAX_SPAN_BATCH_ELEMENTS, SpanBatchElement};
 
 mod validity;
-pub use validity::BatchValidity;
+pub use validity::{BatchValidity, DropReason};
 
 mod single;
 pub use single::SingleBatch;
@@ -5,6 +5,32 @@ use crate::{SpanBatchBits, SpanBatchError, SpanBatchTransactions, SpanDecodingEr
 use alloc::vec::Vec;
 use alloy_primitives::bytes;
 
+/// Decode-time bounds on the sizes declared by a [SpanBatchPayload].
+///
+/// The declared sizes are checked before the corresponding vectors are allocated, so that crafted
+/// L1 data declaring huge sizes is rejected without driving enormous allocations. The default
+/// limits are the protocol's [MAX_SPAN_BATCH_ELEMENTS]; tighter limits reject span batches that are
+/// valid by consensus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpanBatchLimits {
+    /// The maximum number of L2 blocks in the span.
+    pub max_block_count: u64,
+    /// The maximum total number of transactions in the span.
+    pub max_tx_count: u64,
+}
+
+impl SpanBatchLimits {
+    /// The limits imposed by the protocol, both equal to [MAX_SPAN_BATCH_ELEMENTS].
+    pub const PROTOCOL: Self =
+        Self { max_block_count: MAX_SPAN_BATCH_ELEMENTS, max_tx_count: MAX_SPAN_BATCH_ELEMENTS };
+}
+
+impl Default for SpanBatchLimits {
+    fn default() -> Self {
+        Self::PROTOCOL
+    }
+}
+
 /// Span Batch Payload
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct SpanBatchPayload {
@@ -22,10 +48,58 @@ pub struct SpanBatchPayload {
 impl SpanBatchPayload {
     /// Decodes a [SpanBatchPayload] from a reader.
     pub fn decode_payload(r: &mut &[u8]) -> Result<Self, SpanBatchError> {
+        Self::decode_payload_with_limits(r, SpanBatchLimits::default())
+    }
+
+    /// Decodes a [SpanBatchPayload] from a reader, rejecting declared sizes that exceed the
+    /// given [SpanBatchLimits] with [SpanBatchError::TooLarge].
+    pub fn decode_payload_with_limits(
+        r: &mut &[u8],
+        limits: SpanBatchLimits,
+    ) -> Result<Self, SpanBatchError> {
+        Self::decode_payload_inner(r, limits, false)
+    }
+
+    /// Decodes a [SpanBatchPayload] with compact origin bits from a reader, as written by
+    /// [SpanBatchPayload::encode_payload_compact], rejecting declared sizes that exceed the given
+    /// [SpanBatchLimits] with [SpanBatchError::TooLarge].
+    pub fn decode_payload_compact_with_limits(
+        r: &mut &[u8],
+        limits: SpanBatchLimits,
+    ) -> Result<Self, SpanBatchError> {
+        Self::decode_payload_inner(r, limits, true)
+    }
+
+    /// Decodes a [SpanBatchPayload] from a reader, with the origin bits in either the standard or
+    /// the compact format.
+    fn decode_payload_inner(
+        r: &mut &[u8],
+        limits: SpanBatchLimits,
+        compact_origin_bits: bool,
+    ) -> Result<Self, SpanBatchError> {
         let mut payload = Self::default();
         payload.decode_block_count(r)?;
-        payload.decode_origin_bits(r)?;
+        if payload.block_count > limits.max_block_count {
+            return Err(SpanBatchError::TooLarge {
+                declared: payload.block_count,
+                limit: limits.max_block_count,
+            });
+        }
+        if compact_origin_bits {
+            payload.decode_origin_bits_compact(r)?;
+        } else {
+            payload.decode_origin_bits(r)?;
+        }
         payload.decode_block_tx_counts(r)?;
+
+        let total_tx_count =
+            payload.block_tx_counts.iter().fold(0u64, |acc, count| acc.saturating_add(*count));
+        if total_tx_count > limits.max_tx_count {
+            return Err(SpanBatchError::TooLarge {
+                declared: total_tx_count,
+                limit: limits.max_tx_count,
+            });
+        }
         payload.decode_txs(r)?;
         Ok(payload)
     }
@@ -38,6 +112,17 @@ impl SpanBatchPayload {
         self.encode_txs(w)
     }
 
+    /// Encodes a [SpanBatchPayload] into a writer, with the origin bits in the compact format of
+    /// [SpanBatchBits::encode_compact].
+    ///
+    /// This is not the canonical span batch encoding, and is only meant for caching large spans.
+    pub fn encode_payload_compact(&self, w: &mut dyn bytes::BufMut) -> Result<(), SpanBatchError> {
+        self.encode_block_count(w);
+        SpanBatchBits::encode_compact(w, self.block_count as usize, &self.origin_bits)?;
+        self.encode_block_tx_counts(w);
+        self.encode_txs(w)
+    }
+
     /// Decodes the origin bits from a reader.
     pub fn decode_origin_bits(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
         if self.block_count > MAX_SPAN_BATCH_ELEMENTS {
@@ -48,6 +133,17 @@ impl SpanBatchPayload {
         Ok(())
     }
 
+    /// Decodes the origin bits in the compact format of [SpanBatchBits::encode_compact] from a
+    /// reader.
+    pub fn decode_origin_bits_compact(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
+        if self.block_count > MAX_SPAN_BATCH_ELEMENTS {
+            return Err(SpanBatchError::TooBigSpanBatchSize);
+        }
+
+        self.origin_bits = SpanBatchBits::decode_compact(r, self.block_count as usize)?;
+        Ok(())
+    }
+
     /// Decode a block count from a reader.
     pub fn decode_block_count(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
         let (block_count, remaining) = unsigned_varint::decode::u64(r)
@@ -68,8 +164,10 @@ impl SpanBatchPayload {
     /// Decode block transaction counts from a reader.
     pub fn decode_block_tx_counts(&mut self, r: &mut &[u8]) -> Result<(), SpanBatchError> {
         // Initially allocate the vec with the block count, to reduce re-allocations in the first
-        // few blocks.
-        let mut block_tx_counts = Vec::with_capacity(self.block_count as usize);
+        // few blocks. Each count is encoded in at least one byte, so the capacity is also bounded
+        // by the remaining input, regardless of the declared block count.
+        let capacity = (self.block_count as usize).min(r.len());
+        let mut block_tx_counts = Vec::with_capacity(capacity);
 
         for _ in 0..self.block_count {
             let (block_tx_count, remaining) = unsigned_varint::decode::u64(r)
@@ -178,6 +276,45 @@ mod tests {
         assert_eq!(err, SpanBatchError::TooBigSpanBatchSize);
     }
 
+    #[test]
+    fn test_decode_payload_block_count_too_large() {
+        let limits = SpanBatchLimits { max_block_count: 1_000, ..Default::default() };
+
+        // A header declaring an absurd block count, without any of the data backing it.
+        let mut u64_varint_buf = [0; 10];
+        let encoded = unsigned_varint::encode::u64(MAX_SPAN_BATCH_ELEMENTS, &mut u64_varint_buf);
+        let err =
+            SpanBatchPayload::decode_payload_with_limits(&mut &encoded[..], limits).unwrap_err();
+        let expected = SpanBatchError::TooLarge { declared: MAX_SPAN_BATCH_ELEMENTS, limit: 1_000 };
+        assert_eq!(err, expected);
+    }
+
+    #[test]
+    fn test_decode_payload_tx_count_too_large() {
+        let limits = SpanBatchLimits { max_tx_count: 100, ..Default::default() };
+
+        // Two blocks, with 60 transactions each.
+        let mut encoded = Vec::new();
+        let mut u64_varint_buf = [0; 10];
+        encoded.extend_from_slice(unsigned_varint::encode::u64(2, &mut u64_varint_buf));
+        encoded.push(0);
+        for _ in 0..2 {
+            encoded.extend_from_slice(unsigned_varint::encode::u64(60, &mut u64_varint_buf));
+        }
+        let err = SpanBatchPayload::decode_payload_with_limits(&mut encoded.as_slice(), limits)
+            .unwrap_err();
+        assert_eq!(err, SpanBatchError::TooLarge { declared: 120, limit: 100 });
+    }
+
+    #[test]
+    fn test_decode_block_tx_counts_truncated() {
+        // The declared block count is not backed by the remaining input.
+        let mut payload =
+            SpanBatchPayload { block_count: MAX_SPAN_BATCH_ELEMENTS, ..Default::default() };
+        let err = payload.decode_block_tx_counts(&mut [1u8, 1].as_slice()).unwrap_err();
+        assert_eq!(err, SpanBatchError::Decoding(SpanDecodingError::BlockTxCounts));
+    }
+
     #[test]
     fn test_decode_block_tx_counts() {
         let block_count = 2;
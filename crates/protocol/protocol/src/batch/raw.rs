@@ -4,8 +4,8 @@ use alloc::{vec, vec::Vec};
 use alloy_primitives::bytes;
 
 use crate::{
-    BatchType, SpanBatch, SpanBatchElement, SpanBatchError, SpanBatchPayload, SpanBatchPrefix,
-    SpanDecodingError,
+    BatchType, SpanBatch, SpanBatchElement, SpanBatchError, SpanBatchLimits, SpanBatchPayload,
+    SpanBatchPrefix, SpanDecodingError,
 };
 
 /// Raw Span Batch
@@ -29,10 +29,39 @@ impl RawSpanBatch {
         self.payload.encode_payload(w)
     }
 
+    /// Encodes the [RawSpanBatch] into a writer, run-length encoding the origin bits when that is
+    /// smaller than the standard encoding. See [SpanBatchBits::encode_compact].
+    ///
+    /// This is not the canonical span batch encoding, and must not be used for batches posted to
+    /// L1. It is meant for caching large spans, in which the L1 origin rarely changes.
+    ///
+    /// [SpanBatchBits::encode_compact]: crate::SpanBatchBits::encode_compact
+    pub fn encode_compact(&self, w: &mut dyn bytes::BufMut) -> Result<(), SpanBatchError> {
+        self.prefix.encode_prefix(w);
+        self.payload.encode_payload_compact(w)
+    }
+
+    /// Decodes a [RawSpanBatch] written by [RawSpanBatch::encode_compact] from a reader.
+    pub fn decode_compact(r: &mut &[u8]) -> Result<Self, SpanBatchError> {
+        let prefix = SpanBatchPrefix::decode_prefix(r)?;
+        let payload =
+            SpanBatchPayload::decode_payload_compact_with_limits(r, SpanBatchLimits::default())?;
+        Ok(Self { prefix, payload })
+    }
+
     /// Decodes the [RawSpanBatch] from a reader.]
     pub fn decode(r: &mut &[u8]) -> Result<Self, SpanBatchError> {
+        Self::decode_with_limits(r, SpanBatchLimits::default())
+    }
+
+    /// Decodes the [RawSpanBatch] from a reader, bounding the declared sizes of the payload by
+    /// the given [SpanBatchLimits].
+    pub fn decode_with_limits(
+        r: &mut &[u8],
+        limits: SpanBatchLimits,
+    ) -> Result<Self, SpanBatchError> {
         let prefix = SpanBatchPrefix::decode_prefix(r)?;
-        let payload = SpanBatchPayload::decode_payload(r)?;
+        let payload = SpanBatchPayload::decode_payload_with_limits(r, limits)?;
         Ok(Self { prefix, payload })
     }
 
@@ -140,4 +169,15 @@ mod test {
         raw_span_batch.encode(&mut encoding_buf).unwrap();
         assert_eq!(encoding_buf, raw_span_batch_hex);
     }
+
+    #[test]
+    fn test_compact_roundtrip_raw_span_batch() {
+        let raw_span_batch_hex = include_bytes!("./testdata/raw_batch.hex");
+        let raw_span_batch = RawSpanBatch::decode(&mut raw_span_batch_hex.as_slice()).unwrap();
+
+        let mut encoding_buf = Vec::new();
+        raw_span_batch.encode_compact(&mut encoding_buf).unwrap();
+        let decoded = RawSpanBatch::decode_compact(&mut encoding_buf.as_slice()).unwrap();
+        assert_eq!(decoded, raw_span_batch);
+    }
 }
@@ -1,6 +1,6 @@
 //! Contains the [BatchReader] which is used to iteratively consume batches from raw data.
 
-use crate::{Batch, decompress_brotli};
+use crate::{Batch, SpanBatchLimits, decompress_brotli};
 use alloc::vec::Vec;
 use alloy_primitives::Bytes;
 use alloy_rlp::Decodable;
@@ -29,6 +29,8 @@ pub struct BatchReader {
     cursor: usize,
     /// The maximum RLP bytes per channel.
     max_rlp_bytes_per_channel: usize,
+    /// The bounds on the sizes declared by span batches.
+    span_batch_limits: SpanBatchLimits,
 }
 
 impl BatchReader {
@@ -42,9 +44,17 @@ impl BatchReader {
             decompressed: Vec::new(),
             cursor: 0,
             max_rlp_bytes_per_channel,
+            span_batch_limits: SpanBatchLimits::default(),
         }
     }
 
+    /// Bounds the sizes declared by the span batches read from the data by the given
+    /// [SpanBatchLimits], rather than the protocol's maximum.
+    pub const fn with_span_batch_limits(mut self, span_batch_limits: SpanBatchLimits) -> Self {
+        self.span_batch_limits = span_batch_limits;
+        self
+    }
+
     /// Pulls out the next batch from the reader.
     pub fn next_batch(&mut self, cfg: &RollupConfig) -> Option<Batch> {
         // If the data is not already decompressed, decompress it.
@@ -78,7 +88,8 @@ impl BatchReader {
         // Decompress and RLP decode the batch data, before finally decoding the batch itself.
         let decompressed_reader = &mut self.decompressed.as_slice()[self.cursor..].as_ref();
         let bytes = Bytes::decode(decompressed_reader).ok()?;
-        let Ok(batch) = Batch::decode(&mut bytes.as_ref(), cfg) else {
+        let Ok(batch) = Batch::decode_with_limits(&mut bytes.as_ref(), cfg, self.span_batch_limits)
+        else {
             return None;
         };
 
@@ -1,6 +1,6 @@
 //! This module contains the [SingleBatch] type.
 
-use crate::{BatchValidity, BlockInfo, L2BlockInfo};
+use crate::{BatchValidity, BlockInfo, DropReason, L2BlockInfo};
 use alloc::vec::Vec;
 use alloy_eips::BlockNumHash;
 use alloy_primitives::{BlockHash, Bytes};
@@ -45,7 +45,7 @@ impl SingleBatch {
         let next_timestamp = l2_safe_head.block_info.timestamp + cfg.block_time;
         if self.timestamp > next_timestamp {
             if cfg.is_holocene_active(inclusion_block.timestamp) {
-                return BatchValidity::Drop;
+                return BatchValidity::Drop(DropReason::FutureTimestamp);
             }
             return BatchValidity::Future;
         }
@@ -53,7 +53,7 @@ impl SingleBatch {
             if cfg.is_holocene_active(inclusion_block.timestamp) {
                 return BatchValidity::Past;
             }
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::PastTimestamp);
         }
         BatchValidity::Accept
     }
@@ -86,18 +86,18 @@ impl SingleBatch {
         // Dependent on the above timestamp check.
         // If the timestamp is correct, then it must build on top of the safe head.
         if self.parent_hash != l2_safe_head.block_info.hash {
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::ParentHashMismatch);
         }
 
         // Filter out batches that were included too late.
         if self.epoch_num + cfg.seq_window_size < inclusion_block.number {
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::SeqWindowExpired);
         }
 
         // Check the L1 origin of the batch
         let mut batch_origin = epoch;
         if self.epoch_num < epoch.number {
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::EpochTooOld);
         } else if self.epoch_num == epoch.number {
             // Batch is sticking to the current epoch, continue.
         } else if self.epoch_num == epoch.number + 1 {
@@ -111,16 +111,16 @@ impl SingleBatch {
             }
             batch_origin = l1_blocks[1];
         } else {
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::EpochTooFarAhead);
         }
 
         // Validate the batch epoch hash
         if self.epoch_hash != batch_origin.hash {
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::EpochHashMismatch);
         }
 
         if self.timestamp < batch_origin.timestamp {
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::TimestampBeforeL1Origin);
         }
 
         // Check if we ran out of sequencer time drift
@@ -128,7 +128,7 @@ impl SingleBatch {
         let max = if let Some(max) = batch_origin.timestamp.checked_add(max_drift) {
             max
         } else {
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::TimeDriftOverflow);
         };
 
         let no_txs = self.transactions.is_empty();
@@ -136,7 +136,7 @@ impl SingleBatch {
             // If the sequencer is ignoring the time drift rule, then drop the batch and force an
             // empty batch instead, as the sequencer is not allowed to include anything
             // past this point without moving to the next epoch.
-            return BatchValidity::Drop;
+            return BatchValidity::Drop(DropReason::TimeDrift);
         }
         if self.timestamp > max && no_txs {
             // If the sequencer is co-operating by producing an empty batch,
@@ -150,7 +150,7 @@ impl SingleBatch {
                 let next_origin = l1_blocks[1];
                 // Check if the next L1 Origin could have been adopted
                 if self.timestamp >= next_origin.timestamp {
-                    return BatchValidity::Drop;
+                    return BatchValidity::Drop(DropReason::NextOriginNotAdopted);
                 }
             }
         }
@@ -158,16 +158,16 @@ impl SingleBatch {
         // We can do this check earlier, but it's intensive so we do it last for the sad-path.
         for tx in self.transactions.iter() {
             if tx.is_empty() {
-                return BatchValidity::Drop;
+                return BatchValidity::Drop(DropReason::EmptyTransaction);
             }
             if tx.as_ref().first() == Some(&(OpTxType::Deposit as u8)) {
-                return BatchValidity::Drop;
+                return BatchValidity::Drop(DropReason::DepositInBatch);
             }
             // If isthmus is not active yet and the transaction is a 7702, drop the batch.
             if !cfg.is_isthmus_active(self.timestamp) &&
                 tx.as_ref().first() == Some(&(OpTxType::Eip7702 as u8))
             {
-                return BatchValidity::Drop;
+                return BatchValidity::Drop(DropReason::Eip7702PreIsthmus);
             }
         }
 
@@ -226,7 +226,7 @@ mod tests {
         let batch = SingleBatch { parent_hash: BlockHash::from([0x02; 32]), ..Default::default() };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::ParentHashMismatch)
         );
     }
 
@@ -259,7 +259,7 @@ mod tests {
         let batch = SingleBatch { epoch_num: 1, timestamp: 2, ..Default::default() };
         assert_eq!(
             batch.check_batch_timestamp(&cfg, l2_safe_head, &inclusion_block),
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::FutureTimestamp)
         );
     }
 
@@ -292,7 +292,7 @@ mod tests {
         let batch = SingleBatch { epoch_num: 1, timestamp: 1, ..Default::default() };
         assert_eq!(
             batch.check_batch_timestamp(&cfg, l2_safe_head, &inclusion_block),
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::PastTimestamp)
         );
     }
 
@@ -465,7 +465,7 @@ mod tests {
         let inclusion_block = BlockInfo::default();
         assert_eq!(
             single_batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::Eip7702PreIsthmus)
         );
     }
 
@@ -534,7 +534,7 @@ mod tests {
         let inclusion_block = BlockInfo::default();
         assert_eq!(
             single_batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::EmptyTransaction)
         );
     }
 
@@ -577,7 +577,159 @@ mod tests {
         let inclusion_block = BlockInfo::default();
         assert_eq!(
             single_batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::DepositInBatch)
+        );
+    }
+
+    /// Returns a rollup config, two consecutive L1 epochs, a safe head built on the first, and a
+    /// batch that is valid against them.
+    fn validation_fixture() -> (RollupConfig, Vec<BlockInfo>, L2BlockInfo, SingleBatch) {
+        let cfg = RollupConfig {
+            block_time: 2,
+            seq_window_size: 10,
+            max_sequencer_drift: 600,
+            ..Default::default()
+        };
+        let l1_blocks = vec![
+            BlockInfo {
+                number: 10,
+                hash: BlockHash::from([0xaa; 32]),
+                timestamp: 100,
+                ..Default::default()
+            },
+            BlockInfo {
+                number: 11,
+                hash: BlockHash::from([0xbb; 32]),
+                timestamp: 112,
+                ..Default::default()
+            },
+        ];
+        let l2_safe_head = L2BlockInfo {
+            block_info: BlockInfo {
+                hash: BlockHash::from([0x11; 32]),
+                timestamp: 100,
+                ..Default::default()
+            },
+            l1_origin: l1_blocks[0].id(),
+            ..Default::default()
+        };
+        let batch = SingleBatch {
+            parent_hash: l2_safe_head.block_info.hash,
+            epoch_num: 10,
+            epoch_hash: l1_blocks[0].hash,
+            timestamp: 102,
+            transactions: vec![Bytes::from(vec![0x02])],
+        };
+        (cfg, l1_blocks, l2_safe_head, batch)
+    }
+
+    #[test]
+    fn test_check_batch_accept_current_epoch() {
+        let (cfg, l1_blocks, l2_safe_head, batch) = validation_fixture();
+        let inclusion_block = BlockInfo { number: 12, timestamp: 124, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
+            BatchValidity::Accept
+        );
+    }
+
+    #[test]
+    fn test_check_batch_accept_next_epoch() {
+        let (cfg, l1_blocks, mut l2_safe_head, mut batch) = validation_fixture();
+        l2_safe_head.block_info.timestamp = 110;
+        batch.epoch_num = 11;
+        batch.epoch_hash = l1_blocks[1].hash;
+        batch.timestamp = 112;
+        let inclusion_block = BlockInfo { number: 12, timestamp: 124, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
+            BatchValidity::Accept
+        );
+    }
+
+    #[test]
+    fn test_check_batch_undecided_next_epoch() {
+        let (cfg, l1_blocks, l2_safe_head, mut batch) = validation_fixture();
+        batch.epoch_num = 11;
+        batch.epoch_hash = l1_blocks[1].hash;
+        let inclusion_block = BlockInfo { number: 12, timestamp: 124, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks[..1], l2_safe_head, &inclusion_block),
+            BatchValidity::Undecided
+        );
+    }
+
+    #[test]
+    fn test_check_batch_future_timestamp() {
+        let (cfg, l1_blocks, l2_safe_head, mut batch) = validation_fixture();
+        batch.timestamp = 104;
+        let inclusion_block = BlockInfo { number: 12, timestamp: 124, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
+            BatchValidity::Future
+        );
+    }
+
+    #[test]
+    fn test_check_batch_sequence_window_expiry() {
+        let (cfg, l1_blocks, l2_safe_head, batch) = validation_fixture();
+
+        // Included in the last block of the sequence window.
+        let inclusion_block = BlockInfo { number: 20, timestamp: 140, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
+            BatchValidity::Accept
+        );
+
+        // Included one block after the sequence window expired.
+        let inclusion_block = BlockInfo { number: 21, timestamp: 142, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
+            BatchValidity::Drop(DropReason::SeqWindowExpired)
+        );
+    }
+
+    #[test]
+    fn test_check_batch_drop_epoch_too_old() {
+        let (cfg, l1_blocks, l2_safe_head, batch) = validation_fixture();
+        let inclusion_block = BlockInfo { number: 12, timestamp: 124, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks[1..], l2_safe_head, &inclusion_block),
+            BatchValidity::Drop(DropReason::EpochTooOld)
+        );
+    }
+
+    #[test]
+    fn test_check_batch_drop_epoch_too_new() {
+        let (cfg, l1_blocks, l2_safe_head, mut batch) = validation_fixture();
+        batch.epoch_num = 12;
+        let inclusion_block = BlockInfo { number: 12, timestamp: 124, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
+            BatchValidity::Drop(DropReason::EpochTooFarAhead)
+        );
+    }
+
+    #[test]
+    fn test_check_batch_drop_epoch_hash_mismatch() {
+        let (cfg, l1_blocks, l2_safe_head, mut batch) = validation_fixture();
+        batch.epoch_hash = BlockHash::from([0xcc; 32]);
+        let inclusion_block = BlockInfo { number: 12, timestamp: 124, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
+            BatchValidity::Drop(DropReason::EpochHashMismatch)
+        );
+    }
+
+    #[test]
+    fn test_check_batch_drop_timestamp_before_origin() {
+        let (cfg, l1_blocks, l2_safe_head, mut batch) = validation_fixture();
+        batch.epoch_num = 11;
+        batch.epoch_hash = l1_blocks[1].hash;
+        let inclusion_block = BlockInfo { number: 12, timestamp: 124, ..Default::default() };
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block),
+            BatchValidity::Drop(DropReason::TimestampBeforeL1Origin)
         );
     }
 }
@@ -2,15 +2,15 @@
 
 use alloc::vec::Vec;
 use alloy_eips::eip2718::Encodable2718;
-use alloy_primitives::FixedBytes;
+use alloy_primitives::{B256, FixedBytes};
 use kona_genesis::RollupConfig;
 use op_alloy_consensus::OpTxType;
 use tracing::{info, warn};
 
 use crate::{
-    BatchValidationProvider, BatchValidity, BlockInfo, L2BlockInfo, RawSpanBatch, SingleBatch,
-    SpanBatchBits, SpanBatchElement, SpanBatchError, SpanBatchPayload, SpanBatchPrefix,
-    SpanBatchTransactions,
+    BatchValidationProvider, BatchValidity, BlockInfo, DropReason, L2BlockInfo, RawSpanBatch,
+    SingleBatch, SpanBatchBits, SpanBatchElement, SpanBatchError, SpanBatchPayload,
+    SpanBatchPrefix, SpanBatchTransactions,
 };
 
 /// Container of the inputs required to build a span of L2 blocks in derived form.
@@ -135,6 +135,31 @@ impl SpanBatch {
         Ok(single_batches)
     }
 
+    /// Converts all [SpanBatchElement]s after the L2 safe head to [SingleBatch]es, like
+    /// [Self::get_singular_batches], populating the parent hash of each resulting [SingleBatch]
+    /// from `parent_hashes`.
+    ///
+    /// `parent_hashes` must contain exactly one hash per resulting [SingleBatch], in order. The
+    /// first hash is the parent of the first [SingleBatch], usually the L2 safe head's hash.
+    pub fn get_singular_batches_with_parents(
+        &self,
+        l1_origins: &[BlockInfo],
+        l2_safe_head: L2BlockInfo,
+        parent_hashes: &[B256],
+    ) -> Result<Vec<SingleBatch>, SpanBatchError> {
+        let mut single_batches = self.get_singular_batches(l1_origins, l2_safe_head)?;
+        if single_batches.len() != parent_hashes.len() {
+            return Err(SpanBatchError::ParentHashCountMismatch {
+                expected: single_batches.len(),
+                actual: parent_hashes.len(),
+            });
+        }
+        for (batch, parent_hash) in single_batches.iter_mut().zip(parent_hashes) {
+            batch.parent_hash = *parent_hash;
+        }
+        Ok(single_batches)
+    }
+
     /// Append a [SingleBatch] to the [SpanBatch]. Updates the L1 origin check if need be.
     pub fn append_singular_batch(
         &mut self,
@@ -221,7 +246,7 @@ impl SpanBatch {
                     l1_origin.timestamp,
                     l1_origin.id()
                 );
-                return BatchValidity::Drop;
+                return BatchValidity::Drop(DropReason::TimestampBeforeL1Origin);
             }
 
             // Check if we ran out of sequencer time drift
@@ -245,7 +270,7 @@ impl SpanBatch {
                             info!(
                                 "batch exceeded sequencer time drift without adopting next origin, and next L1 origin would have been valid"
                             );
-                            return BatchValidity::Drop;
+                            return BatchValidity::Drop(DropReason::NextOriginNotAdopted);
                         } else {
                             info!(
                                 "continuing with empty batch before late L1 block to preserve L2 time invariant"
@@ -260,7 +285,7 @@ impl SpanBatch {
                         "batch exceeded sequencer time drift, sequencer must adopt new L1 origin to include transactions again, max_time: {}",
                         l1_origin.timestamp + max_drift
                     );
-                    return BatchValidity::Drop;
+                    return BatchValidity::Drop(DropReason::TimeDrift);
                 }
             }
 
@@ -271,14 +296,14 @@ impl SpanBatch {
                         "transaction data must not be empty, but found empty tx, tx_index: {}",
                         i
                     );
-                    return BatchValidity::Drop;
+                    return BatchValidity::Drop(DropReason::EmptyTransaction);
                 }
                 if tx.as_ref().first() == Some(&(OpTxType::Deposit as u8)) {
                     warn!(
                         "sequencers may not embed any deposits into batch data, but found tx that has one, tx_index: {}",
                         i
                     );
-                    return BatchValidity::Drop;
+                    return BatchValidity::Drop(DropReason::DepositInBatch);
                 }
 
                 // If isthmus is not active yet and the transaction is a 7702, drop the batch.
@@ -286,7 +311,7 @@ impl SpanBatch {
                     tx.as_ref().first() == Some(&(OpTxType::Eip7702 as u8))
                 {
                     warn!("EIP-7702 transactions are not supported pre-isthmus. tx_index: {}", i);
-                    return BatchValidity::Drop;
+                    return BatchValidity::Drop(DropReason::Eip7702PreIsthmus);
                 }
             }
         }
@@ -318,7 +343,7 @@ impl SpanBatch {
                         safe_block.transactions.len(),
                         batch_txs.len()
                     );
-                    return BatchValidity::Drop;
+                    return BatchValidity::Drop(DropReason::OverlappedTxCountMismatch);
                 }
                 let batch_txs_len = batch_txs.len();
                 #[allow(clippy::needless_range_loop)]
@@ -327,7 +352,7 @@ impl SpanBatch {
                     safe_block.transactions[j + deposit_count].encode_2718(&mut buf);
                     if buf != batch_txs[j].0 {
                         warn!("overlapped block's transaction does not match");
-                        return BatchValidity::Drop;
+                        return BatchValidity::Drop(DropReason::OverlappedTxMismatch);
                     }
                 }
                 let safe_block_ref = match L2BlockInfo::from_block_and_genesis(
@@ -340,7 +365,7 @@ impl SpanBatch {
                             "failed to extract L2BlockInfo from execution payload, hash: {}, err: {e}",
                             safe_block_payload.header.hash_slow()
                         );
-                        return BatchValidity::Drop;
+                        return BatchValidity::Drop(DropReason::OverlappedBlockInvalid);
                     }
                 };
                 if safe_block_ref.l1_origin.number != self.batches[i as usize].epoch_num {
@@ -348,8 +373,12 @@ impl SpanBatch {
                         "overlapped block's L1 origin number does not match {}, {}",
                         safe_block_ref.l1_origin.number, self.batches[i as usize].epoch_num
                     );
-                    return BatchValidity::Drop;
+                    return BatchValidity::Drop(DropReason::OverlappedOriginMismatch);
                 }
+                // Header fields derived by the execution layer, such as the Holocene EIP-1559
+                // parameters in `extra_data`, are not part of the batch and vary with the block
+                // timestamp. They are intentionally not compared, matching the reference
+                // implementation.
             }
         }
 
@@ -398,7 +427,7 @@ impl SpanBatch {
                 batch_origin.id(),
                 batch_origin.timestamp
             );
-            return (BatchValidity::Drop, None);
+            return (BatchValidity::Drop(DropReason::SpanBatchPreDelta), None);
         }
 
         if self.starting_timestamp() > next_timestamp {
@@ -410,7 +439,7 @@ impl SpanBatch {
 
             // After holocene is activated, gaps are disallowed.
             if cfg.is_holocene_active(inclusion_block.timestamp) {
-                return (BatchValidity::Drop, None);
+                return (BatchValidity::Drop(DropReason::FutureTimestamp), None);
             }
             return (BatchValidity::Future, None);
         }
@@ -421,7 +450,7 @@ impl SpanBatch {
             return if cfg.is_holocene_active(inclusion_block.timestamp) {
                 (BatchValidity::Past, None)
             } else {
-                (BatchValidity::Drop, None)
+                (BatchValidity::Drop(DropReason::NoNewBlocks), None)
             };
         }
 
@@ -434,12 +463,12 @@ impl SpanBatch {
             if self.starting_timestamp() > l2_safe_head.block_info.timestamp {
                 // Batch timestamp cannot be between safe head and next timestamp.
                 warn!("batch has misaligned timestamp, block time is too short");
-                return (BatchValidity::Drop, None);
+                return (BatchValidity::Drop(DropReason::MisalignedTimestamp), None);
             }
             if (l2_safe_head.block_info.timestamp - self.starting_timestamp()) % cfg.block_time != 0
             {
                 warn!("batch has misaligned timestamp, not overlapped exactly");
-                return (BatchValidity::Drop, None);
+                return (BatchValidity::Drop(DropReason::MisalignedTimestamp), None);
             }
             parent_num = l2_safe_head.block_info.number -
                 (l2_safe_head.block_info.timestamp - self.starting_timestamp()) / cfg.block_time -
@@ -458,13 +487,13 @@ impl SpanBatch {
                 "parent block mismatch, expected: {parent_num}, received: {}. parent hash: {}, parent hash check: {}",
                 parent_block.block_info.number, parent_block.block_info.hash, self.parent_check,
             );
-            return (BatchValidity::Drop, None);
+            return (BatchValidity::Drop(DropReason::ParentHashMismatch), None);
         }
 
         // Filter out batches that were included too late.
         if starting_epoch_num + cfg.seq_window_size < inclusion_block.number {
             warn!("batch was included too late, sequence window expired");
-            return (BatchValidity::Drop, None);
+            return (BatchValidity::Drop(DropReason::SeqWindowExpired), None);
         }
 
         // Check the L1 origin of the batch
@@ -474,7 +503,7 @@ impl SpanBatch {
                 starting_epoch_num,
                 parent_block.l1_origin.number + 1
             );
-            return (BatchValidity::Drop, None);
+            return (BatchValidity::Drop(DropReason::EpochTooFarAhead), None);
         }
 
         // Verify the l1 origin hash for each l1 block.
@@ -489,7 +518,7 @@ impl SpanBatch {
                         "batch is for different L1 chain, epoch hash does not match, expected: {}",
                         l1_block.hash
                     );
-                    return (BatchValidity::Drop, None);
+                    return (BatchValidity::Drop(DropReason::EpochHashMismatch), None);
                 }
                 origin_checked = true;
                 break;
@@ -502,7 +531,7 @@ impl SpanBatch {
 
         if starting_epoch_num < parent_block.l1_origin.number {
             warn!("dropped batch, epoch is too old, minimum: {:?}", parent_block.block_info.id());
-            return (BatchValidity::Drop, None);
+            return (BatchValidity::Drop(DropReason::EpochTooOld), None);
         }
 
         (BatchValidity::Accept, Some(parent_block))
@@ -677,6 +706,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_singular_batches_with_parents() {
+        let l1_blocks = vec![
+            BlockInfo { number: 10, hash: B256::from([10; 32]), ..Default::default() },
+            BlockInfo { number: 11, hash: B256::from([11; 32]), ..Default::default() },
+        ];
+        let block_info =
+            BlockInfo { timestamp: 10, hash: B256::from([1; 32]), ..Default::default() };
+        let l2_safe_head = L2BlockInfo { block_info, ..Default::default() };
+        let batch = SpanBatch {
+            batches: vec![
+                SpanBatchElement { epoch_num: 10, timestamp: 10, ..Default::default() },
+                SpanBatchElement { epoch_num: 10, timestamp: 12, ..Default::default() },
+                SpanBatchElement { epoch_num: 11, timestamp: 14, ..Default::default() },
+            ],
+            ..Default::default()
+        };
+        let parent_hashes = [l2_safe_head.block_info.hash, B256::from([2; 32])];
+
+        let expected = batch
+            .get_singular_batches(&l1_blocks, l2_safe_head)
+            .unwrap()
+            .into_iter()
+            .zip(parent_hashes)
+            .map(|(batch, parent_hash)| SingleBatch { parent_hash, ..batch })
+            .collect::<Vec<_>>();
+        let batches =
+            batch.get_singular_batches_with_parents(&l1_blocks, l2_safe_head, &parent_hashes);
+        assert_eq!(batches.unwrap(), expected);
+        assert_eq!(expected.len(), 2);
+        assert_eq!(expected[1].epoch_hash, l1_blocks[1].hash);
+    }
+
+    #[test]
+    fn test_singular_batches_with_parents_count_mismatch() {
+        let l1_blocks = vec![BlockInfo { number: 10, ..Default::default() }];
+        let batch = SpanBatch {
+            batches: vec![SpanBatchElement { epoch_num: 10, timestamp: 10, ..Default::default() }],
+            ..Default::default()
+        };
+        assert_eq!(
+            batch.get_singular_batches_with_parents(&l1_blocks, L2BlockInfo::default(), &[]),
+            Err(SpanBatchError::ParentHashCountMismatch { expected: 1, actual: 0 }),
+        );
+    }
+
     #[tokio::test]
     async fn test_eager_block_missing_origins() {
         let trace_store: TraceStorage = Default::default();
@@ -723,7 +798,7 @@ mod tests {
         let batch = SpanBatch { batches: vec![first], ..Default::default() };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::SpanBatchPreDelta)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -790,7 +865,7 @@ mod tests {
         let batch = SpanBatch { batches: vec![first], ..Default::default() };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::NoNewBlocks)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -857,7 +932,7 @@ mod tests {
         let batch = SpanBatch { batches: vec![first, second], ..Default::default() };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::OverlappedTxCountMismatch)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -941,7 +1016,7 @@ mod tests {
         let batch = SpanBatch { batches: vec![first, second], ..Default::default() };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::OverlappedTxMismatch)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -974,7 +1049,7 @@ mod tests {
         let batch = SpanBatch { batches: vec![first, second, third], ..Default::default() };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::TimestampBeforeL1Origin)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1009,7 +1084,7 @@ mod tests {
         let batch = SpanBatch { batches: vec![first, second], ..Default::default() };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::MisalignedTimestamp)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1040,7 +1115,7 @@ mod tests {
         let batch = SpanBatch { batches: vec![first, second], ..Default::default() };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::MisalignedTimestamp)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1117,7 +1192,7 @@ mod tests {
         // parent number = 41 - (10 - 10) / 10 - 1 = 40
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::ParentHashMismatch)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1164,7 +1239,7 @@ mod tests {
         // parent number = 41 - (10 - 10) / 10 - 1 = 40
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::SeqWindowExpired)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1214,7 +1289,7 @@ mod tests {
         // parent number = 41 - (10 - 10) / 10 - 1 = 40
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::EpochTooFarAhead)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1272,7 +1347,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::EpochHashMismatch)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1382,7 +1457,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::EpochTooOld)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1442,7 +1517,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::NextOriginNotAdopted)
         );
         let logs = trace_store.get_by_level(Level::INFO);
         assert_eq!(logs.len(), 1);
@@ -1570,7 +1645,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::TimeDrift)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1635,7 +1710,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::EmptyTransaction)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1702,7 +1777,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::DepositInBatch)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1769,7 +1844,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::Eip7702PreIsthmus)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1888,7 +1963,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::OverlappedBlockInvalid)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -1962,7 +2037,7 @@ mod tests {
         };
         assert_eq!(
             batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
-            BatchValidity::Drop
+            BatchValidity::Drop(DropReason::OverlappedOriginMismatch)
         );
         let logs = trace_store.get_by_level(Level::WARN);
         assert_eq!(logs.len(), 1);
@@ -2042,4 +2117,91 @@ mod tests {
         );
         assert!(trace_store.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_check_batch_overlapped_straddling_holocene() {
+        let trace_store: TraceStorage = Default::default();
+        let layer = CollectingLayer::new(trace_store.clone());
+        tracing_subscriber::Registry::default().with(layer).init();
+
+        let payload_block_hash =
+            b256!("0e2ee9abe94ee4514b170d7039d8151a7469d434a8575dbab5bd4187a27732dd");
+        let cfg = RollupConfig {
+            seq_window_size: 100,
+            hardforks: HardForkConfig {
+                delta_time: Some(0),
+                holocene_time: Some(15),
+                ..Default::default()
+            },
+            block_time: 10,
+            genesis: ChainGenesis {
+                l2: BlockNumHash { number: 41, hash: payload_block_hash },
+                l1: BlockNumHash { number: 10, ..Default::default() },
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let l1_block_hash =
+            b256!("3333333333333333333333333333333333333333000000000000000000000000");
+        let block =
+            BlockInfo { number: 11, timestamp: 10, hash: l1_block_hash, ..Default::default() };
+        let l1_blocks = vec![block];
+        let parent_hash = b256!("1111111111111111111111111111111111111111000000000000000000000000");
+        let l2_safe_head = L2BlockInfo {
+            block_info: BlockInfo {
+                number: 41,
+                timestamp: 10,
+                hash: parent_hash,
+                ..Default::default()
+            },
+            l1_origin: BlockNumHash { number: 9, ..Default::default() },
+            ..Default::default()
+        };
+        let inclusion_block = BlockInfo { number: 50, ..Default::default() };
+        let l2_block = L2BlockInfo {
+            block_info: BlockInfo {
+                number: 40,
+                hash: parent_hash,
+                timestamp: 10,
+                ..Default::default()
+            },
+            l1_origin: BlockNumHash { number: 9, ..Default::default() },
+            ..Default::default()
+        };
+        // The overlapped block was built before Holocene, so its header carries no EIP-1559
+        // parameters, while the rest of the span is built after activation.
+        let block = OpBlock {
+            header: Header {
+                number: 41,
+                timestamp: 10,
+                extra_data: Default::default(),
+                ..Default::default()
+            },
+            body: alloy_consensus::BlockBody {
+                transactions: Vec::new(),
+                ommers: Vec::new(),
+                withdrawals: None,
+            },
+        };
+        let mut fetcher: TestBatchValidator = TestBatchValidator {
+            blocks: vec![l2_block],
+            op_blocks: vec![block],
+            ..Default::default()
+        };
+        let first = SpanBatchElement { epoch_num: 10, timestamp: 10, ..Default::default() };
+        let second = SpanBatchElement { epoch_num: 11, timestamp: 20, ..Default::default() };
+        let batch = SpanBatch {
+            batches: vec![first, second],
+            parent_check: FixedBytes::<20>::from_slice(&parent_hash[..20]),
+            l1_origin_check: FixedBytes::<20>::from_slice(&l1_block_hash[..20]),
+            ..Default::default()
+        };
+        assert!(!cfg.is_holocene_active(batch.starting_timestamp()));
+        assert!(cfg.is_holocene_active(batch.final_timestamp()));
+        assert_eq!(
+            batch.check_batch(&cfg, &l1_blocks, l2_safe_head, &inclusion_block, &mut fetcher).await,
+            BatchValidity::Accept
+        );
+        assert!(trace_store.is_empty());
+    }
 }
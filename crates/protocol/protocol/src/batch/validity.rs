@@ -1,11 +1,13 @@
 //! Contains the [BatchValidity] and its encodings.
 
+use derive_more::Display;
+
 /// Batch Validity
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BatchValidity {
     /// The batch is invalid now and in the future, unless we reorg, so it can be discarded.
-    Drop,
+    Drop(DropReason),
     /// The batch is valid and should be processed
     Accept,
     /// We are lacking L1 information until we can proceed batch filtering
@@ -25,7 +27,15 @@ impl BatchValidity {
 
     /// Returns whether the batch is dropped.
     pub const fn is_drop(&self) -> bool {
-        matches!(self, Self::Drop)
+        matches!(self, Self::Drop(_))
+    }
+
+    /// Returns the [DropReason] if the batch is dropped.
+    pub const fn drop_reason(&self) -> Option<DropReason> {
+        match self {
+            Self::Drop(reason) => Some(*reason),
+            _ => None,
+        }
     }
 
     /// Returns whether the batch is outdated.
@@ -39,15 +49,94 @@ impl BatchValidity {
     }
 }
 
+/// The reason a batch was dropped with [BatchValidity::Drop].
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DropReason {
+    /// The batch timestamp is ahead of the next expected timestamp, after Holocene.
+    #[display("future timestamp")]
+    FutureTimestamp,
+    /// The batch timestamp is behind the next expected timestamp, before Holocene.
+    #[display("past timestamp")]
+    PastTimestamp,
+    /// The span batch has no new blocks after the safe head, before Holocene.
+    #[display("no new blocks after safe head")]
+    NoNewBlocks,
+    /// The span batch timestamp is not aligned with the L2 block time.
+    #[display("misaligned timestamp")]
+    MisalignedTimestamp,
+    /// The span batch was included before the Delta hardfork.
+    #[display("span batch before delta")]
+    SpanBatchPreDelta,
+    /// The batch does not build on top of the expected parent block.
+    #[display("parent hash mismatch")]
+    ParentHashMismatch,
+    /// The batch was included after the sequencing window of its epoch expired.
+    #[display("sequencing window expired")]
+    SeqWindowExpired,
+    /// The batch epoch is older than the epoch of its parent block.
+    #[display("epoch too old")]
+    EpochTooOld,
+    /// The batch epoch is more than one epoch ahead of its parent block.
+    #[display("epoch too far ahead")]
+    EpochTooFarAhead,
+    /// The batch epoch hash does not match the L1 origin.
+    #[display("epoch hash mismatch")]
+    EpochHashMismatch,
+    /// The batch timestamp is less than the timestamp of its L1 origin.
+    #[display("timestamp before L1 origin")]
+    TimestampBeforeL1Origin,
+    /// The maximum sequencer drift overflows the L1 origin timestamp.
+    #[display("sequencer drift overflow")]
+    TimeDriftOverflow,
+    /// The batch contains transactions but exceeds the maximum sequencer drift.
+    #[display("sequencer time drift exceeded")]
+    TimeDrift,
+    /// The empty batch exceeds the maximum sequencer drift, while the next L1 origin could have
+    /// been adopted.
+    #[display("next L1 origin not adopted")]
+    NextOriginNotAdopted,
+    /// The batch contains an empty transaction.
+    #[display("empty transaction")]
+    EmptyTransaction,
+    /// The batch contains a deposit transaction.
+    #[display("deposit in batch")]
+    DepositInBatch,
+    /// The batch contains an EIP-7702 transaction before Isthmus.
+    #[display("EIP-7702 transaction before isthmus")]
+    Eip7702PreIsthmus,
+    /// The transaction count of an overlapped block does not match the safe chain.
+    #[display("overlapped block transaction count mismatch")]
+    OverlappedTxCountMismatch,
+    /// A transaction of an overlapped block does not match the safe chain.
+    #[display("overlapped block transaction mismatch")]
+    OverlappedTxMismatch,
+    /// The [crate::L2BlockInfo] of an overlapped block could not be derived.
+    #[display("invalid overlapped block")]
+    OverlappedBlockInvalid,
+    /// The L1 origin of an overlapped block does not match the safe chain.
+    #[display("overlapped block L1 origin mismatch")]
+    OverlappedOriginMismatch,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alloc::string::ToString;
 
     #[test]
     fn test_batch_validity() {
         assert!(BatchValidity::Accept.is_accept());
-        assert!(BatchValidity::Drop.is_drop());
+        assert!(BatchValidity::Drop(DropReason::DepositInBatch).is_drop());
         assert!(BatchValidity::Past.is_outdated());
         assert!(BatchValidity::Future.is_future());
     }
+
+    #[test]
+    fn test_drop_reason() {
+        let validity = BatchValidity::Drop(DropReason::SeqWindowExpired);
+        assert_eq!(validity.drop_reason(), Some(DropReason::SeqWindowExpired));
+        assert_eq!(BatchValidity::Accept.drop_reason(), None);
+        assert_eq!(DropReason::SeqWindowExpired.to_string(), "sequencing window expired");
+    }
 }
@@ -2,7 +2,10 @@
 
 use crate::{DecodeError, L1BlockInfoTx};
 use alloy_consensus::{Block, Transaction, Typed2718};
-use alloy_eips::{BlockNumHash, eip2718::Eip2718Error};
+use alloy_eips::{
+    BlockNumHash,
+    eip2718::{Decodable2718, Eip2718Error},
+};
 use alloy_primitives::B256;
 use alloy_rpc_types_engine::ExecutionPayload;
 use derive_more::Display;
@@ -226,7 +229,12 @@ impl L2BlockInfo {
                         return Err(FromBlockError::FirstTxNonDeposit(transactions[0][0]));
                     }
 
-                    let l1_info = L1BlockInfoTx::decode_calldata(tx)
+                    let tx = OpTxEnvelope::decode_2718(&mut tx.as_ref())
+                        .map_err(FromBlockError::TxEnvelopeDecodeError)?;
+                    let Some(tx) = tx.as_deposit() else {
+                        return Err(FromBlockError::FirstTxNonDeposit(tx.ty()));
+                    };
+                    let l1_info = L1BlockInfoTx::decode_calldata(tx.input().as_ref())
                         .map_err(FromBlockError::BlockInfoDecodeError)?;
                     (l1_info.id(), l1_info.sequence_number())
                 }
@@ -339,6 +347,57 @@ mod tests {
         assert_eq!(derived, expected);
     }
 
+    #[test]
+    fn test_from_payload_and_genesis() {
+        use crate::test_utils::RAW_BEDROCK_INFO_TX;
+        use alloy_eips::eip2718::Encodable2718;
+        use alloy_primitives::{Address, Bloom, Bytes, U256};
+        use alloy_rpc_types_engine::ExecutionPayloadV1;
+
+        let genesis = ChainGenesis {
+            l1: BlockNumHash { hash: B256::from([4; 32]), number: 2 },
+            l2: BlockNumHash { hash: B256::from([5; 32]), number: 1 },
+            ..Default::default()
+        };
+        let deposit =
+            OpTxEnvelope::Deposit(alloy_primitives::Sealed::new(op_alloy_consensus::TxDeposit {
+                input: Bytes::from(&RAW_BEDROCK_INFO_TX),
+                ..Default::default()
+            }));
+        let payload = ExecutionPayload::V1(ExecutionPayloadV1 {
+            parent_hash: B256::from([2; 32]),
+            fee_recipient: Address::ZERO,
+            state_root: B256::ZERO,
+            receipts_root: B256::ZERO,
+            logs_bloom: Bloom::ZERO,
+            prev_randao: B256::ZERO,
+            block_number: 3,
+            gas_limit: 0,
+            gas_used: 0,
+            timestamp: 1,
+            extra_data: Bytes::new(),
+            base_fee_per_gas: U256::ZERO,
+            block_hash: B256::from([3; 32]),
+            transactions: vec![deposit.encoded_2718().into()],
+        });
+
+        let expected = L2BlockInfo {
+            block_info: BlockInfo {
+                hash: B256::from([3; 32]),
+                number: 3,
+                parent_hash: B256::from([2; 32]),
+                timestamp: 1,
+            },
+            l1_origin: BlockNumHash {
+                hash: b256!("392012032675be9f94aae5ab442de73c5f4fb1bf30fa7dd0d2442239899a40fc"),
+                number: 18334955,
+            },
+            seq_num: 4,
+        };
+        let derived = L2BlockInfo::from_payload_and_genesis(&payload, &genesis).unwrap();
+        assert_eq!(derived, expected);
+    }
+
     #[test]
     fn test_from_block_error_partial_eq() {
         assert_eq!(FromBlockError::InvalidGenesisHash, FromBlockError::InvalidGenesisHash);
@@ -104,8 +104,8 @@ impl L1BlockInfoTx {
         if rollup_config.is_isthmus_active(l2_block_time) &&
             rollup_config.hardforks.isthmus_time.unwrap_or_default() != l2_block_time
         {
-            let operator_fee_scalar = system_config.operator_fee_scalar.unwrap_or_default();
-            let operator_fee_constant = system_config.operator_fee_constant.unwrap_or_default();
+            let (operator_fee_scalar, operator_fee_constant) =
+                system_config.operator_fee_params(rollup_config, l2_block_time).unwrap_or_default();
             return Ok(Self::Isthmus(L1BlockInfoIsthmus {
                 number: l1_header.number,
                 time: l1_header.timestamp,
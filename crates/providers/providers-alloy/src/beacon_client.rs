@@ -1,13 +1,17 @@
 //! Contains an online implementation of the `BeaconClient` trait.
 
 use alloy_eips::eip4844::IndexedBlobHash;
+use alloy_primitives::B256;
 use alloy_rpc_types_beacon::sidecar::{BeaconBlobBundle, BlobData};
 use async_trait::async_trait;
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use std::{
     boxed::Box,
     format,
+    future::Future,
     string::{String, ToString},
+    time::Duration,
     vec::Vec,
 };
 
@@ -88,6 +92,18 @@ pub trait BeaconClient {
     ) -> Result<Vec<BlobData>, Self::Error>;
 }
 
+/// An extension of the [BeaconClient] for clients that can look up blob sidecars by the root of
+/// the beacon block that confirmed them, rather than by its slot.
+#[async_trait]
+pub trait BeaconClientExt: BeaconClient {
+    /// Fetches all blob sidecars that were confirmed in the beacon block with the given block
+    /// root. Blob data is not checked for validity.
+    async fn beacon_blob_side_cars_by_block_root(
+        &self,
+        root: B256,
+    ) -> Result<Vec<BlobData>, Self::Error>;
+}
+
 /// An online implementation of the [BeaconClient] trait.
 #[derive(Debug, Clone)]
 pub struct OnlineBeaconClient {
@@ -95,6 +111,10 @@ pub struct OnlineBeaconClient {
     pub base: String,
     /// The inner reqwest client.
     pub inner: Client,
+    /// The maximum number of times a failed request is retried.
+    pub max_retries: usize,
+    /// The delay before the first retry, doubled on every subsequent retry.
+    pub backoff: Duration,
 }
 
 impl OnlineBeaconClient {
@@ -104,7 +124,56 @@ impl OnlineBeaconClient {
         if base.ends_with("/") {
             base.remove(base.len() - 1);
         }
-        Self { base, inner: Client::new() }
+        Self { base, inner: Client::new(), max_retries: 0, backoff: Duration::ZERO }
+    }
+
+    /// Retries failed requests up to `max_retries` times, with an exponential backoff starting at
+    /// `backoff`. Requests rejected with a 4xx status or failing to decode are not retried.
+    pub const fn with_retry(mut self, max_retries: usize, backoff: Duration) -> Self {
+        self.max_retries = max_retries;
+        self.backoff = backoff;
+        self
+    }
+
+    /// Sends a GET request for the given beacon API method, decoding the JSON response.
+    async fn get<T: DeserializeOwned>(&self, method: &str) -> Result<T, reqwest::Error> {
+        self.inner
+            .get(format!("{}/{}", self.base, method))
+            .send()
+            .await?
+            .error_for_status()?
+            .json::<T>()
+            .await
+    }
+
+    /// Runs the given request, retrying it according to the client's retry policy.
+    async fn retry<T, F, Fut>(&self, method: &str, request: F) -> Result<T, reqwest::Error>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, reqwest::Error>>,
+    {
+        let mut attempt = 0;
+        loop {
+            match request().await {
+                Err(e) if attempt < self.max_retries && Self::is_retryable(&e) => {
+                    let delay = self.backoff.saturating_mul(2u32.saturating_pow(attempt as u32));
+                    attempt += 1;
+                    warn!(
+                        target: "beacon_client",
+                        "Retrying beacon API request {method} ({attempt}/{}) in {delay:?}: {e}",
+                        self.max_retries
+                    );
+                    tokio::time::sleep(delay).await;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Returns whether a failed request may succeed if retried. Requests rejected with a 4xx
+    /// status and responses that fail to decode are deterministic, and are not retried.
+    fn is_retryable(error: &reqwest::Error) -> bool {
+        !error.is_decode() && !error.status().is_some_and(|status| status.is_client_error())
     }
 }
 
@@ -113,13 +182,11 @@ impl BeaconClient for OnlineBeaconClient {
     type Error = reqwest::Error;
 
     async fn config_spec(&self) -> Result<APIConfigResponse, Self::Error> {
-        let first = self.inner.get(format!("{}/{}", self.base, SPEC_METHOD)).send().await?;
-        first.json::<APIConfigResponse>().await
+        self.retry(SPEC_METHOD, || self.get(SPEC_METHOD)).await
     }
 
     async fn beacon_genesis(&self) -> Result<APIGenesisResponse, Self::Error> {
-        let first = self.inner.get(format!("{}/{}", self.base, GENESIS_METHOD)).send().await?;
-        first.json::<APIGenesisResponse>().await
+        self.retry(GENESIS_METHOD, || self.get(GENESIS_METHOD)).await
     }
 
     async fn beacon_blob_side_cars(
@@ -127,12 +194,8 @@ impl BeaconClient for OnlineBeaconClient {
         slot: u64,
         hashes: &[IndexedBlobHash],
     ) -> Result<Vec<BlobData>, Self::Error> {
-        let raw_response = self
-            .inner
-            .get(format!("{}/{}/{}", self.base, SIDECARS_METHOD_PREFIX, slot))
-            .send()
-            .await?;
-        let raw_response = raw_response.json::<BeaconBlobBundle>().await?;
+        let method = format!("{SIDECARS_METHOD_PREFIX}/{slot}");
+        let raw_response: BeaconBlobBundle = self.retry(&method, || self.get(&method)).await?;
 
         // Filter the sidecars by the hashes, in-order.
         let mut sidecars = Vec::with_capacity(hashes.len());
@@ -147,3 +210,166 @@ impl BeaconClient for OnlineBeaconClient {
         Ok(sidecars)
     }
 }
+
+#[async_trait]
+impl BeaconClientExt for OnlineBeaconClient {
+    async fn beacon_blob_side_cars_by_block_root(
+        &self,
+        root: B256,
+    ) -> Result<Vec<BlobData>, Self::Error> {
+        let method = format!("{SIDECARS_METHOD_PREFIX}/{root}");
+        let raw_response: BeaconBlobBundle = self.retry(&method, || self.get(&method)).await?;
+        Ok(raw_response.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::eip4844::{BYTES_PER_BLOB, Bytes48};
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    /// Spawns a mock beacon API that responds with the given statuses in order, followed by a
+    /// successful config spec response. Returns the base URL and the number of requests served.
+    async fn mock_server(failures: &'static [&'static str]) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+
+        let served = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 1024];
+                let _ = stream.read(&mut buf).await.unwrap();
+
+                let response = match failures.get(served.fetch_add(1, Ordering::SeqCst)) {
+                    Some(status) => format!(
+                        "HTTP/1.1 {status}\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                    ),
+                    None => {
+                        let body = r#"{"data":{"SECONDS_PER_SLOT":"0xc"}}"#;
+                        format!(
+                            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                             content-length: {}\r\nconnection: close\r\n\r\n{body}",
+                            body.len()
+                        )
+                    }
+                };
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        (base, requests)
+    }
+
+    /// Spawns a mock beacon API that serves a single blob sidecar for the beacon block with the
+    /// given root, and responds with `404 Not Found` to every other request.
+    async fn mock_sidecars_server(root: B256) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let path = format!("/{SIDECARS_METHOD_PREFIX}/{root}");
+
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut buf = [0u8; 1024];
+                let n = stream.read(&mut buf).await.unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]);
+
+                let response = if request.starts_with(&format!("GET {path} ")) {
+                    let body = serde_json::json!({
+                        "data": [{
+                            "index": "1",
+                            "blob": format!("0x{}", "00".repeat(BYTES_PER_BLOB)),
+                            "kzg_commitment": Bytes48::ZERO,
+                            "kzg_proof": Bytes48::ZERO,
+                            "signed_block_header": {
+                                "message": {
+                                    "slot": "12",
+                                    "proposer_index": "0",
+                                    "parent_root": B256::ZERO,
+                                    "state_root": B256::ZERO,
+                                    "body_root": B256::ZERO,
+                                },
+                                "signature": format!("0x{}", "00".repeat(96)),
+                            },
+                            "kzg_commitment_inclusion_proof": [],
+                        }]
+                    })
+                    .to_string();
+                    format!(
+                        "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\n\
+                         content-length: {}\r\nconnection: close\r\n\r\n{body}",
+                        body.len()
+                    )
+                } else {
+                    "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n"
+                        .to_string()
+                };
+                stream.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+
+        base
+    }
+
+    #[tokio::test]
+    async fn test_blob_side_cars_by_block_root() {
+        let root = B256::repeat_byte(0xaa);
+        let client = OnlineBeaconClient::new_http(mock_sidecars_server(root).await);
+
+        let sidecars = client.beacon_blob_side_cars_by_block_root(root).await.unwrap();
+        assert_eq!(sidecars.len(), 1);
+        assert_eq!(sidecars[0].index, 1);
+        assert_eq!(sidecars[0].signed_block_header.message.slot, 12);
+
+        let err = client.beacon_blob_side_cars_by_block_root(B256::ZERO).await.unwrap_err();
+        assert_eq!(err.status(), Some(reqwest::StatusCode::NOT_FOUND));
+    }
+
+    #[tokio::test]
+    async fn test_retry_transient_failures() {
+        let (base, requests) = mock_server(&["502 Bad Gateway", "502 Bad Gateway"]).await;
+        let client = OnlineBeaconClient::new_http(base).with_retry(2, Duration::from_millis(1));
+
+        assert_eq!(client.config_spec().await.unwrap(), APIConfigResponse::new(12));
+        assert_eq!(requests.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_retry_exhausted() {
+        let (base, requests) = mock_server(&["502 Bad Gateway", "502 Bad Gateway"]).await;
+        let client = OnlineBeaconClient::new_http(base).with_retry(1, Duration::from_millis(1));
+
+        assert!(client.config_spec().await.is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_client_error() {
+        let (base, requests) = mock_server(&["400 Bad Request"]).await;
+        let client = OnlineBeaconClient::new_http(base).with_retry(2, Duration::from_millis(1));
+
+        let err = client.config_spec().await.unwrap_err();
+        assert_eq!(err.status(), Some(reqwest::StatusCode::BAD_REQUEST));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_no_retry_on_decode_error() {
+        // An empty body is not a valid config spec response.
+        let (base, requests) = mock_server(&["200 OK"]).await;
+        let client = OnlineBeaconClient::new_http(base).with_retry(2, Duration::from_millis(1));
+
+        assert!(client.config_spec().await.unwrap_err().is_decode());
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+}
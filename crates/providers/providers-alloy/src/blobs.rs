@@ -1,12 +1,14 @@
 //! Contains an online implementation of the `BlobProvider` trait.
 
-use crate::BeaconClient;
+use crate::{BeaconClient, BeaconClientExt};
 use alloy_eips::eip4844::{Blob, BlobTransactionSidecarItem, IndexedBlobHash};
+use alloy_primitives::B256;
 use alloy_rpc_types_beacon::sidecar::BlobData;
 use async_trait::async_trait;
 use kona_derive::{errors::BlobProviderError, traits::BlobProvider};
 use kona_protocol::BlockInfo;
-use std::{boxed::Box, string::ToString, vec::Vec};
+use lru::LruCache;
+use std::{boxed::Box, num::NonZeroUsize, string::ToString, vec::Vec};
 
 /// An online implementation of the [BlobProvider] trait.
 #[derive(Debug, Clone)]
@@ -17,18 +19,30 @@ pub struct OnlineBlobProvider<B: BeaconClient> {
     pub genesis_time: u64,
     /// Slot interval used for the time to slot conversion.
     pub slot_interval: u64,
+    /// Cache of verified blobs, keyed by their slot and versioned hash.
+    blob_cache: LruCache<(u64, B256), Box<Blob>>,
 }
 
 impl<B: BeaconClient> OnlineBlobProvider<B> {
-    /// Creates a new instance of the [OnlineBlobProvider].
+    /// Creates a new [OnlineBlobProvider] with the given genesis time and slot interval, caching
+    /// up to `cache_size` verified blobs.
+    pub fn new(
+        beacon_client: B,
+        genesis_time: u64,
+        slot_interval: u64,
+        cache_size: NonZeroUsize,
+    ) -> Self {
+        Self { beacon_client, genesis_time, slot_interval, blob_cache: LruCache::new(cache_size) }
+    }
+
+    /// Creates a new instance of the [OnlineBlobProvider], caching up to `cache_size` verified
+    /// blobs.
     ///
-    /// The `genesis_time` and `slot_interval` arguments are _optional_ and the
-    /// [OnlineBlobProvider] will attempt to load them dynamically at runtime if they are not
-    /// provided.
+    /// The genesis time and slot interval are loaded dynamically from the beacon client.
     ///
     /// ## Panics
     /// Panics if the genesis time or slot interval cannot be loaded from the beacon client.
-    pub async fn init(beacon_client: B) -> Self {
+    pub async fn init(beacon_client: B, cache_size: NonZeroUsize) -> Self {
         let genesis_time = beacon_client
             .beacon_genesis()
             .await
@@ -41,7 +55,7 @@ impl<B: BeaconClient> OnlineBlobProvider<B> {
             .map(|r| r.data.seconds_per_slot)
             .map_err(|e| BlobProviderError::Backend(e.to_string()))
             .expect("Failed to load slot interval from beacon client");
-        Self { beacon_client, genesis_time, slot_interval }
+        Self::new(beacon_client, genesis_time, slot_interval, cache_size)
     }
 
     /// Fetches blob sidecars for the given slot and blob hashes.
@@ -108,6 +122,21 @@ impl<B: BeaconClient> OnlineBlobProvider<B> {
     }
 }
 
+impl<B: BeaconClientExt> OnlineBlobProvider<B> {
+    /// Fetches all blob sidecars for the beacon block with the given block root, without the slot
+    /// lookup required by [OnlineBlobProvider::fetch_sidecars]. Blob data is not checked for
+    /// validity.
+    pub async fn blob_sidecars_by_block_root(
+        &self,
+        root: B256,
+    ) -> Result<Vec<BlobData>, BlobProviderError> {
+        self.beacon_client
+            .beacon_blob_side_cars_by_block_root(root)
+            .await
+            .map_err(|e| BlobProviderError::Backend(e.to_string()))
+    }
+}
+
 #[async_trait]
 impl<B> BlobProvider for OnlineBlobProvider<B>
 where
@@ -118,30 +147,59 @@ where
     /// Fetches blob sidecars that were confirmed in the specified L1 block with the given indexed
     /// hashes. The blobs are validated for their index and hashes using the specified
     /// [IndexedBlobHash].
+    ///
+    /// Verified blobs are cached by slot and versioned hash, and only the blobs missing from the
+    /// cache are fetched from the beacon node.
     async fn get_blobs(
         &mut self,
         block_ref: &BlockInfo,
         blob_hashes: &[IndexedBlobHash],
     ) -> Result<Vec<Box<Blob>>, Self::Error> {
-        // Fetch the blob sidecars for the given block reference and blob hashes.
-        let sidecars = self.fetch_filtered_sidecars(block_ref, blob_hashes).await?;
+        if blob_hashes.is_empty() {
+            return Ok(Vec::new());
+        }
+        let slot = Self::slot(self.genesis_time, self.slot_interval, block_ref.timestamp)?;
 
-        // Validate the blob sidecars straight away with the num hashes.
-        let blobs = sidecars
-            .into_iter()
-            .enumerate()
-            .map(|(i, sidecar)| {
-                let hash = blob_hashes
-                    .get(i)
-                    .ok_or(BlobProviderError::Backend("Missing blob hash".to_string()))?;
+        // Serve as many blobs as possible from the cache.
+        let mut blobs = blob_hashes
+            .iter()
+            .map(|hash| self.blob_cache.get(&(slot, hash.hash)).cloned())
+            .collect::<Vec<_>>();
+        let missing = blob_hashes
+            .iter()
+            .zip(blobs.iter())
+            .filter(|(_, blob)| blob.is_none())
+            .map(|(hash, _)| IndexedBlobHash { hash: hash.hash, index: hash.index })
+            .collect::<Vec<_>>();
+        if missing.is_empty() {
+            return Ok(blobs.into_iter().flatten().collect());
+        }
+
+        // Fetch the missing blob sidecars for the given block reference.
+        let sidecars = self.fetch_filtered_sidecars(block_ref, &missing).await?;
+
+        // Validate the blob sidecars straight away with the num hashes. Blobs are only cached
+        // once they have been verified.
+        let mut fetched = missing
+            .iter()
+            .zip(sidecars)
+            .map(|(hash, sidecar)| {
                 sidecar
-                    .verify_blob(&IndexedBlobHash { hash: hash.hash, index: hash.index })
-                    .map(|_| sidecar.blob)
+                    .verify_blob(hash)
+                    .map(|_| (hash.hash, sidecar.blob))
                     .map_err(|e| BlobProviderError::Backend(e.to_string()))
             })
-            .collect::<Result<Vec<Box<Blob>>, BlobProviderError>>()
-            .map_err(|e| BlobProviderError::Backend(e.to_string()))?;
-        Ok(blobs)
+            .collect::<Result<Vec<_>, BlobProviderError>>()?
+            .into_iter();
+        for blob in blobs.iter_mut().filter(|blob| blob.is_none()) {
+            let (hash, fetched_blob) = fetched
+                .next()
+                .ok_or(BlobProviderError::Backend("Missing blob hash".to_string()))?;
+            self.blob_cache.put((slot, hash), fetched_blob.clone());
+            *blob = Some(fetched_blob);
+        }
+
+        Ok(blobs.into_iter().flatten().collect())
     }
 }
 
@@ -174,3 +232,115 @@ impl<B: BeaconClient + Send + Sync> BlobSidecarProvider for B {
             .map_err(|e| BlobProviderError::Backend(e.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{APIConfigResponse, APIGenesisResponse};
+    use alloy_eips::eip4844::{BYTES_PER_BLOB, Bytes48, kzg_to_versioned_hash};
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    /// Returns the KZG commitment and proof of the zero blob, i.e. the compressed point at
+    /// infinity.
+    fn point_at_infinity() -> Bytes48 {
+        let mut point = Bytes48::ZERO;
+        point[0] = 0xc0;
+        point
+    }
+
+    /// A [BeaconClient] that serves the zero blob and counts the sidecar requests it receives.
+    #[derive(Debug, Default)]
+    struct MockBeaconClient {
+        requests: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BeaconClient for MockBeaconClient {
+        type Error = String;
+
+        async fn config_spec(&self) -> Result<APIConfigResponse, Self::Error> {
+            Err("unimplemented".to_string())
+        }
+
+        async fn beacon_genesis(&self) -> Result<APIGenesisResponse, Self::Error> {
+            Err("unimplemented".to_string())
+        }
+
+        async fn beacon_blob_side_cars(
+            &self,
+            slot: u64,
+            hashes: &[IndexedBlobHash],
+        ) -> Result<Vec<BlobData>, Self::Error> {
+            self.requests.fetch_add(1, Ordering::SeqCst);
+            hashes
+                .iter()
+                .map(|hash| {
+                    serde_json::from_value(serde_json::json!({
+                        "index": hash.index.to_string(),
+                        "blob": format!("0x{}", "00".repeat(BYTES_PER_BLOB)),
+                        "kzg_commitment": point_at_infinity(),
+                        "kzg_proof": point_at_infinity(),
+                        "signed_block_header": {
+                            "message": {
+                                "slot": slot.to_string(),
+                                "proposer_index": "0",
+                                "parent_root": B256::ZERO,
+                                "state_root": B256::ZERO,
+                                "body_root": B256::ZERO,
+                            },
+                            "signature": format!("0x{}", "00".repeat(96)),
+                        },
+                        "kzg_commitment_inclusion_proof": [],
+                    }))
+                    .map_err(|e| e.to_string())
+                })
+                .collect()
+        }
+    }
+
+    fn zero_blob_hash(index: u64) -> IndexedBlobHash {
+        IndexedBlobHash { index, hash: kzg_to_versioned_hash(point_at_infinity().as_slice()) }
+    }
+
+    #[tokio::test]
+    async fn test_get_blobs_caches_verified_blobs() {
+        let mut provider = OnlineBlobProvider::new(
+            MockBeaconClient::default(),
+            0,
+            12,
+            NonZeroUsize::new(16).unwrap(),
+        );
+        let block_ref = BlockInfo { timestamp: 24, ..Default::default() };
+        let hashes = [zero_blob_hash(0)];
+
+        let blobs = provider.get_blobs(&block_ref, &hashes).await.unwrap();
+        assert_eq!(blobs, vec![Box::new(Blob::ZERO)]);
+        assert_eq!(provider.beacon_client.requests.load(Ordering::SeqCst), 1);
+
+        // The second fetch of the same blob is served from the cache.
+        let blobs = provider.get_blobs(&block_ref, &hashes).await.unwrap();
+        assert_eq!(blobs, vec![Box::new(Blob::ZERO)]);
+        assert_eq!(provider.beacon_client.requests.load(Ordering::SeqCst), 1);
+
+        // The same blob in a different slot is not cached.
+        let block_ref = BlockInfo { timestamp: 36, ..Default::default() };
+        provider.get_blobs(&block_ref, &hashes).await.unwrap();
+        assert_eq!(provider.beacon_client.requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_blobs_does_not_cache_unverified_blobs() {
+        let mut provider = OnlineBlobProvider::new(
+            MockBeaconClient::default(),
+            0,
+            12,
+            NonZeroUsize::new(16).unwrap(),
+        );
+        let block_ref = BlockInfo { timestamp: 24, ..Default::default() };
+        let hashes = [IndexedBlobHash { index: 0, hash: B256::ZERO }];
+
+        assert!(provider.get_blobs(&block_ref, &hashes).await.is_err());
+        assert!(provider.get_blobs(&block_ref, &hashes).await.is_err());
+        assert_eq!(provider.beacon_client.requests.load(Ordering::SeqCst), 2);
+    }
+}
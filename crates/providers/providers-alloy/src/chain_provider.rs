@@ -1,8 +1,11 @@
 //! Providers that use alloy provider types on the backend.
 
-use alloy_consensus::{Header, Receipt, TxEnvelope};
-use alloy_eips::BlockId;
-use alloy_primitives::B256;
+use alloy_consensus::{Header, Receipt, ReceiptEnvelope, TxEnvelope};
+use alloy_eips::{
+    BlockId,
+    eip2718::{Decodable2718, Encodable2718},
+};
+use alloy_primitives::{B256, Bytes};
 use alloy_provider::{Provider, RootProvider};
 use alloy_transport::{RpcError, TransportErrorKind};
 use async_trait::async_trait;
@@ -10,8 +13,10 @@ use kona_derive::{
     errors::{PipelineError, PipelineErrorKind},
     traits::ChainProvider,
 };
+use kona_mpt::ordered_trie_with_encoder;
 use kona_protocol::BlockInfo;
 use lru::LruCache;
+use serde::Deserialize;
 use std::{boxed::Box, num::NonZeroUsize, vec::Vec};
 
 /// The [AlloyChainProvider] is a concrete implementation of the [ChainProvider] trait, providing
@@ -26,6 +31,8 @@ pub struct AlloyChainProvider {
     receipts_by_hash_cache: LruCache<B256, Vec<Receipt>>,
     /// `block_info_and_transactions_by_hash` LRU cache.
     block_info_and_transactions_by_hash_cache: LruCache<B256, (BlockInfo, Vec<TxEnvelope>)>,
+    /// Whether receipts are verified against the receipts root of their block's header.
+    verify_receipts: bool,
 }
 
 impl AlloyChainProvider {
@@ -41,9 +48,19 @@ impl AlloyChainProvider {
             block_info_and_transactions_by_hash_cache: LruCache::new(
                 NonZeroUsize::new(cache_size).unwrap(),
             ),
+            verify_receipts: true,
         }
     }
 
+    /// Sets whether the receipts returned by [ChainProvider::receipts_by_hash] are verified
+    /// against the receipts root of their block's header, guarding against faulty RPC endpoints.
+    ///
+    /// Verification is enabled by default.
+    pub const fn with_receipt_verification(mut self, verify: bool) -> Self {
+        self.verify_receipts = verify;
+        self
+    }
+
     /// Creates a new [AlloyChainProvider] from the provided [reqwest::Url].
     pub fn new_http(url: reqwest::Url, cache_size: usize) -> Self {
         let inner = RootProvider::new_http(url);
@@ -59,6 +76,78 @@ impl AlloyChainProvider {
     pub async fn chain_id(&mut self) -> Result<u64, RpcError<TransportErrorKind>> {
         self.inner.get_chain_id().await
     }
+
+    /// Fetches the raw EIP-2718 encoded transaction with the given hash, and decodes it into a
+    /// [TxEnvelope].
+    async fn raw_transaction_by_hash(
+        &self,
+        hash: B256,
+    ) -> Result<TxEnvelope, AlloyChainProviderError> {
+        let raw =
+            self.inner.client().request::<_, Bytes>("eth_getRawTransactionByHash", (hash,)).await?;
+        TxEnvelope::decode_2718(&mut raw.as_ref())
+            .ok()
+            .filter(|tx| *tx.tx_hash() == hash)
+            .ok_or(AlloyChainProviderError::TransactionDecoding(hash))
+    }
+
+    /// Fetches the raw EIP-2718 encoded receipts of the block with the given hash, and decodes
+    /// them into [ReceiptEnvelope]s.
+    async fn raw_receipts_by_hash(
+        &self,
+        hash: B256,
+    ) -> Result<Vec<ReceiptEnvelope>, AlloyChainProviderError> {
+        let raw = self
+            .inner
+            .client()
+            .request::<_, Vec<Bytes>>("debug_getRawReceipts", (BlockId::from(hash),))
+            .await?;
+        raw.iter()
+            .map(|r| ReceiptEnvelope::decode_2718(&mut r.as_ref()).ok())
+            .collect::<Option<Vec<_>>>()
+            .ok_or(AlloyChainProviderError::ReceiptsDecoding(hash))
+    }
+}
+
+/// A block returned by `eth_getBlockByHash` with full transaction objects.
+///
+/// Transaction objects that fail to deserialize, e.g. because the RPC endpoint returns fields
+/// outside of their type's specification such as an `accessList` on a legacy transaction, are
+/// reduced to their hash so that they do not prevent the block from being deserialized.
+#[derive(Debug, Deserialize)]
+struct RpcBlock {
+    /// The consensus header of the block.
+    #[serde(flatten)]
+    header: Header,
+    /// The transactions in the block.
+    transactions: Vec<RpcBlockTransaction>,
+}
+
+/// A transaction in an [RpcBlock].
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RpcBlockTransaction {
+    /// A transaction object.
+    Transaction(Box<alloy_rpc_types_eth::Transaction<TxEnvelope>>),
+    /// A transaction object that could not be deserialized, of which only the hash is retained.
+    Unknown {
+        /// The hash of the transaction.
+        hash: B256,
+    },
+}
+
+/// A receipt returned by `eth_getBlockReceipts`.
+///
+/// Receipt objects that fail to deserialize, e.g. because the RPC endpoint returns fields outside
+/// of their type's specification, are discarded so that they do not prevent the remaining receipts
+/// from being deserialized.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RpcBlockReceipt {
+    /// A receipt object.
+    Receipt(Box<alloy_rpc_types_eth::TransactionReceipt>),
+    /// A receipt object that could not be deserialized.
+    Unknown(serde::de::IgnoredAny),
 }
 
 /// An error for the [AlloyChainProvider].
@@ -74,6 +163,22 @@ pub enum AlloyChainProviderError {
     /// Failed to convert RPC receipts into consensus receipts.
     #[error("Failed to convert RPC receipts into consensus receipts {0}")]
     ReceiptsConversion(B256),
+    /// Failed to decode a raw transaction into a consensus transaction.
+    #[error("Failed to decode raw transaction {0}")]
+    TransactionDecoding(B256),
+    /// Failed to decode the raw receipts of a block into consensus receipts.
+    #[error("Failed to decode raw receipts of block {0}")]
+    ReceiptsDecoding(B256),
+    /// The receipts of a block do not match the receipts root of its header.
+    #[error("Receipts root mismatch for block {block}: expected {expected}, got {computed}")]
+    ReceiptsRootMismatch {
+        /// The hash of the block.
+        block: B256,
+        /// The receipts root of the block header.
+        expected: B256,
+        /// The receipts root computed from the receipts.
+        computed: B256,
+    },
 }
 
 impl From<AlloyChainProviderError> for PipelineErrorKind {
@@ -90,6 +195,15 @@ impl From<AlloyChainProviderError> for PipelineErrorKind {
                     "Failed to convert RPC receipts into consensus receipts".to_string(),
                 ))
             }
+            AlloyChainProviderError::TransactionDecoding(hash) => PipelineErrorKind::Temporary(
+                PipelineError::Provider(format!("Failed to decode raw transaction {hash}")),
+            ),
+            AlloyChainProviderError::ReceiptsDecoding(hash) => PipelineErrorKind::Temporary(
+                PipelineError::Provider(format!("Failed to decode raw receipts of block {hash}")),
+            ),
+            e @ AlloyChainProviderError::ReceiptsRootMismatch { .. } => {
+                PipelineErrorKind::Critical(PipelineError::Provider(e.to_string()))
+            }
         }
     }
 }
@@ -139,12 +253,43 @@ impl ChainProvider for AlloyChainProvider {
 
         let receipts = self
             .inner
-            .get_block_receipts(hash.into())
+            .client()
+            .request::<_, Option<Vec<RpcBlockReceipt>>>(
+                "eth_getBlockReceipts",
+                (BlockId::from(hash),),
+            )
             .await?
             .ok_or(AlloyChainProviderError::BlockNotFound(hash.into()))?;
-        let consensus_receipts = receipts
+
+        // If the RPC receipt types reject any of the block's receipts, the receipts of the whole
+        // block are fetched in their raw EIP-2718 encoding instead.
+        let receipts = match receipts
             .into_iter()
-            .map(|r| r.inner.into_primitives_receipt().as_receipt().cloned())
+            .map(|r| match r {
+                RpcBlockReceipt::Receipt(r) => Some(r.inner.into_primitives_receipt()),
+                RpcBlockReceipt::Unknown(_) => None,
+            })
+            .collect::<Option<Vec<_>>>()
+        {
+            Some(receipts) => receipts,
+            None => self.raw_receipts_by_hash(hash).await?,
+        };
+
+        if self.verify_receipts {
+            let expected = self.header_by_hash(hash).await?.receipts_root;
+            let computed = ordered_trie_with_encoder(&receipts, |r, buf| r.encode_2718(buf)).root();
+            if computed != expected {
+                return Err(AlloyChainProviderError::ReceiptsRootMismatch {
+                    block: hash,
+                    expected,
+                    computed,
+                });
+            }
+        }
+
+        let consensus_receipts = receipts
+            .iter()
+            .map(|r| r.as_receipt().cloned())
             .collect::<Option<Vec<_>>>()
             .ok_or(AlloyChainProviderError::ReceiptsConversion(hash))?;
 
@@ -163,12 +308,21 @@ impl ChainProvider for AlloyChainProvider {
 
         let block = self
             .inner
-            .get_block_by_hash(hash)
-            .full()
+            .client()
+            .request::<_, Option<RpcBlock>>("eth_getBlockByHash", (hash, true))
             .await?
-            .ok_or(AlloyChainProviderError::BlockNotFound(hash.into()))?
-            .into_consensus()
-            .map_transactions(|t| t.inner.into_inner());
+            .ok_or(AlloyChainProviderError::BlockNotFound(hash.into()))?;
+
+        // Transactions that the RPC transaction types reject are fetched in their raw EIP-2718
+        // encoding instead.
+        let mut transactions = Vec::with_capacity(block.transactions.len());
+        for tx in block.transactions {
+            let tx = match tx {
+                RpcBlockTransaction::Transaction(tx) => tx.inner.into_inner(),
+                RpcBlockTransaction::Unknown { hash } => self.raw_transaction_by_hash(hash).await?,
+            };
+            transactions.push(tx);
+        }
 
         let block_info = BlockInfo {
             hash: block.header.hash_slow(),
@@ -178,8 +332,209 @@ impl ChainProvider for AlloyChainProvider {
         };
 
         self.block_info_and_transactions_by_hash_cache
-            .put(hash, (block_info, block.body.transactions.clone()));
+            .put(hash, (block_info, transactions.clone()));
+
+        Ok((block_info, transactions))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{ReceiptEnvelope, ReceiptWithBloom, Transaction};
+    use alloy_primitives::{Address, Bloom};
+    use alloy_rpc_client::RpcClient;
+    use alloy_rpc_types_eth::{Log, TransactionReceipt};
+    use alloy_transport::mock::Asserter;
+    use serde_json::{Value, json};
+
+    /// A synthetic block, modelled on RPC endpoints that return an `accessList` on legacy
+    /// transactions, alongside the raw encodings of its transactions.
+    const FIXTURE: &str = include_str!("../testdata/block_with_access_list.json");
+
+    fn mock_provider(asserter: Asserter) -> AlloyChainProvider {
+        AlloyChainProvider::new(RootProvider::new(RpcClient::mocked(asserter)), 1)
+    }
+
+    #[tokio::test]
+    async fn test_block_info_and_transactions_with_access_list() {
+        let fixture: Value = serde_json::from_str(FIXTURE).unwrap();
+        let block = &fixture["block"];
+        let tx = &block["transactions"][0];
+        assert!(tx.get("accessList").is_some());
+
+        let asserter = Asserter::new();
+        asserter.push_success(block);
+        let mut provider = mock_provider(asserter.clone());
+
+        let hash: B256 = serde_json::from_value(block["hash"].clone()).unwrap();
+        let (block_info, txs) = provider.block_info_and_transactions_by_hash(hash).await.unwrap();
+
+        assert_eq!(block_info.hash, hash);
+        assert_eq!(block_info.number, 0x1234);
+        assert_eq!(block_info.parent_hash, B256::repeat_byte(0x11));
+        assert_eq!(block_info.timestamp, 0x6500_0000);
+
+        assert_eq!(txs.len(), 1);
+        assert!(txs[0].is_legacy());
+        assert_eq!(txs[0].tx_hash(), &serde_json::from_value::<B256>(tx["hash"].clone()).unwrap());
+        assert_eq!(txs[0].nonce(), 7);
+
+        // The block's transactions are fetched in a single request.
+        assert!(asserter.read_q().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_block_info_and_transactions_raw_fallback() {
+        let fixture: Value = serde_json::from_str(FIXTURE).unwrap();
+        let mut block = fixture["block"].clone();
+        block["transactions"][0]["v"] = json!("invalid");
+
+        let asserter = Asserter::new();
+        asserter.push_success(&block);
+        asserter.push_success(&fixture["rawTransactions"][0]);
+        let mut provider = mock_provider(asserter);
+
+        let hash: B256 = serde_json::from_value(block["hash"].clone()).unwrap();
+        let (_, txs) = provider.block_info_and_transactions_by_hash(hash).await.unwrap();
+
+        assert_eq!(txs.len(), 1);
+        assert!(txs[0].is_legacy());
+        assert_eq!(
+            txs[0].tx_hash(),
+            &serde_json::from_value::<B256>(block["transactions"][0]["hash"].clone()).unwrap()
+        );
+        assert_eq!(txs[0].nonce(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_block_info_and_transactions_hash_mismatch() {
+        let fixture: Value = serde_json::from_str(FIXTURE).unwrap();
+        let mut block = fixture["block"].clone();
+        block["transactions"][0]["v"] = json!("invalid");
+        block["transactions"][0]["hash"] = json!(B256::ZERO);
+
+        let asserter = Asserter::new();
+        asserter.push_success(&block);
+        asserter.push_success(&fixture["rawTransactions"][0]);
+        let mut provider = mock_provider(asserter);
+
+        let err = provider.block_info_and_transactions_by_hash(B256::ZERO).await.unwrap_err();
+        assert!(matches!(
+            err,
+            AlloyChainProviderError::TransactionDecoding(hash) if hash.is_zero()
+        ));
+    }
+
+    /// Returns the RPC receipts of a block with two transactions, and a block whose header commits
+    /// to them.
+    fn receipts_fixture() -> (Value, Vec<TransactionReceipt>) {
+        let receipts = (1..=2u64)
+            .map(|i| TransactionReceipt {
+                inner: ReceiptEnvelope::<Log>::Eip1559(ReceiptWithBloom::new(
+                    Receipt { status: true.into(), cumulative_gas_used: 21_000 * i, logs: vec![] },
+                    Bloom::ZERO,
+                )),
+                transaction_hash: B256::with_last_byte(i as u8),
+                transaction_index: Some(i - 1),
+                block_hash: None,
+                block_number: None,
+                gas_used: 21_000,
+                effective_gas_price: 1,
+                blob_gas_used: None,
+                blob_gas_price: None,
+                from: Address::ZERO,
+                to: Some(Address::ZERO),
+                contract_address: None,
+            })
+            .collect::<Vec<_>>();
+
+        let envelopes =
+            receipts.iter().map(|r| r.inner.clone().into_primitives_receipt()).collect::<Vec<_>>();
+        let header = Header {
+            receipts_root: ordered_trie_with_encoder(&envelopes, |r, buf| r.encode_2718(buf))
+                .root(),
+            ..Default::default()
+        };
+        let mut block = serde_json::to_value(&header).unwrap();
+        block["hash"] = json!(header.hash_slow());
+        block["transactions"] = json!([]);
+        block["uncles"] = json!([]);
+        (block, receipts)
+    }
+
+    #[tokio::test]
+    async fn test_receipts_by_hash_verified() {
+        let (block, receipts) = receipts_fixture();
+        let hash: B256 = serde_json::from_value(block["hash"].clone()).unwrap();
+
+        let asserter = Asserter::new();
+        asserter.push_success(&receipts);
+        asserter.push_success(&block);
+        let mut provider = mock_provider(asserter);
+
+        let fetched = provider.receipts_by_hash(hash).await.unwrap();
+        assert_eq!(fetched.len(), 2);
+        assert_eq!(fetched[1].cumulative_gas_used, 42_000);
+    }
+
+    #[tokio::test]
+    async fn test_receipts_by_hash_raw_fallback() {
+        let (block, receipts) = receipts_fixture();
+        let hash: B256 = serde_json::from_value(block["hash"].clone()).unwrap();
+        let raw = receipts
+            .iter()
+            .map(|r| Bytes::from(r.inner.clone().into_primitives_receipt().encoded_2718()))
+            .collect::<Vec<_>>();
+        let mut rpc_receipts = serde_json::to_value(&receipts).unwrap();
+        rpc_receipts[1]["status"] = json!("invalid");
+
+        let asserter = Asserter::new();
+        asserter.push_success(&rpc_receipts);
+        asserter.push_success(&raw);
+        asserter.push_success(&block);
+        let mut provider = mock_provider(asserter.clone());
+
+        let fetched = provider.receipts_by_hash(hash).await.unwrap();
+        assert_eq!(fetched.len(), 2);
+        assert!(fetched[1].status.coerce_status());
+        assert_eq!(fetched[1].cumulative_gas_used, 42_000);
+        assert!(asserter.read_q().is_empty());
+
+        // Raw receipts that fail to decode are rejected.
+        let asserter = Asserter::new();
+        asserter.push_success(&rpc_receipts);
+        asserter.push_success(&vec![Bytes::from_static(&[0xff])]);
+        let mut provider = mock_provider(asserter);
+        let err = provider.receipts_by_hash(hash).await.unwrap_err();
+        assert!(matches!(err, AlloyChainProviderError::ReceiptsDecoding(h) if h == hash));
+    }
+
+    #[tokio::test]
+    async fn test_receipts_by_hash_tampered() {
+        let (block, mut receipts) = receipts_fixture();
+        let hash: B256 = serde_json::from_value(block["hash"].clone()).unwrap();
+        let ReceiptEnvelope::Eip1559(receipt) = &mut receipts[1].inner else { unreachable!() };
+        receipt.receipt.cumulative_gas_used += 1;
+
+        let asserter = Asserter::new();
+        asserter.push_success(&receipts);
+        asserter.push_success(&block);
+        let mut provider = mock_provider(asserter);
+
+        let err = provider.receipts_by_hash(hash).await.unwrap_err();
+        assert!(matches!(
+            err,
+            AlloyChainProviderError::ReceiptsRootMismatch { block, expected, .. }
+                if block == hash && expected != B256::ZERO
+        ));
+        assert!(matches!(PipelineErrorKind::from(err), PipelineErrorKind::Critical(_)));
 
-        Ok((block_info, block.body.transactions))
+        // Without verification, the tampered receipts are returned as-is.
+        let asserter = Asserter::new();
+        asserter.push_success(&receipts);
+        let mut provider = mock_provider(asserter).with_receipt_verification(false);
+        let fetched = provider.receipts_by_hash(hash).await.unwrap();
+        assert_eq!(fetched[1].cumulative_gas_used, 42_001);
     }
 }
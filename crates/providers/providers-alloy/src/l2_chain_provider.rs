@@ -1,7 +1,9 @@
 //! Providers that use alloy provider types on the backend.
 
-use alloy_eips::BlockId;
-use alloy_primitives::Bytes;
+use alloy_consensus::Transaction;
+use alloy_eips::{BlockId, BlockNumberOrTag};
+use alloy_network::AnyRpcBlock;
+use alloy_primitives::{B256, Bytes};
 use alloy_provider::{Provider, RootProvider};
 use alloy_rpc_client::RpcClient;
 use alloy_rpc_types_engine::JwtSecret;
@@ -34,11 +36,15 @@ pub struct AlloyL2ChainProvider {
     rollup_config: Arc<RollupConfig>,
     /// The `block_by_number` LRU cache.
     block_by_number_cache: LruCache<u64, OpBlock>,
+    /// The `l2_block_info_by_number` LRU cache.
+    l2_block_info_by_number_cache: LruCache<u64, L2BlockInfo>,
 }
 
 impl AlloyL2ChainProvider {
     /// Creates a new [AlloyL2ChainProvider] with the given alloy provider and [RollupConfig].
     ///
+    /// The block and [L2BlockInfo] caches each hold up to `cache_size` entries.
+    ///
     /// ## Panics
     /// - Panics if `cache_size` is zero.
     pub fn new(
@@ -46,10 +52,12 @@ impl AlloyL2ChainProvider {
         rollup_config: Arc<RollupConfig>,
         cache_size: usize,
     ) -> Self {
+        let cache_size = NonZeroUsize::new(cache_size).unwrap();
         Self {
             inner,
             rollup_config,
-            block_by_number_cache: LruCache::new(NonZeroUsize::new(cache_size).unwrap()),
+            block_by_number_cache: LruCache::new(cache_size),
+            l2_block_info_by_number_cache: LruCache::new(cache_size),
         }
     }
 
@@ -93,6 +101,32 @@ impl AlloyL2ChainProvider {
         }
     }
 
+    /// Returns the EIP-4844 versioned hashes referenced by the transactions of the L2 block with
+    /// the given number, in block order.
+    ///
+    /// Blob transactions are not permitted on L2, so this is expected to be empty for canonical
+    /// blocks. It allows consumers reconciling blob availability to assert as much.
+    pub async fn blob_hashes_by_number(
+        &mut self,
+        number: u64,
+    ) -> Result<Vec<B256>, AlloyL2ChainProviderError> {
+        // The block is fetched as an [AnyRpcBlock], since blob transactions cannot be represented
+        // by the transactions of an [OpBlock].
+        let block: Option<AnyRpcBlock> = self
+            .inner
+            .client()
+            .request("eth_getBlockByNumber", (BlockNumberOrTag::Number(number), true))
+            .await?;
+        let block = block.ok_or(AlloyL2ChainProviderError::BlockNotFound(number))?;
+        Ok(block
+            .transactions
+            .txns()
+            .filter_map(|tx| tx.blob_versioned_hashes())
+            .flatten()
+            .copied()
+            .collect())
+    }
+
     /// Creates a new [AlloyL2ChainProvider] from the provided [reqwest::Url].
     pub fn new_http(
         url: reqwest::Url,
@@ -155,12 +189,19 @@ impl BatchValidationProvider for AlloyL2ChainProvider {
     type Error = AlloyL2ChainProviderError;
 
     async fn l2_block_info_by_number(&mut self, number: u64) -> Result<L2BlockInfo, Self::Error> {
+        if let Some(block_info) = self.l2_block_info_by_number_cache.get(&number) {
+            return Ok(*block_info);
+        }
+
         let block = self
             .block_by_number(number)
             .await
             .map_err(|_| AlloyL2ChainProviderError::BlockNotFound(number))?;
-        L2BlockInfo::from_block_and_genesis(&block, &self.rollup_config.genesis)
-            .map_err(|_| AlloyL2ChainProviderError::L2BlockInfoConstruction(number))
+        let block_info = L2BlockInfo::from_block_and_genesis(&block, &self.rollup_config.genesis)
+            .map_err(|_| AlloyL2ChainProviderError::L2BlockInfoConstruction(number))?;
+
+        self.l2_block_info_by_number_cache.put(number, block_info);
+        Ok(block_info)
     }
 
     async fn block_by_number(&mut self, number: u64) -> Result<OpBlock, Self::Error> {
@@ -199,3 +240,112 @@ impl L2ChainProvider for AlloyL2ChainProvider {
             .map_err(|_| AlloyL2ChainProviderError::SystemConfigConversion(number))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_consensus::{
+        Header, Sealed, SignableTransaction, TxEip1559, TxEip4844, TxEip4844Variant, TxEnvelope,
+        transaction::Recovered,
+    };
+    use alloy_primitives::{Address, Signature};
+    use alloy_transport::mock::Asserter;
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+
+    fn mock_provider(asserter: Asserter) -> AlloyL2ChainProvider {
+        let inner = RootProvider::new(RpcClient::mocked(asserter));
+        AlloyL2ChainProvider::new(inner, Arc::new(RollupConfig::default()), 1)
+    }
+
+    /// Returns the JSON-RPC representation of a block containing the given transactions.
+    fn rpc_block<T>(transactions: Vec<T>) -> serde_json::Value
+    where
+        T: Transaction + Clone + serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let header = Header::default();
+        let transactions = transactions
+            .into_iter()
+            .map(|tx| alloy_rpc_types_eth::Transaction {
+                inner: Recovered::new_unchecked(tx, Address::ZERO),
+                block_hash: Some(header.hash_slow()),
+                block_number: Some(header.number),
+                transaction_index: None,
+                effective_gas_price: None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut block = serde_json::to_value(&header).unwrap();
+        block["hash"] = serde_json::json!(header.hash_slow());
+        block["transactions"] = serde_json::json!(transactions);
+        block["uncles"] = serde_json::json!([]);
+        block
+    }
+
+    #[tokio::test]
+    async fn test_blob_hashes_by_number_without_blob_transactions() {
+        let transactions = vec![
+            OpTxEnvelope::Deposit(Sealed::new(TxDeposit::default())),
+            OpTxEnvelope::Eip1559(TxEip1559::default().into_signed(Signature::test_signature())),
+        ];
+        let asserter = Asserter::new();
+        asserter.push_success(&rpc_block(transactions));
+        let mut provider = mock_provider(asserter);
+
+        assert!(provider.blob_hashes_by_number(1).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_blob_hashes_by_number_with_blob_transaction() {
+        let blob_hashes = vec![B256::repeat_byte(0x01), B256::repeat_byte(0x02)];
+        let blob_tx =
+            TxEip4844 { blob_versioned_hashes: blob_hashes.clone(), ..Default::default() };
+        let transactions = vec![
+            TxEnvelope::Eip1559(TxEip1559::default().into_signed(Signature::test_signature())),
+            TxEnvelope::Eip4844(
+                TxEip4844Variant::TxEip4844(blob_tx).into_signed(Signature::test_signature()),
+            ),
+        ];
+        let asserter = Asserter::new();
+        asserter.push_success(&rpc_block(transactions));
+        let mut provider = mock_provider(asserter);
+
+        assert_eq!(provider.blob_hashes_by_number(1).await.unwrap(), blob_hashes);
+    }
+
+    #[tokio::test]
+    async fn test_l2_block_info_by_number_cached() {
+        let header = Header::default();
+        let mut rollup_config = RollupConfig::default();
+        rollup_config.genesis.l2.hash = header.hash_slow();
+
+        let mut block = serde_json::to_value(&header).unwrap();
+        block["hash"] = serde_json::json!(header.hash_slow());
+        block["transactions"] = serde_json::json!([]);
+        block["uncles"] = serde_json::json!([]);
+
+        // Only a single response is available, so a second provider call would fail.
+        let asserter = Asserter::new();
+        asserter.push_success(&block);
+        let inner = RootProvider::new(RpcClient::mocked(asserter));
+        let mut provider = AlloyL2ChainProvider::new(inner, Arc::new(rollup_config), 1);
+
+        let block_info = provider.l2_block_info_by_number(0).await.unwrap();
+        assert_eq!(block_info.block_info.hash, header.hash_slow());
+
+        // The block info is served from its own cache, even once the block has been evicted.
+        provider.block_by_number_cache.clear();
+        assert_eq!(provider.l2_block_info_by_number(0).await.unwrap(), block_info);
+    }
+
+    #[tokio::test]
+    async fn test_blob_hashes_by_number_missing_block() {
+        let asserter = Asserter::new();
+        asserter.push_success(&serde_json::Value::Null);
+        let mut provider = mock_provider(asserter);
+
+        assert!(matches!(
+            provider.blob_hashes_by_number(1).await,
+            Err(AlloyL2ChainProviderError::BlockNotFound(1))
+        ));
+    }
+}
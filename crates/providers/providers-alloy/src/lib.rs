@@ -6,10 +6,13 @@
 )]
 #![cfg_attr(docsrs, feature(doc_cfg, doc_auto_cfg))]
 
+#[macro_use]
+extern crate tracing;
+
 mod beacon_client;
 pub use beacon_client::{
-    APIConfigResponse, APIGenesisResponse, BeaconClient, OnlineBeaconClient, ReducedConfigData,
-    ReducedGenesisData,
+    APIConfigResponse, APIGenesisResponse, BeaconClient, BeaconClientExt, OnlineBeaconClient,
+    ReducedConfigData, ReducedGenesisData,
 };
 
 mod blobs;
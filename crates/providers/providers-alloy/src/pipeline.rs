@@ -5,7 +5,7 @@ use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_derive::{
     attributes::StatefulAttributesBuilder,
-    errors::PipelineErrorKind,
+    errors::{PipelineError, PipelineErrorKind},
     pipeline::{DerivationPipeline, PipelineBuilder},
     prelude::AttributesQueueStage,
     sources::EthereumDataSource,
@@ -46,6 +46,10 @@ pub struct OnlinePipeline {
 
 impl OnlinePipeline {
     /// Constructs a new oracle-backed derivation pipeline.
+    ///
+    /// ## Errors
+    /// Returns a critical [PipelineError::OriginAheadOfSafeHead] if `l1_origin` is ahead of the L1
+    /// origin of `l2_safe_head`, as derivation would skip the L1 data of the safe chain.
     pub async fn new(
         cfg: Arc<RollupConfig>,
         l2_safe_head: L2BlockInfo,
@@ -54,6 +58,14 @@ impl OnlinePipeline {
         chain_provider: AlloyChainProvider,
         mut l2_chain_provider: AlloyL2ChainProvider,
     ) -> PipelineResult<Self> {
+        if l1_origin.number > l2_safe_head.l1_origin.number {
+            return Err(PipelineError::OriginAheadOfSafeHead(
+                l1_origin.number,
+                l2_safe_head.l1_origin.number,
+            )
+            .crit());
+        }
+
         let attributes = StatefulAttributesBuilder::new(
             cfg.clone(),
             l2_chain_provider.clone(),
@@ -137,3 +149,41 @@ impl Pipeline for OnlinePipeline {
         self.pipeline.system_config_by_number(number).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::BlockNumHash;
+    use alloy_provider::RootProvider;
+    use alloy_rpc_client::RpcClient;
+    use alloy_transport::mock::Asserter;
+    use std::num::NonZeroUsize;
+
+    #[tokio::test]
+    async fn test_new_origin_ahead_of_safe_head() {
+        let cfg = Arc::new(RollupConfig::default());
+        let asserter = Asserter::new();
+        let l2_safe_head = L2BlockInfo {
+            l1_origin: BlockNumHash { number: 10, ..Default::default() },
+            ..Default::default()
+        };
+        let l1_origin = BlockInfo { number: 11, ..Default::default() };
+
+        let err = OnlinePipeline::new(
+            cfg.clone(),
+            l2_safe_head,
+            l1_origin,
+            OnlineBlobProvider::new(
+                OnlineBeaconClient::new_http(String::new()),
+                0,
+                12,
+                NonZeroUsize::new(1).unwrap(),
+            ),
+            AlloyChainProvider::new(RootProvider::new(RpcClient::mocked(asserter.clone())), 1),
+            AlloyL2ChainProvider::new(RootProvider::new(RpcClient::mocked(asserter)), cfg, 1),
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err, PipelineError::OriginAheadOfSafeHead(11, 10).crit());
+    }
+}
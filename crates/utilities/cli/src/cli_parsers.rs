@@ -0,0 +1,31 @@
+//! Parsers for CLI arguments.
+
+use crate::LogFormat;
+
+/// Parses the [LogFormat] of the `--log-format` flag, one of `full`, `pretty`, `json` or
+/// `compact`.
+pub fn parse_log_format(arg: &str) -> Result<LogFormat, String> {
+    match arg {
+        "full" => Ok(LogFormat::Full),
+        "pretty" => Ok(LogFormat::Pretty),
+        "json" => Ok(LogFormat::Json),
+        "compact" => Ok(LogFormat::Compact),
+        _ => {
+            Err(format!("invalid log format `{arg}`, expected one of: full, pretty, json, compact"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_log_format() {
+        assert_eq!(parse_log_format("full"), Ok(LogFormat::Full));
+        assert_eq!(parse_log_format("pretty"), Ok(LogFormat::Pretty));
+        assert_eq!(parse_log_format("json"), Ok(LogFormat::Json));
+        assert_eq!(parse_log_format("compact"), Ok(LogFormat::Compact));
+        assert!(parse_log_format("yaml").is_err());
+    }
+}
@@ -10,8 +10,10 @@ pub use clap::cli_styles;
 
 pub mod backtrace;
 
+pub mod cli_parsers;
+
 mod tracing;
-pub use tracing::{init_test_tracing, init_tracing_subscriber};
+pub use tracing::{LogFormat, init_test_tracing, init_tracing_subscriber};
 
 mod prometheus;
 pub use prometheus::init_prometheus_server;
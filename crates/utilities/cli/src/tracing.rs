@@ -1,20 +1,47 @@
 //! [tracing_subscriber] utilities.
 
-use tracing::{Level, subscriber::SetGlobalDefaultError};
+use tracing::{Level, Subscriber, subscriber::SetGlobalDefaultError};
 use tracing_subscriber::EnvFilter;
 
+/// The format of the logs printed by the tracing subscriber, parsed from `--log-format` by
+/// [`parse_log_format`](crate::cli_parsers::parse_log_format).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LogFormat {
+    /// Single-line human-readable logs, with all fields and span context.
+    #[default]
+    Full,
+    /// Multi-line human-readable logs.
+    Pretty,
+    /// Newline-delimited JSON logs, for log aggregation.
+    Json,
+    /// Abbreviated human-readable logs.
+    Compact,
+}
+
 /// Initializes the tracing subscriber
 ///
 /// # Arguments
 /// * `verbosity_level` - The verbosity level (0-5). If `0`, no logs are printed.
+/// * `format` - The [LogFormat] of the printed logs.
 /// * `env_filter` - Optional environment filter for the subscriber.
 ///
 /// # Returns
 /// * `Result<()>` - Ok if successful, Err otherwise.
 pub fn init_tracing_subscriber(
     verbosity_level: u8,
+    format: LogFormat,
     env_filter: Option<impl Into<EnvFilter>>,
 ) -> Result<(), SetGlobalDefaultError> {
+    tracing::subscriber::set_global_default(subscriber(verbosity_level, format, env_filter))
+}
+
+/// Builds the tracing subscriber for the given verbosity level, [LogFormat] and optional
+/// environment filter.
+fn subscriber(
+    verbosity_level: u8,
+    format: LogFormat,
+    env_filter: Option<impl Into<EnvFilter>>,
+) -> Box<dyn Subscriber + Send + Sync> {
     let level = match verbosity_level {
         1 => Level::ERROR,
         2 => Level::WARN,
@@ -23,12 +50,17 @@ pub fn init_tracing_subscriber(
         _ => Level::TRACE,
     };
     if verbosity_level == 0 {
-        return tracing::subscriber::set_global_default(tracing_subscriber::fmt().finish());
+        return Box::new(tracing_subscriber::fmt().finish());
     }
     let filter = env_filter.map(|e| e.into()).unwrap_or(EnvFilter::from_default_env());
     let filter = filter.add_directive(level.into());
     let subscriber = tracing_subscriber::fmt().with_max_level(level);
-    tracing::subscriber::set_global_default(subscriber.with_env_filter(filter).finish())
+    match format {
+        LogFormat::Full => Box::new(subscriber.with_env_filter(filter).finish()),
+        LogFormat::Pretty => Box::new(subscriber.pretty().with_env_filter(filter).finish()),
+        LogFormat::Json => Box::new(subscriber.json().with_env_filter(filter).finish()),
+        LogFormat::Compact => Box::new(subscriber.compact().with_env_filter(filter).finish()),
+    }
 }
 
 /// This provides function for init tracing in testing
@@ -38,5 +70,20 @@ pub fn init_tracing_subscriber(
 /// - `init_tracing_subscriber`: Initializes the tracing subscriber with a specified verbosity level
 ///   and optional environment filter.
 pub fn init_test_tracing() {
-    let _ = init_tracing_subscriber(4, None::<EnvFilter>);
+    let _ = init_tracing_subscriber(4, LogFormat::Full, None::<EnvFilter>);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_format_subscriber() {
+        for format in [LogFormat::Full, LogFormat::Pretty, LogFormat::Json, LogFormat::Compact] {
+            for verbosity_level in 0..=5 {
+                let subscriber = subscriber(verbosity_level, format, None::<EnvFilter>);
+                tracing::subscriber::with_default(subscriber, || tracing::info!("log format"));
+            }
+        }
+    }
 }
@@ -19,7 +19,7 @@
 
 use clap::{ArgAction, Parser};
 use discv5::enr::CombinedKey;
-use kona_cli::init_tracing_subscriber;
+use kona_cli::{LogFormat, init_tracing_subscriber};
 use kona_p2p::{Discv5Builder, LocalNode};
 use std::net::{IpAddr, Ipv4Addr};
 
@@ -48,7 +48,7 @@ impl DiscCommand {
     pub async fn run(self) -> anyhow::Result<()> {
         let filter = tracing_subscriber::EnvFilter::from_default_env()
             .add_directive("discv5=error".parse()?);
-        init_tracing_subscriber(self.v, Some(filter))?;
+        init_tracing_subscriber(self.v, LogFormat::Full, Some(filter))?;
 
         let CombinedKey::Secp256k1(secret_key) = CombinedKey::generate_secp256k1() else {
             unreachable!()
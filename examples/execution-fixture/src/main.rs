@@ -18,7 +18,7 @@
 
 use anyhow::{Result, anyhow};
 use clap::{ArgAction, Parser};
-use kona_cli::init_tracing_subscriber;
+use kona_cli::{LogFormat, init_tracing_subscriber};
 use kona_executor::test_utils::ExecutorTestFixtureCreator;
 use std::path::PathBuf;
 use tracing::info;
@@ -48,7 +48,7 @@ pub struct ExecutionFixtureCommand {
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = ExecutionFixtureCommand::parse();
-    init_tracing_subscriber(cli.v, None::<EnvFilter>)?;
+    init_tracing_subscriber(cli.v, LogFormat::Full, None::<EnvFilter>)?;
 
     let output_dir = if let Some(output_dir) = cli.output_dir {
         output_dir
@@ -19,11 +19,12 @@
 
 use clap::{ArgAction, Parser};
 use discv5::enr::CombinedKey;
-use kona_cli::init_tracing_subscriber;
+use kona_cli::{LogFormat, init_tracing_subscriber};
 use kona_p2p::{LocalNode, Network};
 use kona_registry::ROLLUP_CONFIGS;
 use libp2p::Multiaddr;
 use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use tokio_util::sync::CancellationToken;
 use tracing_subscriber::EnvFilter;
 
 /// The gossip command.
@@ -52,7 +53,7 @@ pub struct GossipCommand {
 impl GossipCommand {
     /// Run the gossip subcommand.
     pub async fn run(self) -> anyhow::Result<()> {
-        init_tracing_subscriber(self.v, None::<EnvFilter>)?;
+        init_tracing_subscriber(self.v, LogFormat::Full, None::<EnvFilter>)?;
 
         let signer = ROLLUP_CONFIGS
             .get(&self.l2_chain_id)
@@ -87,7 +88,7 @@ impl GossipCommand {
             .build()?;
 
         let mut recv = network.unsafe_block_recv();
-        network.start()?;
+        network.start(CancellationToken::new())?;
         tracing::info!("Gossip driver started, receiving blocks.");
         loop {
             match recv.recv().await {